@@ -1,7 +1,7 @@
 use std::env;
 use std::path::PathBuf;
 use bitcoin::{bip32::{ExtendedPrivKey, ExtendedPubKey}, blockdata::fee_rate};
-use exports::component::node::types::{Initialization, NodeConfig, BitcoinNetwork, Ipv4SocketAdress};
+use exports::component::node::types::{Initialization, NodeConfig, BitcoinNetwork, Ipv4SocketAdress, NodeKeys, Outpoint, Recipient, UnspentOutput, Balance, TransactionDetails};
 use rand::Rng;
 use wasmtime::component::*;
 use bitcoin::key::Secp256k1;
@@ -39,7 +39,7 @@ impl BitspendClient {
         let instance =  Nodeworld::instantiate(&mut store, &component, &linker)
             .unwrap();
         let init = Initialization::Config(node_config);
-        let resource = instance.component_node_types().client_node().call_constructor(&mut store, &init).unwrap();
+        let resource = instance.component_node_types().client_node().call_create(&mut store, &init).unwrap().unwrap();
 
         return Self { store, component: resource, world: instance };
     }
@@ -74,14 +74,71 @@ impl BitspendClient {
         return balance
     }
 
+    pub fn pending_balance(& mut self) -> u64 {
+        let balance = self.world.component_node_types().client_node().call_get_pending_balance(&mut self.store, self.component.clone()).unwrap().unwrap();
+        return balance
+    }
+
+    pub fn balance_breakdown(& mut self) -> Balance {
+        return self.world.component_node_types().client_node().call_get_balance_breakdown(&mut self.store, self.component.clone()).unwrap().unwrap();
+    }
+
     pub fn get_receive_address(& mut self) -> String {
         let address = self.world.component_node_types().client_node().call_get_receive_address(&mut self.store, self.component.clone()).unwrap().unwrap();
         return address
     }
 
-    pub fn send_to_address(& mut self, receipient: Vec<u8>, amount: u64, fee_rate: u64)  {
-        self.world.component_node_types().client_node().call_send_to_address(&mut self.store, self.component.clone(), &receipient, amount, fee_rate).unwrap().unwrap();
+    pub fn send_to_address(& mut self, address: String, amount: u64, fee_rate: u64)  {
+        self.world.component_node_types().client_node().call_send_to_address(&mut self.store, self.component.clone(), &address, amount, fee_rate).unwrap().unwrap();
+
+    }
+
+    pub fn send_many(& mut self, recipients: Vec<Recipient>, fee_rate: u64, must_spend: Vec<Outpoint>) {
+        self.world.component_node_types().client_node().call_send_many(&mut self.store, self.component.clone(), &recipients, fee_rate, &must_spend).unwrap().unwrap();
+    }
+
+    pub fn drain_to_address(& mut self, address: String, fee_rate: u64, outpoints: Option<Vec<Outpoint>>) {
+        self.world.component_node_types().client_node().call_drain_to_address(&mut self.store, self.component.clone(), &address, fee_rate, outpoints.as_deref()).unwrap().unwrap();
+    }
+
+    pub fn bump_fee(& mut self, txid: &str, new_fee_rate: u64) -> String {
+        return self.world.component_node_types().client_node().call_bump_fee(&mut self.store, self.component.clone(), txid, new_fee_rate).unwrap().unwrap();
+    }
+
+    pub fn cpfp(& mut self, txid: &str, target_fee_rate: u64) -> String {
+        return self.world.component_node_types().client_node().call_cpfp(&mut self.store, self.component.clone(), txid, target_fee_rate).unwrap().unwrap();
+    }
+
+    pub fn create_psbt(& mut self, address: String, amount: u64, fee_rate: u64) -> Vec<u8> {
+        return self.world.component_node_types().client_node().call_create_psbt(&mut self.store, self.component.clone(), &address, amount, fee_rate).unwrap().unwrap();
+    }
+
+    pub fn broadcast_psbt(& mut self, psbt: Vec<u8>) -> String {
+        return self.world.component_node_types().client_node().call_broadcast_psbt(&mut self.store, self.component.clone(), &psbt).unwrap().unwrap();
+    }
+
+    pub fn list_unspent(& mut self) -> Vec<UnspentOutput> {
+        return self.world.component_node_types().client_node().call_list_unspent(&mut self.store, self.component.clone()).unwrap().unwrap();
+    }
+
+    pub fn freeze_utxo(& mut self, outpoint: Outpoint) {
+        self.world.component_node_types().client_node().call_freeze_utxo(&mut self.store, self.component.clone(), &outpoint).unwrap().unwrap();
+    }
+
+    pub fn unfreeze_utxo(& mut self, outpoint: Outpoint) {
+        self.world.component_node_types().client_node().call_unfreeze_utxo(&mut self.store, self.component.clone(), &outpoint).unwrap().unwrap();
+    }
+
+    pub fn rescan(& mut self, from_height: u64) {
+        self.world.component_node_types().client_node().call_rescan(&mut self.store, self.component.clone(), from_height).unwrap().unwrap();
+    }
+
+    pub fn list_transactions(& mut self, offset: u32, limit: u32) -> Vec<TransactionDetails> {
+        return self.world.component_node_types().client_node().call_list_transactions(&mut self.store, self.component.clone(), offset, limit).unwrap().unwrap();
+    }
 
+    pub fn get_transaction(& mut self, txid: &str) -> Option<TransactionDetails> {
+        return self.world.component_node_types().client_node().call_get_transaction(&mut self.store, self.component.clone(), txid).unwrap().unwrap();
     }
 }
 
@@ -94,7 +151,7 @@ pub fn generate_node_regtest_config() -> NodeConfig {
     let entropy: [u8; 16] = rng.gen();
     let  xpriv = ExtendedPrivKey::new_master(bitcoin::Network::Regtest, &entropy).unwrap();
 
-    return NodeConfig { network, xpriv : xpriv.to_string(), socket_address}
+    return NodeConfig { network, wallet_type: None, keys: NodeKeys::Xpriv(xpriv.to_string()), socket_address, peers: vec![], max_connections: None, use_seeds: false, birthday: None, lookahead: None, min_confirmations: None}
 
 }
 
@@ -34,13 +34,24 @@ pub fn test_new(bitspend_client: & mut BitspendClient) -> u64 {
 
     let balance = bitspend_client.balance();
     assert_eq!(balance, total_amount);
+    let breakdown = bitspend_client.balance_breakdown();
+    assert_eq!(breakdown.confirmed, total_amount);
+    assert_eq!(breakdown.trusted_pending + breakdown.untrusted_pending + breakdown.immature, 0);
     
-    let sending = bitspend_client.send_to_address(mine_to_address.script_pubkey().to_bytes(), transfer_amount, 3);
+    let sending = bitspend_client.send_to_address(mine_to_address.to_string(), transfer_amount, 3);
     bitcoin_rpc.generate_to_address(1, &mine_to_address).unwrap();
     let balance = bitspend_client.balance();
-    let fee = 341;
+    let fee = 626;
     assert_eq!(balance, total_amount - (100_000 + fee));
 
+    // The send is the most recent wallet transaction
+    let sent = bitspend_client.list_transactions(0, 1).remove(0);
+    assert_eq!(sent.net_amount, -(100_000 + fee as i64));
+    assert_eq!(sent.fee, Some(fee));
+    assert!(sent.block_height.is_some());
+    assert!(sent.outputs.iter().all(|output| output.is_change));
+    assert_eq!(bitspend_client.get_transaction(&sent.txid).unwrap().txid, sent.txid);
+
     return balance;
     
 }
@@ -77,11 +88,15 @@ pub fn test_restore(bitspend_client: & mut BitspendClient, stored_balance: u64)
     let balance = bitspend_client.balance();
     assert_eq!(balance, total_amount);
     
-    let sending = bitspend_client.send_to_address(mine_to_address.script_pubkey().to_bytes(), transfer_amount, 3);
+    let sending = bitspend_client.send_to_address(mine_to_address.to_string(), transfer_amount, 3);
     bitcoin_rpc.generate_to_address(1, &mine_to_address).unwrap();
     let balance = bitspend_client.balance();
-    let fee = 341;
+    let fee = 626;
     assert_eq!(balance, total_amount - (100_000 + fee));
 
+    // Scanning everything again rebuilds the same wallet state
+    bitspend_client.rescan(0);
+    assert_eq!(bitspend_client.balance(), balance);
+
     
 }
\ No newline at end of file
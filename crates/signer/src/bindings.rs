@@ -62,6 +62,90 @@ pub mod exports {
                 }
 
                 impl std::error::Error for Error {}
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum Network {
+                    Bitcoin,
+                    Testnet,
+                    Testnet4,
+                    Signet,
+                    Regtest,
+                }
+                impl ::core::fmt::Debug for Network {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            Network::Bitcoin => f.debug_tuple("Network::Bitcoin").finish(),
+                            Network::Testnet => f.debug_tuple("Network::Testnet").finish(),
+                            Network::Testnet4 => f.debug_tuple("Network::Testnet4").finish(),
+                            Network::Signet => f.debug_tuple("Network::Signet").finish(),
+                            Network::Regtest => f.debug_tuple("Network::Regtest").finish(),
+                        }
+                    }
+                }
+
+                impl Network {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> Network {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+
+                        match val {
+                            0 => Network::Bitcoin,
+                            1 => Network::Testnet,
+                            2 => Network::Testnet4,
+                            3 => Network::Signet,
+                            4 => Network::Regtest,
+
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum WalletType {
+                    P2wpkh,
+                    /// Single key taproot, BIP86
+                    P2tr,
+                    /// Cosigner of a p2wsh multisig, BIP48
+                    P2wshMultisig,
+                    /// Cosigner of a taproot multisig
+                    P2trMultisig,
+                }
+                impl ::core::fmt::Debug for WalletType {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            WalletType::P2wpkh => f.debug_tuple("WalletType::P2wpkh").finish(),
+                            WalletType::P2tr => f.debug_tuple("WalletType::P2tr").finish(),
+                            WalletType::P2wshMultisig => {
+                                f.debug_tuple("WalletType::P2wshMultisig").finish()
+                            }
+                            WalletType::P2trMultisig => {
+                                f.debug_tuple("WalletType::P2trMultisig").finish()
+                            }
+                        }
+                    }
+                }
+
+                impl WalletType {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> WalletType {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+
+                        match val {
+                            0 => WalletType::P2wpkh,
+                            1 => WalletType::P2tr,
+                            2 => WalletType::P2wshMultisig,
+                            3 => WalletType::P2trMultisig,
+
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+
                 pub type AccountXpub = _rt::String;
                 pub type MasterFingerprint = _rt::String;
                 pub type AccountDerivation = _rt::String;
@@ -246,11 +330,16 @@ pub mod exports {
                     T: GuestSimpleSigner,
                 >(
                     arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let result0 =
-                        T::derive_account(SimpleSignerBorrow::lift(arg0 as u32 as usize).get());
+                    let result0 = T::derive_account(
+                        SimpleSignerBorrow::lift(arg0 as u32 as usize).get(),
+                        WalletType::_lift(arg1 as u8),
+                        Network::_lift(arg2 as u8),
+                    );
                     let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
                     match result0 {
                         Ok(e) => {
@@ -446,8 +535,12 @@ pub mod exports {
                     }
 
                     fn new(init: Initialization) -> Self;
+                    /// Account 0 of the wallet type, at m/84h/0h/0h, m/86h/0h/0h, m/48h/0h/0h/2h or
+                    /// m/48h/0h/0h/3h on mainnet, with coin type 1h instead of 0h on the other networks
                     fn derive_account(
                         &self,
+                        wallet_type: WalletType,
+                        network: Network,
                     ) -> Result<(AccountXpub, MasterFingerprint, AccountDerivation), Error>;
                     fn sign_psbt(&self, psbt: _rt::Vec<u8>) -> Result<_rt::Vec<u8>, Error>;
                     fn get_state(&self) -> _rt::Vec<u8>;
@@ -462,8 +555,8 @@ pub mod exports {
       $($path_to_types)*::_export_constructor_simple_signer_cabi::<<$ty as $($path_to_types)*::Guest>::SimpleSigner>(arg0, arg1, arg2)
     }
     #[export_name = "component:signer/types@0.1.0#[method]simple-signer.derive-account"]
-    unsafe extern "C" fn export_method_simple_signer_derive_account(arg0: *mut u8,) -> *mut u8 {
-      $($path_to_types)*::_export_method_simple_signer_derive_account_cabi::<<$ty as $($path_to_types)*::Guest>::SimpleSigner>(arg0)
+    unsafe extern "C" fn export_method_simple_signer_derive_account(arg0: *mut u8,arg1: i32,arg2: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_method_simple_signer_derive_account_cabi::<<$ty as $($path_to_types)*::Guest>::SimpleSigner>(arg0, arg1, arg2)
     }
     #[export_name = "cabi_post_component:signer/types@0.1.0#[method]simple-signer.derive-account"]
     unsafe extern "C" fn _post_return_method_simple_signer_derive_account(arg0: *mut u8,) {
@@ -661,21 +754,24 @@ pub(crate) use __export_singer_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.25.0:singer:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 628] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xf7\x03\x01A\x02\x01\
-A\x02\x01B\x1b\x01r\x01\x04xpivs\x04\0\x06config\x03\0\0\x01p}\x01q\x02\x09old-s\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 767] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x82\x05\x01A\x02\x01\
+A\x02\x01B\x1f\x01r\x01\x04xpivs\x04\0\x06config\x03\0\0\x01p}\x01q\x02\x09old-s\
 tate\x01\x02\0\x06config\x01\x01\0\x04\0\x0einitialization\x03\0\x03\x01q\x02\x10\
-derivation-error\0\0\x0dsigning-error\0\0\x04\0\x05error\x03\0\x05\x01s\x04\0\x0c\
-account-xpub\x03\0\x07\x01s\x04\0\x12master-fingerprint\x03\0\x09\x01s\x04\0\x12\
-account-derivation\x03\0\x0b\x04\0\x0dsimple-signer\x03\x01\x01i\x0d\x01@\x01\x04\
-init\x04\0\x0e\x04\0\x1a[constructor]simple-signer\x01\x0f\x01h\x0d\x01o\x03\x08\
-\x0a\x0c\x01j\x01\x11\x01\x06\x01@\x01\x04self\x10\0\x12\x04\0$[method]simple-si\
-gner.derive-account\x01\x13\x01j\x01\x02\x01\x06\x01@\x02\x04self\x10\x04psbt\x02\
-\0\x14\x04\0\x1f[method]simple-signer.sign-psbt\x01\x15\x01@\x01\x04self\x10\0\x02\
-\x04\0\x1f[method]simple-signer.get-state\x01\x16\x04\x01\x1ccomponent:signer/ty\
-pes@0.1.0\x05\0\x04\x01\x1dcomponent:signer/singer@0.1.0\x04\0\x0b\x0c\x01\0\x06\
-singer\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.208\
-.1\x10wit-bindgen-rust\x060.25.0";
+derivation-error\0\0\x0dsigning-error\0\0\x04\0\x05error\x03\0\x05\x01m\x05\x07b\
+itcoin\x07testnet\x08testnet4\x06signet\x07regtest\x04\0\x07network\x03\0\x07\x01\
+m\x04\x06p2wpkh\x04p2tr\x0ep2wsh-multisig\x0dp2tr-multisig\x04\0\x0bwallet-type\x03\
+\0\x09\x01s\x04\0\x0caccount-xpub\x03\0\x0b\x01s\x04\0\x12master-fingerprint\x03\
+\0\x0d\x01s\x04\0\x12account-derivation\x03\0\x0f\x04\0\x0dsimple-signer\x03\x01\
+\x01i\x11\x01@\x01\x04init\x04\0\x12\x04\0\x1a[constructor]simple-signer\x01\x13\
+\x01h\x11\x01o\x03\x0c\x0e\x10\x01j\x01\x15\x01\x06\x01@\x03\x04self\x14\x0bwall\
+et-type\x0a\x07network\x08\0\x16\x04\0$[method]simple-signer.derive-account\x01\x17\
+\x01j\x01\x02\x01\x06\x01@\x02\x04self\x14\x04psbt\x02\0\x18\x04\0\x1f[method]si\
+mple-signer.sign-psbt\x01\x19\x01@\x01\x04self\x14\0\x02\x04\0\x1f[method]simple\
+-signer.get-state\x01\x1a\x04\x01\x1ccomponent:signer/types@0.1.0\x05\0\x04\x01\x1d\
+component:signer/singer@0.1.0\x04\0\x0b\x0c\x01\0\x06singer\x03\0\0\0G\x09produc\
+ers\x01\x0cprocessed-by\x02\x0dwit-component\x070.208.1\x10wit-bindgen-rust\x060\
+.25.0";
 
 #[inline(never)]
 #[doc(hidden)]
@@ -1,5 +1,6 @@
 use bitcoin::bip32;
 
+#[derive(Debug)]
 pub enum Error {
    DerivationError(bip32::Error),
    SigningError
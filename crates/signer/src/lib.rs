@@ -4,9 +4,9 @@ mod simple_signer;
 mod errors;
 use std::{cell::RefCell, str::FromStr};
 
-use bindings::exports::component::signer::{self, types::{Error, Guest, GuestSimpleSigner, SimpleSigner}};
+use bindings::exports::component::signer::{self, types::{Error, Guest, GuestSimpleSigner, Network, SimpleSigner, WalletType}};
 
-use bitcoin::{bip32::Xpriv, psbt, Psbt};
+use bitcoin::{bip32::Xpriv, psbt, NetworkKind, Psbt};
 
 struct SimpleSignerStruct {
     inner: RefCell<simple_signer::SimpleSigner>,
@@ -29,8 +29,20 @@ impl GuestSimpleSigner for SimpleSignerStruct {
 
     fn derive_account(
         &self,
+        wallet_type: WalletType,
+        network: Network,
     ) -> Result<(signer::types::AccountXpub, signer::types::MasterFingerprint, signer::types::AccountDerivation), Error> {
-        let (xpub, fingerprint, derivation_path) = self.inner.borrow_mut().derive_account().map_err(|_| Error::DerivationError )?;
+        let wallet_type = match wallet_type {
+            WalletType::P2wpkh => simple_signer::WalletType::P2WPKH,
+            WalletType::P2tr => simple_signer::WalletType::P2TR,
+            WalletType::P2wshMultisig => simple_signer::WalletType::P2WSHMultisig,
+            WalletType::P2trMultisig => simple_signer::WalletType::P2TRMultisig,
+        };
+        let network = match network {
+            Network::Bitcoin => NetworkKind::Main,
+            Network::Testnet | Network::Testnet4 | Network::Signet | Network::Regtest => NetworkKind::Test,
+        };
+        let (xpub, fingerprint, derivation_path) = self.inner.borrow_mut().derive_account(wallet_type, network).map_err(|_| Error::DerivationError )?;
         let account_xpub = xpub.to_string();
         let master_fingerprint = fingerprint.to_string();
         let account_derivation_path = derivation_path.to_string();
@@ -1,4 +1,4 @@
-use bitcoin::{bip32::{DerivationPath, Fingerprint, IntoDerivationPath, Xpriv, Xpub}, key::Secp256k1, Psbt};
+use bitcoin::{bip32::{DerivationPath, Fingerprint, IntoDerivationPath, Xpriv, Xpub}, key::Secp256k1, NetworkKind, Psbt};
 use serde::Serialize;
 
 use crate::errors::Error;
@@ -6,6 +6,35 @@ use crate::errors::Error;
 
 type ExportedData = (Xpub, Fingerprint, DerivationPath);
 
+/// Script type of an account, it decides the account path
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WalletType {
+    /// BIP84 accounts
+    P2WPKH,
+    /// BIP86 single key taproot accounts
+    P2TR,
+    /// BIP48 cosigner accounts of P2WSH multisig wallets
+    P2WSHMultisig,
+    /// Cosigner accounts of taproot multisig wallets, the BIP48 path with script type 3
+    P2TRMultisig,
+}
+
+impl WalletType {
+    /// Account 0 path, with coin type 0h on mainnet and 1h on every test network
+    fn account_path(&self, network: NetworkKind) -> String {
+        let coin_type = match network {
+            NetworkKind::Main => 0,
+            NetworkKind::Test => 1,
+        };
+        match self {
+            WalletType::P2WPKH => format!("84h/{}h/0h", coin_type),
+            WalletType::P2TR => format!("86h/{}h/0h", coin_type),
+            WalletType::P2WSHMultisig => format!("48h/{}h/0h/2h", coin_type),
+            WalletType::P2TRMultisig => format!("48h/{}h/0h/3h", coin_type),
+        }
+    }
+}
+
 #[derive(serde::Deserialize, Serialize)]
 pub struct SimpleSigner {
     /// The master extended private key.
@@ -28,10 +57,10 @@ impl SimpleSigner {
         return deserialized_state
     }
 
-    pub fn derive_account(& self) -> Result<ExportedData, Error>  {
+    pub fn derive_account(& self, wallet_type: WalletType, network: NetworkKind) -> Result<ExportedData, Error>  {
         // Only One Account is used for now
         let secp = Secp256k1::new();
-        let path = "84h/0h/0h".into_derivation_path().map_err(|err| Error::DerivationError(err) )?;
+        let path = wallet_type.account_path(network).into_derivation_path().map_err(|err| Error::DerivationError(err) )?;
         let account_0_xpriv = self.master_xpriv.derive_priv(&secp, &path).map_err(|err| Error::DerivationError(err) )?;
         let account_0_xpub = Xpub::from_priv(&secp, &account_0_xpriv);
  
@@ -39,6 +68,11 @@ impl SimpleSigner {
     } 
 
     /// Signs `psbt` with this signer.
+    ///
+    /// Inputs listing a `bip32_derivation` get an ECDSA signature, taproot inputs listing
+    /// `tap_key_origins` and their internal key get a schnorr key path signature, and one per
+    /// leaf for the keys listed with leaf hashes. Inputs with none of our keys are left to the
+    /// other cosigners.
     pub fn sign_psbt(&self, mut psbt: Psbt) -> Result<Psbt, Error> {
         let secp = Secp256k1::new();
         if let Ok(_) =  psbt.sign(&self.master_xpriv, &secp) {
@@ -51,4 +85,58 @@ impl SimpleSigner {
     pub fn get_state(& self) -> Vec<u8> {
         return bincode::serialize(self).unwrap();
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, str::FromStr};
+
+    use bitcoin::{absolute::LockTime, hashes::Hash, key::TapTweak, secp256k1::Message, sighash::{Prevouts, SighashCache}, transaction::Version, Address, Amount, Network, OutPoint, Transaction, TxIn, TxOut};
+
+    use super::*;
+
+    #[test]
+    fn signs_taproot_key_path() {
+        let secp = Secp256k1::new();
+        // BIP86 test vector root key
+        let master_xpriv = Xpriv::from_str("xprv9s21ZrQH143K3GJpoapnV8SFfukcVBSfeCficPSGfubmSFDxo1kuHnLisriDvSnRRuL2Qrg5ggqHKNVpxR86QEC8w35uxmGoggxtQTPvfUu").unwrap();
+        let signer = SimpleSigner::new(master_xpriv);
+
+        let (account_xpub, fingerprint, account_path) = signer.derive_account(WalletType::P2TR, NetworkKind::Main).unwrap();
+        assert_eq!(account_path, DerivationPath::from_str("m/86'/0'/0'").unwrap());
+        let child = account_xpub.derive_pub(&secp, &DerivationPath::from_str("m/0/0").unwrap()).unwrap();
+        let internal_key = child.public_key.x_only_public_key().0;
+        let spent_output = TxOut { value: Amount::from_sat(100000), script_pubkey: Address::p2tr(&secp, internal_key, None, Network::Bitcoin).script_pubkey() };
+
+        let transaction = Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn { previous_output: OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap(), ..Default::default() }],
+            output: vec![TxOut { value: Amount::from_sat(99000), script_pubkey: spent_output.script_pubkey.clone() }],
+        };
+        let mut psbt = Psbt::from_unsigned_tx(transaction.clone()).unwrap();
+        let mut tap_key_origins = BTreeMap::new();
+        tap_key_origins.insert(internal_key, (vec![], (fingerprint, account_path.extend(DerivationPath::from_str("m/0/0").unwrap()))));
+        psbt.inputs[0].witness_utxo = Some(spent_output.clone());
+        psbt.inputs[0].tap_internal_key = Some(internal_key);
+        psbt.inputs[0].tap_key_origins = tap_key_origins;
+
+        let signed = signer.sign_psbt(psbt).unwrap();
+        let signature = signed.inputs[0].tap_key_sig.unwrap();
+
+        let sighash = SighashCache::new(&transaction).taproot_key_spend_signature_hash(0, &Prevouts::All(&[spent_output]), signature.sighash_type).unwrap();
+        let output_key = internal_key.tap_tweak(&secp, None).0.to_x_only_public_key();
+        assert!(secp.verify_schnorr(&signature.signature, &Message::from_digest(sighash.to_byte_array()), &output_key).is_ok());
+    }
+
+    #[test]
+    fn uses_test_coin_type_off_mainnet() {
+        let master_xpriv = Xpriv::from_str("tprv8ZgxMBicQKsPd7Uf69XL1XwhmjHopUGep8GuEiJDZmbQz6o58LninorQAfcKZWARbtRtfnLcJ5MQ2AtHcQJCCRUcMRvmDUjyEmNUWwx8UbK").unwrap();
+        let signer = SimpleSigner::new(master_xpriv);
+
+        let (_, _, p2wpkh_path) = signer.derive_account(WalletType::P2WPKH, NetworkKind::Test).unwrap();
+        assert_eq!(p2wpkh_path, DerivationPath::from_str("m/84'/1'/0'").unwrap());
+        let (_, _, multisig_path) = signer.derive_account(WalletType::P2TRMultisig, NetworkKind::Test).unwrap();
+        assert_eq!(multisig_path, DerivationPath::from_str("m/48'/1'/0'/3'").unwrap());
+    }
+}
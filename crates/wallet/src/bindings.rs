@@ -64,48 +64,177 @@ pub mod exports {
                     }
                 }
 
-                #[derive(Clone, Copy)]
-                pub enum Error {
-                    CoinSelection,
-                    Psbt,
-                    MissingNonWitnessUtxo,
-                    NoPubkey,
-                    PubkeyError,
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum WalletType {
+                    P2wpkh,
+                    /// Single key taproot, BIP86
+                    P2tr,
                 }
-                impl ::core::fmt::Debug for Error {
+                impl ::core::fmt::Debug for WalletType {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                         match self {
-                            Error::CoinSelection => f.debug_tuple("Error::CoinSelection").finish(),
-                            Error::Psbt => f.debug_tuple("Error::Psbt").finish(),
-                            Error::MissingNonWitnessUtxo => {
-                                f.debug_tuple("Error::MissingNonWitnessUtxo").finish()
-                            }
-                            Error::NoPubkey => f.debug_tuple("Error::NoPubkey").finish(),
-                            Error::PubkeyError => f.debug_tuple("Error::PubkeyError").finish(),
+                            WalletType::P2wpkh => f.debug_tuple("WalletType::P2wpkh").finish(),
+                            WalletType::P2tr => f.debug_tuple("WalletType::P2tr").finish(),
                         }
                     }
                 }
-                impl ::core::fmt::Display for Error {
+
+                impl WalletType {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> WalletType {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+
+                        match val {
+                            0 => WalletType::P2wpkh,
+                            1 => WalletType::P2tr,
+
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+
+                /// Single key account, receiving on `/0/*` and paying change to `/1/*`
+                #[derive(Clone)]
+                pub struct Account {
+                    pub xpub: _rt::String,
+                    pub account_derivation: _rt::String,
+                    pub master_fingerprint: _rt::String,
+                    /// Script type of the account, p2wpkh when not set
+                    pub wallet_type: Option<WalletType>,
+                }
+                impl ::core::fmt::Debug for Account {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                        write!(f, "{:?}", self)
+                        f.debug_struct("Account")
+                            .field("xpub", &self.xpub)
+                            .field("account-derivation", &self.account_derivation)
+                            .field("master-fingerprint", &self.master_fingerprint)
+                            .field("wallet-type", &self.wallet_type)
+                            .finish()
+                    }
+                }
+                /// Output descriptors of the receive and change scripts, checksums optional
+                #[derive(Clone)]
+                pub struct Descriptors {
+                    pub external: _rt::String,
+                    /// Taken from a `<0;1>` multipath step of the external descriptor when not set
+                    pub internal: Option<_rt::String>,
+                }
+                impl ::core::fmt::Debug for Descriptors {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Descriptors")
+                            .field("external", &self.external)
+                            .field("internal", &self.internal)
+                            .finish()
+                    }
+                }
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum MultisigType {
+                    /// `wsh(sortedmulti)`
+                    P2wsh,
+                    /// `sortedmulti_a` leaf under an unspendable internal key
+                    P2tr,
+                }
+                impl ::core::fmt::Debug for MultisigType {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            MultisigType::P2wsh => f.debug_tuple("MultisigType::P2wsh").finish(),
+                            MultisigType::P2tr => f.debug_tuple("MultisigType::P2tr").finish(),
+                        }
                     }
                 }
 
-                impl std::error::Error for Error {}
+                impl MultisigType {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> MultisigType {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+
+                        match val {
+                            0 => MultisigType::P2wsh,
+                            1 => MultisigType::P2tr,
+
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+
+                /// Account of one cosigner, receiving on `/0/*` and paying change to `/1/*`
                 #[derive(Clone)]
-                pub struct Config {
+                pub struct Cosigner {
                     pub xpub: _rt::String,
                     pub account_derivation: _rt::String,
                     pub master_fingerprint: _rt::String,
-                    pub network: BitcoinNetwork,
                 }
-                impl ::core::fmt::Debug for Config {
+                impl ::core::fmt::Debug for Cosigner {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                        f.debug_struct("Config")
+                        f.debug_struct("Cosigner")
                             .field("xpub", &self.xpub)
                             .field("account-derivation", &self.account_derivation)
                             .field("master-fingerprint", &self.master_fingerprint)
+                            .finish()
+                    }
+                }
+                /// Sorted multisig needing `threshold` of the cosigners' signatures
+                #[derive(Clone)]
+                pub struct Multisig {
+                    pub threshold: u32,
+                    pub cosigners: _rt::Vec<Cosigner>,
+                    pub multisig_type: MultisigType,
+                }
+                impl ::core::fmt::Debug for Multisig {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Multisig")
+                            .field("threshold", &self.threshold)
+                            .field("cosigners", &self.cosigners)
+                            .field("multisig-type", &self.multisig_type)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub enum Keys {
+                    Account(Account),
+                    Descriptors(Descriptors),
+                    Multisig(Multisig),
+                    /// Primary key or timelocked recovery keys, such as
+                    /// `or(pk(A),and(pk(B),older(26280)))`, every key with a `<0;1>` multipath step
+                    Policy(_rt::String),
+                }
+                impl ::core::fmt::Debug for Keys {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            Keys::Account(e) => f.debug_tuple("Keys::Account").field(e).finish(),
+                            Keys::Descriptors(e) => {
+                                f.debug_tuple("Keys::Descriptors").field(e).finish()
+                            }
+                            Keys::Multisig(e) => f.debug_tuple("Keys::Multisig").field(e).finish(),
+                            Keys::Policy(e) => f.debug_tuple("Keys::Policy").field(e).finish(),
+                        }
+                    }
+                }
+                #[derive(Clone)]
+                pub struct Config {
+                    pub keys: Keys,
+                    pub network: BitcoinNetwork,
+                    /// Unused receive scripts watched past the last used one, 20 when not set
+                    pub lookahead: Option<u32>,
+                    /// Unused change scripts watched past the last used one, lookahead when not set
+                    pub change_lookahead: Option<u32>,
+                    /// Confirmations an output needs before it is spent, 0 allows unconfirmed ones, 1 when not set
+                    pub min_confirmations: Option<u32>,
+                }
+                impl ::core::fmt::Debug for Config {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Config")
+                            .field("keys", &self.keys)
                             .field("network", &self.network)
+                            .field("lookahead", &self.lookahead)
+                            .field("change-lookahead", &self.change_lookahead)
+                            .field("min-confirmations", &self.min_confirmations)
                             .finish()
                     }
                 }
@@ -126,6 +255,62 @@ pub mod exports {
                         }
                     }
                 }
+                /// Wallet balance split by how final the outputs are
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct Balance {
+                    /// Mined outputs, immature coinbase left out
+                    pub confirmed: u64,
+                    /// Unconfirmed change of our own transactions
+                    pub trusted_pending: u64,
+                    /// Unconfirmed payments from others
+                    pub untrusted_pending: u64,
+                    /// Coinbase outputs that are not 100 blocks deep yet
+                    pub immature: u64,
+                }
+                impl ::core::fmt::Debug for Balance {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Balance")
+                            .field("confirmed", &self.confirmed)
+                            .field("trusted-pending", &self.trusted_pending)
+                            .field("untrusted-pending", &self.untrusted_pending)
+                            .field("immature", &self.immature)
+                            .finish()
+                    }
+                }
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum Keychain {
+                    /// Receive addresses handed out to payers
+                    External,
+                    /// Change outputs of the wallet's own transactions
+                    Internal,
+                }
+                impl ::core::fmt::Debug for Keychain {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            Keychain::External => f.debug_tuple("Keychain::External").finish(),
+                            Keychain::Internal => f.debug_tuple("Keychain::Internal").finish(),
+                        }
+                    }
+                }
+
+                impl Keychain {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> Keychain {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+
+                        match val {
+                            0 => Keychain::External,
+                            1 => Keychain::Internal,
+
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+
                 #[derive(Clone)]
                 pub struct PartialUtxo {
                     pub txid: _rt::Vec<u8>,
@@ -133,6 +318,9 @@ pub mod exports {
                     pub amount: u64,
                     pub script: _rt::Vec<u8>,
                     pub is_spent: bool,
+                    /// Height of the block that mined the output, none while unconfirmed
+                    pub height: Option<u32>,
+                    pub is_coinbase: bool,
                 }
                 impl ::core::fmt::Debug for PartialUtxo {
                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -142,10 +330,164 @@ pub mod exports {
                             .field("amount", &self.amount)
                             .field("script", &self.script)
                             .field("is-spent", &self.is_spent)
+                            .field("height", &self.height)
+                            .field("is-coinbase", &self.is_coinbase)
                             .finish()
                     }
                 }
                 pub type Pubkey = _rt::Vec<u8>;
+                #[derive(Clone)]
+                pub struct Outpoint {
+                    pub txid: _rt::Vec<u8>,
+                    pub vout: u32,
+                }
+                impl ::core::fmt::Debug for Outpoint {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Outpoint")
+                            .field("txid", &self.txid)
+                            .field("vout", &self.vout)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub enum Error {
+                    CoinSelection,
+                    Psbt,
+                    MissingNonWitnessUtxo,
+                    NoPubkey,
+                    PubkeyError,
+                    InvalidDescriptor(_rt::String),
+                    MissingSignature,
+                    InvalidSpendingPath(u32),
+                    TimelockNotReached,
+                    NoRecipients,
+                    /// Index of the recipient paid less than its script's dust limit
+                    OutputBelowDustLimit(u32),
+                    /// Outpoint that is not a confirmed, spendable output of the wallet
+                    UnknownUtxo(Outpoint),
+                    /// Outpoint frozen by coin control that a transaction was asked to spend
+                    FrozenUtxo(Outpoint),
+                    /// Txid of a transaction that does not signal replaceability
+                    IrreplaceableTransaction(_rt::Vec<u8>),
+                    /// Txid of a transaction that was already mined
+                    TransactionConfirmed(_rt::Vec<u8>),
+                    /// Txid of a transaction paying nothing to the wallet
+                    NoWalletOutputs(_rt::Vec<u8>),
+                    /// Lowest fee rate in sat/vB the replacement or child may pay
+                    FeeRateTooLow(u64),
+                    /// Lowest absolute fee the replacement may pay
+                    FeeTooLow(u64),
+                    /// Fee rate in sat/vB too high to be represented
+                    InvalidFeeRate(u64),
+                }
+                impl ::core::fmt::Debug for Error {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            Error::CoinSelection => f.debug_tuple("Error::CoinSelection").finish(),
+                            Error::Psbt => f.debug_tuple("Error::Psbt").finish(),
+                            Error::MissingNonWitnessUtxo => {
+                                f.debug_tuple("Error::MissingNonWitnessUtxo").finish()
+                            }
+                            Error::NoPubkey => f.debug_tuple("Error::NoPubkey").finish(),
+                            Error::PubkeyError => f.debug_tuple("Error::PubkeyError").finish(),
+                            Error::InvalidDescriptor(e) => {
+                                f.debug_tuple("Error::InvalidDescriptor").field(e).finish()
+                            }
+                            Error::MissingSignature => {
+                                f.debug_tuple("Error::MissingSignature").finish()
+                            }
+                            Error::InvalidSpendingPath(e) => f
+                                .debug_tuple("Error::InvalidSpendingPath")
+                                .field(e)
+                                .finish(),
+                            Error::TimelockNotReached => {
+                                f.debug_tuple("Error::TimelockNotReached").finish()
+                            }
+                            Error::NoRecipients => f.debug_tuple("Error::NoRecipients").finish(),
+                            Error::OutputBelowDustLimit(e) => f
+                                .debug_tuple("Error::OutputBelowDustLimit")
+                                .field(e)
+                                .finish(),
+                            Error::UnknownUtxo(e) => {
+                                f.debug_tuple("Error::UnknownUtxo").field(e).finish()
+                            }
+                            Error::FrozenUtxo(e) => {
+                                f.debug_tuple("Error::FrozenUtxo").field(e).finish()
+                            }
+                            Error::IrreplaceableTransaction(e) => f
+                                .debug_tuple("Error::IrreplaceableTransaction")
+                                .field(e)
+                                .finish(),
+                            Error::TransactionConfirmed(e) => f
+                                .debug_tuple("Error::TransactionConfirmed")
+                                .field(e)
+                                .finish(),
+                            Error::NoWalletOutputs(e) => {
+                                f.debug_tuple("Error::NoWalletOutputs").field(e).finish()
+                            }
+                            Error::FeeRateTooLow(e) => {
+                                f.debug_tuple("Error::FeeRateTooLow").field(e).finish()
+                            }
+                            Error::FeeTooLow(e) => {
+                                f.debug_tuple("Error::FeeTooLow").field(e).finish()
+                            }
+                            Error::InvalidFeeRate(e) => {
+                                f.debug_tuple("Error::InvalidFeeRate").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                impl ::core::fmt::Display for Error {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        write!(f, "{:?}", self)
+                    }
+                }
+
+                impl std::error::Error for Error {}
+                /// Unspent wallet output as coin control sees it
+                #[derive(Clone)]
+                pub struct UnspentUtxo {
+                    pub outpoint: Outpoint,
+                    pub amount: u64,
+                    pub script: _rt::Vec<u8>,
+                    pub keychain: Keychain,
+                    pub derivation_index: u32,
+                    /// Height of the block that mined the output, none while unconfirmed
+                    pub height: Option<u32>,
+                    pub confirmations: u32,
+                    pub is_coinbase: bool,
+                    /// Left out of coin selection until unfrozen
+                    pub is_frozen: bool,
+                }
+                impl ::core::fmt::Debug for UnspentUtxo {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("UnspentUtxo")
+                            .field("outpoint", &self.outpoint)
+                            .field("amount", &self.amount)
+                            .field("script", &self.script)
+                            .field("keychain", &self.keychain)
+                            .field("derivation-index", &self.derivation_index)
+                            .field("height", &self.height)
+                            .field("confirmations", &self.confirmations)
+                            .field("is-coinbase", &self.is_coinbase)
+                            .field("is-frozen", &self.is_frozen)
+                            .finish()
+                    }
+                }
+                /// Output of a transaction paying several recipients
+                #[derive(Clone)]
+                pub struct Recipient {
+                    pub script: _rt::Vec<u8>,
+                    pub amount: u64,
+                }
+                impl ::core::fmt::Debug for Recipient {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Recipient")
+                            .field("script", &self.script)
+                            .field("amount", &self.amount)
+                            .finish()
+                    }
+                }
 
                 #[derive(Debug)]
                 #[repr(transparent)]
@@ -290,257 +632,4500 @@ pub mod exports {
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_constructor_watch_only_cabi<T: GuestWatchOnly>(
-                    arg0: i32,
-                    arg1: *mut u8,
-                    arg2: usize,
-                    arg3: *mut u8,
-                    arg4: usize,
-                    arg5: *mut u8,
-                    arg6: usize,
-                    arg7: i32,
+                    arg0: *mut u8,
                 ) -> i32 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let v4 = match arg0 {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    let v48 = match l0 {
                         0 => {
-                            let e4 = {
-                                let len0 = arg2;
+                            let e48 = {
+                                let l1 = *arg0.add(4).cast::<*mut u8>();
+                                let l2 = *arg0.add(8).cast::<usize>();
+                                let len3 = l2;
 
-                                _rt::Vec::from_raw_parts(arg1.cast(), len0, len0)
+                                _rt::Vec::from_raw_parts(l1.cast(), len3, len3)
                             };
-                            Initialization::OldState(e4)
+                            Initialization::OldState(e48)
                         }
                         n => {
                             debug_assert_eq!(n, 1, "invalid enum discriminant");
-                            let e4 = {
-                                let len1 = arg2;
-                                let bytes1 = _rt::Vec::from_raw_parts(arg1.cast(), len1, len1);
-                                let len2 = arg4;
-                                let bytes2 = _rt::Vec::from_raw_parts(arg3.cast(), len2, len2);
-                                let len3 = arg6;
-                                let bytes3 = _rt::Vec::from_raw_parts(arg5.cast(), len3, len3);
+                            let e48 = {
+                                let l4 = i32::from(*arg0.add(4).cast::<u8>());
+                                let v40 = match l4 {
+                                    0 => {
+                                        let e40 = {
+                                            let l5 = *arg0.add(8).cast::<*mut u8>();
+                                            let l6 = *arg0.add(12).cast::<usize>();
+                                            let len7 = l6;
+                                            let bytes7 =
+                                                _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+                                            let l8 = *arg0.add(16).cast::<*mut u8>();
+                                            let l9 = *arg0.add(20).cast::<usize>();
+                                            let len10 = l9;
+                                            let bytes10 =
+                                                _rt::Vec::from_raw_parts(l8.cast(), len10, len10);
+                                            let l11 = *arg0.add(24).cast::<*mut u8>();
+                                            let l12 = *arg0.add(28).cast::<usize>();
+                                            let len13 = l12;
+                                            let bytes13 =
+                                                _rt::Vec::from_raw_parts(l11.cast(), len13, len13);
+                                            let l14 = i32::from(*arg0.add(32).cast::<u8>());
+
+                                            Account {
+                                                xpub: _rt::string_lift(bytes7),
+                                                account_derivation: _rt::string_lift(bytes10),
+                                                master_fingerprint: _rt::string_lift(bytes13),
+                                                wallet_type: match l14 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l15 = i32::from(
+                                                                *arg0.add(33).cast::<u8>(),
+                                                            );
+
+                                                            WalletType::_lift(l15 as u8)
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                            }
+                                        };
+                                        Keys::Account(e40)
+                                    }
+                                    1 => {
+                                        let e40 = {
+                                            let l16 = *arg0.add(8).cast::<*mut u8>();
+                                            let l17 = *arg0.add(12).cast::<usize>();
+                                            let len18 = l17;
+                                            let bytes18 =
+                                                _rt::Vec::from_raw_parts(l16.cast(), len18, len18);
+                                            let l19 = i32::from(*arg0.add(16).cast::<u8>());
+
+                                            Descriptors {
+                                                external: _rt::string_lift(bytes18),
+                                                internal: match l19 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l20 =
+                                                                *arg0.add(20).cast::<*mut u8>();
+                                                            let l21 = *arg0.add(24).cast::<usize>();
+                                                            let len22 = l21;
+                                                            let bytes22 = _rt::Vec::from_raw_parts(
+                                                                l20.cast(),
+                                                                len22,
+                                                                len22,
+                                                            );
+
+                                                            _rt::string_lift(bytes22)
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                            }
+                                        };
+                                        Keys::Descriptors(e40)
+                                    }
+                                    2 => {
+                                        let e40 = {
+                                            let l23 = *arg0.add(8).cast::<i32>();
+                                            let l24 = *arg0.add(12).cast::<*mut u8>();
+                                            let l25 = *arg0.add(16).cast::<usize>();
+                                            let base35 = l24;
+                                            let len35 = l25;
+                                            let mut result35 = _rt::Vec::with_capacity(len35);
+                                            for i in 0..len35 {
+                                                let base = base35.add(i * 24);
+                                                let e35 = {
+                                                    let l26 = *base.add(0).cast::<*mut u8>();
+                                                    let l27 = *base.add(4).cast::<usize>();
+                                                    let len28 = l27;
+                                                    let bytes28 = _rt::Vec::from_raw_parts(
+                                                        l26.cast(),
+                                                        len28,
+                                                        len28,
+                                                    );
+                                                    let l29 = *base.add(8).cast::<*mut u8>();
+                                                    let l30 = *base.add(12).cast::<usize>();
+                                                    let len31 = l30;
+                                                    let bytes31 = _rt::Vec::from_raw_parts(
+                                                        l29.cast(),
+                                                        len31,
+                                                        len31,
+                                                    );
+                                                    let l32 = *base.add(16).cast::<*mut u8>();
+                                                    let l33 = *base.add(20).cast::<usize>();
+                                                    let len34 = l33;
+                                                    let bytes34 = _rt::Vec::from_raw_parts(
+                                                        l32.cast(),
+                                                        len34,
+                                                        len34,
+                                                    );
+
+                                                    Cosigner {
+                                                        xpub: _rt::string_lift(bytes28),
+                                                        account_derivation: _rt::string_lift(
+                                                            bytes31,
+                                                        ),
+                                                        master_fingerprint: _rt::string_lift(
+                                                            bytes34,
+                                                        ),
+                                                    }
+                                                };
+                                                result35.push(e35);
+                                            }
+                                            _rt::cabi_dealloc(base35, len35 * 24, 4);
+                                            let l36 = i32::from(*arg0.add(20).cast::<u8>());
+
+                                            Multisig {
+                                                threshold: l23 as u32,
+                                                cosigners: result35,
+                                                multisig_type: MultisigType::_lift(l36 as u8),
+                                            }
+                                        };
+                                        Keys::Multisig(e40)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                        let e40 = {
+                                            let l37 = *arg0.add(8).cast::<*mut u8>();
+                                            let l38 = *arg0.add(12).cast::<usize>();
+                                            let len39 = l38;
+                                            let bytes39 =
+                                                _rt::Vec::from_raw_parts(l37.cast(), len39, len39);
+
+                                            _rt::string_lift(bytes39)
+                                        };
+                                        Keys::Policy(e40)
+                                    }
+                                };
+                                let l41 = i32::from(*arg0.add(36).cast::<u8>());
+                                let l42 = i32::from(*arg0.add(40).cast::<u8>());
+                                let l44 = i32::from(*arg0.add(48).cast::<u8>());
+                                let l46 = i32::from(*arg0.add(56).cast::<u8>());
 
                                 Config {
-                                    xpub: _rt::string_lift(bytes1),
-                                    account_derivation: _rt::string_lift(bytes2),
-                                    master_fingerprint: _rt::string_lift(bytes3),
-                                    network: BitcoinNetwork::_lift(arg7 as u8),
+                                    keys: v40,
+                                    network: BitcoinNetwork::_lift(l41 as u8),
+                                    lookahead: match l42 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l43 = *arg0.add(44).cast::<i32>();
+
+                                                l43 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    change_lookahead: match l44 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l45 = *arg0.add(52).cast::<i32>();
+
+                                                l45 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    min_confirmations: match l46 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l47 = *arg0.add(60).cast::<i32>();
+
+                                                l47 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
                                 }
                             };
-                            Initialization::Config(e4)
+                            Initialization::Config(e48)
                         }
                     };
-                    let result5 = WatchOnly::new(T::new(v4));
-                    (result5).take_handle() as i32
+                    let result49 = WatchOnly::new(T::new(v48));
+                    _rt::cabi_dealloc(arg0, 64, 4);
+                    (result49).take_handle() as i32
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_watch_only_new_address_cabi<T: GuestWatchOnly>(
+                pub unsafe fn _export_static_watch_only_create_cabi<T: GuestWatchOnly>(
                     arg0: *mut u8,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let result0 = T::new_address(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
-                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    match result0 {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    let v48 = match l0 {
+                        0 => {
+                            let e48 = {
+                                let l1 = *arg0.add(4).cast::<*mut u8>();
+                                let l2 = *arg0.add(8).cast::<usize>();
+                                let len3 = l2;
+
+                                _rt::Vec::from_raw_parts(l1.cast(), len3, len3)
+                            };
+                            Initialization::OldState(e48)
+                        }
+                        n => {
+                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                            let e48 = {
+                                let l4 = i32::from(*arg0.add(4).cast::<u8>());
+                                let v40 = match l4 {
+                                    0 => {
+                                        let e40 = {
+                                            let l5 = *arg0.add(8).cast::<*mut u8>();
+                                            let l6 = *arg0.add(12).cast::<usize>();
+                                            let len7 = l6;
+                                            let bytes7 =
+                                                _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+                                            let l8 = *arg0.add(16).cast::<*mut u8>();
+                                            let l9 = *arg0.add(20).cast::<usize>();
+                                            let len10 = l9;
+                                            let bytes10 =
+                                                _rt::Vec::from_raw_parts(l8.cast(), len10, len10);
+                                            let l11 = *arg0.add(24).cast::<*mut u8>();
+                                            let l12 = *arg0.add(28).cast::<usize>();
+                                            let len13 = l12;
+                                            let bytes13 =
+                                                _rt::Vec::from_raw_parts(l11.cast(), len13, len13);
+                                            let l14 = i32::from(*arg0.add(32).cast::<u8>());
+
+                                            Account {
+                                                xpub: _rt::string_lift(bytes7),
+                                                account_derivation: _rt::string_lift(bytes10),
+                                                master_fingerprint: _rt::string_lift(bytes13),
+                                                wallet_type: match l14 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l15 = i32::from(
+                                                                *arg0.add(33).cast::<u8>(),
+                                                            );
+
+                                                            WalletType::_lift(l15 as u8)
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                            }
+                                        };
+                                        Keys::Account(e40)
+                                    }
+                                    1 => {
+                                        let e40 = {
+                                            let l16 = *arg0.add(8).cast::<*mut u8>();
+                                            let l17 = *arg0.add(12).cast::<usize>();
+                                            let len18 = l17;
+                                            let bytes18 =
+                                                _rt::Vec::from_raw_parts(l16.cast(), len18, len18);
+                                            let l19 = i32::from(*arg0.add(16).cast::<u8>());
+
+                                            Descriptors {
+                                                external: _rt::string_lift(bytes18),
+                                                internal: match l19 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l20 =
+                                                                *arg0.add(20).cast::<*mut u8>();
+                                                            let l21 = *arg0.add(24).cast::<usize>();
+                                                            let len22 = l21;
+                                                            let bytes22 = _rt::Vec::from_raw_parts(
+                                                                l20.cast(),
+                                                                len22,
+                                                                len22,
+                                                            );
+
+                                                            _rt::string_lift(bytes22)
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                            }
+                                        };
+                                        Keys::Descriptors(e40)
+                                    }
+                                    2 => {
+                                        let e40 = {
+                                            let l23 = *arg0.add(8).cast::<i32>();
+                                            let l24 = *arg0.add(12).cast::<*mut u8>();
+                                            let l25 = *arg0.add(16).cast::<usize>();
+                                            let base35 = l24;
+                                            let len35 = l25;
+                                            let mut result35 = _rt::Vec::with_capacity(len35);
+                                            for i in 0..len35 {
+                                                let base = base35.add(i * 24);
+                                                let e35 = {
+                                                    let l26 = *base.add(0).cast::<*mut u8>();
+                                                    let l27 = *base.add(4).cast::<usize>();
+                                                    let len28 = l27;
+                                                    let bytes28 = _rt::Vec::from_raw_parts(
+                                                        l26.cast(),
+                                                        len28,
+                                                        len28,
+                                                    );
+                                                    let l29 = *base.add(8).cast::<*mut u8>();
+                                                    let l30 = *base.add(12).cast::<usize>();
+                                                    let len31 = l30;
+                                                    let bytes31 = _rt::Vec::from_raw_parts(
+                                                        l29.cast(),
+                                                        len31,
+                                                        len31,
+                                                    );
+                                                    let l32 = *base.add(16).cast::<*mut u8>();
+                                                    let l33 = *base.add(20).cast::<usize>();
+                                                    let len34 = l33;
+                                                    let bytes34 = _rt::Vec::from_raw_parts(
+                                                        l32.cast(),
+                                                        len34,
+                                                        len34,
+                                                    );
+
+                                                    Cosigner {
+                                                        xpub: _rt::string_lift(bytes28),
+                                                        account_derivation: _rt::string_lift(
+                                                            bytes31,
+                                                        ),
+                                                        master_fingerprint: _rt::string_lift(
+                                                            bytes34,
+                                                        ),
+                                                    }
+                                                };
+                                                result35.push(e35);
+                                            }
+                                            _rt::cabi_dealloc(base35, len35 * 24, 4);
+                                            let l36 = i32::from(*arg0.add(20).cast::<u8>());
+
+                                            Multisig {
+                                                threshold: l23 as u32,
+                                                cosigners: result35,
+                                                multisig_type: MultisigType::_lift(l36 as u8),
+                                            }
+                                        };
+                                        Keys::Multisig(e40)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                        let e40 = {
+                                            let l37 = *arg0.add(8).cast::<*mut u8>();
+                                            let l38 = *arg0.add(12).cast::<usize>();
+                                            let len39 = l38;
+                                            let bytes39 =
+                                                _rt::Vec::from_raw_parts(l37.cast(), len39, len39);
+
+                                            _rt::string_lift(bytes39)
+                                        };
+                                        Keys::Policy(e40)
+                                    }
+                                };
+                                let l41 = i32::from(*arg0.add(36).cast::<u8>());
+                                let l42 = i32::from(*arg0.add(40).cast::<u8>());
+                                let l44 = i32::from(*arg0.add(48).cast::<u8>());
+                                let l46 = i32::from(*arg0.add(56).cast::<u8>());
+
+                                Config {
+                                    keys: v40,
+                                    network: BitcoinNetwork::_lift(l41 as u8),
+                                    lookahead: match l42 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l43 = *arg0.add(44).cast::<i32>();
+
+                                                l43 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    change_lookahead: match l44 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l45 = *arg0.add(52).cast::<i32>();
+
+                                                l45 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    min_confirmations: match l46 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l47 = *arg0.add(60).cast::<i32>();
+
+                                                l47 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                }
+                            };
+                            Initialization::Config(e48)
+                        }
+                    };
+                    let result49 = T::create(v48);
+                    _rt::cabi_dealloc(arg0, 64, 4);
+                    let ptr50 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result49 {
                         Ok(e) => {
-                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                            let vec2 = (e.into_bytes()).into_boxed_slice();
-                            let ptr2 = vec2.as_ptr().cast::<u8>();
-                            let len2 = vec2.len();
-                            ::core::mem::forget(vec2);
-                            *ptr1.add(8).cast::<usize>() = len2;
-                            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+                            *ptr50.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr50.add(8).cast::<i32>() = (e).take_handle() as i32;
                         }
                         Err(e) => {
-                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr50.add(0).cast::<u8>() = (1i32) as u8;
                             match e {
                                 Error::CoinSelection => {
-                                    *ptr1.add(4).cast::<u8>() = (0i32) as u8;
+                                    *ptr50.add(8).cast::<u8>() = (0i32) as u8;
                                 }
                                 Error::Psbt => {
-                                    *ptr1.add(4).cast::<u8>() = (1i32) as u8;
+                                    *ptr50.add(8).cast::<u8>() = (1i32) as u8;
                                 }
                                 Error::MissingNonWitnessUtxo => {
-                                    *ptr1.add(4).cast::<u8>() = (2i32) as u8;
+                                    *ptr50.add(8).cast::<u8>() = (2i32) as u8;
                                 }
                                 Error::NoPubkey => {
-                                    *ptr1.add(4).cast::<u8>() = (3i32) as u8;
+                                    *ptr50.add(8).cast::<u8>() = (3i32) as u8;
                                 }
                                 Error::PubkeyError => {
-                                    *ptr1.add(4).cast::<u8>() = (4i32) as u8;
+                                    *ptr50.add(8).cast::<u8>() = (4i32) as u8;
                                 }
-                            }
-                        }
-                    };
-                    ptr1
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_watch_only_new_address<T: GuestWatchOnly>(
-                    arg0: *mut u8,
-                ) {
-                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
-                    match l0 {
-                        0 => {
-                            let l1 = *arg0.add(4).cast::<*mut u8>();
-                            let l2 = *arg0.add(8).cast::<usize>();
-                            _rt::cabi_dealloc(l1, l2, 1);
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr50.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec51 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr51 = vec51.as_ptr().cast::<u8>();
+                                    let len51 = vec51.len();
+                                    ::core::mem::forget(vec51);
+                                    *ptr50.add(20).cast::<usize>() = len51;
+                                    *ptr50.add(16).cast::<*mut u8>() = ptr51.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr50.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr50.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr50.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr50.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr50.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr50.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr50.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr50.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid52,
+                                        vout: vout52,
+                                    } = e;
+                                    let vec53 = (txid52).into_boxed_slice();
+                                    let ptr53 = vec53.as_ptr().cast::<u8>();
+                                    let len53 = vec53.len();
+                                    ::core::mem::forget(vec53);
+                                    *ptr50.add(20).cast::<usize>() = len53;
+                                    *ptr50.add(16).cast::<*mut u8>() = ptr53.cast_mut();
+                                    *ptr50.add(24).cast::<i32>() = _rt::as_i32(vout52);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr50.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid54,
+                                        vout: vout54,
+                                    } = e;
+                                    let vec55 = (txid54).into_boxed_slice();
+                                    let ptr55 = vec55.as_ptr().cast::<u8>();
+                                    let len55 = vec55.len();
+                                    ::core::mem::forget(vec55);
+                                    *ptr50.add(20).cast::<usize>() = len55;
+                                    *ptr50.add(16).cast::<*mut u8>() = ptr55.cast_mut();
+                                    *ptr50.add(24).cast::<i32>() = _rt::as_i32(vout54);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr50.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec56 = (e).into_boxed_slice();
+                                    let ptr56 = vec56.as_ptr().cast::<u8>();
+                                    let len56 = vec56.len();
+                                    ::core::mem::forget(vec56);
+                                    *ptr50.add(20).cast::<usize>() = len56;
+                                    *ptr50.add(16).cast::<*mut u8>() = ptr56.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr50.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec57 = (e).into_boxed_slice();
+                                    let ptr57 = vec57.as_ptr().cast::<u8>();
+                                    let len57 = vec57.len();
+                                    ::core::mem::forget(vec57);
+                                    *ptr50.add(20).cast::<usize>() = len57;
+                                    *ptr50.add(16).cast::<*mut u8>() = ptr57.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr50.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec58 = (e).into_boxed_slice();
+                                    let ptr58 = vec58.as_ptr().cast::<u8>();
+                                    let len58 = vec58.len();
+                                    ::core::mem::forget(vec58);
+                                    *ptr50.add(20).cast::<usize>() = len58;
+                                    *ptr50.add(16).cast::<*mut u8>() = ptr58.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr50.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr50.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr50.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr50.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr50.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr50.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr50
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_static_watch_only_create<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    let base6 = l4;
+                                    let len6 = l5;
+                                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                                }
+                                12 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                13 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                14 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                15 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_new_address_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::new_address(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec2 = (e.into_bytes()).into_boxed_slice();
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            ::core::mem::forget(vec2);
+                            *ptr1.add(12).cast::<usize>() = len2;
+                            *ptr1.add(8).cast::<*mut u8>() = ptr2.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr1.add(20).cast::<usize>() = len3;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid4,
+                                        vout: vout4,
+                                    } = e;
+                                    let vec5 = (txid4).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr1.add(20).cast::<usize>() = len5;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout4);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid6,
+                                        vout: vout6,
+                                    } = e;
+                                    let vec7 = (txid6).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr1.add(20).cast::<usize>() = len7;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout6);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec8 = (e).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr1.add(20).cast::<usize>() = len8;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr1.add(20).cast::<usize>() = len9;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec10 = (e).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr1.add(20).cast::<usize>() = len10;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_new_address<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                        _ => {
+                            let l3 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l3 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0.add(20).cast::<usize>();
+                                    let base8 = l6;
+                                    let len8 = l7;
+                                    _rt::cabi_dealloc(base8, len8 * 1, 1);
+                                }
+                                12 => {
+                                    let l9 = *arg0.add(16).cast::<*mut u8>();
+                                    let l10 = *arg0.add(20).cast::<usize>();
+                                    let base11 = l9;
+                                    let len11 = l10;
+                                    _rt::cabi_dealloc(base11, len11 * 1, 1);
+                                }
+                                13 => {
+                                    let l12 = *arg0.add(16).cast::<*mut u8>();
+                                    let l13 = *arg0.add(20).cast::<usize>();
+                                    let base14 = l12;
+                                    let len14 = l13;
+                                    _rt::cabi_dealloc(base14, len14 * 1, 1);
+                                }
+                                14 => {
+                                    let l15 = *arg0.add(16).cast::<*mut u8>();
+                                    let l16 = *arg0.add(20).cast::<usize>();
+                                    let base17 = l15;
+                                    let len17 = l16;
+                                    _rt::cabi_dealloc(base17, len17 * 1, 1);
+                                }
+                                15 => {
+                                    let l18 = *arg0.add(16).cast::<*mut u8>();
+                                    let l19 = *arg0.add(20).cast::<usize>();
+                                    let base20 = l18;
+                                    let len20 = l19;
+                                    _rt::cabi_dealloc(base20, len20 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_create_transaction_cabi<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                    arg4: i64,
+                    arg5: i32,
+                    arg6: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let result1 = T::create_transaction(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                        arg3 as u64,
+                        arg4 as u64,
+                        match arg5 {
+                            0 => None,
+                            1 => {
+                                let e = arg6 as u32;
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec3 = (e).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *ptr2.add(12).cast::<usize>() = len3;
+                            *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(20).cast::<usize>() = len4;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid5,
+                                        vout: vout5,
+                                    } = e;
+                                    let vec6 = (txid5).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2.add(20).cast::<usize>() = len6;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout5);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid7,
+                                        vout: vout7,
+                                    } = e;
+                                    let vec8 = (txid7).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr2.add(20).cast::<usize>() = len8;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout7);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr2.add(20).cast::<usize>() = len9;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec10 = (e).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr2.add(20).cast::<usize>() = len10;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec11 = (e).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr2.add(20).cast::<usize>() = len11;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_create_transaction<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 1, 1);
+                        }
+                        _ => {
+                            let l4 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l4 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l5 = *arg0.add(16).cast::<*mut u8>();
+                                    let l6 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                12 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                13 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                14 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                15 => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0.add(20).cast::<usize>();
+                                    let base21 = l19;
+                                    let len21 = l20;
+                                    _rt::cabi_dealloc(base21, len21 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_create_batch_transaction_cabi<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                    arg4: *mut u8,
+                    arg5: usize,
+                    arg6: i32,
+                    arg7: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let base4 = arg1;
+                    let len4 = arg2;
+                    let mut result4 = _rt::Vec::with_capacity(len4);
+                    for i in 0..len4 {
+                        let base = base4.add(i * 16);
+                        let e4 = {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            let len2 = l1;
+                            let l3 = *base.add(8).cast::<i64>();
+
+                            Recipient {
+                                script: _rt::Vec::from_raw_parts(l0.cast(), len2, len2),
+                                amount: l3 as u64,
+                            }
+                        };
+                        result4.push(e4);
+                    }
+                    _rt::cabi_dealloc(base4, len4 * 16, 8);
+                    let base9 = arg4;
+                    let len9 = arg5;
+                    let mut result9 = _rt::Vec::with_capacity(len9);
+                    for i in 0..len9 {
+                        let base = base9.add(i * 12);
+                        let e9 = {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base.add(4).cast::<usize>();
+                            let len7 = l6;
+                            let l8 = *base.add(8).cast::<i32>();
+
+                            Outpoint {
+                                txid: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                                vout: l8 as u32,
+                            }
+                        };
+                        result9.push(e9);
+                    }
+                    _rt::cabi_dealloc(base9, len9 * 12, 4);
+                    let result10 = T::create_batch_transaction(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        result4,
+                        arg3 as u64,
+                        result9,
+                        match arg6 {
+                            0 => None,
+                            1 => {
+                                let e = arg7 as u32;
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr11 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result10 {
+                        Ok(e) => {
+                            *ptr11.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec12 = (e).into_boxed_slice();
+                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                            let len12 = vec12.len();
+                            ::core::mem::forget(vec12);
+                            *ptr11.add(12).cast::<usize>() = len12;
+                            *ptr11.add(8).cast::<*mut u8>() = ptr12.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr11.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr11.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr11.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr11.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr11.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr11.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr11.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr11.add(20).cast::<usize>() = len13;
+                                    *ptr11.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr11.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr11.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr11.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr11.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr11.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr11.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr11.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr11.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid14,
+                                        vout: vout14,
+                                    } = e;
+                                    let vec15 = (txid14).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr11.add(20).cast::<usize>() = len15;
+                                    *ptr11.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                    *ptr11.add(24).cast::<i32>() = _rt::as_i32(vout14);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr11.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid16,
+                                        vout: vout16,
+                                    } = e;
+                                    let vec17 = (txid16).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr11.add(20).cast::<usize>() = len17;
+                                    *ptr11.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                    *ptr11.add(24).cast::<i32>() = _rt::as_i32(vout16);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr11.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec18 = (e).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr11.add(20).cast::<usize>() = len18;
+                                    *ptr11.add(16).cast::<*mut u8>() = ptr18.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr11.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec19 = (e).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr11.add(20).cast::<usize>() = len19;
+                                    *ptr11.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr11.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec20 = (e).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr11.add(20).cast::<usize>() = len20;
+                                    *ptr11.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr11.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr11.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr11.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr11.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr11.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr11.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr11
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_create_batch_transaction<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 1, 1);
+                        }
+                        _ => {
+                            let l4 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l4 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l5 = *arg0.add(16).cast::<*mut u8>();
+                                    let l6 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                12 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                13 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                14 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                15 => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0.add(20).cast::<usize>();
+                                    let base21 = l19;
+                                    let len21 = l20;
+                                    _rt::cabi_dealloc(base21, len21 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_create_drain_transaction_cabi<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                    arg4: i32,
+                    arg5: *mut u8,
+                    arg6: usize,
+                    arg7: i32,
+                    arg8: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let result6 = T::create_drain_transaction(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                        arg3 as u64,
+                        match arg4 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let base5 = arg5;
+                                    let len5 = arg6;
+                                    let mut result5 = _rt::Vec::with_capacity(len5);
+                                    for i in 0..len5 {
+                                        let base = base5.add(i * 12);
+                                        let e5 = {
+                                            let l1 = *base.add(0).cast::<*mut u8>();
+                                            let l2 = *base.add(4).cast::<usize>();
+                                            let len3 = l2;
+                                            let l4 = *base.add(8).cast::<i32>();
+
+                                            Outpoint {
+                                                txid: _rt::Vec::from_raw_parts(
+                                                    l1.cast(),
+                                                    len3,
+                                                    len3,
+                                                ),
+                                                vout: l4 as u32,
+                                            }
+                                        };
+                                        result5.push(e5);
+                                    }
+                                    _rt::cabi_dealloc(base5, len5 * 12, 4);
+
+                                    result5
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        match arg7 {
+                            0 => None,
+                            1 => {
+                                let e = arg8 as u32;
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr7 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result6 {
+                        Ok(e) => {
+                            *ptr7.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec8 = (e).into_boxed_slice();
+                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                            let len8 = vec8.len();
+                            ::core::mem::forget(vec8);
+                            *ptr7.add(12).cast::<usize>() = len8;
+                            *ptr7.add(8).cast::<*mut u8>() = ptr8.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr7.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr7.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr7.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr7.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr7.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr7.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr7.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr7.add(20).cast::<usize>() = len9;
+                                    *ptr7.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr7.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr7.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr7.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr7.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr7.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr7.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr7.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr7.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid10,
+                                        vout: vout10,
+                                    } = e;
+                                    let vec11 = (txid10).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr7.add(20).cast::<usize>() = len11;
+                                    *ptr7.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                    *ptr7.add(24).cast::<i32>() = _rt::as_i32(vout10);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr7.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid12,
+                                        vout: vout12,
+                                    } = e;
+                                    let vec13 = (txid12).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr7.add(20).cast::<usize>() = len13;
+                                    *ptr7.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                    *ptr7.add(24).cast::<i32>() = _rt::as_i32(vout12);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr7.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec14 = (e).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr7.add(20).cast::<usize>() = len14;
+                                    *ptr7.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr7.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec15 = (e).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr7.add(20).cast::<usize>() = len15;
+                                    *ptr7.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr7.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec16 = (e).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *ptr7.add(20).cast::<usize>() = len16;
+                                    *ptr7.add(16).cast::<*mut u8>() = ptr16.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr7.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr7.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr7.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr7.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr7.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr7.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr7
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_create_drain_transaction<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 1, 1);
+                        }
+                        _ => {
+                            let l4 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l4 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l5 = *arg0.add(16).cast::<*mut u8>();
+                                    let l6 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                12 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                13 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                14 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                15 => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0.add(20).cast::<usize>();
+                                    let base21 = l19;
+                                    let len21 = l20;
+                                    _rt::cabi_dealloc(base21, len21 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_create_bump_fee_transaction_cabi<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let result1 = T::create_bump_fee_transaction(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                        arg3 as u64,
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec3 = (e).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *ptr2.add(12).cast::<usize>() = len3;
+                            *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(20).cast::<usize>() = len4;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid5,
+                                        vout: vout5,
+                                    } = e;
+                                    let vec6 = (txid5).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2.add(20).cast::<usize>() = len6;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout5);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid7,
+                                        vout: vout7,
+                                    } = e;
+                                    let vec8 = (txid7).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr2.add(20).cast::<usize>() = len8;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout7);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr2.add(20).cast::<usize>() = len9;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec10 = (e).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr2.add(20).cast::<usize>() = len10;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec11 = (e).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr2.add(20).cast::<usize>() = len11;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_create_bump_fee_transaction<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 1, 1);
+                        }
+                        _ => {
+                            let l4 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l4 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l5 = *arg0.add(16).cast::<*mut u8>();
+                                    let l6 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                12 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                13 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                14 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                15 => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0.add(20).cast::<usize>();
+                                    let base21 = l19;
+                                    let len21 = l20;
+                                    _rt::cabi_dealloc(base21, len21 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_create_cpfp_transaction_cabi<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let result1 = T::create_cpfp_transaction(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                        arg3 as u64,
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec3 = (e).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *ptr2.add(12).cast::<usize>() = len3;
+                            *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(20).cast::<usize>() = len4;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid5,
+                                        vout: vout5,
+                                    } = e;
+                                    let vec6 = (txid5).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2.add(20).cast::<usize>() = len6;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout5);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid7,
+                                        vout: vout7,
+                                    } = e;
+                                    let vec8 = (txid7).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr2.add(20).cast::<usize>() = len8;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout7);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr2.add(20).cast::<usize>() = len9;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec10 = (e).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr2.add(20).cast::<usize>() = len10;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec11 = (e).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr2.add(20).cast::<usize>() = len11;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_create_cpfp_transaction<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 1, 1);
+                        }
+                        _ => {
+                            let l4 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l4 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l5 = *arg0.add(16).cast::<*mut u8>();
+                                    let l6 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                12 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                13 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                14 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                15 => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0.add(20).cast::<usize>();
+                                    let base21 = l19;
+                                    let len21 = l20;
+                                    _rt::cabi_dealloc(base21, len21 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_get_utxos_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::get_utxos(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec5 = e;
+                            let len5 = vec5.len();
+                            let layout5 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec5.len() * 48, 8);
+                            let result5 = if layout5.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout5);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec5.into_iter().enumerate() {
+                                let base = result5.add(i * 48);
+                                {
+                                    let PartialUtxo {
+                                        txid: txid2,
+                                        vout: vout2,
+                                        amount: amount2,
+                                        script: script2,
+                                        is_spent: is_spent2,
+                                        height: height2,
+                                        is_coinbase: is_coinbase2,
+                                    } = e;
+                                    let vec3 = (txid2).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *base.add(4).cast::<usize>() = len3;
+                                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(vout2);
+                                    *base.add(16).cast::<i64>() = _rt::as_i64(amount2);
+                                    let vec4 = (script2).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *base.add(28).cast::<usize>() = len4;
+                                    *base.add(24).cast::<*mut u8>() = ptr4.cast_mut();
+                                    *base.add(32).cast::<u8>() = (match is_spent2 {
+                                        true => 1,
+                                        false => 0,
+                                    })
+                                        as u8;
+                                    match height2 {
+                                        Some(e) => {
+                                            *base.add(36).cast::<u8>() = (1i32) as u8;
+                                            *base.add(40).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(36).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base.add(44).cast::<u8>() = (match is_coinbase2 {
+                                        true => 1,
+                                        false => 0,
+                                    })
+                                        as u8;
+                                }
+                            }
+                            *ptr1.add(12).cast::<usize>() = len5;
+                            *ptr1.add(8).cast::<*mut u8>() = result5;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr1.add(20).cast::<usize>() = len6;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid7,
+                                        vout: vout7,
+                                    } = e;
+                                    let vec8 = (txid7).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr1.add(20).cast::<usize>() = len8;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout7);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid9,
+                                        vout: vout9,
+                                    } = e;
+                                    let vec10 = (txid9).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr1.add(20).cast::<usize>() = len10;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout9);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec11 = (e).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr1.add(20).cast::<usize>() = len11;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec12 = (e).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr1.add(20).cast::<usize>() = len12;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr12.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec13 = (e).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr1.add(20).cast::<usize>() = len13;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_get_utxos<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l7 = *arg0.add(8).cast::<*mut u8>();
+                            let l8 = *arg0.add(12).cast::<usize>();
+                            let base9 = l7;
+                            let len9 = l8;
+                            for i in 0..len9 {
+                                let base = base9.add(i * 48);
+                                {
+                                    let l1 = *base.add(0).cast::<*mut u8>();
+                                    let l2 = *base.add(4).cast::<usize>();
+                                    let base3 = l1;
+                                    let len3 = l2;
+                                    _rt::cabi_dealloc(base3, len3 * 1, 1);
+                                    let l4 = *base.add(24).cast::<*mut u8>();
+                                    let l5 = *base.add(28).cast::<usize>();
+                                    let base6 = l4;
+                                    let len6 = l5;
+                                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base9, len9 * 48, 8);
+                        }
+                        _ => {
+                            let l10 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l10 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l11 = *arg0.add(16).cast::<*mut u8>();
+                                    let l12 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l11, l12, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                12 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                13 => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0.add(20).cast::<usize>();
+                                    let base21 = l19;
+                                    let len21 = l20;
+                                    _rt::cabi_dealloc(base21, len21 * 1, 1);
+                                }
+                                14 => {
+                                    let l22 = *arg0.add(16).cast::<*mut u8>();
+                                    let l23 = *arg0.add(20).cast::<usize>();
+                                    let base24 = l22;
+                                    let len24 = l23;
+                                    _rt::cabi_dealloc(base24, len24 * 1, 1);
+                                }
+                                15 => {
+                                    let l25 = *arg0.add(16).cast::<*mut u8>();
+                                    let l26 = *arg0.add(20).cast::<usize>();
+                                    let base27 = l25;
+                                    let len27 = l26;
+                                    _rt::cabi_dealloc(base27, len27 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_list_unspent_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 =
+                        T::list_unspent(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec6 = result0;
+                    let len6 = vec6.len();
+                    let layout6 = _rt::alloc::Layout::from_size_align_unchecked(vec6.len() * 56, 8);
+                    let result6 = if layout6.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout6);
+                        }
+                        ptr
+                    } else {
+                        {
+                            ::core::ptr::null_mut()
+                        }
+                    };
+                    for (i, e) in vec6.into_iter().enumerate() {
+                        let base = result6.add(i * 56);
+                        {
+                            let UnspentUtxo {
+                                outpoint: outpoint2,
+                                amount: amount2,
+                                script: script2,
+                                keychain: keychain2,
+                                derivation_index: derivation_index2,
+                                height: height2,
+                                confirmations: confirmations2,
+                                is_coinbase: is_coinbase2,
+                                is_frozen: is_frozen2,
+                            } = e;
+                            let Outpoint {
+                                txid: txid3,
+                                vout: vout3,
+                            } = outpoint2;
+                            let vec4 = (txid3).into_boxed_slice();
+                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                            let len4 = vec4.len();
+                            ::core::mem::forget(vec4);
+                            *base.add(4).cast::<usize>() = len4;
+                            *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                            *base.add(8).cast::<i32>() = _rt::as_i32(vout3);
+                            *base.add(16).cast::<i64>() = _rt::as_i64(amount2);
+                            let vec5 = (script2).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *base.add(28).cast::<usize>() = len5;
+                            *base.add(24).cast::<*mut u8>() = ptr5.cast_mut();
+                            *base.add(32).cast::<u8>() = (keychain2.clone() as i32) as u8;
+                            *base.add(36).cast::<i32>() = _rt::as_i32(derivation_index2);
+                            match height2 {
+                                Some(e) => {
+                                    *base.add(40).cast::<u8>() = (1i32) as u8;
+                                    *base.add(44).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                None => {
+                                    *base.add(40).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            *base.add(48).cast::<i32>() = _rt::as_i32(confirmations2);
+                            *base.add(52).cast::<u8>() = (match is_coinbase2 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *base.add(53).cast::<u8>() = (match is_frozen2 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                        }
+                    }
+                    *ptr1.add(4).cast::<usize>() = len6;
+                    *ptr1.add(0).cast::<*mut u8>() = result6;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_list_unspent<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) {
+                    let l6 = *arg0.add(0).cast::<*mut u8>();
+                    let l7 = *arg0.add(4).cast::<usize>();
+                    let base8 = l6;
+                    let len8 = l7;
+                    for i in 0..len8 {
+                        let base = base8.add(i * 56);
+                        {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            let base2 = l0;
+                            let len2 = l1;
+                            _rt::cabi_dealloc(base2, len2 * 1, 1);
+                            let l3 = *base.add(24).cast::<*mut u8>();
+                            let l4 = *base.add(28).cast::<usize>();
+                            let base5 = l3;
+                            let len5 = l4;
+                            _rt::cabi_dealloc(base5, len5 * 1, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(base8, len8 * 56, 8);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_freeze_utxo_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let result1 = T::freeze_utxo(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        Outpoint {
+                            txid: _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                            vout: arg3 as u32,
+                        },
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr2.add(20).cast::<usize>() = len3;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid4,
+                                        vout: vout4,
+                                    } = e;
+                                    let vec5 = (txid4).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr2.add(20).cast::<usize>() = len5;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout4);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid6,
+                                        vout: vout6,
+                                    } = e;
+                                    let vec7 = (txid6).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr2.add(20).cast::<usize>() = len7;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout6);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec8 = (e).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr2.add(20).cast::<usize>() = len8;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr2.add(20).cast::<usize>() = len9;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec10 = (e).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr2.add(20).cast::<usize>() = len10;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_freeze_utxo<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    let base6 = l4;
+                                    let len6 = l5;
+                                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                                }
+                                12 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                13 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                14 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                15 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_unfreeze_utxo_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let result1 = T::unfreeze_utxo(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        Outpoint {
+                            txid: _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                            vout: arg3 as u32,
+                        },
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr2.add(20).cast::<usize>() = len3;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid4,
+                                        vout: vout4,
+                                    } = e;
+                                    let vec5 = (txid4).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr2.add(20).cast::<usize>() = len5;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout4);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid6,
+                                        vout: vout6,
+                                    } = e;
+                                    let vec7 = (txid6).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr2.add(20).cast::<usize>() = len7;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout6);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec8 = (e).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr2.add(20).cast::<usize>() = len8;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr2.add(20).cast::<usize>() = len9;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec10 = (e).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr2.add(20).cast::<usize>() = len10;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_unfreeze_utxo<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    let base6 = l4;
+                                    let len6 = l5;
+                                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                                }
+                                12 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                13 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                14 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                15 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_insert_utxos_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let base12 = arg1;
+                    let len12 = arg2;
+                    let mut result12 = _rt::Vec::with_capacity(len12);
+                    for i in 0..len12 {
+                        let base = base12.add(i * 48);
+                        let e12 = {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            let len2 = l1;
+                            let l3 = *base.add(8).cast::<i32>();
+                            let l4 = *base.add(16).cast::<i64>();
+                            let l5 = *base.add(24).cast::<*mut u8>();
+                            let l6 = *base.add(28).cast::<usize>();
+                            let len7 = l6;
+                            let l8 = i32::from(*base.add(32).cast::<u8>());
+                            let l9 = i32::from(*base.add(36).cast::<u8>());
+                            let l11 = i32::from(*base.add(44).cast::<u8>());
+
+                            PartialUtxo {
+                                txid: _rt::Vec::from_raw_parts(l0.cast(), len2, len2),
+                                vout: l3 as u32,
+                                amount: l4 as u64,
+                                script: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                                is_spent: _rt::bool_lift(l8 as u8),
+                                height: match l9 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l10 = *base.add(40).cast::<i32>();
+
+                                            l10 as u32
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                is_coinbase: _rt::bool_lift(l11 as u8),
+                            }
+                        };
+                        result12.push(e12);
+                    }
+                    _rt::cabi_dealloc(base12, len12 * 48, 8);
+                    let result13 = T::insert_utxos(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        result12,
+                    );
+                    let ptr14 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result13 {
+                        Ok(_) => {
+                            *ptr14.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr14.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr14.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr14.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr14.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr14.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr14.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr14.add(20).cast::<usize>() = len15;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr14.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr14.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr14.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr14.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr14.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid16,
+                                        vout: vout16,
+                                    } = e;
+                                    let vec17 = (txid16).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr14.add(20).cast::<usize>() = len17;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                    *ptr14.add(24).cast::<i32>() = _rt::as_i32(vout16);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid18,
+                                        vout: vout18,
+                                    } = e;
+                                    let vec19 = (txid18).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr14.add(20).cast::<usize>() = len19;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                    *ptr14.add(24).cast::<i32>() = _rt::as_i32(vout18);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec20 = (e).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr14.add(20).cast::<usize>() = len20;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec21 = (e).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *ptr14.add(20).cast::<usize>() = len21;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr21.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec22 = (e).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr14.add(20).cast::<usize>() = len22;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr22.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr14.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr14.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr14.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr14
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_insert_utxos<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    let base6 = l4;
+                                    let len6 = l5;
+                                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                                }
+                                12 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                13 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                14 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                15 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_insert_unconfirmed_utxos_cabi<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let base12 = arg1;
+                    let len12 = arg2;
+                    let mut result12 = _rt::Vec::with_capacity(len12);
+                    for i in 0..len12 {
+                        let base = base12.add(i * 48);
+                        let e12 = {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            let len2 = l1;
+                            let l3 = *base.add(8).cast::<i32>();
+                            let l4 = *base.add(16).cast::<i64>();
+                            let l5 = *base.add(24).cast::<*mut u8>();
+                            let l6 = *base.add(28).cast::<usize>();
+                            let len7 = l6;
+                            let l8 = i32::from(*base.add(32).cast::<u8>());
+                            let l9 = i32::from(*base.add(36).cast::<u8>());
+                            let l11 = i32::from(*base.add(44).cast::<u8>());
+
+                            PartialUtxo {
+                                txid: _rt::Vec::from_raw_parts(l0.cast(), len2, len2),
+                                vout: l3 as u32,
+                                amount: l4 as u64,
+                                script: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                                is_spent: _rt::bool_lift(l8 as u8),
+                                height: match l9 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l10 = *base.add(40).cast::<i32>();
+
+                                            l10 as u32
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                is_coinbase: _rt::bool_lift(l11 as u8),
+                            }
+                        };
+                        result12.push(e12);
+                    }
+                    _rt::cabi_dealloc(base12, len12 * 48, 8);
+                    let result13 = T::insert_unconfirmed_utxos(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        result12,
+                    );
+                    let ptr14 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result13 {
+                        Ok(_) => {
+                            *ptr14.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr14.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr14.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr14.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr14.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr14.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr14.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr14.add(20).cast::<usize>() = len15;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr14.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr14.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr14.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr14.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr14.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid16,
+                                        vout: vout16,
+                                    } = e;
+                                    let vec17 = (txid16).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr14.add(20).cast::<usize>() = len17;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                    *ptr14.add(24).cast::<i32>() = _rt::as_i32(vout16);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid18,
+                                        vout: vout18,
+                                    } = e;
+                                    let vec19 = (txid18).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr14.add(20).cast::<usize>() = len19;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                    *ptr14.add(24).cast::<i32>() = _rt::as_i32(vout18);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec20 = (e).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr14.add(20).cast::<usize>() = len20;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec21 = (e).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *ptr14.add(20).cast::<usize>() = len21;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr21.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec22 = (e).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr14.add(20).cast::<usize>() = len22;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr22.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr14.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr14.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr14.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr14
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_insert_unconfirmed_utxos<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    let base6 = l4;
+                                    let len6 = l5;
+                                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                                }
+                                12 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                13 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                14 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                15 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_remove_utxos_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let base12 = arg1;
+                    let len12 = arg2;
+                    let mut result12 = _rt::Vec::with_capacity(len12);
+                    for i in 0..len12 {
+                        let base = base12.add(i * 48);
+                        let e12 = {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            let len2 = l1;
+                            let l3 = *base.add(8).cast::<i32>();
+                            let l4 = *base.add(16).cast::<i64>();
+                            let l5 = *base.add(24).cast::<*mut u8>();
+                            let l6 = *base.add(28).cast::<usize>();
+                            let len7 = l6;
+                            let l8 = i32::from(*base.add(32).cast::<u8>());
+                            let l9 = i32::from(*base.add(36).cast::<u8>());
+                            let l11 = i32::from(*base.add(44).cast::<u8>());
+
+                            PartialUtxo {
+                                txid: _rt::Vec::from_raw_parts(l0.cast(), len2, len2),
+                                vout: l3 as u32,
+                                amount: l4 as u64,
+                                script: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                                is_spent: _rt::bool_lift(l8 as u8),
+                                height: match l9 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l10 = *base.add(40).cast::<i32>();
+
+                                            l10 as u32
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                is_coinbase: _rt::bool_lift(l11 as u8),
+                            }
+                        };
+                        result12.push(e12);
+                    }
+                    _rt::cabi_dealloc(base12, len12 * 48, 8);
+                    let result13 = T::remove_utxos(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        result12,
+                    );
+                    let ptr14 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result13 {
+                        Ok(_) => {
+                            *ptr14.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr14.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr14.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr14.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr14.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr14.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr14.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr14.add(20).cast::<usize>() = len15;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr14.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr14.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr14.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr14.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr14.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid16,
+                                        vout: vout16,
+                                    } = e;
+                                    let vec17 = (txid16).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr14.add(20).cast::<usize>() = len17;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                    *ptr14.add(24).cast::<i32>() = _rt::as_i32(vout16);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid18,
+                                        vout: vout18,
+                                    } = e;
+                                    let vec19 = (txid18).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr14.add(20).cast::<usize>() = len19;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                    *ptr14.add(24).cast::<i32>() = _rt::as_i32(vout18);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec20 = (e).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr14.add(20).cast::<usize>() = len20;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec21 = (e).into_boxed_slice();
+                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                    let len21 = vec21.len();
+                                    ::core::mem::forget(vec21);
+                                    *ptr14.add(20).cast::<usize>() = len21;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr21.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec22 = (e).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr14.add(20).cast::<usize>() = len22;
+                                    *ptr14.add(16).cast::<*mut u8>() = ptr22.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr14.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr14.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr14.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr14.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr14
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_remove_utxos<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    let base6 = l4;
+                                    let len6 = l5;
+                                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                                }
+                                12 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                13 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                14 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                15 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_mark_pending_spent_cabi<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let result1 = T::mark_pending_spent(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr2.add(20).cast::<usize>() = len3;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid4,
+                                        vout: vout4,
+                                    } = e;
+                                    let vec5 = (txid4).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr2.add(20).cast::<usize>() = len5;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout4);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid6,
+                                        vout: vout6,
+                                    } = e;
+                                    let vec7 = (txid6).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr2.add(20).cast::<usize>() = len7;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout6);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec8 = (e).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr2.add(20).cast::<usize>() = len8;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr2.add(20).cast::<usize>() = len9;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec10 = (e).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr2.add(20).cast::<usize>() = len10;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_mark_pending_spent<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    let base6 = l4;
+                                    let len6 = l5;
+                                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                                }
+                                12 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                13 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                14 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                15 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_release_pending_spent_cabi<
+                    T: GuestWatchOnly,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    T::release_pending_spent(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_get_pubkeys_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::get_pubkeys(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec3 = e;
+                            let len3 = vec3.len();
+                            let layout3 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec3.len() * 8, 4);
+                            let result3 = if layout3.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout3);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec3.into_iter().enumerate() {
+                                let base = result3.add(i * 8);
+                                {
+                                    let vec2 = (e).into_boxed_slice();
+                                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                                    let len2 = vec2.len();
+                                    ::core::mem::forget(vec2);
+                                    *base.add(4).cast::<usize>() = len2;
+                                    *base.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                                }
+                            }
+                            *ptr1.add(12).cast::<usize>() = len3;
+                            *ptr1.add(8).cast::<*mut u8>() = result3;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            match e {
+                                Error::CoinSelection => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                                Error::Psbt => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                }
+                                Error::MissingNonWitnessUtxo => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                }
+                                Error::NoPubkey => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                }
+                                Error::PubkeyError => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr1.add(20).cast::<usize>() = len4;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid5,
+                                        vout: vout5,
+                                    } = e;
+                                    let vec6 = (txid5).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr1.add(20).cast::<usize>() = len6;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout5);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid7,
+                                        vout: vout7,
+                                    } = e;
+                                    let vec8 = (txid7).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr1.add(20).cast::<usize>() = len8;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout7);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr1.add(20).cast::<usize>() = len9;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec10 = (e).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr1.add(20).cast::<usize>() = len10;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec11 = (e).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr1.add(20).cast::<usize>() = len11;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_get_pubkeys<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l4 = *arg0.add(8).cast::<*mut u8>();
+                            let l5 = *arg0.add(12).cast::<usize>();
+                            let base6 = l4;
+                            let len6 = l5;
+                            for i in 0..len6 {
+                                let base = base6.add(i * 8);
+                                {
+                                    let l1 = *base.add(0).cast::<*mut u8>();
+                                    let l2 = *base.add(4).cast::<usize>();
+                                    let base3 = l1;
+                                    let len3 = l2;
+                                    _rt::cabi_dealloc(base3, len3 * 1, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base6, len6 * 8, 4);
+                        }
+                        _ => {
+                            let l7 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l7 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l8 = *arg0.add(16).cast::<*mut u8>();
+                                    let l9 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                12 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                13 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                14 => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0.add(20).cast::<usize>();
+                                    let base21 = l19;
+                                    let len21 = l20;
+                                    _rt::cabi_dealloc(base21, len21 * 1, 1);
+                                }
+                                15 => {
+                                    let l22 = *arg0.add(16).cast::<*mut u8>();
+                                    let l23 = *arg0.add(20).cast::<usize>();
+                                    let base24 = l22;
+                                    let len24 = l23;
+                                    _rt::cabi_dealloc(base24, len24 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_get_keychain_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let result1 = T::get_keychain(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Some(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(1).cast::<u8>() = (e.clone() as i32) as u8;
+                        }
+                        None => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_get_state_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::get_state(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let vec2 = (result0).into_boxed_slice();
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    ::core::mem::forget(vec2);
+                    *ptr1.add(4).cast::<usize>() = len2;
+                    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_get_state<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 1, 1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_get_descriptors_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 =
+                        T::get_descriptors(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    let Descriptors {
+                        external: external2,
+                        internal: internal2,
+                    } = result0;
+                    let vec3 = (external2.into_bytes()).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *ptr1.add(4).cast::<usize>() = len3;
+                    *ptr1.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                    match internal2 {
+                        Some(e) => {
+                            *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                            let vec4 = (e.into_bytes()).into_boxed_slice();
+                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                            let len4 = vec4.len();
+                            ::core::mem::forget(vec4);
+                            *ptr1.add(16).cast::<usize>() = len4;
+                            *ptr1.add(12).cast::<*mut u8>() = ptr4.cast_mut();
+                        }
+                        None => {
+                            *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_watch_only_get_descriptors<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                    let l2 = i32::from(*arg0.add(8).cast::<u8>());
+                    match l2 {
+                        0 => (),
+                        _ => {
+                            let l3 = *arg0.add(12).cast::<*mut u8>();
+                            let l4 = *arg0.add(16).cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
                         }
-                        _ => (),
                     }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_watch_only_create_transaction_cabi<
-                    T: GuestWatchOnly,
-                >(
+                pub unsafe fn _export_method_watch_only_balance_cabi<T: GuestWatchOnly>(
                     arg0: *mut u8,
-                    arg1: *mut u8,
-                    arg2: usize,
-                    arg3: i64,
-                    arg4: i64,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let len0 = arg2;
-                    let result1 = T::create_transaction(
-                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
-                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
-                        arg3 as u64,
-                        arg4 as u64,
-                    );
-                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    match result1 {
+                    let result0 = T::balance(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
                         Ok(e) => {
-                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
-                            let vec3 = (e).into_boxed_slice();
-                            let ptr3 = vec3.as_ptr().cast::<u8>();
-                            let len3 = vec3.len();
-                            ::core::mem::forget(vec3);
-                            *ptr2.add(8).cast::<usize>() = len3;
-                            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr1.add(8).cast::<i64>() = _rt::as_i64(e);
                         }
                         Err(e) => {
-                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
                             match e {
                                 Error::CoinSelection => {
-                                    *ptr2.add(4).cast::<u8>() = (0i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
                                 }
                                 Error::Psbt => {
-                                    *ptr2.add(4).cast::<u8>() = (1i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
                                 }
                                 Error::MissingNonWitnessUtxo => {
-                                    *ptr2.add(4).cast::<u8>() = (2i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
                                 }
                                 Error::NoPubkey => {
-                                    *ptr2.add(4).cast::<u8>() = (3i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
                                 }
                                 Error::PubkeyError => {
-                                    *ptr2.add(4).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec2 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                                    let len2 = vec2.len();
+                                    ::core::mem::forget(vec2);
+                                    *ptr1.add(20).cast::<usize>() = len2;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr2.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid3,
+                                        vout: vout3,
+                                    } = e;
+                                    let vec4 = (txid3).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr1.add(20).cast::<usize>() = len4;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout3);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid5,
+                                        vout: vout5,
+                                    } = e;
+                                    let vec6 = (txid5).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr1.add(20).cast::<usize>() = len6;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout5);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec7 = (e).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr1.add(20).cast::<usize>() = len7;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec8 = (e).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr1.add(20).cast::<usize>() = len8;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr1.add(20).cast::<usize>() = len9;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
                                 }
                             }
                         }
                     };
-                    ptr2
+                    ptr1
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_watch_only_create_transaction<
-                    T: GuestWatchOnly,
-                >(
+                pub unsafe fn __post_return_method_watch_only_balance<T: GuestWatchOnly>(
                     arg0: *mut u8,
                 ) {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
-                        0 => {
-                            let l1 = *arg0.add(4).cast::<*mut u8>();
-                            let l2 = *arg0.add(8).cast::<usize>();
-                            let base3 = l1;
-                            let len3 = l2;
-                            _rt::cabi_dealloc(base3, len3 * 1, 1);
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    let base6 = l4;
+                                    let len6 = l5;
+                                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                                }
+                                12 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                13 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                14 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                15 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
                         }
-                        _ => (),
                     }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_watch_only_get_utxos_cabi<T: GuestWatchOnly>(
+                pub unsafe fn _export_method_watch_only_pending_balance_cabi<T: GuestWatchOnly>(
                     arg0: *mut u8,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let result0 = T::get_utxos(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
+                    let result0 =
+                        T::pending_balance(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
                     let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
                     match result0 {
                         Ok(e) => {
                             *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                            let vec5 = e;
-                            let len5 = vec5.len();
-                            let layout5 =
-                                _rt::alloc::Layout::from_size_align_unchecked(vec5.len() * 40, 8);
-                            let result5 = if layout5.size() != 0 {
-                                let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
-                                if ptr.is_null() {
-                                    _rt::alloc::handle_alloc_error(layout5);
-                                }
-                                ptr
-                            } else {
-                                {
-                                    ::core::ptr::null_mut()
-                                }
-                            };
-                            for (i, e) in vec5.into_iter().enumerate() {
-                                let base = result5.add(i * 40);
-                                {
-                                    let PartialUtxo {
-                                        txid: txid2,
-                                        vout: vout2,
-                                        amount: amount2,
-                                        script: script2,
-                                        is_spent: is_spent2,
-                                    } = e;
-                                    let vec3 = (txid2).into_boxed_slice();
-                                    let ptr3 = vec3.as_ptr().cast::<u8>();
-                                    let len3 = vec3.len();
-                                    ::core::mem::forget(vec3);
-                                    *base.add(4).cast::<usize>() = len3;
-                                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
-                                    *base.add(8).cast::<i32>() = _rt::as_i32(vout2);
-                                    *base.add(16).cast::<i64>() = _rt::as_i64(amount2);
-                                    let vec4 = (script2).into_boxed_slice();
-                                    let ptr4 = vec4.as_ptr().cast::<u8>();
-                                    let len4 = vec4.len();
-                                    ::core::mem::forget(vec4);
-                                    *base.add(28).cast::<usize>() = len4;
-                                    *base.add(24).cast::<*mut u8>() = ptr4.cast_mut();
-                                    *base.add(32).cast::<u8>() = (match is_spent2 {
-                                        true => 1,
-                                        false => 0,
-                                    })
-                                        as u8;
-                                }
-                            }
-                            *ptr1.add(8).cast::<usize>() = len5;
-                            *ptr1.add(4).cast::<*mut u8>() = result5;
+                            *ptr1.add(8).cast::<i64>() = _rt::as_i64(e);
                         }
                         Err(e) => {
                             *ptr1.add(0).cast::<u8>() = (1i32) as u8;
                             match e {
                                 Error::CoinSelection => {
-                                    *ptr1.add(4).cast::<u8>() = (0i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
                                 }
                                 Error::Psbt => {
-                                    *ptr1.add(4).cast::<u8>() = (1i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
                                 }
                                 Error::MissingNonWitnessUtxo => {
-                                    *ptr1.add(4).cast::<u8>() = (2i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
                                 }
                                 Error::NoPubkey => {
-                                    *ptr1.add(4).cast::<u8>() = (3i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
                                 }
                                 Error::PubkeyError => {
-                                    *ptr1.add(4).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec2 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                                    let len2 = vec2.len();
+                                    ::core::mem::forget(vec2);
+                                    *ptr1.add(20).cast::<usize>() = len2;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr2.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid3,
+                                        vout: vout3,
+                                    } = e;
+                                    let vec4 = (txid3).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr1.add(20).cast::<usize>() = len4;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout3);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid5,
+                                        vout: vout5,
+                                    } = e;
+                                    let vec6 = (txid5).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr1.add(20).cast::<usize>() = len6;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout5);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec7 = (e).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr1.add(20).cast::<usize>() = len7;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec8 = (e).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr1.add(20).cast::<usize>() = len8;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr1.add(20).cast::<usize>() = len9;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
                                 }
                             }
                         }
@@ -549,160 +5134,208 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_watch_only_get_utxos<T: GuestWatchOnly>(
+                pub unsafe fn __post_return_method_watch_only_pending_balance<T: GuestWatchOnly>(
                     arg0: *mut u8,
                 ) {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
-                        0 => {
-                            let l7 = *arg0.add(4).cast::<*mut u8>();
-                            let l8 = *arg0.add(8).cast::<usize>();
-                            let base9 = l7;
-                            let len9 = l8;
-                            for i in 0..len9 {
-                                let base = base9.add(i * 40);
-                                {
-                                    let l1 = *base.add(0).cast::<*mut u8>();
-                                    let l2 = *base.add(4).cast::<usize>();
-                                    let base3 = l1;
-                                    let len3 = l2;
-                                    _rt::cabi_dealloc(base3, len3 * 1, 1);
-                                    let l4 = *base.add(24).cast::<*mut u8>();
-                                    let l5 = *base.add(28).cast::<usize>();
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
                                     let base6 = l4;
                                     let len6 = l5;
                                     _rt::cabi_dealloc(base6, len6 * 1, 1);
                                 }
+                                12 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                13 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                14 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                15 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
                             }
-                            _rt::cabi_dealloc(base9, len9 * 40, 8);
                         }
-                        _ => (),
                     }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_watch_only_insert_utxos_cabi<T: GuestWatchOnly>(
+                pub unsafe fn _export_method_watch_only_balance_breakdown_cabi<
+                    T: GuestWatchOnly,
+                >(
                     arg0: *mut u8,
-                    arg1: *mut u8,
-                    arg2: usize,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let base9 = arg1;
-                    let len9 = arg2;
-                    let mut result9 = _rt::Vec::with_capacity(len9);
-                    for i in 0..len9 {
-                        let base = base9.add(i * 40);
-                        let e9 = {
-                            let l0 = *base.add(0).cast::<*mut u8>();
-                            let l1 = *base.add(4).cast::<usize>();
-                            let len2 = l1;
-                            let l3 = *base.add(8).cast::<i32>();
-                            let l4 = *base.add(16).cast::<i64>();
-                            let l5 = *base.add(24).cast::<*mut u8>();
-                            let l6 = *base.add(28).cast::<usize>();
-                            let len7 = l6;
-                            let l8 = i32::from(*base.add(32).cast::<u8>());
-
-                            PartialUtxo {
-                                txid: _rt::Vec::from_raw_parts(l0.cast(), len2, len2),
-                                vout: l3 as u32,
-                                amount: l4 as u64,
-                                script: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
-                                is_spent: _rt::bool_lift(l8 as u8),
-                            }
-                        };
-                        result9.push(e9);
-                    }
-                    _rt::cabi_dealloc(base9, len9 * 40, 8);
-                    let result10 =
-                        T::insert_utxos(WatchOnlyBorrow::lift(arg0 as u32 as usize).get(), result9);
-                    let ptr11 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    match result10 {
-                        Ok(_) => {
-                            *ptr11.add(0).cast::<u8>() = (0i32) as u8;
+                    let result0 =
+                        T::balance_breakdown(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let Balance {
+                                confirmed: confirmed2,
+                                trusted_pending: trusted_pending2,
+                                untrusted_pending: untrusted_pending2,
+                                immature: immature2,
+                            } = e;
+                            *ptr1.add(8).cast::<i64>() = _rt::as_i64(confirmed2);
+                            *ptr1.add(16).cast::<i64>() = _rt::as_i64(trusted_pending2);
+                            *ptr1.add(24).cast::<i64>() = _rt::as_i64(untrusted_pending2);
+                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(immature2);
                         }
                         Err(e) => {
-                            *ptr11.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
                             match e {
                                 Error::CoinSelection => {
-                                    *ptr11.add(1).cast::<u8>() = (0i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
                                 }
                                 Error::Psbt => {
-                                    *ptr11.add(1).cast::<u8>() = (1i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
                                 }
                                 Error::MissingNonWitnessUtxo => {
-                                    *ptr11.add(1).cast::<u8>() = (2i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
                                 }
                                 Error::NoPubkey => {
-                                    *ptr11.add(1).cast::<u8>() = (3i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
                                 }
                                 Error::PubkeyError => {
-                                    *ptr11.add(1).cast::<u8>() = (4i32) as u8;
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
                                 }
-                            }
-                        }
-                    };
-                    ptr11
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn _export_method_watch_only_get_pubkeys_cabi<T: GuestWatchOnly>(
-                    arg0: *mut u8,
-                ) -> *mut u8 {
-                    #[cfg(target_arch = "wasm32")]
-                    _rt::run_ctors_once();
-                    let result0 = T::get_pubkeys(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
-                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    match result0 {
-                        Ok(e) => {
-                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                            let vec3 = e;
-                            let len3 = vec3.len();
-                            let layout3 =
-                                _rt::alloc::Layout::from_size_align_unchecked(vec3.len() * 8, 4);
-                            let result3 = if layout3.size() != 0 {
-                                let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
-                                if ptr.is_null() {
-                                    _rt::alloc::handle_alloc_error(layout3);
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr1.add(20).cast::<usize>() = len3;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr3.cast_mut();
                                 }
-                                ptr
-                            } else {
-                                {
-                                    ::core::ptr::null_mut()
+                                Error::MissingSignature => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid4,
+                                        vout: vout4,
+                                    } = e;
+                                    let vec5 = (txid4).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr1.add(20).cast::<usize>() = len5;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout4);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid6,
+                                        vout: vout6,
+                                    } = e;
+                                    let vec7 = (txid6).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr1.add(20).cast::<usize>() = len7;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout6);
                                 }
-                            };
-                            for (i, e) in vec3.into_iter().enumerate() {
-                                let base = result3.add(i * 8);
-                                {
-                                    let vec2 = (e).into_boxed_slice();
-                                    let ptr2 = vec2.as_ptr().cast::<u8>();
-                                    let len2 = vec2.len();
-                                    ::core::mem::forget(vec2);
-                                    *base.add(4).cast::<usize>() = len2;
-                                    *base.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec8 = (e).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr1.add(20).cast::<usize>() = len8;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr8.cast_mut();
                                 }
-                            }
-                            *ptr1.add(8).cast::<usize>() = len3;
-                            *ptr1.add(4).cast::<*mut u8>() = result3;
-                        }
-                        Err(e) => {
-                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
-                            match e {
-                                Error::CoinSelection => {
-                                    *ptr1.add(4).cast::<u8>() = (0i32) as u8;
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr1.add(20).cast::<usize>() = len9;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr9.cast_mut();
                                 }
-                                Error::Psbt => {
-                                    *ptr1.add(4).cast::<u8>() = (1i32) as u8;
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec10 = (e).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr1.add(20).cast::<usize>() = len10;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr10.cast_mut();
                                 }
-                                Error::MissingNonWitnessUtxo => {
-                                    *ptr1.add(4).cast::<u8>() = (2i32) as u8;
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
                                 }
-                                Error::NoPubkey => {
-                                    *ptr1.add(4).cast::<u8>() = (3i32) as u8;
+                                Error::FeeTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
                                 }
-                                Error::PubkeyError => {
-                                    *ptr1.add(4).cast::<u8>() = (4i32) as u8;
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
                                 }
                             }
                         }
@@ -711,72 +5344,108 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_watch_only_get_pubkeys<T: GuestWatchOnly>(
+                pub unsafe fn __post_return_method_watch_only_balance_breakdown<
+                    T: GuestWatchOnly,
+                >(
                     arg0: *mut u8,
                 ) {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
-                        0 => {
-                            let l4 = *arg0.add(4).cast::<*mut u8>();
-                            let l5 = *arg0.add(8).cast::<usize>();
-                            let base6 = l4;
-                            let len6 = l5;
-                            for i in 0..len6 {
-                                let base = base6.add(i * 8);
-                                {
-                                    let l1 = *base.add(0).cast::<*mut u8>();
-                                    let l2 = *base.add(4).cast::<usize>();
-                                    let base3 = l1;
-                                    let len3 = l2;
-                                    _rt::cabi_dealloc(base3, len3 * 1, 1);
+                        0 => (),
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    let base6 = l4;
+                                    let len6 = l5;
+                                    _rt::cabi_dealloc(base6, len6 * 1, 1);
+                                }
+                                12 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                13 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
                                 }
+                                14 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                15 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
                             }
-                            _rt::cabi_dealloc(base6, len6 * 8, 4);
                         }
-                        _ => (),
                     }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_watch_only_get_state_cabi<T: GuestWatchOnly>(
+                pub unsafe fn _export_method_watch_only_set_tip_height_cabi<T: GuestWatchOnly>(
                     arg0: *mut u8,
-                ) -> *mut u8 {
+                    arg1: i32,
+                ) {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let result0 = T::get_state(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
-                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    let vec2 = (result0).into_boxed_slice();
-                    let ptr2 = vec2.as_ptr().cast::<u8>();
-                    let len2 = vec2.len();
-                    ::core::mem::forget(vec2);
-                    *ptr1.add(4).cast::<usize>() = len2;
-                    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
-                    ptr1
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_watch_only_get_state<T: GuestWatchOnly>(
-                    arg0: *mut u8,
-                ) {
-                    let l0 = *arg0.add(0).cast::<*mut u8>();
-                    let l1 = *arg0.add(4).cast::<usize>();
-                    let base2 = l0;
-                    let len2 = l1;
-                    _rt::cabi_dealloc(base2, len2 * 1, 1);
+                    T::set_tip_height(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                    );
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_watch_only_balance_cabi<T: GuestWatchOnly>(
+                pub unsafe fn _export_method_watch_only_get_receive_address_cabi<
+                    T: GuestWatchOnly,
+                >(
                     arg0: *mut u8,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let result0 = T::balance(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
+                    let result0 =
+                        T::get_receive_address(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
                     let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
                     match result0 {
                         Ok(e) => {
                             *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                            *ptr1.add(8).cast::<i64>() = _rt::as_i64(e);
+                            let vec2 = (e.into_bytes()).into_boxed_slice();
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            ::core::mem::forget(vec2);
+                            *ptr1.add(12).cast::<usize>() = len2;
+                            *ptr1.add(8).cast::<*mut u8>() = ptr2.cast_mut();
                         }
                         Err(e) => {
                             *ptr1.add(0).cast::<u8>() = (1i32) as u8;
@@ -796,6 +5465,99 @@ pub mod exports {
                                 Error::PubkeyError => {
                                     *ptr1.add(8).cast::<u8>() = (4i32) as u8;
                                 }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr1.add(20).cast::<usize>() = len3;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr1.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr1.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid4,
+                                        vout: vout4,
+                                    } = e;
+                                    let vec5 = (txid4).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr1.add(20).cast::<usize>() = len5;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout4);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid6,
+                                        vout: vout6,
+                                    } = e;
+                                    let vec7 = (txid6).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr1.add(20).cast::<usize>() = len7;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(vout6);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec8 = (e).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr1.add(20).cast::<usize>() = len8;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr1.add(20).cast::<usize>() = len9;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec10 = (e).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr1.add(20).cast::<usize>() = len10;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr1.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
                             }
                         }
                     };
@@ -803,64 +5565,309 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_watch_only_get_receive_address_cabi<
+                pub unsafe fn __post_return_method_watch_only_get_receive_address<
                     T: GuestWatchOnly,
                 >(
                     arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                        _ => {
+                            let l3 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l3 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0.add(20).cast::<usize>();
+                                    let base8 = l6;
+                                    let len8 = l7;
+                                    _rt::cabi_dealloc(base8, len8 * 1, 1);
+                                }
+                                12 => {
+                                    let l9 = *arg0.add(16).cast::<*mut u8>();
+                                    let l10 = *arg0.add(20).cast::<usize>();
+                                    let base11 = l9;
+                                    let len11 = l10;
+                                    _rt::cabi_dealloc(base11, len11 * 1, 1);
+                                }
+                                13 => {
+                                    let l12 = *arg0.add(16).cast::<*mut u8>();
+                                    let l13 = *arg0.add(20).cast::<usize>();
+                                    let base14 = l12;
+                                    let len14 = l13;
+                                    _rt::cabi_dealloc(base14, len14 * 1, 1);
+                                }
+                                14 => {
+                                    let l15 = *arg0.add(16).cast::<*mut u8>();
+                                    let l16 = *arg0.add(20).cast::<usize>();
+                                    let base17 = l15;
+                                    let len17 = l16;
+                                    _rt::cabi_dealloc(base17, len17 * 1, 1);
+                                }
+                                15 => {
+                                    let l18 = *arg0.add(16).cast::<*mut u8>();
+                                    let l19 = *arg0.add(20).cast::<usize>();
+                                    let base20 = l18;
+                                    let len20 = l19;
+                                    _rt::cabi_dealloc(base20, len20 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_watch_only_combine_psbts_cabi<T: GuestWatchOnly>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let result0 =
-                        T::get_receive_address(WatchOnlyBorrow::lift(arg0 as u32 as usize).get());
-                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-                    match result0 {
+                    let len0 = arg2;
+                    let base4 = arg3;
+                    let len4 = arg4;
+                    let mut result4 = _rt::Vec::with_capacity(len4);
+                    for i in 0..len4 {
+                        let base = base4.add(i * 8);
+                        let e4 = {
+                            let l1 = *base.add(0).cast::<*mut u8>();
+                            let l2 = *base.add(4).cast::<usize>();
+                            let len3 = l2;
+
+                            _rt::Vec::from_raw_parts(l1.cast(), len3, len3)
+                        };
+                        result4.push(e4);
+                    }
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                    let result5 = T::combine_psbts(
+                        WatchOnlyBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                        result4,
+                    );
+                    let ptr6 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result5 {
                         Ok(e) => {
-                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                            let vec2 = (e.into_bytes()).into_boxed_slice();
-                            let ptr2 = vec2.as_ptr().cast::<u8>();
-                            let len2 = vec2.len();
-                            ::core::mem::forget(vec2);
-                            *ptr1.add(8).cast::<usize>() = len2;
-                            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+                            *ptr6.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec7 = (e).into_boxed_slice();
+                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                            let len7 = vec7.len();
+                            ::core::mem::forget(vec7);
+                            *ptr6.add(12).cast::<usize>() = len7;
+                            *ptr6.add(8).cast::<*mut u8>() = ptr7.cast_mut();
                         }
                         Err(e) => {
-                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr6.add(0).cast::<u8>() = (1i32) as u8;
                             match e {
                                 Error::CoinSelection => {
-                                    *ptr1.add(4).cast::<u8>() = (0i32) as u8;
+                                    *ptr6.add(8).cast::<u8>() = (0i32) as u8;
                                 }
                                 Error::Psbt => {
-                                    *ptr1.add(4).cast::<u8>() = (1i32) as u8;
+                                    *ptr6.add(8).cast::<u8>() = (1i32) as u8;
                                 }
                                 Error::MissingNonWitnessUtxo => {
-                                    *ptr1.add(4).cast::<u8>() = (2i32) as u8;
+                                    *ptr6.add(8).cast::<u8>() = (2i32) as u8;
                                 }
                                 Error::NoPubkey => {
-                                    *ptr1.add(4).cast::<u8>() = (3i32) as u8;
+                                    *ptr6.add(8).cast::<u8>() = (3i32) as u8;
                                 }
                                 Error::PubkeyError => {
-                                    *ptr1.add(4).cast::<u8>() = (4i32) as u8;
+                                    *ptr6.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr6.add(20).cast::<usize>() = len8;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr6.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr6.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr6.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr6.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr6.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid9,
+                                        vout: vout9,
+                                    } = e;
+                                    let vec10 = (txid9).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr6.add(20).cast::<usize>() = len10;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                    *ptr6.add(24).cast::<i32>() = _rt::as_i32(vout9);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid11,
+                                        vout: vout11,
+                                    } = e;
+                                    let vec12 = (txid11).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr6.add(20).cast::<usize>() = len12;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr12.cast_mut();
+                                    *ptr6.add(24).cast::<i32>() = _rt::as_i32(vout11);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec13 = (e).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr6.add(20).cast::<usize>() = len13;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec14 = (e).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr6.add(20).cast::<usize>() = len14;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec15 = (e).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr6.add(20).cast::<usize>() = len15;
+                                    *ptr6.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr6.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr6.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr6.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr6.add(16).cast::<i64>() = _rt::as_i64(e);
                                 }
                             }
                         }
                     };
-                    ptr1
+                    ptr6
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_watch_only_get_receive_address<
-                    T: GuestWatchOnly,
-                >(
+                pub unsafe fn __post_return_method_watch_only_combine_psbts<T: GuestWatchOnly>(
                     arg0: *mut u8,
                 ) {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
                         0 => {
-                            let l1 = *arg0.add(4).cast::<*mut u8>();
-                            let l2 = *arg0.add(8).cast::<usize>();
-                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 1, 1);
+                        }
+                        _ => {
+                            let l4 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l4 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l5 = *arg0.add(16).cast::<*mut u8>();
+                                    let l6 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                12 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                13 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                14 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                15 => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0.add(20).cast::<usize>();
+                                    let base21 = l19;
+                                    let len21 = l20;
+                                    _rt::cabi_dealloc(base21, len21 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
                         }
-                        _ => (),
                     }
                 }
                 #[doc(hidden)]
@@ -887,26 +5894,119 @@ pub mod exports {
                             let ptr3 = vec3.as_ptr().cast::<u8>();
                             let len3 = vec3.len();
                             ::core::mem::forget(vec3);
-                            *ptr2.add(8).cast::<usize>() = len3;
-                            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+                            *ptr2.add(12).cast::<usize>() = len3;
+                            *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
                         }
                         Err(e) => {
                             *ptr2.add(0).cast::<u8>() = (1i32) as u8;
                             match e {
                                 Error::CoinSelection => {
-                                    *ptr2.add(4).cast::<u8>() = (0i32) as u8;
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
                                 }
                                 Error::Psbt => {
-                                    *ptr2.add(4).cast::<u8>() = (1i32) as u8;
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
                                 }
                                 Error::MissingNonWitnessUtxo => {
-                                    *ptr2.add(4).cast::<u8>() = (2i32) as u8;
+                                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
                                 }
                                 Error::NoPubkey => {
-                                    *ptr2.add(4).cast::<u8>() = (3i32) as u8;
+                                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
                                 }
                                 Error::PubkeyError => {
-                                    *ptr2.add(4).cast::<u8>() = (4i32) as u8;
+                                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                                }
+                                Error::InvalidDescriptor(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(20).cast::<usize>() = len4;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                Error::MissingSignature => {
+                                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                                }
+                                Error::InvalidSpendingPath(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (7i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::TimelockNotReached => {
+                                    *ptr2.add(8).cast::<u8>() = (8i32) as u8;
+                                }
+                                Error::NoRecipients => {
+                                    *ptr2.add(8).cast::<u8>() = (9i32) as u8;
+                                }
+                                Error::OutputBelowDustLimit(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (10i32) as u8;
+                                    *ptr2.add(16).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                Error::UnknownUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (11i32) as u8;
+                                    let Outpoint {
+                                        txid: txid5,
+                                        vout: vout5,
+                                    } = e;
+                                    let vec6 = (txid5).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2.add(20).cast::<usize>() = len6;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout5);
+                                }
+                                Error::FrozenUtxo(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (12i32) as u8;
+                                    let Outpoint {
+                                        txid: txid7,
+                                        vout: vout7,
+                                    } = e;
+                                    let vec8 = (txid7).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr2.add(20).cast::<usize>() = len8;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                    *ptr2.add(24).cast::<i32>() = _rt::as_i32(vout7);
+                                }
+                                Error::IrreplaceableTransaction(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (13i32) as u8;
+                                    let vec9 = (e).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr2.add(20).cast::<usize>() = len9;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                Error::TransactionConfirmed(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (14i32) as u8;
+                                    let vec10 = (e).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr2.add(20).cast::<usize>() = len10;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                Error::NoWalletOutputs(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (15i32) as u8;
+                                    let vec11 = (e).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr2.add(20).cast::<usize>() = len11;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                Error::FeeRateTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (16i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::FeeTooLow(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (17i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                Error::InvalidFeeRate(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (18i32) as u8;
+                                    *ptr2.add(16).cast::<i64>() = _rt::as_i64(e);
                                 }
                             }
                         }
@@ -923,13 +6023,70 @@ pub mod exports {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
                         0 => {
-                            let l1 = *arg0.add(4).cast::<*mut u8>();
-                            let l2 = *arg0.add(8).cast::<usize>();
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
                             let base3 = l1;
                             let len3 = l2;
                             _rt::cabi_dealloc(base3, len3 * 1, 1);
                         }
-                        _ => (),
+                        _ => {
+                            let l4 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l4 {
+                                0 => (),
+                                1 => (),
+                                2 => (),
+                                3 => (),
+                                4 => (),
+                                5 => {
+                                    let l5 = *arg0.add(16).cast::<*mut u8>();
+                                    let l6 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l5, l6, 1);
+                                }
+                                6 => (),
+                                7 => (),
+                                8 => (),
+                                9 => (),
+                                10 => (),
+                                11 => {
+                                    let l7 = *arg0.add(16).cast::<*mut u8>();
+                                    let l8 = *arg0.add(20).cast::<usize>();
+                                    let base9 = l7;
+                                    let len9 = l8;
+                                    _rt::cabi_dealloc(base9, len9 * 1, 1);
+                                }
+                                12 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0.add(20).cast::<usize>();
+                                    let base12 = l10;
+                                    let len12 = l11;
+                                    _rt::cabi_dealloc(base12, len12 * 1, 1);
+                                }
+                                13 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0.add(20).cast::<usize>();
+                                    let base15 = l13;
+                                    let len15 = l14;
+                                    _rt::cabi_dealloc(base15, len15 * 1, 1);
+                                }
+                                14 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0.add(20).cast::<usize>();
+                                    let base18 = l16;
+                                    let len18 = l17;
+                                    _rt::cabi_dealloc(base18, len18 * 1, 1);
+                                }
+                                15 => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0.add(20).cast::<usize>();
+                                    let base21 = l19;
+                                    let len21 = l20;
+                                    _rt::cabi_dealloc(base21, len21 * 1, 1);
+                                }
+                                16 => (),
+                                17 => (),
+                                _ => (),
+                            }
+                        }
                     }
                 }
                 pub trait Guest {
@@ -980,20 +6137,90 @@ pub mod exports {
                         }
                     }
 
+                    /// Traps on a config whose keys, descriptors or policy do not parse, `create` reports
+                    /// them instead
                     fn new(init: Initialization) -> Self;
+                    /// Fails with invalid-descriptor when an xpub, derivation, fingerprint, descriptor or
+                    /// policy of the config does not parse
+                    fn create(init: Initialization) -> Result<WatchOnly, Error>;
                     fn new_address(&self) -> Result<_rt::String, Error>;
+                    /// Spends through the primary key of a policy unless `spending-path` picks its nth
+                    /// recovery path, setting the sequence or lock time that path needs
                     fn create_transaction(
                         &self,
                         recepient: _rt::Vec<u8>,
                         amount: u64,
                         fee_rate: u64,
+                        spending_path: Option<u32>,
+                    ) -> Result<_rt::Vec<u8>, Error>;
+                    /// Pays every recipient from one transaction, with a single change output. Every
+                    /// `must-spend` output is an input whether coin selection needs it or not.
+                    fn create_batch_transaction(
+                        &self,
+                        recipients: _rt::Vec<Recipient>,
+                        fee_rate: u64,
+                        must_spend: _rt::Vec<Outpoint>,
+                        spending_path: Option<u32>,
+                    ) -> Result<_rt::Vec<u8>, Error>;
+                    /// Spends every confirmed output, or only `outpoints`, to `destination` with the fee
+                    /// taken from that output and no change
+                    fn create_drain_transaction(
+                        &self,
+                        destination: _rt::Vec<u8>,
+                        fee_rate: u64,
+                        outpoints: Option<_rt::Vec<Outpoint>>,
+                        spending_path: Option<u32>,
+                    ) -> Result<_rt::Vec<u8>, Error>;
+                    /// Replaces the unconfirmed `transaction` by one paying the same recipients at `fee-rate`,
+                    /// out of its change or extra confirmed inputs
+                    fn create_bump_fee_transaction(
+                        &self,
+                        transaction: _rt::Vec<u8>,
+                        fee_rate: u64,
+                    ) -> Result<_rt::Vec<u8>, Error>;
+                    /// Child spending what the unconfirmed `parent` pays the wallet, bringing the fee rate of
+                    /// both to `fee-rate`. The parent counts as paying no fee unless every input is ours.
+                    fn create_cpfp_transaction(
+                        &self,
+                        parent: _rt::Vec<u8>,
+                        fee_rate: u64,
                     ) -> Result<_rt::Vec<u8>, Error>;
                     fn get_utxos(&self) -> Result<_rt::Vec<PartialUtxo>, Error>;
+                    /// Unspent outputs, oldest first
+                    fn list_unspent(&self) -> _rt::Vec<UnspentUtxo>;
+                    /// Keeps coin selection from spending the unspent output until it is unfrozen
+                    fn freeze_utxo(&self, outpoint: Outpoint) -> Result<(), Error>;
+                    fn unfreeze_utxo(&self, outpoint: Outpoint) -> Result<(), Error>;
                     fn insert_utxos(&self, utxos: _rt::Vec<PartialUtxo>) -> Result<(), Error>;
+                    fn insert_unconfirmed_utxos(
+                        &self,
+                        utxos: _rt::Vec<PartialUtxo>,
+                    ) -> Result<(), Error>;
+                    fn remove_utxos(&self, utxos: _rt::Vec<PartialUtxo>) -> Result<(), Error>;
+                    /// Holds the wallet outputs a broadcast transaction spends until a block mines it
+                    fn mark_pending_spent(&self, transaction: _rt::Vec<u8>) -> Result<(), Error>;
+                    /// Frees the outputs the unconfirmed transaction `txid` still holds, after it was
+                    /// replaced or dropped
+                    fn release_pending_spent(&self, txid: _rt::Vec<u8>);
                     fn get_pubkeys(&self) -> Result<_rt::Vec<Pubkey>, Error>;
+                    /// Keychain the script belongs to, none when it is not a wallet script
+                    fn get_keychain(&self, script: Pubkey) -> Option<Keychain>;
                     fn get_state(&self) -> _rt::Vec<u8>;
+                    /// Receive and change descriptors with their checksums
+                    fn get_descriptors(&self) -> Descriptors;
                     fn balance(&self) -> Result<u64, Error>;
+                    fn pending_balance(&self) -> Result<u64, Error>;
+                    fn balance_breakdown(&self) -> Result<Balance, Error>;
+                    /// Height of the last block scanned, confirmations are counted from it
+                    fn set_tip_height(&self, height: u32);
                     fn get_receive_address(&self) -> Result<_rt::String, Error>;
+                    /// Merges the signatures of cosigners who signed their own copy of `psbt`
+                    fn combine_psbts(
+                        &self,
+                        psbt: _rt::Vec<u8>,
+                        others: _rt::Vec<_rt::Vec<u8>>,
+                    ) -> Result<_rt::Vec<u8>, Error>;
+                    /// Builds the transaction once every input has the signatures its script needs
                     fn finalise_transaction(
                         &self,
                         psbt: _rt::Vec<u8>,
@@ -1005,8 +6232,16 @@ pub mod exports {
   ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
     #[export_name = "component:wallet/types@0.1.0#[constructor]watch-only"]
-    unsafe extern "C" fn export_constructor_watch_only(arg0: i32,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,arg5: *mut u8,arg6: usize,arg7: i32,) -> i32 {
-      $($path_to_types)*::_export_constructor_watch_only_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7)
+    unsafe extern "C" fn export_constructor_watch_only(arg0: *mut u8,) -> i32 {
+      $($path_to_types)*::_export_constructor_watch_only_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[static]watch-only.create"]
+    unsafe extern "C" fn export_static_watch_only_create(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_static_watch_only_create_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[static]watch-only.create"]
+    unsafe extern "C" fn _post_return_static_watch_only_create(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_static_watch_only_create::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
     }
     #[export_name = "component:wallet/types@0.1.0#[method]watch-only.new-address"]
     unsafe extern "C" fn export_method_watch_only_new_address(arg0: *mut u8,) -> *mut u8 {
@@ -1017,13 +6252,45 @@ pub mod exports {
       $($path_to_types)*::__post_return_method_watch_only_new_address::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
     }
     #[export_name = "component:wallet/types@0.1.0#[method]watch-only.create-transaction"]
-    unsafe extern "C" fn export_method_watch_only_create_transaction(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,arg4: i64,) -> *mut u8 {
-      $($path_to_types)*::_export_method_watch_only_create_transaction_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2, arg3, arg4)
+    unsafe extern "C" fn export_method_watch_only_create_transaction(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,arg4: i64,arg5: i32,arg6: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_create_transaction_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2, arg3, arg4, arg5, arg6)
     }
     #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.create-transaction"]
     unsafe extern "C" fn _post_return_method_watch_only_create_transaction(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_method_watch_only_create_transaction::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
     }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.create-batch-transaction"]
+    unsafe extern "C" fn export_method_watch_only_create_batch_transaction(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,arg4: *mut u8,arg5: usize,arg6: i32,arg7: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_create_batch_transaction_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.create-batch-transaction"]
+    unsafe extern "C" fn _post_return_method_watch_only_create_batch_transaction(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_create_batch_transaction::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.create-drain-transaction"]
+    unsafe extern "C" fn export_method_watch_only_create_drain_transaction(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,arg4: i32,arg5: *mut u8,arg6: usize,arg7: i32,arg8: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_create_drain_transaction_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.create-drain-transaction"]
+    unsafe extern "C" fn _post_return_method_watch_only_create_drain_transaction(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_create_drain_transaction::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.create-bump-fee-transaction"]
+    unsafe extern "C" fn export_method_watch_only_create_bump_fee_transaction(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_create_bump_fee_transaction_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.create-bump-fee-transaction"]
+    unsafe extern "C" fn _post_return_method_watch_only_create_bump_fee_transaction(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_create_bump_fee_transaction::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.create-cpfp-transaction"]
+    unsafe extern "C" fn export_method_watch_only_create_cpfp_transaction(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_create_cpfp_transaction_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.create-cpfp-transaction"]
+    unsafe extern "C" fn _post_return_method_watch_only_create_cpfp_transaction(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_create_cpfp_transaction::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
     #[export_name = "component:wallet/types@0.1.0#[method]watch-only.get-utxos"]
     unsafe extern "C" fn export_method_watch_only_get_utxos(arg0: *mut u8,) -> *mut u8 {
       $($path_to_types)*::_export_method_watch_only_get_utxos_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
@@ -1032,10 +6299,66 @@ pub mod exports {
     unsafe extern "C" fn _post_return_method_watch_only_get_utxos(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_method_watch_only_get_utxos::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
     }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.list-unspent"]
+    unsafe extern "C" fn export_method_watch_only_list_unspent(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_list_unspent_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.list-unspent"]
+    unsafe extern "C" fn _post_return_method_watch_only_list_unspent(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_list_unspent::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.freeze-utxo"]
+    unsafe extern "C" fn export_method_watch_only_freeze_utxo(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_freeze_utxo_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.freeze-utxo"]
+    unsafe extern "C" fn _post_return_method_watch_only_freeze_utxo(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_freeze_utxo::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.unfreeze-utxo"]
+    unsafe extern "C" fn export_method_watch_only_unfreeze_utxo(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_unfreeze_utxo_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.unfreeze-utxo"]
+    unsafe extern "C" fn _post_return_method_watch_only_unfreeze_utxo(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_unfreeze_utxo::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
     #[export_name = "component:wallet/types@0.1.0#[method]watch-only.insert-utxos"]
     unsafe extern "C" fn export_method_watch_only_insert_utxos(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
       $($path_to_types)*::_export_method_watch_only_insert_utxos_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2)
     }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.insert-utxos"]
+    unsafe extern "C" fn _post_return_method_watch_only_insert_utxos(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_insert_utxos::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.insert-unconfirmed-utxos"]
+    unsafe extern "C" fn export_method_watch_only_insert_unconfirmed_utxos(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_insert_unconfirmed_utxos_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.insert-unconfirmed-utxos"]
+    unsafe extern "C" fn _post_return_method_watch_only_insert_unconfirmed_utxos(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_insert_unconfirmed_utxos::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.remove-utxos"]
+    unsafe extern "C" fn export_method_watch_only_remove_utxos(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_remove_utxos_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.remove-utxos"]
+    unsafe extern "C" fn _post_return_method_watch_only_remove_utxos(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_remove_utxos::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.mark-pending-spent"]
+    unsafe extern "C" fn export_method_watch_only_mark_pending_spent(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_mark_pending_spent_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.mark-pending-spent"]
+    unsafe extern "C" fn _post_return_method_watch_only_mark_pending_spent(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_mark_pending_spent::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.release-pending-spent"]
+    unsafe extern "C" fn export_method_watch_only_release_pending_spent(arg0: *mut u8,arg1: *mut u8,arg2: usize,) {
+      $($path_to_types)*::_export_method_watch_only_release_pending_spent_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2)
+    }
     #[export_name = "component:wallet/types@0.1.0#[method]watch-only.get-pubkeys"]
     unsafe extern "C" fn export_method_watch_only_get_pubkeys(arg0: *mut u8,) -> *mut u8 {
       $($path_to_types)*::_export_method_watch_only_get_pubkeys_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
@@ -1044,6 +6367,10 @@ pub mod exports {
     unsafe extern "C" fn _post_return_method_watch_only_get_pubkeys(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_method_watch_only_get_pubkeys::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
     }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.get-keychain"]
+    unsafe extern "C" fn export_method_watch_only_get_keychain(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_get_keychain_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2)
+    }
     #[export_name = "component:wallet/types@0.1.0#[method]watch-only.get-state"]
     unsafe extern "C" fn export_method_watch_only_get_state(arg0: *mut u8,) -> *mut u8 {
       $($path_to_types)*::_export_method_watch_only_get_state_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
@@ -1052,10 +6379,42 @@ pub mod exports {
     unsafe extern "C" fn _post_return_method_watch_only_get_state(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_method_watch_only_get_state::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
     }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.get-descriptors"]
+    unsafe extern "C" fn export_method_watch_only_get_descriptors(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_get_descriptors_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.get-descriptors"]
+    unsafe extern "C" fn _post_return_method_watch_only_get_descriptors(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_get_descriptors::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
     #[export_name = "component:wallet/types@0.1.0#[method]watch-only.balance"]
     unsafe extern "C" fn export_method_watch_only_balance(arg0: *mut u8,) -> *mut u8 {
       $($path_to_types)*::_export_method_watch_only_balance_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
     }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.balance"]
+    unsafe extern "C" fn _post_return_method_watch_only_balance(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_balance::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.pending-balance"]
+    unsafe extern "C" fn export_method_watch_only_pending_balance(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_pending_balance_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.pending-balance"]
+    unsafe extern "C" fn _post_return_method_watch_only_pending_balance(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_pending_balance::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.balance-breakdown"]
+    unsafe extern "C" fn export_method_watch_only_balance_breakdown(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_balance_breakdown_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.balance-breakdown"]
+    unsafe extern "C" fn _post_return_method_watch_only_balance_breakdown(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_balance_breakdown::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.set-tip-height"]
+    unsafe extern "C" fn export_method_watch_only_set_tip_height(arg0: *mut u8,arg1: i32,) {
+      $($path_to_types)*::_export_method_watch_only_set_tip_height_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1)
+    }
     #[export_name = "component:wallet/types@0.1.0#[method]watch-only.get-receive-address"]
     unsafe extern "C" fn export_method_watch_only_get_receive_address(arg0: *mut u8,) -> *mut u8 {
       $($path_to_types)*::_export_method_watch_only_get_receive_address_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
@@ -1064,6 +6423,14 @@ pub mod exports {
     unsafe extern "C" fn _post_return_method_watch_only_get_receive_address(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_method_watch_only_get_receive_address::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
     }
+    #[export_name = "component:wallet/types@0.1.0#[method]watch-only.combine-psbts"]
+    unsafe extern "C" fn export_method_watch_only_combine_psbts(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: *mut u8,arg4: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_watch_only_combine_psbts_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[export_name = "cabi_post_component:wallet/types@0.1.0#[method]watch-only.combine-psbts"]
+    unsafe extern "C" fn _post_return_method_watch_only_combine_psbts(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_watch_only_combine_psbts::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0)
+    }
     #[export_name = "component:wallet/types@0.1.0#[method]watch-only.finalise-transaction"]
     unsafe extern "C" fn export_method_watch_only_finalise_transaction(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
       $($path_to_types)*::_export_method_watch_only_finalise_transaction_cabi::<<$ty as $($path_to_types)*::Guest>::WatchOnly>(arg0, arg1, arg2)
@@ -1089,8 +6456,8 @@ pub mod exports {
                 #[doc(hidden)]
                 pub(crate) use __export_component_wallet_types_0_1_0_cabi;
                 #[repr(align(8))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 16]);
-                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 16]);
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 40]);
+                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 40]);
             }
         }
     }
@@ -1206,6 +6573,13 @@ mod _rt {
             String::from_utf8_unchecked(bytes)
         }
     }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            core::hint::unreachable_unchecked()
+        }
+    }
     pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
         if size == 0 {
             return;
@@ -1357,30 +6731,68 @@ pub(crate) use __export_wallet_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.25.0:wallet:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1113] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xdc\x07\x01A\x02\x01\
-A\x02\x01B+\x01m\x05\x07bitcoin\x07testnet\x08testnet4\x06signet\x07regtest\x04\0\
-\x0fbitcoin-network\x03\0\0\x01q\x05\x0ecoin-selection\0\0\x04psbt\0\0\x18missin\
-g-non-witness-utxo\0\0\x09no-pubkey\0\0\x0cpubkey-error\0\0\x04\0\x05error\x03\0\
-\x02\x01r\x04\x04xpubs\x12account-derivations\x12master-fingerprints\x07network\x01\
-\x04\0\x06config\x03\0\x04\x01p}\x01q\x02\x09old-state\x01\x06\0\x06config\x01\x05\
-\0\x04\0\x0einitialization\x03\0\x07\x01r\x05\x04txid\x06\x04vouty\x06amountw\x06\
-script\x06\x08is-spent\x7f\x04\0\x0cpartial-utxo\x03\0\x09\x01p}\x04\0\x06pubkey\
-\x03\0\x0b\x04\0\x0awatch-only\x03\x01\x01i\x0d\x01@\x01\x04init\x08\0\x0e\x04\0\
-\x17[constructor]watch-only\x01\x0f\x01h\x0d\x01j\x01s\x01\x03\x01@\x01\x04self\x10\
-\0\x11\x04\0\x1e[method]watch-only.new-address\x01\x12\x01j\x01\x06\x01\x03\x01@\
-\x04\x04self\x10\x09recepient\x06\x06amountw\x08fee-ratew\0\x13\x04\0%[method]wa\
-tch-only.create-transaction\x01\x14\x01p\x0a\x01j\x01\x15\x01\x03\x01@\x01\x04se\
-lf\x10\0\x16\x04\0\x1c[method]watch-only.get-utxos\x01\x17\x01j\0\x01\x03\x01@\x02\
-\x04self\x10\x05utxos\x15\0\x18\x04\0\x1f[method]watch-only.insert-utxos\x01\x19\
-\x01p\x0c\x01j\x01\x1a\x01\x03\x01@\x01\x04self\x10\0\x1b\x04\0\x1e[method]watch\
--only.get-pubkeys\x01\x1c\x01@\x01\x04self\x10\0\x06\x04\0\x1c[method]watch-only\
-.get-state\x01\x1d\x01j\x01w\x01\x03\x01@\x01\x04self\x10\0\x1e\x04\0\x1a[method\
-]watch-only.balance\x01\x1f\x04\0&[method]watch-only.get-receive-address\x01\x12\
-\x01@\x02\x04self\x10\x04psbt\x06\0\x13\x04\0'[method]watch-only.finalise-transa\
-ction\x01\x20\x04\x01\x1ccomponent:wallet/types@0.1.0\x05\0\x04\x01\x1dcomponent\
-:wallet/wallet@0.1.0\x04\0\x0b\x0c\x01\0\x06wallet\x03\0\0\0G\x09producers\x01\x0c\
-processed-by\x02\x0dwit-component\x070.208.1\x10wit-bindgen-rust\x060.25.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3235] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xa6\x18\x01A\x02\x01\
+A\x02\x01Bo\x01m\x05\x07bitcoin\x07testnet\x08testnet4\x06signet\x07regtest\x04\0\
+\x0fbitcoin-network\x03\0\0\x01m\x02\x06p2wpkh\x04p2tr\x04\0\x0bwallet-type\x03\0\
+\x02\x01k\x03\x01r\x04\x04xpubs\x12account-derivations\x12master-fingerprints\x0b\
+wallet-type\x04\x04\0\x07account\x03\0\x05\x01ks\x01r\x02\x08externals\x08intern\
+al\x07\x04\0\x0bdescriptors\x03\0\x08\x01m\x02\x05p2wsh\x04p2tr\x04\0\x0dmultisi\
+g-type\x03\0\x0a\x01r\x03\x04xpubs\x12account-derivations\x12master-fingerprints\
+\x04\0\x08cosigner\x03\0\x0c\x01p\x0d\x01r\x03\x09thresholdy\x09cosigners\x0e\x0d\
+multisig-type\x0b\x04\0\x08multisig\x03\0\x0f\x01q\x04\x07account\x01\x06\0\x0bd\
+escriptors\x01\x09\0\x08multisig\x01\x10\0\x06policy\x01s\0\x04\0\x04keys\x03\0\x11\
+\x01ky\x01r\x05\x04keys\x12\x07network\x01\x09lookahead\x13\x10change-lookahead\x13\
+\x11min-confirmations\x13\x04\0\x06config\x03\0\x14\x01p}\x01q\x02\x09old-state\x01\
+\x16\0\x06config\x01\x15\0\x04\0\x0einitialization\x03\0\x17\x01r\x04\x09confirm\
+edw\x0ftrusted-pendingw\x11untrusted-pendingw\x08immaturew\x04\0\x07balance\x03\0\
+\x19\x01m\x02\x08external\x08internal\x04\0\x08keychain\x03\0\x1b\x01r\x07\x04tx\
+id\x16\x04vouty\x06amountw\x06script\x16\x08is-spent\x7f\x06height\x13\x0bis-coi\
+nbase\x7f\x04\0\x0cpartial-utxo\x03\0\x1d\x01p}\x04\0\x06pubkey\x03\0\x1f\x01r\x02\
+\x04txid\x16\x04vouty\x04\0\x08outpoint\x03\0!\x01q\x13\x0ecoin-selection\0\0\x04\
+psbt\0\0\x18missing-non-witness-utxo\0\0\x09no-pubkey\0\0\x0cpubkey-error\0\0\x12\
+invalid-descriptor\x01s\0\x11missing-signature\0\0\x15invalid-spending-path\x01y\
+\0\x14timelock-not-reached\0\0\x0dno-recipients\0\0\x17output-below-dust-limit\x01\
+y\0\x0cunknown-utxo\x01\"\0\x0bfrozen-utxo\x01\"\0\x19irreplaceable-transaction\x01\
+\x16\0\x15transaction-confirmed\x01\x16\0\x11no-wallet-outputs\x01\x16\0\x10fee-\
+rate-too-low\x01w\0\x0bfee-too-low\x01w\0\x10invalid-fee-rate\x01w\0\x04\0\x05er\
+ror\x03\0#\x01r\x09\x08outpoint\"\x06amountw\x06script\x16\x08keychain\x1c\x10de\
+rivation-indexy\x06height\x13\x0dconfirmationsy\x0bis-coinbase\x7f\x09is-frozen\x7f\
+\x04\0\x0cunspent-utxo\x03\0%\x01r\x02\x06script\x16\x06amountw\x04\0\x09recipie\
+nt\x03\0'\x04\0\x0awatch-only\x03\x01\x01i)\x01@\x01\x04init\x18\0*\x04\0\x17[co\
+nstructor]watch-only\x01+\x01j\x01*\x01$\x01@\x01\x04init\x18\0,\x04\0\x19[stati\
+c]watch-only.create\x01-\x01h)\x01j\x01s\x01$\x01@\x01\x04self.\0/\x04\0\x1e[met\
+hod]watch-only.new-address\x010\x01j\x01\x16\x01$\x01@\x05\x04self.\x09recepient\
+\x16\x06amountw\x08fee-ratew\x0dspending-path\x13\01\x04\0%[method]watch-only.cr\
+eate-transaction\x012\x01p(\x01p\"\x01@\x05\x04self.\x0arecipients3\x08fee-ratew\
+\x0amust-spend4\x0dspending-path\x13\01\x04\0+[method]watch-only.create-batch-tr\
+ansaction\x015\x01k4\x01@\x05\x04self.\x0bdestination\x16\x08fee-ratew\x09outpoi\
+nts6\x0dspending-path\x13\01\x04\0+[method]watch-only.create-drain-transaction\x01\
+7\x01@\x03\x04self.\x0btransaction\x16\x08fee-ratew\01\x04\0.[method]watch-only.\
+create-bump-fee-transaction\x018\x01@\x03\x04self.\x06parent\x16\x08fee-ratew\01\
+\x04\0*[method]watch-only.create-cpfp-transaction\x019\x01p\x1e\x01j\x01:\x01$\x01\
+@\x01\x04self.\0;\x04\0\x1c[method]watch-only.get-utxos\x01<\x01p&\x01@\x01\x04s\
+elf.\0=\x04\0\x1f[method]watch-only.list-unspent\x01>\x01j\0\x01$\x01@\x02\x04se\
+lf.\x08outpoint\"\0?\x04\0\x1e[method]watch-only.freeze-utxo\x01@\x04\0\x20[meth\
+od]watch-only.unfreeze-utxo\x01@\x01@\x02\x04self.\x05utxos:\0?\x04\0\x1f[method\
+]watch-only.insert-utxos\x01A\x04\0+[method]watch-only.insert-unconfirmed-utxos\x01\
+A\x04\0\x1f[method]watch-only.remove-utxos\x01A\x01@\x02\x04self.\x0btransaction\
+\x16\0?\x04\0%[method]watch-only.mark-pending-spent\x01B\x01@\x02\x04self.\x04tx\
+id\x16\x01\0\x04\0([method]watch-only.release-pending-spent\x01C\x01p\x20\x01j\x01\
+\xc4\0\x01$\x01@\x01\x04self.\0\xc5\0\x04\0\x1e[method]watch-only.get-pubkeys\x01\
+F\x01k\x1c\x01@\x02\x04self.\x06script\x20\0\xc7\0\x04\0\x1f[method]watch-only.g\
+et-keychain\x01H\x01@\x01\x04self.\0\x16\x04\0\x1c[method]watch-only.get-state\x01\
+I\x01@\x01\x04self.\0\x09\x04\0\"[method]watch-only.get-descriptors\x01J\x01j\x01\
+w\x01$\x01@\x01\x04self.\0\xcb\0\x04\0\x1a[method]watch-only.balance\x01L\x04\0\"\
+[method]watch-only.pending-balance\x01L\x01j\x01\x1a\x01$\x01@\x01\x04self.\0\xcd\
+\0\x04\0$[method]watch-only.balance-breakdown\x01N\x01@\x02\x04self.\x06heighty\x01\
+\0\x04\0![method]watch-only.set-tip-height\x01O\x04\0&[method]watch-only.get-rec\
+eive-address\x010\x01p\x16\x01@\x03\x04self.\x04psbt\x16\x06others\xd0\0\01\x04\0\
+\x20[method]watch-only.combine-psbts\x01Q\x01@\x02\x04self.\x04psbt\x16\01\x04\0\
+'[method]watch-only.finalise-transaction\x01R\x04\x01\x1ccomponent:wallet/types@\
+0.1.0\x05\0\x04\x01\x1dcomponent:wallet/wallet@0.1.0\x04\0\x0b\x0c\x01\0\x06wall\
+et\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.208.1\x10\
+wit-bindgen-rust\x060.25.0";
 
 #[inline(never)]
 #[doc(hidden)]
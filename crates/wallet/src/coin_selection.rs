@@ -718,6 +718,8 @@ mod test {
                 },
                 keychain: KeychainKind::External,
                 is_spent: false,
+                is_confirmed: true,
+                is_coinbase: false,
                 derivation_index: 42,
                 chain_position: Some(chain_position),
             },
@@ -750,6 +752,8 @@ mod test {
                     },
                     keychain: KeychainKind::External,
                     is_spent: false,
+                    is_confirmed: true,
+                    is_coinbase: false,
                     derivation_index: rng.next_u32(),
                     chain_position: Some(rng.next_u32()),
                 },
@@ -774,6 +778,8 @@ mod test {
                     },
                     keychain: KeychainKind::External,
                     is_spent: false,
+                    is_confirmed: true,
+                    is_coinbase: false,
                     derivation_index: 42,
                     chain_position: Some(0),
                 },
@@ -1289,6 +1295,8 @@ mod test {
                     },
                     keychain: KeychainKind::External,
                     is_spent: false,
+                    is_confirmed: true,
+                    is_coinbase: false,
                     derivation_index: 0,
                     chain_position: Some(0),
                 },
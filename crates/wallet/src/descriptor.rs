@@ -0,0 +1,635 @@
+//! Output descriptors the wallet derives its scripts from
+//!
+//! Only the ranged descriptors a single account needs are understood: `pkh`, `wpkh`, `sh(wpkh)`,
+//! `tr` with a key path alone, `wsh(multi)`/`wsh(sortedmulti)` and `tr` with a single
+//! `multi_a`/`sortedmulti_a` leaf and the `wsh` miniscript of [`policy`] wallets, every key an
+//! xpub ending in `/*`. Descriptors are checked and exported with their BIP380 checksum.
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use bitcoin::{bip32::{ChildNumber, DerivationPath, Fingerprint, Xpub}, blockdata::{opcodes::all::{OP_CHECKMULTISIG, OP_CHECKSIG, OP_CHECKSIGADD, OP_NUMEQUAL}, script::{Builder, Instruction, PushBytes}}, key::Secp256k1, psbt::{Input, PsbtSighashType}, taproot::{LeafVersion, TapLeafHash, TaprootBuilder, TaprootSpendInfo}, CompressedPublicKey, EcdsaSighashType, PublicKey, ScriptBuf, VarInt, Weight, Witness, XOnlyPublicKey};
+use serde::Serialize;
+
+use crate::{errors::Error, policy::{self, Recovery, Timelock, Tree}, watch_wallet::{MultisigType, WalletType}};
+
+const INPUT_CHARSET: &str = "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+const CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const CHECKSUM_LENGTH: usize = 8;
+
+/// Keys `OP_CHECKMULTISIG` accepts at most
+const MAX_MULTISIG_KEYS: usize = 20;
+
+/// Keys a `multi_a` leaf takes at most
+const MAX_MULTI_A_KEYS: usize = 999;
+
+/// Internal key nobody knows the private key of, BIP341 `H`, so taproot multisig outputs can only
+/// be spent through their script
+const UNSPENDABLE_KEY: &str = "50929b74c1a04954b78b4b6035e97a5e078a5a0f28ec96d547bfee9ace803ac0";
+
+fn polymod(mut checksum: u64, value: u64) -> u64 {
+    const GENERATOR: [u64; 5] = [0xf5dee51989, 0xa9fdca3312, 0x1bab10e32d, 0x3706b1677a, 0x644d626ffd];
+    let top = checksum >> 35;
+    checksum = ((checksum & 0x7ffffffff) << 5) ^ value;
+    for (bit, generator) in GENERATOR.iter().enumerate() {
+        if (top >> bit) & 1 == 1 {
+            checksum ^= generator;
+        }
+    }
+    checksum
+}
+
+/// BIP380 checksum of a descriptor written without one
+pub fn checksum(descriptor: &str) -> Result<String, Error> {
+    let mut checksum = 1;
+    let mut classes = Vec::with_capacity(3);
+    for character in descriptor.chars() {
+        let position = INPUT_CHARSET.find(character)
+            .ok_or_else(|| Error::InvalidDescriptor(format!("invalid character {:?}", character)))? as u64;
+        checksum = polymod(checksum, position & 31);
+        classes.push(position >> 5);
+        if classes.len() == 3 {
+            checksum = polymod(checksum, classes[0] * 9 + classes[1] * 3 + classes[2]);
+            classes.clear();
+        }
+    }
+    match classes.as_slice() {
+        [first] => checksum = polymod(checksum, *first),
+        [first, second] => checksum = polymod(checksum, first * 3 + second),
+        _ => {},
+    }
+    for _ in 0..CHECKSUM_LENGTH {
+        checksum = polymod(checksum, 0);
+    }
+    checksum ^= 1;
+
+    Ok((0..CHECKSUM_LENGTH).map(|index| CHECKSUM_CHARSET[((checksum >> (5 * (7 - index))) & 31) as usize] as char).collect())
+}
+
+/// Strips the checksum of a descriptor after checking it, descriptors without one are accepted
+fn verify_checksum(descriptor: &str) -> Result<&str, Error> {
+    match descriptor.split_once('#') {
+        Some((body, found)) => {
+            let expected = checksum(body)?;
+            if found != expected {
+                return Err(Error::InvalidDescriptor(format!("checksum {} does not match, expected {}", found, expected)));
+            }
+            Ok(body)
+        },
+        None => Ok(descriptor),
+    }
+}
+
+/// Picks one path of every `<a;b>` multipath step, BIP389, `choice` 0 for the first
+fn select_multipath(descriptor: &str, choice: usize) -> Result<String, Error> {
+    let mut selected = String::with_capacity(descriptor.len());
+    let mut rest = descriptor;
+    while let Some(start) = rest.find('<') {
+        let end = rest[start..].find('>').ok_or_else(|| Error::InvalidDescriptor("unclosed multipath step".to_string()))? + start;
+        let paths: Vec<_> = rest[start + 1..end].split(';').collect();
+        if paths.len() != 2 {
+            return Err(Error::InvalidDescriptor("multipath steps must hold the receive and change paths".to_string()));
+        }
+        selected.push_str(&rest[..start]);
+        selected.push_str(paths[choice]);
+        rest = &rest[end + 1..];
+    }
+    selected.push_str(rest);
+    Ok(selected)
+}
+
+/// Receive and change descriptors of a wallet
+///
+/// Without a change descriptor the receive one must use a `<0;1>` style multipath step to tell
+/// the two keychains apart.
+pub fn parse_keychains(descriptor: &str, change_descriptor: Option<&str>) -> Result<(Descriptor, Descriptor), Error> {
+    match change_descriptor {
+        Some(change_descriptor) => Ok((Descriptor::from_str(descriptor)?, Descriptor::from_str(change_descriptor)?)),
+        None => {
+            let body = verify_checksum(descriptor)?;
+            if !body.contains('<') {
+                return Err(Error::InvalidDescriptor("a change descriptor or a multipath step is needed".to_string()));
+            }
+            Ok((Descriptor::from_str(&select_multipath(body, 0)?)?, Descriptor::from_str(&select_multipath(body, 1)?)?))
+        },
+    }
+}
+
+fn check_threshold(threshold: usize, keys: usize, max_keys: usize) -> Result<(), Error> {
+    if threshold == 0 || threshold > keys || keys > max_keys {
+        return Err(Error::InvalidDescriptor(format!("{} of {} multisig is not allowed", threshold, keys)));
+    }
+    Ok(())
+}
+
+/// Threshold and keys of `multi` style arguments
+fn parse_multisig(arguments: &str, max_keys: usize) -> Result<(usize, Vec<DescriptorKey>), Error> {
+    let mut arguments = arguments.split(',');
+    let threshold: usize = arguments.next().and_then(|threshold| threshold.parse().ok())
+        .ok_or_else(|| Error::InvalidDescriptor("invalid multisig threshold".to_string()))?;
+    let keys = arguments.map(DescriptorKey::from_str).collect::<Result<Vec<_>, _>>()?;
+    check_threshold(threshold, keys.len(), max_keys)?;
+    Ok((threshold, keys))
+}
+
+/// Receive and change descriptors of a spending policy whose keys use `<0;1>` multipath steps
+pub fn policy_keychains(policy: &str) -> Result<(Descriptor, Descriptor), Error> {
+    if !policy.contains('<') {
+        return Err(Error::InvalidDescriptor("policy keys need a multipath step".to_string()));
+    }
+    Ok((Descriptor::from_policy(&select_multipath(policy, 0)?)?, Descriptor::from_policy(&select_multipath(policy, 1)?)?))
+}
+
+/// Ranged xpub of a descriptor, `[fingerprint/origin]xpub/path/*`
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, Serialize)]
+pub struct DescriptorKey {
+    /// Master key fingerprint and path to the xpub, signers look their keys up by it
+    origin: Option<(Fingerprint, DerivationPath)>,
+    xpub: Xpub,
+    /// Unhardened steps between the xpub and the wildcard
+    path: DerivationPath,
+}
+
+impl DescriptorKey {
+
+    /// Public key at `index` with the origin a signer derives it from
+    pub(crate) fn derive(&self, index: u32) -> Result<(CompressedPublicKey, (Fingerprint, DerivationPath)), Error> {
+        let secp = Secp256k1::verification_only();
+        let path = self.path.child(ChildNumber::Normal { index });
+        let child = self.xpub.derive_pub(&secp, &path).map_err(Error::PubKeyError)?;
+        let origin = match &self.origin {
+            Some((fingerprint, origin_path)) => (*fingerprint, origin_path.extend(path)),
+            None => (self.xpub.fingerprint(), path),
+        };
+        Ok((child.to_pub(), origin))
+    }
+}
+
+impl FromStr for DescriptorKey {
+    type Err = Error;
+
+    fn from_str(key: &str) -> Result<Self, Error> {
+        let invalid = |reason: &str| Error::InvalidDescriptor(format!("{}: {}", reason, key));
+        let (origin, key_path) = match key.strip_prefix('[') {
+            Some(rest) => {
+                let (origin, key_path) = rest.split_once(']').ok_or_else(|| invalid("unclosed key origin"))?;
+                let (fingerprint, path) = origin.split_once('/').unwrap_or((origin, ""));
+                let fingerprint = Fingerprint::from_str(fingerprint).map_err(|_| invalid("invalid origin fingerprint"))?;
+                let path = DerivationPath::from_str(path).map_err(|_| invalid("invalid origin path"))?;
+                (Some((fingerprint, path)), key_path)
+            },
+            None => (None, key),
+        };
+
+        let (xpub, steps) = key_path.split_once('/').ok_or_else(|| invalid("keys must be ranged"))?;
+        let xpub = Xpub::from_str(xpub).map_err(|_| invalid("invalid xpub"))?;
+        let steps = steps.strip_suffix('*').and_then(|steps| steps.strip_suffix('/').or(steps.is_empty().then_some("")))
+            .ok_or_else(|| invalid("keys must end with an unhardened wildcard"))?;
+        let path = DerivationPath::from_str(steps).map_err(|_| invalid("invalid derivation path"))?;
+        if path.into_iter().any(|step| step.is_hardened()) {
+            return Err(invalid("hardened steps cannot follow an xpub"));
+        }
+
+        Ok(Self { origin, xpub, path })
+    }
+}
+
+impl fmt::Display for DescriptorKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((fingerprint, path)) = &self.origin {
+            write!(f, "[{}", fingerprint)?;
+            if !path.is_empty() {
+                write!(f, "/{}", path)?;
+            }
+            write!(f, "]")?;
+        }
+        write!(f, "{}", self.xpub)?;
+        if !self.path.is_empty() {
+            write!(f, "/{}", self.path)?;
+        }
+        write!(f, "/*")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, Serialize)]
+pub enum Descriptor {
+    Pkh(DescriptorKey),
+    Wpkh(DescriptorKey),
+    ShWpkh(DescriptorKey),
+    /// Key path only, BIP86 when the key follows its derivation
+    Tr(DescriptorKey),
+    /// `threshold` of `keys` signing a bare multisig script, keys sorted per child for
+    /// `sortedmulti`
+    WshMulti { threshold: usize, keys: Vec<DescriptorKey>, sorted: bool },
+    /// `threshold` of `keys` signing the single `multi_a` leaf under `internal_key`
+    TrMulti { internal_key: XOnlyPublicKey, threshold: usize, keys: Vec<DescriptorKey>, sorted: bool },
+    /// Primary key or timelocked recovery keys, see [`policy`]
+    WshPolicy { primary: DescriptorKey, recoveries: Vec<Recovery> },
+}
+
+impl Descriptor {
+
+    /// Descriptor of a single key account, `keychain` being the unhardened step after the xpub
+    pub fn account(wallet_type: WalletType, xpub: Xpub, master_fingerprint: Fingerprint, account_derivation: DerivationPath, keychain: u32) -> Self {
+        let key = DescriptorKey { origin: Some((master_fingerprint, account_derivation)), xpub, path: DerivationPath::from(vec![ChildNumber::Normal { index: keychain }]) };
+        match wallet_type {
+            WalletType::P2WPKH => Descriptor::Wpkh(key),
+            WalletType::P2TR => Descriptor::Tr(key),
+        }
+    }
+
+    /// Sorted multisig of the cosigners' `(xpub, master fingerprint, account derivation)`
+    pub fn multisig(multisig_type: MultisigType, threshold: usize, cosigners: &[(Xpub, Fingerprint, DerivationPath)], keychain: u32) -> Result<Self, Error> {
+        let keys: Vec<_> = cosigners.iter().map(|(xpub, master_fingerprint, account_derivation)| DescriptorKey {
+            origin: Some((*master_fingerprint, account_derivation.clone())),
+            xpub: *xpub,
+            path: DerivationPath::from(vec![ChildNumber::Normal { index: keychain }]),
+        }).collect();
+        let descriptor = match multisig_type {
+            MultisigType::P2WSH => {
+                check_threshold(threshold, keys.len(), MAX_MULTISIG_KEYS)?;
+                Descriptor::WshMulti { threshold, keys, sorted: true }
+            },
+            MultisigType::P2TR => {
+                check_threshold(threshold, keys.len(), MAX_MULTI_A_KEYS)?;
+                let internal_key = XOnlyPublicKey::from_str(UNSPENDABLE_KEY).expect("valid key");
+                Descriptor::TrMulti { internal_key, threshold, keys, sorted: true }
+            },
+        };
+        Ok(descriptor)
+    }
+
+    /// Compiles a spending policy, see [`policy`]
+    pub fn from_policy(policy: &str) -> Result<Self, Error> {
+        let (primary, recoveries) = policy::compile(policy)?;
+        Ok(Descriptor::WshPolicy { primary, recoveries })
+    }
+
+    /// Timelock of the spending path, paths other than 0 only exist for policies
+    pub fn timelock(&self, path: usize) -> Result<Option<Timelock>, Error> {
+        match (self, path) {
+            (_, 0) => Ok(None),
+            (Descriptor::WshPolicy { recoveries, .. }, path) if path <= recoveries.len() => Ok(Some(recoveries[path - 1].timelock)),
+            _ => Err(Error::InvalidSpendingPath(path)),
+        }
+    }
+
+    /// Descriptor followed by its checksum
+    pub fn to_string_with_checksum(&self) -> String {
+        let descriptor = self.to_string();
+        // Descriptors we print only hold characters of the checksum charset
+        let checksum = checksum(&descriptor).expect("printed descriptor is valid");
+        format!("{}#{}", descriptor, checksum)
+    }
+
+    /// Multisig script with the keys at `index`
+    fn multisig_script(threshold: usize, keys: &[DescriptorKey], sorted: bool, index: u32) -> Result<ScriptBuf, Error> {
+        let mut pubkeys = keys.iter().map(|key| key.derive(index).map(|(pubkey, _)| pubkey)).collect::<Result<Vec<_>, _>>()?;
+        if sorted {
+            pubkeys.sort_by_key(|pubkey| pubkey.to_bytes());
+        }
+        let builder = pubkeys.iter().fold(Builder::new().push_int(threshold as i64), |builder, pubkey| builder.push_slice(pubkey.to_bytes()));
+        Ok(builder.push_int(pubkeys.len() as i64).push_opcode(OP_CHECKMULTISIG).into_script())
+    }
+
+    /// `multi_a` leaf with the keys at `index`
+    fn multi_a_script(threshold: usize, keys: &[DescriptorKey], sorted: bool, index: u32) -> Result<ScriptBuf, Error> {
+        let mut pubkeys = keys.iter().map(|key| key.derive(index).map(|(pubkey, _)| pubkey.0.x_only_public_key().0)).collect::<Result<Vec<_>, _>>()?;
+        if sorted {
+            pubkeys.sort_by_key(|pubkey| pubkey.serialize());
+        }
+        let mut builder = Builder::new();
+        for (position, pubkey) in pubkeys.iter().enumerate() {
+            let opcode = if position == 0 { OP_CHECKSIG } else { OP_CHECKSIGADD };
+            builder = builder.push_x_only_key(pubkey).push_opcode(opcode);
+        }
+        Ok(builder.push_int(threshold as i64).push_opcode(OP_NUMEQUAL).into_script())
+    }
+
+    fn tap_spend_info(internal_key: XOnlyPublicKey, leaf: &ScriptBuf) -> TaprootSpendInfo {
+        TaprootBuilder::new()
+            .add_leaf(0, leaf.clone()).expect("a single leaf is a valid tree")
+            .finalize(&Secp256k1::verification_only(), internal_key).expect("the tree is complete")
+    }
+
+    pub fn script_pubkey(&self, index: u32) -> Result<ScriptBuf, Error> {
+        let script = match self {
+            Descriptor::Pkh(key) => ScriptBuf::new_p2pkh(&key.derive(index)?.0.pubkey_hash()),
+            Descriptor::Wpkh(key) => ScriptBuf::new_p2wpkh(&key.derive(index)?.0.wpubkey_hash()),
+            Descriptor::ShWpkh(key) => ScriptBuf::new_p2sh(&ScriptBuf::new_p2wpkh(&key.derive(index)?.0.wpubkey_hash()).script_hash()),
+            Descriptor::Tr(key) => ScriptBuf::new_p2tr(&Secp256k1::verification_only(), key.derive(index)?.0.0.x_only_public_key().0, None),
+            Descriptor::WshMulti { threshold, keys, sorted } => Self::multisig_script(*threshold, keys, *sorted, index)?.to_p2wsh(),
+            Descriptor::TrMulti { internal_key, threshold, keys, sorted } => {
+                let leaf = Self::multi_a_script(*threshold, keys, *sorted, index)?;
+                let merkle_root = Self::tap_spend_info(*internal_key, &leaf).merkle_root();
+                ScriptBuf::new_p2tr(&Secp256k1::verification_only(), *internal_key, merkle_root)
+            },
+            Descriptor::WshPolicy { primary, recoveries } => policy::script(primary, recoveries, index)?.to_p2wsh(),
+        };
+        Ok(script)
+    }
+
+    /// Weight of the witness and script sig spending one of the outputs through `path`, the
+    /// witness item count is part of the input weight coin selection adds
+    pub fn satisfaction_weight(&self, path: usize) -> Weight {
+        // DER signature with its sighash byte, compressed pubkey
+        let signature = 1 + 72;
+        let pubkey = 1 + 33;
+        let wu = match self {
+            Descriptor::Pkh(_) => 4 * (signature + pubkey),
+            Descriptor::Wpkh(_) => signature + pubkey,
+            // The redeem script push, then the p2wpkh witness
+            Descriptor::ShWpkh(_) => 4 * (1 + 22) + signature + pubkey,
+            // Schnorr signature using the default sighash
+            Descriptor::Tr(_) => 1 + 64,
+            Descriptor::WshMulti { threshold, keys, .. } => {
+                let script_len = 3 + 34 * keys.len();
+                // The empty push `OP_CHECKMULTISIG` pops too many
+                1 + threshold * signature + VarInt(script_len as u64).size() + script_len
+            },
+            Descriptor::TrMulti { threshold, keys, .. } => {
+                let script_len = 2 + 34 * keys.len();
+                // A schnorr signature or an empty push per key, the leaf and a control block
+                // without siblings
+                threshold * (1 + 64) + (keys.len() - threshold) + VarInt(script_len as u64).size() + script_len + 1 + 33
+            },
+            Descriptor::WshPolicy { primary, recoveries } => return policy::satisfaction_weight(primary, recoveries, path),
+        };
+        Weight::from_wu(wu as u64)
+    }
+
+    /// Adds what a signer and the finaliser need to spend the output at `index`
+    pub fn update_input(&self, index: u32, input: &mut Input) -> Result<(), Error> {
+        let ecdsa_sighash = PsbtSighashType::from(EcdsaSighashType::All);
+        match self {
+            Descriptor::Pkh(key) | Descriptor::Wpkh(key) | Descriptor::ShWpkh(key) => {
+                let (pubkey, origin) = key.derive(index)?;
+                input.bip32_derivation.insert(pubkey.0, origin);
+                input.sighash_type = Some(ecdsa_sighash);
+                let wpkh = ScriptBuf::new_p2wpkh(&pubkey.wpubkey_hash());
+                match self {
+                    Descriptor::Wpkh(_) => input.witness_script = Some(wpkh),
+                    Descriptor::ShWpkh(_) => input.redeem_script = Some(wpkh),
+                    _ => {},
+                }
+            },
+            Descriptor::Tr(key) => {
+                // BIP86 keys have no script tree, the signer tweaks the internal key alone
+                let (pubkey, origin) = key.derive(index)?;
+                let internal_key = pubkey.0.x_only_public_key().0;
+                input.tap_internal_key = Some(internal_key);
+                input.tap_key_origins.insert(internal_key, (vec![], origin));
+            },
+            Descriptor::WshMulti { threshold, keys, sorted } => {
+                for key in keys {
+                    let (pubkey, origin) = key.derive(index)?;
+                    input.bip32_derivation.insert(pubkey.0, origin);
+                }
+                input.sighash_type = Some(ecdsa_sighash);
+                input.witness_script = Some(Self::multisig_script(*threshold, keys, *sorted, index)?);
+            },
+            Descriptor::TrMulti { internal_key, threshold, keys, sorted } => {
+                let leaf = Self::multi_a_script(*threshold, keys, *sorted, index)?;
+                let spend_info = Self::tap_spend_info(*internal_key, &leaf);
+                let leaf_hash = TapLeafHash::from_script(&leaf, LeafVersion::TapScript);
+                let control_block = spend_info.control_block(&(leaf.clone(), LeafVersion::TapScript)).expect("the leaf is in the tree");
+                for key in keys {
+                    let (pubkey, origin) = key.derive(index)?;
+                    input.tap_key_origins.insert(pubkey.0.x_only_public_key().0, (vec![leaf_hash], origin));
+                }
+                input.tap_internal_key = Some(*internal_key);
+                input.tap_merkle_root = spend_info.merkle_root();
+                input.tap_scripts.insert(control_block, (leaf, LeafVersion::TapScript));
+            },
+            Descriptor::WshPolicy { primary, recoveries } => {
+                for key in std::iter::once(primary).chain(recoveries.iter().map(|recovery| &recovery.key)) {
+                    let (pubkey, origin) = key.derive(index)?;
+                    input.bip32_derivation.insert(pubkey.0, origin);
+                }
+                input.sighash_type = Some(ecdsa_sighash);
+                input.witness_script = Some(policy::script(primary, recoveries, index)?);
+            },
+        }
+        Ok(())
+    }
+
+    /// Builds the final script sig and witness of a signed input
+    pub fn finalize_input(&self, input: &mut Input) -> Result<(), Error> {
+        let push = |bytes: &[u8]| <&PushBytes>::try_from(bytes).map(|bytes| bytes.to_owned()).map_err(|_| Error::InvalidDescriptor("push too large".to_string()));
+        let mut witness = Witness::new();
+        match self {
+            // Key path spend, the signature alone is the witness
+            Descriptor::Tr(_) => witness.push(input.tap_key_sig.ok_or(Error::MissingSignature)?.to_vec()),
+            Descriptor::Pkh(_) | Descriptor::Wpkh(_) | Descriptor::ShWpkh(_) => {
+                let (pubkey, signature) = input.partial_sigs.first_key_value().ok_or(Error::MissingSignature)?;
+                match self {
+                    Descriptor::Pkh(_) => {
+                        let script_sig = Builder::new().push_slice(push(&signature.serialize())?).push_slice(push(&pubkey.to_bytes())?).into_script();
+                        input.final_script_sig = Some(script_sig);
+                    },
+                    _ => {
+                        witness.push(signature.serialize());
+                        witness.push(pubkey.to_bytes());
+                    },
+                }
+                if let Descriptor::ShWpkh(_) = self {
+                    let redeem_script = input.redeem_script.as_ref().ok_or(Error::MissingSignature)?;
+                    input.final_script_sig = Some(Builder::new().push_slice(push(redeem_script.as_bytes())?).into_script());
+                }
+            },
+            Descriptor::WshMulti { threshold, .. } => {
+                let witness_script = input.witness_script.clone().ok_or(Error::MissingSignature)?;
+                // Signatures go in the order of the keys in the script
+                let signatures: Vec<_> = witness_script.instructions()
+                    .filter_map(|instruction| match instruction {
+                        Ok(Instruction::PushBytes(bytes)) => PublicKey::from_slice(bytes.as_bytes()).ok(),
+                        _ => None,
+                    })
+                    .filter_map(|pubkey| input.partial_sigs.get(&pubkey))
+                    .take(*threshold)
+                    .collect();
+                if signatures.len() < *threshold {
+                    return Err(Error::MissingSignature);
+                }
+                witness.push([0u8; 0]);
+                for signature in signatures {
+                    witness.push(signature.serialize());
+                }
+                witness.push(witness_script.as_bytes());
+            },
+            Descriptor::TrMulti { threshold, .. } => {
+                let (control_block, (leaf, leaf_version)) = input.tap_scripts.first_key_value().ok_or(Error::MissingSignature)?;
+                let leaf_hash = TapLeafHash::from_script(leaf, *leaf_version);
+                // Exactly `threshold` signatures, an empty push for every other key
+                let mut missing = *threshold;
+                let items: Vec<Vec<u8>> = leaf.instructions()
+                    .filter_map(|instruction| match instruction {
+                        Ok(Instruction::PushBytes(bytes)) => XOnlyPublicKey::from_slice(bytes.as_bytes()).ok(),
+                        _ => None,
+                    })
+                    .map(|pubkey| match input.tap_script_sigs.get(&(pubkey, leaf_hash)) {
+                        Some(signature) if missing > 0 => {
+                            missing -= 1;
+                            signature.to_vec()
+                        },
+                        _ => vec![],
+                    })
+                    .collect();
+                if missing > 0 {
+                    return Err(Error::MissingSignature);
+                }
+                // The first key checks the top of the stack, so the last item
+                for item in items.iter().rev() {
+                    witness.push(item);
+                }
+                witness.push(leaf.as_bytes());
+                witness.push(control_block.serialize());
+            },
+            Descriptor::WshPolicy { .. } => witness = policy::witness(input)?,
+        }
+        if !witness.is_empty() {
+            input.final_script_witness = Some(witness);
+        }
+
+        // Clear all the data fields as per the spec.
+        input.partial_sigs = BTreeMap::new();
+        input.sighash_type = None;
+        input.redeem_script = None;
+        input.witness_script = None;
+        input.bip32_derivation = BTreeMap::new();
+        input.tap_key_sig = None;
+        input.tap_internal_key = None;
+        input.tap_key_origins = BTreeMap::new();
+        input.tap_scripts = BTreeMap::new();
+        input.tap_script_sigs = BTreeMap::new();
+        input.tap_merkle_root = None;
+        Ok(())
+    }
+}
+
+impl FromStr for Descriptor {
+    type Err = Error;
+
+    fn from_str(descriptor: &str) -> Result<Self, Error> {
+        let body = verify_checksum(descriptor)?;
+        let inner = |prefix: &str, closing: usize| {
+            body.strip_prefix(prefix).and_then(|rest| rest.strip_suffix(&")".repeat(closing)))
+        };
+
+        if let Some(key) = inner("sh(wpkh(", 2) {
+            return Ok(Descriptor::ShWpkh(DescriptorKey::from_str(key)?));
+        }
+        if let Some((arguments, sorted)) = inner("wsh(multi(", 2).map(|arguments| (arguments, false)).or(inner("wsh(sortedmulti(", 2).map(|arguments| (arguments, true))) {
+            let (threshold, keys) = parse_multisig(arguments, MAX_MULTISIG_KEYS)?;
+            return Ok(Descriptor::WshMulti { threshold, keys, sorted });
+        }
+        if let Some(miniscript) = inner("wsh(", 1) {
+            let (primary, recoveries) = policy::parse_miniscript(&Tree::parse(miniscript)?)?;
+            return Ok(Descriptor::WshPolicy { primary, recoveries });
+        }
+        if let Some(key) = inner("wpkh(", 1) {
+            return Ok(Descriptor::Wpkh(DescriptorKey::from_str(key)?));
+        }
+        if let Some(key) = inner("pkh(", 1) {
+            return Ok(Descriptor::Pkh(DescriptorKey::from_str(key)?));
+        }
+        if let Some(key) = inner("tr(", 1) {
+            let Some((internal_key, tree)) = key.split_once(',') else {
+                return Ok(Descriptor::Tr(DescriptorKey::from_str(key)?));
+            };
+            let leaf = |prefix: &str| tree.strip_prefix(prefix).and_then(|rest| rest.strip_suffix(')'));
+            let Some((arguments, sorted)) = leaf("multi_a(").map(|arguments| (arguments, false)).or(leaf("sortedmulti_a(").map(|arguments| (arguments, true))) else {
+                return Err(Error::InvalidDescriptor("taproot script trees other than a single multi_a leaf are not supported".to_string()));
+            };
+            let internal_key = XOnlyPublicKey::from_str(internal_key)
+                .map_err(|_| Error::InvalidDescriptor(format!("internal key must be a fixed x-only key: {}", internal_key)))?;
+            let (threshold, keys) = parse_multisig(arguments, MAX_MULTI_A_KEYS)?;
+            return Ok(Descriptor::TrMulti { internal_key, threshold, keys, sorted });
+        }
+
+        Err(Error::InvalidDescriptor(format!("unsupported descriptor: {}", body)))
+    }
+}
+
+impl fmt::Display for Descriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Descriptor::Pkh(key) => write!(f, "pkh({})", key),
+            Descriptor::Wpkh(key) => write!(f, "wpkh({})", key),
+            Descriptor::ShWpkh(key) => write!(f, "sh(wpkh({}))", key),
+            Descriptor::Tr(key) => write!(f, "tr({})", key),
+            Descriptor::WshMulti { threshold, keys, sorted } => {
+                let keys: Vec<_> = keys.iter().map(ToString::to_string).collect();
+                let name = if *sorted { "sortedmulti" } else { "multi" };
+                write!(f, "wsh({}({},{}))", name, threshold, keys.join(","))
+            },
+            Descriptor::TrMulti { internal_key, threshold, keys, sorted } => {
+                let keys: Vec<_> = keys.iter().map(ToString::to_string).collect();
+                let name = if *sorted { "sortedmulti_a" } else { "multi_a" };
+                write!(f, "tr({},{}({},{}))", internal_key, name, threshold, keys.join(","))
+            },
+            Descriptor::WshPolicy { primary, recoveries } => write!(f, "wsh({})", policy::miniscript(primary, recoveries)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::{Address, Network};
+
+    use super::*;
+
+    // Accounts of the "abandon ... about" test mnemonic, master fingerprint 73c5da0a
+    const BIP44_XPUB: &str = "xpub6BosfCnifzxcFwrSzQiqu2DBVTshkCXacvNsWGYJVVhhawA7d4R5WSWGFNbi8Aw6ZRc1brxMyWMzG3DSSSSoekkudhUd9yLb6qx39T9nMdj";
+    const BIP49_XPUB: &str = "xpub6C6nQwHaWbSrzs5tZ1q7m5R9cPK9eYpNMFesiXsYrgc1P8bvLLAet9JfHjYXKjToD8cBRswJXXbbFpXgwsswVPAZzKMa1jUp2kVkGVUaJa7";
+    const BIP48_XPUB: &str = "xpub6DkFAXWQ2dHxq2vatrt9qyA3bXYU4ToWQwCHbf5XB2mSTexcHZCeKS1VZYcPoBd5X8yVcbXFHJR9R8UCVpt82VX1VhR28mCyxUFL4r6KFrf";
+
+    fn address(descriptor: &str, index: u32) -> String {
+        let script = Descriptor::from_str(descriptor).unwrap().script_pubkey(index).unwrap();
+        Address::from_script(&script, Network::Bitcoin).unwrap().to_string()
+    }
+
+    #[test]
+    fn computes_checksums() {
+        assert_eq!(checksum("raw(deadbeef)").unwrap(), "89f8spxm");
+
+        let descriptor = "wpkh([d34db33f/84'/0'/0']xpub6BgqrNmJjjiaQASxUaBH9xLTBtnVpSoTzSBiGt12K572ofLub5U2rvZok5MJ5qFnBqPVi2HmnMhzQsAuZ1jG7ppoizmEzbuuCTtj9rm9Cpp/0/*)";
+        let parsed = Descriptor::from_str(&format!("{}#zejyth28", descriptor)).unwrap();
+        assert_eq!(parsed.to_string_with_checksum(), format!("{}#zejyth28", descriptor));
+        assert!(matches!(Descriptor::from_str(&format!("{}#zejyth29", descriptor)), Err(Error::InvalidDescriptor(_))));
+    }
+
+    #[test]
+    fn derives_legacy_and_nested_addresses() {
+        assert_eq!(address(&format!("pkh([73c5da0a/44h/0h/0h]{}/0/*)", BIP44_XPUB), 0), "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA");
+        assert_eq!(address(&format!("sh(wpkh([73c5da0a/49'/0'/0']{}/0/*))", BIP49_XPUB), 0), "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf");
+    }
+
+    #[test]
+    fn sorts_multisig_keys() {
+        let first = format!("[73c5da0a/44'/0'/0']{}/0/*", BIP44_XPUB);
+        let second = format!("[73c5da0a/48'/0'/0'/2']{}/0/*", BIP48_XPUB);
+        let sorted = Descriptor::from_str(&format!("wsh(sortedmulti(2,{},{}))", first, second)).unwrap();
+        let swapped = Descriptor::from_str(&format!("wsh(sortedmulti(2,{},{}))", second, first)).unwrap();
+        assert_eq!(sorted.script_pubkey(3).unwrap(), swapped.script_pubkey(3).unwrap());
+        assert!(sorted.script_pubkey(3).unwrap().is_p2wsh());
+        // Two signatures of 73, the empty push and a 71 byte script
+        assert_eq!(sorted.satisfaction_weight(0), Weight::from_wu(1 + 2 * 73 + 1 + 71));
+
+        assert!(Descriptor::from_str(&format!("wsh(multi(3,{},{}))", first, second)).is_err());
+        assert!(Descriptor::from_str(&format!("wsh(multi(0,{}))", first)).is_err());
+    }
+
+    #[test]
+    fn splits_multipath_descriptors() {
+        let descriptor = format!("tr([73c5da0a/86'/0'/0']{}/<0;1>/*)", BIP44_XPUB);
+        let (external, internal) = parse_keychains(&descriptor, None).unwrap();
+        assert_eq!(external.to_string(), format!("tr([73c5da0a/86'/0'/0']{}/0/*)", BIP44_XPUB));
+        assert_eq!(internal.to_string(), format!("tr([73c5da0a/86'/0'/0']{}/1/*)", BIP44_XPUB));
+
+        let single = format!("wpkh({}/0/*)", BIP44_XPUB);
+        assert!(parse_keychains(&single, None).is_err());
+        assert!(Descriptor::from_str(&format!("wpkh({}/0h/*)", BIP44_XPUB)).is_err());
+        assert!(Descriptor::from_str(&format!("wpkh({}/0)", BIP44_XPUB)).is_err());
+        assert!(Descriptor::from_str(&format!("tr({}/0/*,pk({}/1/*))", BIP44_XPUB, BIP44_XPUB)).is_err());
+    }
+}
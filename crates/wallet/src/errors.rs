@@ -13,7 +13,7 @@
 
 
 use crate::coin_selection;
-use bitcoin::{absolute, psbt, Amount, OutPoint, Sequence, Txid, bip32::Error as Bip32_Error};
+use bitcoin::{absolute, psbt, Amount, FeeRate, OutPoint, Sequence, Txid, bip32::Error as Bip32_Error};
 use core::fmt;
 
 
@@ -28,13 +28,48 @@ pub enum Error {
 
     /// Partially signed bitcoin transaction error
     Psbt(psbt::Error),
+    /// Finalised PSBT whose transaction could not be extracted
+    ExtractTx(Box<psbt::ExtractTxError>),
 
     /// Missing non_witness_utxo on foreign utxo for given `OutPoint`
     MissingNonWitnessUtxo(OutPoint),
     /// Creating Pubkey Error
     PubKeyError(Bip32_Error),
     NoPubKey,
-    
+    /// Descriptor the wallet cannot parse or derive from
+    InvalidDescriptor(String),
+    /// Input without the signatures its script needs
+    MissingSignature,
+    /// Spending path the wallet's descriptor does not have
+    InvalidSpendingPath(usize),
+    /// Timelock of the spending path has not passed for any spendable output
+    TimelockNotReached,
+    /// Transaction without any output to pay
+    NoRecipients,
+    /// Output at this index pays less than the dust limit of its script
+    OutputBelowDustLimit(usize),
+    /// Outpoint that is not a confirmed, spendable output of the wallet
+    UnknownUtxo(OutPoint),
+    /// Output frozen by coin control that a transaction was asked to spend
+    FrozenUtxo(OutPoint),
+    /// Transaction to replace does not signal BIP125 replaceability
+    IrreplaceableTransaction(Txid),
+    /// Transaction to replace was already mined
+    TransactionConfirmed(Txid),
+    /// Transaction pays nothing to the wallet
+    NoWalletOutputs(Txid),
+    /// Replacement or child must pay a higher fee rate than the transaction it accelerates
+    FeeRateTooLow {
+        /// Lowest fee rate accepted
+        required: FeeRate,
+    },
+    /// Replacement must pay the fee it replaces and its own relay fee
+    FeeTooLow {
+        /// Lowest absolute fee accepted
+        required: Amount,
+    },
+    /// Fee rate in sat/vB too high to be represented
+    InvalidFeeRate(u64),
 }
 
 impl fmt::Display for Error {
@@ -44,12 +79,27 @@ impl fmt::Display for Error {
             Error::CoinSelection(e) => e.fmt(f),
  
             Error::Psbt(e) => e.fmt(f),
+            Error::ExtractTx(e) => e.fmt(f),
 
             Error::MissingNonWitnessUtxo(outpoint) => {
                 write!(f, "Missing non_witness_utxo on foreign utxo {}", outpoint)
             }
             Error::PubKeyError(error) => error.fmt(f),
-            Error::NoPubKey => write!(f, "Cannot find PubKey")
+            Error::NoPubKey => write!(f, "Cannot find PubKey"),
+            Error::InvalidDescriptor(reason) => write!(f, "Invalid descriptor: {}", reason),
+            Error::MissingSignature => write!(f, "Input is missing signatures"),
+            Error::InvalidSpendingPath(path) => write!(f, "No spending path {}", path),
+            Error::TimelockNotReached => write!(f, "Timelock of the spending path has not passed"),
+            Error::NoRecipients => write!(f, "Cannot build a transaction without recipients"),
+            Error::OutputBelowDustLimit(index) => write!(f, "Output below the dust limit: {}", index),
+            Error::UnknownUtxo(outpoint) => write!(f, "No spendable wallet output {}", outpoint),
+            Error::FrozenUtxo(outpoint) => write!(f, "Wallet output {} is frozen", outpoint),
+            Error::IrreplaceableTransaction(txid) => write!(f, "Transaction {} does not signal replaceability", txid),
+            Error::TransactionConfirmed(txid) => write!(f, "Transaction {} is already confirmed", txid),
+            Error::NoWalletOutputs(txid) => write!(f, "Transaction {} pays nothing to the wallet", txid),
+            Error::FeeRateTooLow { required } => write!(f, "Fee rate too low, required {} sat/kwu", required.to_sat_per_kwu()),
+            Error::FeeTooLow { required } => write!(f, "Fee too low, required {}", required),
+            Error::InvalidFeeRate(fee_rate) => write!(f, "Fee rate of {} sat/vB is out of range", fee_rate),
         }
     }
 }
@@ -62,6 +112,12 @@ impl From<psbt::Error> for Error {
     }
 }
 
+impl From<psbt::ExtractTxError> for Error {
+    fn from(err: psbt::ExtractTxError) -> Self {
+        Error::ExtractTx(Box::new(err))
+    }
+}
+
 impl From<coin_selection::InsufficientFunds> for Error {
     fn from(err: coin_selection::InsufficientFunds) -> Self {
         Error::CoinSelection(err)
@@ -3,9 +3,9 @@ mod bindings;
 
 use std::{cell::RefCell, str::FromStr};
 
-use bindings::exports::component::wallet::{self, types::{Error, Guest, GuestWatchOnly, BitcoinNetwork as ConfigNetwork, PartialUtxo, WatchOnly}};
+use bindings::exports::component::wallet::{self, types::{Error, Guest, GuestWatchOnly, Balance, BitcoinNetwork as ConfigNetwork, Descriptors, Keychain, Keys, MultisigType as ConfigMultisigType, PartialUtxo, Recipient, UnspentUtxo, WalletType as ConfigWalletType, WatchOnly}};
 
-use bitcoin::{bip32::{Fingerprint, IntoDerivationPath, Xpub}, hashes::Hash, Amount, FeeRate, Network, OutPoint, Psbt, Txid};
+use bitcoin::{bip32::{Fingerprint, IntoDerivationPath, Xpub}, consensus::encode, hashes::Hash, Amount, FeeRate, Network, OutPoint, Psbt, ScriptBuf, Txid};
 use rand_core::RngCore;
 use wasi::random::random::{get_random_u64, get_random_bytes};
 
@@ -13,6 +13,8 @@ mod coin_selection;
 mod utils;
 mod types;
 mod errors;
+mod descriptor;
+mod policy;
 mod watch_wallet;
 
 struct WasiRandom;
@@ -52,14 +54,56 @@ impl Into<Network> for  ConfigNetwork {
     }
 }
 
+impl Into<watch_wallet::WalletType> for ConfigWalletType {
+    fn into(self) -> watch_wallet::WalletType {
+        match self {
+            ConfigWalletType::P2wpkh => watch_wallet::WalletType::P2WPKH,
+            ConfigWalletType::P2tr => watch_wallet::WalletType::P2TR,
+        }
+    }
+}
+
+impl Into<watch_wallet::MultisigType> for ConfigMultisigType {
+    fn into(self) -> watch_wallet::MultisigType {
+        match self {
+            ConfigMultisigType::P2wsh => watch_wallet::MultisigType::P2WSH,
+            ConfigMultisigType::P2tr => watch_wallet::MultisigType::P2TR,
+        }
+    }
+}
+
+impl Into<Keychain> for types::KeychainKind {
+    fn into(self) -> Keychain {
+        match self {
+            types::KeychainKind::External => Keychain::External,
+            types::KeychainKind::Internal => Keychain::Internal,
+        }
+    }
+}
+
 impl From<errors::Error> for  Error {
     fn from(value: errors::Error) -> Self {
         match value {
             errors::Error::CoinSelection(_) => Error::CoinSelection,
             errors::Error::Psbt(_) => Error::Psbt,
+            errors::Error::ExtractTx(_) => Error::Psbt,
             errors::Error::MissingNonWitnessUtxo(_) => Error::MissingNonWitnessUtxo,
             errors::Error::PubKeyError(_) => Error::PubkeyError,
             errors::Error::NoPubKey => Error::NoPubkey,
+            errors::Error::InvalidDescriptor(reason) => Error::InvalidDescriptor(reason),
+            errors::Error::MissingSignature => Error::MissingSignature,
+            errors::Error::InvalidSpendingPath(path) => Error::InvalidSpendingPath(path as u32),
+            errors::Error::TimelockNotReached => Error::TimelockNotReached,
+            errors::Error::NoRecipients => Error::NoRecipients,
+            errors::Error::OutputBelowDustLimit(index) => Error::OutputBelowDustLimit(index as u32),
+            errors::Error::UnknownUtxo(outpoint) => Error::UnknownUtxo(outpoint.into()),
+            errors::Error::FrozenUtxo(outpoint) => Error::FrozenUtxo(outpoint.into()),
+            errors::Error::IrreplaceableTransaction(txid) => Error::IrreplaceableTransaction(txid.as_raw_hash().to_byte_array().to_vec()),
+            errors::Error::TransactionConfirmed(txid) => Error::TransactionConfirmed(txid.as_raw_hash().to_byte_array().to_vec()),
+            errors::Error::NoWalletOutputs(txid) => Error::NoWalletOutputs(txid.as_raw_hash().to_byte_array().to_vec()),
+            errors::Error::FeeRateTooLow { required } => Error::FeeRateTooLow(required.to_sat_per_vb_ceil()),
+            errors::Error::FeeTooLow { required } => Error::FeeTooLow(required.to_sat()),
+            errors::Error::InvalidFeeRate(fee_rate) => Error::InvalidFeeRate(fee_rate),
         }
     }
 }
@@ -72,6 +116,8 @@ impl Into <wallet::types::PartialUtxo> for types::PartialUtxo {
             script: self.script,
             is_spent: self.is_spent,
             amount: self.amount,
+            height: self.height,
+            is_coinbase: self.is_coinbase,
         }
     }
 }
@@ -85,10 +131,93 @@ impl From<wallet::types::PartialUtxo> for types::PartialUtxo {
             amount: value.amount,
             is_spent: value.is_spent,
             script: value.script,
+            height: value.height,
+            is_coinbase: value.is_coinbase,
         }
     }
 } 
 
+impl From<OutPoint> for wallet::types::Outpoint {
+    fn from(value: OutPoint) -> Self {
+        Self { txid: value.txid.as_raw_hash().to_byte_array().to_vec(), vout: value.vout }
+    }
+}
+
+/// A txid that is not 32 bytes long cannot be an output of the wallet
+impl TryFrom<wallet::types::Outpoint> for OutPoint {
+    type Error = Error;
+
+    fn try_from(value: wallet::types::Outpoint) -> Result<Self, Error> {
+        match Txid::from_slice(&value.txid) {
+            Ok(txid) => Ok(OutPoint { txid, vout: value.vout }),
+            Err(_) => Err(Error::UnknownUtxo(value)),
+        }
+    }
+}
+
+impl From<types::LocalUtxo> for UnspentUtxo {
+    fn from(value: types::LocalUtxo) -> Self {
+        let utxo = value.utxo;
+        Self {
+            outpoint: utxo.outpoint.into(),
+            amount: utxo.txout.value.to_sat(),
+            script: utxo.txout.script_pubkey.to_bytes(),
+            keychain: utxo.keychain.into(),
+            derivation_index: utxo.derivation_index,
+            height: utxo.chain_position,
+            confirmations: value.confirmations,
+            is_coinbase: utxo.is_coinbase,
+            is_frozen: value.is_frozen,
+        }
+    }
+}
+
+/// Fee rate of the sat/vB a caller asked for, failing when it overflows
+fn fee_rate_from_sat_per_vb(fee_rate: u64) -> Result<FeeRate, errors::Error> {
+    FeeRate::from_sat_per_vb(fee_rate).ok_or(errors::Error::InvalidFeeRate(fee_rate))
+}
+
+/// Reports a key or derivation of the config that does not parse as an invalid descriptor
+fn parse_key<T, E: std::fmt::Display>(field: &str, parsed: Result<T, E>) -> Result<T, errors::Error> {
+    parsed.map_err(|err| errors::Error::InvalidDescriptor(format!("{}: {}", field, err)))
+}
+
+/// Wallet of the keys in `config`, failing on an xpub, derivation, fingerprint, descriptor or
+/// policy that does not parse
+fn wallet_from_config(config: wallet::types::Config) -> Result<watch_wallet::WatchOnly, errors::Error> {
+    let lookahead = config.lookahead.unwrap_or(watch_wallet::DEFAULT_LOOKAHEAD);
+    let options = watch_wallet::WalletOptions {
+        lookahead,
+        change_lookahead: config.change_lookahead.unwrap_or(lookahead),
+        min_confirmations: config.min_confirmations.unwrap_or(watch_wallet::DEFAULT_MIN_CONFIRMATIONS),
+    };
+    match config.keys {
+        Keys::Account(account) => {
+            let xpub = parse_key("xpub", Xpub::from_str(&account.xpub))?;
+            let account_derivation = parse_key("account derivation", account.account_derivation.into_derivation_path())?;
+            let master_fingerprint = parse_key("master fingerprint", Fingerprint::from_str(&account.master_fingerprint))?;
+            let wallet_type = account.wallet_type.map_or(watch_wallet::WalletType::P2WPKH, |wallet_type| wallet_type.into());
+            watch_wallet::WatchOnly::from_account(xpub, config.network.into(), account_derivation, master_fingerprint, wallet_type, options)
+        },
+        Keys::Multisig(multisig) => {
+            let cosigners = multisig.cosigners.into_iter().map(|cosigner| Ok((
+                parse_key("xpub", Xpub::from_str(&cosigner.xpub))?,
+                parse_key("master fingerprint", Fingerprint::from_str(&cosigner.master_fingerprint))?,
+                parse_key("account derivation", cosigner.account_derivation.into_derivation_path())?,
+            ))).collect::<Result<Vec<_>, errors::Error>>()?;
+            watch_wallet::WatchOnly::from_multisig(cosigners, multisig.threshold as usize, multisig.multisig_type.into(), config.network.into(), options)
+        },
+        Keys::Policy(policy) => {
+            let (descriptor, change_descriptor) = descriptor::policy_keychains(&policy)?;
+            watch_wallet::WatchOnly::new(descriptor, change_descriptor, config.network.into(), options)
+        },
+        Keys::Descriptors(descriptors) => {
+            let (descriptor, change_descriptor) = descriptor::parse_keychains(&descriptors.external, descriptors.internal.as_deref())?;
+            watch_wallet::WatchOnly::new(descriptor, change_descriptor, config.network.into(), options)
+        },
+    }
+}
+
 impl GuestWatchOnly for WatchOnyWallet {
     fn new(init: wallet::types::Initialization) -> Self {
         match init {
@@ -97,18 +226,23 @@ impl GuestWatchOnly for WatchOnyWallet {
                 Self{ inner:  RefCell::new(wallet)}
             },
             wallet::types::Initialization::Config(config) => {
-                let xpub = Xpub::from_str(&config.xpub).unwrap();
-                let account_derivation = config.account_derivation.into_derivation_path().unwrap();
-                let master_fingerprint = Fingerprint::from_str(&config.master_fingerprint).unwrap();
-                let wallet =  watch_wallet::WatchOnly::new(xpub, config.network.into(), account_derivation, master_fingerprint );
+                let wallet = wallet_from_config(config).expect("invalid wallet config");
                 Self{ inner:  RefCell::new(wallet)}
             },
         }
 
     }
 
+    fn create(init: wallet::types::Initialization) -> Result<WatchOnly, Error> {
+        let wallet = match init {
+            wallet::types::Initialization::OldState(state) => watch_wallet::WatchOnly::from(state),
+            wallet::types::Initialization::Config(config) => wallet_from_config(config)?,
+        };
+        Ok(WatchOnly::new(WatchOnyWallet { inner: RefCell::new(wallet) }))
+    }
+
     fn new_address(&self) -> Result<String, Error> {
-        return self.inner.borrow_mut().get_receive_address().map_err(|err| {
+        return self.inner.borrow_mut().new_receive_address().map_err(|err| {
             err.into()
         })
     }
@@ -118,37 +252,137 @@ impl GuestWatchOnly for WatchOnyWallet {
         recipient: Vec<u8>,
         amount: u64,
         fee_rate: u64,
+        spending_path: Option<u32>,
     ) -> Result<Vec<u8>, Error> {
-        let fee_rate = FeeRate::from_sat_per_vb(fee_rate).unwrap();
+        let fee_rate = fee_rate_from_sat_per_vb(fee_rate)?;
         let amount = Amount::from_sat(amount);
-        return self.inner.borrow_mut().create_psbt_tx(recipient, fee_rate, amount, & mut WasiRandom).map_err(|err| err.into())
+        return self.inner.borrow_mut().create_psbt_tx(recipient, fee_rate, amount, spending_path.unwrap_or(0) as usize, & mut WasiRandom).map_err(|err| err.into())
+    }
+
+
+    fn create_batch_transaction(
+        &self,
+        recipients: Vec<Recipient>,
+        fee_rate: u64,
+        must_spend: Vec<wallet::types::Outpoint>,
+        spending_path: Option<u32>,
+    ) -> Result<Vec<u8>, Error> {
+        let fee_rate = fee_rate_from_sat_per_vb(fee_rate)?;
+        let recipients = recipients.into_iter().map(|recipient| (ScriptBuf::from(recipient.script), Amount::from_sat(recipient.amount))).collect();
+        let must_spend = must_spend.into_iter().map(OutPoint::try_from).collect::<Result<Vec<_>, _>>()?;
+        return self.inner.borrow_mut().create_batch_psbt_tx(recipients, fee_rate, &must_spend, spending_path.unwrap_or(0) as usize, & mut WasiRandom).map_err(|err| err.into())
+    }
+
+    fn create_drain_transaction(
+        &self,
+        destination: Vec<u8>,
+        fee_rate: u64,
+        outpoints: Option<Vec<wallet::types::Outpoint>>,
+        spending_path: Option<u32>,
+    ) -> Result<Vec<u8>, Error> {
+        let fee_rate = fee_rate_from_sat_per_vb(fee_rate)?;
+        let outpoints = outpoints.map(|outpoints| outpoints.into_iter().map(OutPoint::try_from).collect::<Result<Vec<_>, _>>()).transpose()?;
+        return self.inner.borrow_mut().create_drain_psbt_tx(ScriptBuf::from(destination), fee_rate, outpoints, spending_path.unwrap_or(0) as usize).map_err(|err| err.into())
+    }
+
+    fn create_bump_fee_transaction(&self, transaction: Vec<u8>, fee_rate: u64) -> Result<Vec<u8>, Error> {
+        let transaction = encode::deserialize(&transaction).map_err(|_| Error::Psbt)?;
+        let fee_rate = fee_rate_from_sat_per_vb(fee_rate)?;
+        return self.inner.borrow_mut().create_bump_fee_psbt_tx(transaction, fee_rate, & mut WasiRandom).map_err(|err| err.into())
     }
 
+    fn create_cpfp_transaction(&self, parent: Vec<u8>, fee_rate: u64) -> Result<Vec<u8>, Error> {
+        let parent = encode::deserialize(&parent).map_err(|_| Error::Psbt)?;
+        let fee_rate = fee_rate_from_sat_per_vb(fee_rate)?;
+        return self.inner.borrow_mut().create_cpfp_psbt_tx(parent, fee_rate, & mut WasiRandom).map_err(|err| err.into())
+    }
     
     fn get_utxos(&self) -> Result<Vec<wallet::types::PartialUtxo>, Error> {
         let partial_utxos = self.inner.borrow_mut().get_utxos().map_err(Error::from)?;
         return Ok(partial_utxos.into_iter().map(|utxo| utxo.into()).collect())
     }
     
+    fn list_unspent(&self) -> Vec<UnspentUtxo> {
+        return self.inner.borrow().list_unspent().into_iter().map(From::from).collect()
+    }
+
+    fn freeze_utxo(&self, outpoint: wallet::types::Outpoint) -> Result<(), Error> {
+        return self.inner.borrow_mut().freeze_utxo(outpoint.try_into()?).map_err(|err| err.into())
+    }
+
+    fn unfreeze_utxo(&self, outpoint: wallet::types::Outpoint) -> Result<(), Error> {
+        return self.inner.borrow_mut().unfreeze_utxo(outpoint.try_into()?).map_err(|err| err.into())
+    }
+
     fn insert_utxos(&self, utxos: Vec<wallet::types::PartialUtxo>) -> Result<(), Error> {
         let mapped_utxos: Vec<_> = utxos.into_iter().map(From::from).collect();
         return self.inner.borrow_mut().insert_utxos(mapped_utxos).map_err(|err| err.into())
     }
+
+    fn insert_unconfirmed_utxos(&self, utxos: Vec<wallet::types::PartialUtxo>) -> Result<(), Error> {
+        let mapped_utxos: Vec<_> = utxos.into_iter().map(From::from).collect();
+        return self.inner.borrow_mut().insert_unconfirmed_utxos(mapped_utxos).map_err(|err| err.into())
+    }
+
+    fn remove_utxos(&self, utxos: Vec<wallet::types::PartialUtxo>) -> Result<(), Error> {
+        let mapped_utxos: Vec<_> = utxos.into_iter().map(From::from).collect();
+        return self.inner.borrow_mut().remove_utxos(mapped_utxos).map_err(|err| err.into())
+    }
     
+    fn mark_pending_spent(&self, transaction: Vec<u8>) -> Result<(), Error> {
+        let transaction = encode::deserialize(&transaction).map_err(|_| Error::Psbt)?;
+        self.inner.borrow_mut().mark_pending_spent(&transaction);
+        Ok(())
+    }
+
+    fn release_pending_spent(&self, txid: Vec<u8>) {
+        if let Ok(txid) = Txid::from_slice(&txid) {
+            self.inner.borrow_mut().release_pending_spent(txid)
+        }
+    }
+
     fn get_pubkeys(&self) -> Result<Vec<wallet::types::Pubkey>, Error> {
         return self.inner.borrow_mut().get_pubkeys().map_err(|err| err.into())
     }
     
+    fn get_keychain(&self, script: wallet::types::Pubkey) -> Option<Keychain> {
+        return self.inner.borrow().get_keychain(&script).map(|keychain| keychain.into())
+    }
+
     fn balance(&self) -> Result<u64, Error> {
         return self.inner.borrow_mut().balance().map(|amount| amount.to_sat()).map_err(|err| err.into())
     }
     
+    fn pending_balance(&self) -> Result<u64, Error> {
+        return self.inner.borrow_mut().pending_balance().map(|amount| amount.to_sat()).map_err(|err| err.into())
+    }
+
+    fn balance_breakdown(&self) -> Result<Balance, Error> {
+        let balance = self.inner.borrow().balance_breakdown().map_err(Error::from)?;
+        return Ok(Balance {
+            confirmed: balance.confirmed.to_sat(),
+            trusted_pending: balance.trusted_pending.to_sat(),
+            untrusted_pending: balance.untrusted_pending.to_sat(),
+            immature: balance.immature.to_sat(),
+        })
+    }
+
+    fn set_tip_height(&self, height: u32) {
+        self.inner.borrow_mut().set_tip_height(height)
+    }
+
     fn get_receive_address(&self) -> Result<String, Error> {
         return self.inner.borrow_mut().get_receive_address().map_err(|err| err.into())
     }
     
+    fn combine_psbts(&self, psbt: Vec<u8>, others: Vec<Vec<u8>>) -> Result<Vec<u8>, Error> {
+        let psbt = Psbt::deserialize(&psbt).map_err(|_| Error::Psbt)?;
+        let others = others.iter().map(|other| Psbt::deserialize(other)).collect::<Result<Vec<_>, _>>().map_err(|_| Error::Psbt)?;
+        return self.inner.borrow().combine_psbts(psbt, others).map_err(|err| err.into())
+    }
+
     fn finalise_transaction( &self, psbt: Vec<u8>) -> Result<Vec<u8>, Error> {
-        let psbt = Psbt::deserialize(&psbt).unwrap();
+        let psbt = Psbt::deserialize(&psbt).map_err(|_| Error::Psbt)?;
         return self.inner.borrow_mut().finalise_psbt_tx(psbt).map_err(|err| err.into())
     }
     
@@ -156,6 +390,11 @@ impl GuestWatchOnly for WatchOnyWallet {
         return self.inner.borrow_mut().get_state();
     }
 
+    fn get_descriptors(&self) -> Descriptors {
+        let (external, internal) = self.inner.borrow().descriptors();
+        Descriptors { external, internal: Some(internal) }
+    }
+
 }
 
 
@@ -167,3 +406,52 @@ impl Guest for Component {
 }
 
 bindings::export!(Component with_types_in bindings);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bindings::exports::component::wallet::types::{Account, Config, Cosigner, Multisig};
+
+    const XPUB: &str = "xpub6BgqrNmJjjiaQASxUaBH9xLTBtnVpSoTzSBiGt12K572ofLub5U2rvZok5MJ5qFnBqPVi2HmnMhzQsAuZ1jG7ppoizmEzbuuCTtj9rm9Cpp";
+
+    fn config(keys: Keys) -> Config {
+        Config { keys, network: ConfigNetwork::Bitcoin, lookahead: None, change_lookahead: None, min_confirmations: None }
+    }
+
+    fn account(xpub: &str, account_derivation: &str, master_fingerprint: &str) -> Keys {
+        Keys::Account(Account { xpub: xpub.to_string(), account_derivation: account_derivation.to_string(), master_fingerprint: master_fingerprint.to_string(), wallet_type: None })
+    }
+
+    #[test]
+    fn test_wallet_from_config_rejects_what_does_not_parse() {
+        assert!(wallet_from_config(config(account(XPUB, "m/84'/0'/0'", "d34db33f"))).is_ok());
+
+        for keys in [
+            account("xpub", "m/84'/0'/0'", "d34db33f"),
+            account(XPUB, "m/84'/x", "d34db33f"),
+            account(XPUB, "m/84'/0'/0'", "beef"),
+            Keys::Descriptors(Descriptors { external: "wpkh(nokey)".to_string(), internal: None }),
+            Keys::Policy("or(pk(A),".to_string()),
+            Keys::Multisig(Multisig { threshold: 1, multisig_type: ConfigMultisigType::P2wsh, cosigners: vec![
+                Cosigner { xpub: XPUB.to_string(), account_derivation: "m/48'/0'/0'/2'".to_string(), master_fingerprint: "nothex!!".to_string() },
+            ] }),
+        ] {
+            assert!(matches!(wallet_from_config(config(keys)), Err(errors::Error::InvalidDescriptor(_))));
+        }
+    }
+
+    #[test]
+    fn test_outpoint_with_short_txid_is_unknown() {
+        let outpoint = wallet::types::Outpoint { txid: vec![1; 32], vout: 2 };
+        assert_eq!(OutPoint::try_from(outpoint).unwrap().vout, 2);
+
+        let short = wallet::types::Outpoint { txid: vec![1; 31], vout: 2 };
+        assert!(matches!(OutPoint::try_from(short), Err(Error::UnknownUtxo(outpoint)) if outpoint.txid.len() == 31));
+    }
+
+    #[test]
+    fn test_fee_rate_from_sat_per_vb_rejects_overflows() {
+        assert_eq!(fee_rate_from_sat_per_vb(3).unwrap(), FeeRate::from_sat_per_kwu(750));
+        assert!(matches!(fee_rate_from_sat_per_vb(u64::MAX), Err(errors::Error::InvalidFeeRate(u64::MAX))));
+    }
+}
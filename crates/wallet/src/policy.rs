@@ -0,0 +1,316 @@
+//! Spending policies with timelocked recovery paths
+//!
+//! A policy is a primary key that can always spend, or'ed with recovery keys that can only spend
+//! once a timelock passed, for instance `or(99@pk(A),and(pk(B),older(26280)))`. It compiles to a
+//! `wsh` miniscript descriptor, `or_d(pk(A),and_v(v:pk(B),older(26280)))`, further recovery
+//! paths nesting under `or_i`. Spending path 0 is the primary key, path `n` the nth recovery
+//! key in the order the policy lists them.
+
+use std::fmt;
+
+use bitcoin::{blockdata::{opcodes::all::{OP_CHECKSIG, OP_CHECKSIGVERIFY, OP_CLTV, OP_CSV, OP_ELSE, OP_ENDIF, OP_IF, OP_IFDUP, OP_NOTIF}, script::{Builder, Instruction}}, psbt::Input, PublicKey, ScriptBuf, VarInt, Weight, Witness};
+use serde::Serialize;
+
+use crate::{descriptor::DescriptorKey, errors::Error};
+
+/// Largest relative timelock in blocks, time based ones are not supported
+const MAX_OLDER: u32 = 0xffff;
+
+/// Heights from which `nLockTime` counts seconds instead
+const LOCKTIME_THRESHOLD: u32 = 500_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, Serialize)]
+pub enum Timelock {
+    /// Blocks the spent output must be deep, `OP_CHECKSEQUENCEVERIFY`
+    Older(u32),
+    /// Height the spending transaction can be mined after, `OP_CHECKLOCKTIMEVERIFY`
+    After(u32),
+}
+
+impl Timelock {
+    fn parse(name: &str, value: &str) -> Result<Self, Error> {
+        let value: u32 = value.parse().map_err(|_| Error::InvalidDescriptor(format!("invalid timelock: {}", value)))?;
+        match name {
+            "older" if (1..=MAX_OLDER).contains(&value) => Ok(Timelock::Older(value)),
+            "after" if (1..LOCKTIME_THRESHOLD).contains(&value) => Ok(Timelock::After(value)),
+            _ => Err(Error::InvalidDescriptor(format!("unsupported timelock: {}({})", name, value))),
+        }
+    }
+}
+
+impl fmt::Display for Timelock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Timelock::Older(blocks) => write!(f, "older({})", blocks),
+            Timelock::After(height) => write!(f, "after({})", height),
+        }
+    }
+}
+
+/// Key that spends once its timelock passed
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, Serialize)]
+pub struct Recovery {
+    pub key: DescriptorKey,
+    pub timelock: Timelock,
+}
+
+/// Expression of the policy and descriptor languages, `name(args, ...)`
+pub(crate) struct Tree<'a> {
+    pub name: &'a str,
+    pub args: Vec<Tree<'a>>,
+}
+
+impl<'a> Tree<'a> {
+
+    pub fn parse(expression: &'a str) -> Result<Self, Error> {
+        match Self::parse_prefix(expression)? {
+            (tree, "") => Ok(tree),
+            (_, rest) => Err(Error::InvalidDescriptor(format!("unexpected {}", rest))),
+        }
+    }
+
+    fn parse_prefix(expression: &'a str) -> Result<(Self, &'a str), Error> {
+        let end = expression.find(['(', ',', ')']).unwrap_or(expression.len());
+        let (name, mut rest) = expression.split_at(end);
+        let mut args = Vec::new();
+        if let Some(inner) = rest.strip_prefix('(') {
+            rest = inner;
+            loop {
+                let (arg, after) = Self::parse_prefix(rest)?;
+                args.push(arg);
+                match after.chars().next() {
+                    Some(',') => rest = &after[1..],
+                    Some(')') => {
+                        rest = &after[1..];
+                        break;
+                    },
+                    _ => return Err(Error::InvalidDescriptor(format!("unclosed {}(", name))),
+                }
+            }
+        }
+        Ok((Tree { name, args }, rest))
+    }
+
+    fn key(&self) -> Result<DescriptorKey, Error> {
+        match self.args.as_slice() {
+            [] => self.name.parse(),
+            _ => Err(Error::InvalidDescriptor(format!("expected a key, found {}", self.name))),
+        }
+    }
+
+    /// Argument of a single argument fragment
+    fn only_arg(&self) -> Result<&Tree<'a>, Error> {
+        match self.args.as_slice() {
+            [arg] => Ok(arg),
+            _ => Err(Error::InvalidDescriptor(format!("{} takes one argument", self.name))),
+        }
+    }
+}
+
+/// Primary key and recovery paths of a policy, keys still in descriptor form
+pub fn compile(policy: &str) -> Result<(DescriptorKey, Vec<Recovery>), Error> {
+    let tree = Tree::parse(policy)?;
+    let mut branches = Vec::new();
+    flatten_or(&tree, &mut branches);
+
+    let mut primary = None;
+    let mut recoveries = Vec::new();
+    for branch in branches {
+        match (strip_probability(branch), branch.args.as_slice()) {
+            ("pk", [key]) if primary.is_none() => primary = Some(key.key()?),
+            ("and", [first, second]) => {
+                let (key, timelock) = match (first.name, second.name) {
+                    ("pk", _) => (first, second),
+                    (_, "pk") => (second, first),
+                    _ => return Err(Error::InvalidDescriptor("recovery paths need a key and a timelock".to_string())),
+                };
+                let timelock = Timelock::parse(timelock.name, timelock.only_arg()?.name)?;
+                recoveries.push(Recovery { key: key.only_arg()?.key()?, timelock });
+            },
+            _ => return Err(Error::InvalidDescriptor(format!("unsupported policy branch {}", branch.name))),
+        }
+    }
+
+    match primary {
+        Some(primary) if !recoveries.is_empty() => Ok((primary, recoveries)),
+        _ => Err(Error::InvalidDescriptor("policies need a primary key and a recovery path".to_string())),
+    }
+}
+
+/// Branches of nested `or`s, probabilities dropped
+fn flatten_or<'a, 'b>(tree: &'b Tree<'a>, branches: &mut Vec<&'b Tree<'a>>) {
+    match (strip_probability(tree), tree.args.as_slice()) {
+        ("or", [left, right]) => {
+            flatten_or(left, branches);
+            flatten_or(right, branches);
+        },
+        _ => branches.push(tree),
+    }
+}
+
+/// Drops a `weight@` probability prefix
+fn strip_probability<'a>(tree: &Tree<'a>) -> &'a str {
+    tree.name.split_once('@').map_or(tree.name, |(_, name)| name)
+}
+
+/// Primary key and recovery paths of the miniscript inside `wsh()`
+pub(crate) fn parse_miniscript(tree: &Tree) -> Result<(DescriptorKey, Vec<Recovery>), Error> {
+    let invalid = || Error::InvalidDescriptor("unsupported miniscript".to_string());
+    let [primary, recovery] = tree.args.as_slice() else { return Err(invalid()) };
+    if tree.name != "or_d" || primary.name != "pk" {
+        return Err(invalid());
+    }
+
+    let mut recoveries = Vec::new();
+    let mut next = recovery;
+    loop {
+        let (branch, rest) = match (next.name, next.args.as_slice()) {
+            ("or_i", [branch, rest]) => (branch, Some(rest)),
+            _ => (next, None),
+        };
+        let [key, timelock] = branch.args.as_slice() else { return Err(invalid()) };
+        if branch.name != "and_v" || key.name != "v:pk" {
+            return Err(invalid());
+        }
+        recoveries.push(Recovery { key: key.only_arg()?.key()?, timelock: Timelock::parse(timelock.name, timelock.only_arg()?.name)? });
+        match rest {
+            Some(rest) => next = rest,
+            None => break,
+        }
+    }
+    Ok((primary.only_arg()?.key()?, recoveries))
+}
+
+/// Miniscript of the policy, without the `wsh()`
+pub fn miniscript(primary: &DescriptorKey, recoveries: &[Recovery]) -> String {
+    fn recovery(recoveries: &[Recovery]) -> String {
+        let first = format!("and_v(v:pk({}),{})", recoveries[0].key, recoveries[0].timelock);
+        match recoveries.len() {
+            1 => first,
+            _ => format!("or_i({},{})", first, recovery(&recoveries[1..])),
+        }
+    }
+    format!("or_d(pk({}),{})", primary, recovery(recoveries))
+}
+
+/// Witness script with the keys at `index`
+pub fn script(primary: &DescriptorKey, recoveries: &[Recovery], index: u32) -> Result<ScriptBuf, Error> {
+    fn recovery(builder: Builder, recoveries: &[Recovery], index: u32) -> Result<Builder, Error> {
+        let (pubkey, _) = recoveries[0].key.derive(index)?;
+        let builder = builder.push_slice(pubkey.to_bytes()).push_opcode(OP_CHECKSIGVERIFY);
+        let first = match recoveries[0].timelock {
+            Timelock::Older(blocks) => builder.push_int(blocks as i64).push_opcode(OP_CSV),
+            Timelock::After(height) => builder.push_int(height as i64).push_opcode(OP_CLTV),
+        };
+        match recoveries.len() {
+            1 => Ok(first),
+            // The selector picks the first path on a 1, the rest on an empty push
+            _ => Ok(recovery(first.push_opcode(OP_ELSE), &recoveries[1..], index)?.push_opcode(OP_ENDIF)),
+        }
+    }
+
+    let (pubkey, _) = primary.derive(index)?;
+    let builder = Builder::new().push_slice(pubkey.to_bytes()).push_opcode(OP_CHECKSIG).push_opcode(OP_IFDUP).push_opcode(OP_NOTIF);
+    let builder = match recoveries.len() {
+        1 => recovery(builder, recoveries, index)?,
+        _ => recovery(builder.push_opcode(OP_IF), recoveries, index)?,
+    };
+    Ok(builder.push_opcode(OP_ENDIF).into_script())
+}
+
+/// Witness items spending through `path` without the signature and the script, bottom of the
+/// stack first
+fn selectors(recoveries: usize, path: usize) -> Vec<Vec<u8>> {
+    match path {
+        0 => vec![],
+        _ => {
+            let recovery = path - 1;
+            // Picks the recovery branch unless it is the last one, then skips every branch
+            // before it, and finally fails the primary key check
+            let mut items = Vec::new();
+            if recovery + 1 < recoveries {
+                items.push(vec![1]);
+            }
+            items.extend((0..=recovery).map(|_| vec![]));
+            items
+        },
+    }
+}
+
+/// Weight of the witness spending through `path`, the item count left out
+pub fn satisfaction_weight(primary: &DescriptorKey, recoveries: &[Recovery], path: usize) -> Weight {
+    let script_len = script(primary, recoveries, 0).expect("index 0 is unhardened").len();
+    let selectors: usize = selectors(recoveries.len(), path).iter().map(|item| 1 + item.len()).sum();
+    // DER signature with its sighash byte
+    let wu = 1 + 72 + selectors + VarInt(script_len as u64).size() + script_len;
+    Weight::from_wu(wu as u64)
+}
+
+/// Witness of a signed input, through the primary key when it signed, the first recovery key
+/// that signed otherwise
+pub fn witness(input: &Input) -> Result<Witness, Error> {
+    let witness_script = input.witness_script.as_ref().ok_or(Error::MissingSignature)?;
+    // The primary key comes first, then one key per recovery path
+    let keys: Vec<_> = witness_script.instructions()
+        .filter_map(|instruction| match instruction {
+            Ok(Instruction::PushBytes(bytes)) => PublicKey::from_slice(bytes.as_bytes()).ok(),
+            _ => None,
+        })
+        .collect();
+    let (path, signature) = keys.iter().enumerate()
+        .find_map(|(path, key)| input.partial_sigs.get(key).map(|signature| (path, signature)))
+        .ok_or(Error::MissingSignature)?;
+
+    let mut witness = Witness::new();
+    witness.push(signature.serialize());
+    for item in selectors(keys.len() - 1, path) {
+        witness.push(item);
+    }
+    witness.push(witness_script.as_bytes());
+    Ok(witness)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bitcoin::blockdata::opcodes::all::OP_PUSHNUM_5;
+
+    use super::*;
+    use crate::descriptor::Descriptor;
+
+    const PRIMARY: &str = "[73c5da0a/44'/0'/0']xpub6BosfCnifzxcFwrSzQiqu2DBVTshkCXacvNsWGYJVVhhawA7d4R5WSWGFNbi8Aw6ZRc1brxMyWMzG3DSSSSoekkudhUd9yLb6qx39T9nMdj/0/*";
+    const RECOVERY: &str = "[73c5da0a/49'/0'/0']xpub6C6nQwHaWbSrzs5tZ1q7m5R9cPK9eYpNMFesiXsYrgc1P8bvLLAet9JfHjYXKjToD8cBRswJXXbbFpXgwsswVPAZzKMa1jUp2kVkGVUaJa7/0/*";
+
+    #[test]
+    fn compiles_recovery_paths() {
+        let descriptor = Descriptor::from_policy(&format!("or(99@pk({}),1@and(pk({}),older(26280)))", PRIMARY, RECOVERY)).unwrap();
+        assert_eq!(descriptor.to_string(), format!("wsh(or_d(pk({}),and_v(v:pk({}),older(26280))))", PRIMARY, RECOVERY));
+        assert_eq!(Descriptor::from_str(&descriptor.to_string_with_checksum()).unwrap(), descriptor);
+        assert_eq!(descriptor.timelock(1).unwrap(), Some(Timelock::Older(26280)));
+
+        // The timelock can come first and recovery paths nest under or_i
+        let nested = Descriptor::from_policy(&format!("or(pk({}),or(and(older(5),pk({})),and(pk({}),after(800000))))", PRIMARY, RECOVERY, PRIMARY)).unwrap();
+        assert!(nested.to_string().contains(",or_i(and_v(v:pk("));
+        assert_eq!(Descriptor::from_str(&nested.to_string()).unwrap(), nested);
+        assert_eq!(nested.timelock(2).unwrap(), Some(Timelock::After(800000)));
+
+        let Descriptor::WshPolicy { primary, recoveries } = nested else { panic!("policy descriptor") };
+        let script = script(&primary, &recoveries, 0).unwrap();
+        let opcodes: Vec<_> = script.instructions().filter_map(|instruction| instruction.unwrap().opcode()).collect();
+        assert_eq!(opcodes, [OP_CHECKSIG, OP_IFDUP, OP_NOTIF, OP_IF, OP_CHECKSIGVERIFY, OP_PUSHNUM_5, OP_CSV, OP_ELSE, OP_CHECKSIGVERIFY, OP_CLTV, OP_ENDIF, OP_ENDIF]);
+
+        assert!(Descriptor::from_policy(&format!("pk({})", PRIMARY)).is_err());
+        assert!(Descriptor::from_policy(&format!("or(pk({}),and(pk({}),older(70000)))", PRIMARY, RECOVERY)).is_err());
+        assert!(Descriptor::from_policy(&format!("or(pk({}),pk({}))", PRIMARY, RECOVERY)).is_err());
+    }
+
+    #[test]
+    fn selects_recovery_branches() {
+        assert!(selectors(2, 0).is_empty());
+        assert_eq!(selectors(1, 1), vec![vec![]]);
+        assert_eq!(selectors(3, 1), vec![vec![1], vec![]]);
+        assert_eq!(selectors(3, 2), vec![vec![1], vec![], vec![]]);
+        assert_eq!(selectors(3, 3), vec![vec![], vec![], vec![]]);
+    }
+}
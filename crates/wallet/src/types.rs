@@ -13,7 +13,7 @@ use core::convert::AsRef;
 
 use bitcoin::hashes::Hash;
 use bitcoin::transaction::{OutPoint, Sequence, TxOut};
-use bitcoin::{psbt, Weight};
+use bitcoin::{psbt, Amount, Weight};
 
 use serde::{Deserialize, Serialize};
 
@@ -70,20 +70,37 @@ pub struct Utxo {
    pub keychain: KeychainKind,
    /// Whether this UTXO is spent or not
    pub is_spent: bool,
+   /// Whether the transaction creating this UTXO was seen in a block, not only in the mempool
+   pub is_confirmed: bool,
+   /// Whether the UTXO is a coinbase output, which only matures after 100 blocks
+   pub is_coinbase: bool,
    /// The derivation index for the script pubkey in the wallet
    pub derivation_index: u32,
-   /// The position of the output in the blockchain.
+   /// Height of the block that mined the UTXO, `None` while unconfirmed
    pub chain_position: Option<u32>,
     
 }
 
+/// Unspent wallet output with what coin control needs to know about it
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalUtxo {
+    /// The UTXO
+    pub utxo: Utxo,
+    /// Blocks from the one that mined it up to the tip, 0 while unconfirmed
+    pub confirmations: u32,
+    /// Coin selection leaves it alone until it is unfrozen
+    pub is_frozen: bool,
+}
+
 impl Into<PartialUtxo> for Utxo {
     fn into(self) -> PartialUtxo {
         return PartialUtxo {
             outpoint: self.outpoint,
             amount: self.txout.value.to_sat(),
             script: self.txout.script_pubkey.to_bytes(),
-            is_spent: self.is_spent
+            is_spent: self.is_spent,
+            height: self.chain_position,
+            is_coinbase: self.is_coinbase,
         }
     }
 }
@@ -92,9 +109,22 @@ pub struct PartialUtxo {
     pub outpoint: OutPoint,
     pub amount: u64,
     pub script: Vec<u8>,
-    pub is_spent: bool
-
+    pub is_spent: bool,
+    pub height: Option<u32>,
+    pub is_coinbase: bool,
+}
 
+/// Wallet balance split by how final the outputs are
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Balance {
+    /// Mined outputs, immature coinbase left out
+    pub confirmed: Amount,
+    /// Unconfirmed change of our own transactions
+    pub trusted_pending: Amount,
+    /// Unconfirmed payments from others, they may never be mined
+    pub untrusted_pending: Amount,
+    /// Coinbase outputs that are not 100 blocks deep yet
+    pub immature: Amount,
 }
 
 #[derive(serde::Deserialize, Serialize)]
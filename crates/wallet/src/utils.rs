@@ -9,8 +9,7 @@
 // You may not use this file except in accordance with one or both of these
 // licenses.
 
-use bitcoin::secp256k1::{All, Secp256k1};
-use bitcoin::{absolute, relative, Amount, Script, Sequence};
+use bitcoin::{Amount, Script};
 
 
 use rand_core::RngCore;
@@ -49,29 +48,13 @@ impl After {
             assume_height_reached,
         }
     }
-}
-
-pub(crate) fn check_nsequence_rbf(sequence: Sequence, csv: Sequence) -> bool {
-    // The nSequence value must enable relative timelocks
-    if !sequence.is_relative_lock_time() {
-        return false;
-    }
-
-    // Both values should be represented in the same unit (either time-based or
-    // block-height based)
-    if sequence.is_time_locked() != csv.is_time_locked() {
-        return false;
-    }
 
-    // The value should be at least `csv`
-    if sequence < csv {
-        return false;
+    /// Whether a transaction mined in the next block satisfies `after(height)`
+    pub(crate) fn is_satisfied(&self, height: u32) -> bool {
+        self.assume_height_reached || self.current_height.is_some_and(|current| current >= height)
     }
-
-    true
 }
 
-
 pub struct Older {
     pub current_height: Option<u32>,
     pub create_height: Option<u32>,
@@ -90,6 +73,15 @@ impl Older {
             assume_height_reached,
         }
     }
+
+    /// Whether a transaction mined in the next block satisfies `older(blocks)`
+    pub(crate) fn is_satisfied(&self, blocks: u32) -> bool {
+        match (self.current_height, self.create_height) {
+            _ if self.assume_height_reached => true,
+            (Some(current), Some(create)) => current >= create && current - create + 1 >= blocks,
+            _ => false,
+        }
+    }
 }
 
 
@@ -107,16 +99,10 @@ pub(crate) fn shuffle_slice<T>(list: &mut [T], rng: &mut impl RngCore) {
     }
 }
 
-pub(crate) type SecpCtx = Secp256k1<All>;
-
 // #[cfg(test)]
 // mod test {
-//     // When nSequence is lower than this flag the timelock is interpreted as block-height-based,
-//     // otherwise it's time-based
-//     pub(crate) const SEQUENCE_LOCKTIME_TYPE_FLAG: u32 = 1 << 22;
-
-//     use super::{check_nsequence_rbf, shuffle_slice, IsDust};
-//     use crate::bitcoin::{Address, Network, Sequence};
+//     use super::{shuffle_slice, IsDust};
+//     use crate::bitcoin::{Address, Network};
 //     use alloc::vec::Vec;
 //     use core::str::FromStr;
 //     use rand::{rngs::StdRng, thread_rng, SeedableRng};
@@ -142,46 +128,6 @@ pub(crate) type SecpCtx = Secp256k1<All>;
 //         assert!(!294.is_dust(&script_p2wpkh));
 //     }
 
-//     #[test]
-//     fn test_check_nsequence_rbf_msb_set() {
-//         let result = check_nsequence_rbf(Sequence(0x80000000), Sequence(5000));
-//         assert!(!result);
-//     }
-
-//     #[test]
-//     fn test_check_nsequence_rbf_lt_csv() {
-//         let result = check_nsequence_rbf(Sequence(4000), Sequence(5000));
-//         assert!(!result);
-//     }
-
-//     #[test]
-//     fn test_check_nsequence_rbf_different_unit() {
-//         let result =
-//             check_nsequence_rbf(Sequence(SEQUENCE_LOCKTIME_TYPE_FLAG + 5000), Sequence(5000));
-//         assert!(!result);
-//     }
-
-//     #[test]
-//     fn test_check_nsequence_rbf_mask() {
-//         let result = check_nsequence_rbf(Sequence(0x3f + 10_000), Sequence(5000));
-//         assert!(result);
-//     }
-
-//     #[test]
-//     fn test_check_nsequence_rbf_same_unit_blocks() {
-//         let result = check_nsequence_rbf(Sequence(10_000), Sequence(5000));
-//         assert!(result);
-//     }
-
-//     #[test]
-//     fn test_check_nsequence_rbf_same_unit_time() {
-//         let result = check_nsequence_rbf(
-//             Sequence(SEQUENCE_LOCKTIME_TYPE_FLAG + 10_000),
-//             Sequence(SEQUENCE_LOCKTIME_TYPE_FLAG + 5000),
-//         );
-//         assert!(result);
-//     }
-
 //     #[test]
 //     #[cfg(feature = "std")]
 //     fn test_shuffle_slice_empty_vec() {
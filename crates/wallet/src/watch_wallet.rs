@@ -1,31 +1,82 @@
-use std::{collections::BTreeMap, vec};
+use std::{collections::{BTreeMap, BTreeSet}, vec};
 
-use bitcoin::{absolute::LockTime, bip32::{ChildNumber, DerivationPath, Fingerprint, Xpub}, consensus::{encode, Encodable}, key::Secp256k1, psbt::{self, Input, PsbtSighashType}, transaction::Version, Address, Amount, CompressedPublicKey, EcdsaSighashType, FeeRate, Network, OutPoint, Psbt, Script, ScriptBuf, Transaction, TxIn, TxOut, Weight, Witness};
+use bitcoin::{absolute::LockTime, bip32::{DerivationPath, Fingerprint, Xpub}, consensus::{encode, Encodable}, psbt::{self, Input}, transaction::Version, Address, Amount, FeeRate, Network, OutPoint, Psbt, Script, ScriptBuf, Sequence, Transaction, TxIn, TxOut, Txid};
 use serde::Serialize;
 
-use crate::{coin_selection::{CoinSelectionAlgorithm, DefaultCoinSelectionAlgorithm, Excess}, errors::{self, Error}, types::{self, KeychainKind, PartialUtxo, PubkeyDetails, Utxo, WeightedUtxo}};
+use crate::{coin_selection::{CoinSelectionAlgorithm, DefaultCoinSelectionAlgorithm, Excess, InsufficientFunds}, descriptor::Descriptor, errors::{self, Error}, policy::Timelock, utils::{After, IsDust, Older}, types::{self, Balance, KeychainKind, LocalUtxo, PartialUtxo, PubkeyDetails, Utxo, WeightedUtxo}};
 use rand_core::RngCore;
 
 
-#[derive(Copy, Clone, serde::Deserialize, Serialize)]
+/// Scripts derived past the last used index of each keychain when none is configured
+pub const DEFAULT_LOOKAHEAD: u32 = 20;
+
+/// Confirmations an output needs before coin selection spends it when none is configured
+pub const DEFAULT_MIN_CONFIRMATIONS: u32 = 1;
+
+/// Confirmations before a coinbase output can be spent
+const COINBASE_MATURITY: u32 = 100;
+
+/// Scripts watched past the depth of each keychain and confirmations coin selection waits for
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WalletOptions {
+    pub lookahead: u32,
+    pub change_lookahead: u32,
+    pub min_confirmations: u32,
+}
+
+impl Default for WalletOptions {
+    fn default() -> Self {
+        WalletOptions { lookahead: DEFAULT_LOOKAHEAD, change_lookahead: DEFAULT_LOOKAHEAD, min_confirmations: DEFAULT_MIN_CONFIRMATIONS }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, serde::Deserialize, Serialize)]
 pub enum WalletType {
     P2WPKH,
+    /// Single key taproot spent through the key path, BIP86
+    P2TR,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, serde::Deserialize, Serialize)]
+pub enum MultisigType {
+    /// `wsh(sortedmulti)`
+    P2WSH,
+    /// `sortedmulti_a` leaf under an unspendable internal key
+    P2TR,
 }
 
 
 #[derive(serde::Deserialize, Serialize)]
 #[allow(dead_code)]
 pub struct  WatchOnly {
-    account_xpub: Xpub,
+    /// Derives the receive scripts
+    descriptor: Descriptor,
+    /// Derives the change scripts
+    change_descriptor: Descriptor,
     network: Network,
     pubkey_map: BTreeMap<Vec<u8>, PubkeyDetails>,
-    wallet_type: WalletType,
+    /// Next receive index to hand out, past every receive script seen used
     receive_depth: u32,
+    /// Next change index to pay to, past every change script seen used
     change_depth: u32,
+    /// Scripts watched beyond the receive depth, so payments to them are found after restoring
+    /// a seed
+    lookahead: u32,
+    /// Scripts watched beyond the change depth, change is only ever paid by this wallet so a
+    /// smaller window usually does
+    change_lookahead: u32,
+    /// Number of scripts derived so far on each keychain
+    receive_derived: u32,
+    change_derived: u32,
+    /// Height of the last block scanned for the wallet
+    tip_height: u32,
+    /// Confirmations an output needs before coin selection spends it, 0 allows unconfirmed ones
+    min_confirmations: u32,
     utxo_map: BTreeMap<OutPoint, WeightedUtxo>,
-    account_derivation: DerivationPath,
-    master_fingerprint: Fingerprint,
-
+    /// Outputs coin selection must not spend, such as dust sent to track the wallet
+    frozen: BTreeSet<OutPoint>,
+    /// Outputs spent by our own transactions that no block mined yet, with the spending txid
+    pending_spends: BTreeMap<OutPoint, Txid>,
 }
 
 
@@ -33,17 +84,53 @@ pub struct  WatchOnly {
 #[allow(dead_code)]
 impl WatchOnly {
 
-    pub fn new(account_xpub: Xpub, network: Network, account_derivation: DerivationPath, master_fingerprint: Fingerprint) -> Self {
-        WatchOnly {
-            account_xpub,
+    pub fn new(descriptor: Descriptor, change_descriptor: Descriptor, network: Network, options: WalletOptions) -> Result<Self, errors::Error> {
+        let mut wallet = WatchOnly {
+            descriptor,
+            change_descriptor,
             network,
             pubkey_map: BTreeMap::new(),
             utxo_map: BTreeMap::new(),
-            wallet_type: WalletType::P2WPKH,
+            frozen: BTreeSet::new(),
+            pending_spends: BTreeMap::new(),
             receive_depth: 0,
             change_depth: 0,
-            account_derivation,
-            master_fingerprint,
+            lookahead: options.lookahead,
+            change_lookahead: options.change_lookahead,
+            receive_derived: 0,
+            change_derived: 0,
+            tip_height: 0,
+            min_confirmations: options.min_confirmations,
+        };
+        wallet.fill_lookahead()?;
+        Ok(wallet)
+    }
+
+    /// Single key account of `wallet_type` at `account_derivation`, receiving on `/0/*` and
+    /// paying change to `/1/*`
+    pub fn from_account(account_xpub: Xpub, network: Network, account_derivation: DerivationPath, master_fingerprint: Fingerprint, wallet_type: WalletType, options: WalletOptions) -> Result<Self, errors::Error> {
+        let descriptor = Descriptor::account(wallet_type, account_xpub, master_fingerprint, account_derivation.clone(), KeychainKind::External.as_u32());
+        let change_descriptor = Descriptor::account(wallet_type, account_xpub, master_fingerprint, account_derivation, KeychainKind::Internal.as_u32());
+        Self::new(descriptor, change_descriptor, network, options)
+    }
+
+    /// Sorted `threshold` of n multisig of the cosigners' `(xpub, master fingerprint, account
+    /// derivation)`, receiving on `/0/*` and paying change to `/1/*`
+    pub fn from_multisig(cosigners: Vec<(Xpub, Fingerprint, DerivationPath)>, threshold: usize, multisig_type: MultisigType, network: Network, options: WalletOptions) -> Result<Self, errors::Error> {
+        let descriptor = Descriptor::multisig(multisig_type, threshold, &cosigners, KeychainKind::External.as_u32())?;
+        let change_descriptor = Descriptor::multisig(multisig_type, threshold, &cosigners, KeychainKind::Internal.as_u32())?;
+        Self::new(descriptor, change_descriptor, network, options)
+    }
+
+    /// Receive and change descriptors, checksums included
+    pub fn descriptors(&self) -> (String, String) {
+        (self.descriptor.to_string_with_checksum(), self.change_descriptor.to_string_with_checksum())
+    }
+
+    fn keychain_descriptor(&self, keychain: KeychainKind) -> &Descriptor {
+        match keychain {
+            KeychainKind::External => &self.descriptor,
+            KeychainKind::Internal => &self.change_descriptor,
         }
     }
 
@@ -57,6 +144,31 @@ impl WatchOnly {
         Ok(partial_utxos)
     }
 
+    /// Unspent outputs of the wallet, oldest first
+    pub fn list_unspent(&self) -> Vec<LocalUtxo> {
+        let mut unspent: Vec<LocalUtxo> = self.utxo_map.values().map(|utxo| &utxo.utxo).filter(|utxo| self.is_unspent(utxo))
+            .map(|utxo| LocalUtxo { utxo: utxo.clone(), confirmations: self.confirmations(utxo), is_frozen: self.frozen.contains(&utxo.outpoint) })
+            .collect();
+        unspent.sort_by_key(|utxo| (utxo.utxo.chain_position.is_none(), utxo.utxo.chain_position));
+        unspent
+    }
+
+    /// Keeps coin selection from spending the output until it is unfrozen
+    pub fn freeze_utxo(&mut self, outpoint: OutPoint) -> Result<(), errors::Error> {
+        if self.utxo_map.get(&outpoint).is_none_or(|utxo| utxo.utxo.is_spent) {
+            return Err(errors::Error::UnknownUtxo(outpoint));
+        }
+        self.frozen.insert(outpoint);
+        Ok(())
+    }
+
+    pub fn unfreeze_utxo(&mut self, outpoint: OutPoint) -> Result<(), errors::Error> {
+        if !self.frozen.remove(&outpoint) {
+            return Err(errors::Error::UnknownUtxo(outpoint));
+        }
+        Ok(())
+    }
+
     pub fn get_state(& self) -> Vec<u8> {
         return bincode::serialize(self).unwrap();
     }
@@ -73,14 +185,21 @@ impl WatchOnly {
                 Some(utxo) =>  {
                     let mut modified_utxo = utxo.clone();
                     modified_utxo.utxo.is_spent = partial_utxo.is_spent;
+                    if partial_utxo.is_spent {
+                        self.pending_spends.remove(&partial_utxo.outpoint);
+                    }
+                    modified_utxo.utxo.is_confirmed = true;
+                    modified_utxo.utxo.chain_position = partial_utxo.height.or(modified_utxo.utxo.chain_position);
                     self.utxo_map.insert(partial_utxo.outpoint, modified_utxo);
                 },
                 None => {
                     let pubkey_details  = self.pubkey_map.get(&partial_utxo.script).ok_or(errors::Error::NoPubKey)?;
-                    let txout = TxOut { value: Amount::from_sat(partial_utxo.amount), script_pubkey: ScriptBuf::from_bytes(partial_utxo.script) };
-                    let utxo = Utxo { outpoint: partial_utxo.outpoint, keychain: pubkey_details.key_type, txout , derivation_index: pubkey_details.key_depth, chain_position: None, is_spent: partial_utxo.is_spent};
-                    let weighted_utxo = WeightedUtxo { utxo, satisfaction_weight:  Weight::ZERO };
+                    let txout = TxOut { value: Amount::from_sat(partial_utxo.amount), script_pubkey: ScriptBuf::from_bytes(partial_utxo.script.clone()) };
+                    let utxo = Utxo { outpoint: partial_utxo.outpoint, keychain: pubkey_details.key_type, txout , derivation_index: pubkey_details.key_depth, chain_position: partial_utxo.height, is_spent: partial_utxo.is_spent, is_confirmed: true, is_coinbase: partial_utxo.is_coinbase};
+                    let satisfaction_weight = self.keychain_descriptor(utxo.keychain).satisfaction_weight(0);
+                    let weighted_utxo = WeightedUtxo { utxo, satisfaction_weight };
                     self.utxo_map.insert(partial_utxo.outpoint, weighted_utxo);
+                    self.mark_used(&partial_utxo.script)?;
                 },
             }
         }
@@ -88,147 +207,446 @@ impl WatchOnly {
         Ok(())
     }
 
+    /// Records outputs paying the wallet from transactions that are not in a block yet
+    ///
+    /// Outputs already known are left untouched, a block may have confirmed them meanwhile.
+    pub fn insert_unconfirmed_utxos(&mut self, partial_utxos: Vec<types::PartialUtxo>) -> Result<(), errors::Error> {
+        for partial_utxo in partial_utxos {
+            if self.utxo_map.contains_key(&partial_utxo.outpoint) {
+                continue;
+            }
+            let pubkey_details  = self.pubkey_map.get(&partial_utxo.script).ok_or(errors::Error::NoPubKey)?;
+            let txout = TxOut { value: Amount::from_sat(partial_utxo.amount), script_pubkey: ScriptBuf::from_bytes(partial_utxo.script.clone()) };
+            let utxo = Utxo { outpoint: partial_utxo.outpoint, keychain: pubkey_details.key_type, txout , derivation_index: pubkey_details.key_depth, chain_position: None, is_spent: partial_utxo.is_spent, is_confirmed: false, is_coinbase: partial_utxo.is_coinbase};
+            let satisfaction_weight = self.keychain_descriptor(utxo.keychain).satisfaction_weight(0);
+                    let weighted_utxo = WeightedUtxo { utxo, satisfaction_weight };
+            self.utxo_map.insert(partial_utxo.outpoint, weighted_utxo);
+            self.mark_used(&partial_utxo.script)?;
+        }
+
+        Ok(())
+    }
+
+    /// Marks the wallet outputs `transaction` spends as spent until a block mines it, after it
+    /// was broadcast. Inputs of others are skipped.
+    pub fn mark_pending_spent(&mut self, transaction: &Transaction) {
+        let txid = transaction.compute_txid();
+        for input in transaction.input.iter() {
+            if self.utxo_map.contains_key(&input.previous_output) {
+                self.pending_spends.insert(input.previous_output, txid);
+            }
+        }
+    }
+
+    /// Makes the outputs still held by the unconfirmed transaction `txid` spendable again, once
+    /// it was replaced or dropped
+    pub fn release_pending_spent(&mut self, txid: Txid) {
+        self.pending_spends.retain(|_, spending_txid| *spending_txid != txid);
+    }
+
+    /// Forgets utxos created by blocks that are no longer on the best chain
+    pub fn remove_utxos(&mut self, partial_utxos: Vec<types::PartialUtxo>) -> Result<(), errors::Error> {
+        for partial_utxo in partial_utxos {
+            self.utxo_map.remove(&partial_utxo.outpoint);
+        }
+
+        Ok(())
+    }
+
+    /// Value of the mined and mature unspent outputs
     pub fn balance(&mut self) -> Result<Amount, errors::Error> {
-        let mut utxos: Vec<_> = self.utxo_map.values().cloned().collect();
-        
-        utxos.sort_by(|a,b | a.utxo.is_spent.cmp(&b.utxo.is_spent));
-
-        let mut balance = Amount::ZERO;
-        for utxo in utxos {
-            let inner = utxo.utxo;
-            
-            if !inner.is_spent {
-                balance = balance.checked_add(inner.txout.value).unwrap();
+        Ok(self.balance_breakdown()?.confirmed)
+    }
+
+    /// Value of the unspent outputs only seen in the mempool so far
+    pub fn pending_balance(&self) -> Result<Amount, errors::Error> {
+        let balance = self.balance_breakdown()?;
+        Ok(balance.trusted_pending + balance.untrusted_pending)
+    }
+
+    pub fn balance_breakdown(&self) -> Result<Balance, errors::Error> {
+        let mut balance = Balance::default();
+        for utxo in self.utxo_map.values().map(|utxo| &utxo.utxo).filter(|utxo| self.is_unspent(utxo)) {
+            let value = utxo.txout.value;
+            if !utxo.is_confirmed {
+                // Change only comes from transactions this wallet signed
+                match utxo.keychain {
+                    KeychainKind::Internal => balance.trusted_pending += value,
+                    KeychainKind::External => balance.untrusted_pending += value,
+                }
+            } else if !self.is_mature(utxo) {
+                balance.immature += value;
+            } else {
+                balance.confirmed += value;
             }
         }
-    
         Ok(balance)
+    }
 
+    /// Records the height of the last block scanned, confirmations are counted from it
+    pub fn set_tip_height(&mut self, height: u32) {
+        self.tip_height = height;
     }
 
-    pub fn get_receive_address(& mut self) -> Result<String ,errors::Error>{
-        let secp = Secp256k1::new();
-        let child_pub = self.account_xpub
-            .ckd_pub(&secp, bitcoin::bip32::ChildNumber::Normal { index: 0 })
-            .map_err(|err| errors::Error::PubKeyError(err) )?
-            .ckd_pub(&secp, bitcoin::bip32::ChildNumber::Normal { index: self.receive_depth })
-            .map_err(|err| errors::Error::PubKeyError(err) )?.to_pub();
+    /// Blocks from the one that mined the output up to the tip, 0 while unconfirmed
+    fn confirmations(&self, utxo: &Utxo) -> u32 {
+        match (utxo.is_confirmed, utxo.chain_position) {
+            (false, _) => 0,
+            (true, Some(height)) if height <= self.tip_height => self.tip_height - height + 1,
+            (true, _) => 1,
+        }
+    }
+
+    fn is_mature(&self, utxo: &Utxo) -> bool {
+        !utxo.is_coinbase || self.confirmations(utxo) >= COINBASE_MATURITY
+    }
+
+    /// Neither mined in a spend nor spent by one of our unconfirmed transactions
+    fn is_unspent(&self, utxo: &Utxo) -> bool {
+        !utxo.is_spent && !self.pending_spends.contains_key(&utxo.outpoint)
+    }
+
+    /// Whether coin selection may spend the output
+    fn is_spendable(&self, utxo: &Utxo) -> bool {
+        self.is_unspent(utxo) && !self.frozen.contains(&utxo.outpoint) && self.is_mature(utxo) && self.confirmations(utxo) >= self.min_confirmations
+    }
+
+    /// Derives the scripts missing from the lookahead window of both keychains
+    fn fill_lookahead(&mut self) -> Result<(), errors::Error> {
+        while self.receive_derived < self.receive_depth + self.lookahead {
+            let script = self.derive_script(KeychainKind::External, self.receive_derived)?;
+            self.pubkey_map.insert(script, PubkeyDetails{ key_type: KeychainKind::External, key_depth: self.receive_derived });
+            self.receive_derived += 1;
+        }
+        while self.change_derived < self.change_depth + self.change_lookahead {
+            let script = self.derive_script(KeychainKind::Internal, self.change_derived)?;
+            self.pubkey_map.insert(script, PubkeyDetails{ key_type: KeychainKind::Internal, key_depth: self.change_derived });
+            self.change_derived += 1;
+        }
+        Ok(())
+    }
 
-        let pub_key = Address::p2wpkh(&child_pub, self.network);
+    /// Moves the keychain of a script found on chain past it and extends the window to match
+    fn mark_used(&mut self, script: &[u8]) -> Result<(), errors::Error> {
+        let (key_type, key_depth) = match self.pubkey_map.get(script) {
+            Some(details) => (details.key_type, details.key_depth),
+            None => return Ok(()),
+        };
+        match key_type {
+            KeychainKind::External => self.receive_depth = self.receive_depth.max(key_depth + 1),
+            KeychainKind::Internal => self.change_depth = self.change_depth.max(key_depth + 1),
+        }
+        self.fill_lookahead()
+    }
+
+    fn derive_script(&self, keychain: KeychainKind, index: u32) -> Result<Vec<u8>, errors::Error> {
+        Ok(self.keychain_descriptor(keychain).script_pubkey(index)?.to_bytes())
+    }
+
+    fn derive_address(&self, keychain: KeychainKind, index: u32) -> Result<Address, errors::Error> {
+        let script = self.keychain_descriptor(keychain).script_pubkey(index)?;
+        // Every descriptor we parse has an address form
+        Ok(Address::from_script(&script, self.network).expect("descriptor scripts are standard"))
+    }
+
+    /// Hands out a receive address never returned before
+    pub fn new_receive_address(& mut self) -> Result<String ,errors::Error>{
+        let address = self.get_receive_address()?;
+        self.receive_depth += 1;
+        self.fill_lookahead()?;
+        Ok(address)
+    }
+
+    /// First receive address after the last one seen used
+    pub fn get_receive_address(& mut self) -> Result<String ,errors::Error>{
+        let pub_key = self.derive_address(KeychainKind::External, self.receive_depth)?;
         let script_pub =  pub_key.script_pubkey().to_bytes();
         self.pubkey_map.insert(script_pub, PubkeyDetails{ key_type: KeychainKind::External, key_depth: self.receive_depth });
         return  Ok(pub_key.to_string())
         
     }
 
+    /// First change script after the last one seen used, it only counts as used once a
+    /// transaction pays to it
     fn get_change_script(& mut self) -> Result< Vec<u8> ,errors::Error>{
-        let secp = Secp256k1::new();
-        let child_pub = self.account_xpub
-            .ckd_pub(&secp, bitcoin::bip32::ChildNumber::Normal { index: 1 })
-            .map_err(|err| errors::Error::PubKeyError(err) )?
-            .ckd_pub(&secp, bitcoin::bip32::ChildNumber::Normal { index: self.change_depth })
-            .map_err(|err| errors::Error::PubKeyError(err) )?.to_pub();
-
-        self.change_depth +=1;    
-        let script_pub = Address::p2wpkh(&child_pub, self.network)
-            .script_pubkey();
+        let script_pub = self.derive_script(KeychainKind::Internal, self.change_depth)?;
+        self.pubkey_map.insert(script_pub.clone(), PubkeyDetails{ key_type: KeychainKind::Internal, key_depth: self.change_depth });
 
-        self.pubkey_map.insert(script_pub.to_bytes(), PubkeyDetails{ key_type: KeychainKind::External, key_depth: self.receive_depth });
-
-        return  Ok(script_pub.to_bytes().to_vec())
+        return  Ok(script_pub)
         
     }
 
-    fn derive_pubkey(&self, utxo: Utxo) -> Result<CompressedPublicKey, errors::Error> {
-        let secp = Secp256k1::new();
-        let child_pub = self.account_xpub
-            .ckd_pub(&secp, bitcoin::bip32::ChildNumber::Normal { index: utxo.keychain.as_u32()})
-            .map_err(|err| errors::Error::PubKeyError(err) )?
-            .ckd_pub(&secp, bitcoin::bip32::ChildNumber::Normal { index: utxo.derivation_index })
-            .map_err(|err| errors::Error::PubKeyError(err) )?.to_pub();
+    /// Keychain a wallet script was derived from, `None` for scripts of other wallets
+    pub fn get_keychain(&self, script: &[u8]) -> Option<KeychainKind> {
+        self.pubkey_map.get(script).map(|details| details.key_type)
+    }
+
+    /// Whether the timelock of the spending path lets a transaction in the next block spend `utxo`
+    fn is_unlocked(&self, utxo: &Utxo, timelock: Option<Timelock>) -> bool {
+        match timelock {
+            None => true,
+            Some(Timelock::Older(blocks)) => Older::new(Some(self.tip_height), utxo.chain_position.filter(|_| utxo.is_confirmed), false).is_satisfied(blocks),
+            Some(Timelock::After(height)) => After::new(Some(self.tip_height), false).is_satisfied(height),
+        }
+    }
 
-        Ok(child_pub)
+    /// Pays `amount` to `recipient` spending through `spending_path`, 0 unless a policy wallet
+    /// spends through one of its recovery keys
+    pub fn create_psbt_tx<T: RngCore>(& mut self, recipient: Vec<u8>, fee_rate: FeeRate, amount: Amount, spending_path: usize, rand: T) -> Result<Vec<u8>, errors::Error> {
+        self.create_batch_psbt_tx(vec![(ScriptBuf::from(recipient), amount)], fee_rate, &[], spending_path, rand)
     }
 
-    pub fn create_psbt_tx<T: RngCore>(& mut self, recipient: Vec<u8>, fee_rate: FeeRate, amount: Amount, mut rand: T) -> Result<Vec<u8>, errors::Error> {
+    /// Pays every recipient from one transaction, selecting coins for their total and the fee
+    /// of their outputs. The `must_spend` outputs are spent whether coin selection needs them
+    /// or not.
+    pub fn create_batch_psbt_tx<T: RngCore>(& mut self, recipients: Vec<(ScriptBuf, Amount)>, fee_rate: FeeRate, must_spend: &[OutPoint], spending_path: usize, mut rand: T) -> Result<Vec<u8>, errors::Error> {
+        if recipients.is_empty() {
+            return Err(errors::Error::NoRecipients);
+        }
+        if let Some(index) = recipients.iter().position(|(script, amount)| amount.is_dust(script)) {
+            return Err(errors::Error::OutputBelowDustLimit(index));
+        }
+        let mut outputs: Vec<TxOut> = recipients.into_iter().map(|(script_pubkey, value)| TxOut { script_pubkey, value }).collect();
+        let outgoing = outputs.iter().map(|output| output.value).sum::<Amount>();
+
+        // Both keychains share the policy, so its paths
+        let timelock = self.descriptor.timelock(spending_path)?;
         let change_script = self.get_change_script()?;
-        let utxos: Vec<_> = self.utxo_map.values().cloned().collect();
-        let coinselection = DefaultCoinSelectionAlgorithm::default().coin_select(vec![], utxos, fee_rate, amount, Script::from_bytes(&change_script), &mut rand).map_err(|err| errors::Error::CoinSelection(err))?;
-        
-        let inputs = coinselection.selected.clone().iter().map(|utxo| TxIn {
-            previous_output: utxo.outpoint,
-            script_sig: Default::default(),
-            sequence: Default::default(),
-            witness: Default::default(),
-        }).collect();
+        let (required, optional) = self.split_required(self.spendable_utxos(timelock, spending_path)?, must_spend)?;
 
-        let mut recipients = vec![TxOut {
-            script_pubkey: ScriptBuf::from(recipient),
-            value: amount,
-        }];
+        // Header and outputs, the inputs are paid for by coin selection
+        let unfunded = Transaction { version: Version::TWO, lock_time: LockTime::ZERO, input: vec![], output: outputs.clone() };
+        let target_amount = outgoing + fee_rate * unfunded.weight();
+        let coinselection = DefaultCoinSelectionAlgorithm::default().coin_select(required, optional, fee_rate, target_amount, Script::from_bytes(&change_script), &mut rand).map_err(|err| errors::Error::CoinSelection(err))?;
 
         if let Excess::Change { amount, .. } = coinselection.excess {
-            recipients.push(TxOut {
+            // The next transaction must not pay change to the same script
+            self.mark_used(&change_script)?;
+            outputs.push(TxOut {
                 script_pubkey: ScriptBuf::from(change_script),
                 value: amount,
             });
         }
 
+        self.build_psbt(coinselection.selected, outputs, timelock, spending_path)
+
+    }
+
+    /// Spends every confirmed output, or only `outpoints`, to `destination` and takes the fee
+    /// from that single output
+    pub fn create_drain_psbt_tx(& mut self, destination: ScriptBuf, fee_rate: FeeRate, outpoints: Option<Vec<OutPoint>>, spending_path: usize) -> Result<Vec<u8>, errors::Error> {
+        let timelock = self.descriptor.timelock(spending_path)?;
+        let mut utxos: Vec<_> = self.spendable_utxos(timelock, spending_path)?.into_iter().filter(|utxo| utxo.utxo.is_confirmed).collect();
+        if let Some(outpoints) = outpoints {
+            utxos = self.split_required(utxos, &outpoints)?.0;
+        }
+
+        let available = utxos.iter().map(|utxo| utxo.utxo.txout.value).sum::<Amount>();
+        let mut output = TxOut { script_pubkey: destination, value: Amount::ZERO };
+        let unfunded = Transaction { version: Version::TWO, lock_time: LockTime::ZERO, input: vec![], output: vec![output.clone()] };
+        let weight = utxos.iter().fold(unfunded.weight(), |weight, utxo| weight + TxIn::default().segwit_weight() + utxo.satisfaction_weight);
+        let fee = fee_rate * weight;
+        output.value = match available.checked_sub(fee) {
+            Some(value) if !value.is_dust(&output.script_pubkey) => value,
+            _ => return Err(errors::Error::CoinSelection(InsufficientFunds { needed: fee + output.script_pubkey.minimal_non_dust(), available })),
+        };
+
+        self.build_psbt(utxos.into_iter().map(|utxo| utxo.utxo).collect(), vec![output], timelock, spending_path)
+    }
+
+    /// Replacement for the unconfirmed `transaction` paying the same recipients at `fee_rate`,
+    /// taken from its change or from extra confirmed inputs following the BIP125 rules
+    pub fn create_bump_fee_psbt_tx<T: RngCore>(& mut self, transaction: Transaction, fee_rate: FeeRate, mut rand: T) -> Result<Vec<u8>, errors::Error> {
+        let txid = transaction.compute_txid();
+        if !transaction.is_explicitly_rbf() {
+            return Err(errors::Error::IrreplaceableTransaction(txid));
+        }
+
+        let mut original_utxos = Vec::new();
+        for input in transaction.input.iter() {
+            let utxo = self.utxo_map.get(&input.previous_output).ok_or(errors::Error::UnknownUtxo(input.previous_output))?;
+            // Its inputs are only marked spent once a block mined it
+            if utxo.utxo.is_spent {
+                return Err(errors::Error::TransactionConfirmed(txid));
+            }
+            // Pending spends of the original are the ones being replaced, another transaction
+            // holding the input means the original was replaced already
+            if self.pending_spends.get(&input.previous_output).is_some_and(|spending_txid| *spending_txid != txid) {
+                return Err(errors::Error::IrreplaceableTransaction(txid));
+            }
+            original_utxos.push(WeightedUtxo { satisfaction_weight: self.keychain_descriptor(utxo.utxo.keychain).satisfaction_weight(0), utxo: utxo.utxo.clone() });
+        }
+        let original_fee = original_utxos.iter().map(|utxo| utxo.utxo.txout.value).sum::<Amount>()
+            .checked_sub(transaction.output.iter().map(|output| output.value).sum()).ok_or(errors::Error::Psbt(psbt::Error::NegativeFee))?;
+        let original_fee_rate = original_fee / transaction.weight();
+        if fee_rate <= original_fee_rate {
+            return Err(errors::Error::FeeRateTooLow { required: FeeRate::from_sat_per_kwu(original_fee_rate.to_sat_per_kwu() + 1) });
+        }
+
+        // The change is dropped and paid again from whatever coin selection leaves over
+        let (change, mut outputs): (Vec<TxOut>, Vec<TxOut>) = transaction.output.into_iter().partition(|output| self.get_keychain(output.script_pubkey.as_bytes()) == Some(KeychainKind::Internal));
+        let change_script = match change.first() {
+            Some(output) => output.script_pubkey.to_bytes(),
+            None => self.get_change_script()?,
+        };
+        let outgoing = outputs.iter().map(|output| output.value).sum::<Amount>();
+
+        // BIP125 does not let the replacement spend new unconfirmed outputs
+        let optional_utxos: Vec<_> = self.spendable_utxos(None, 0)?.into_iter()
+            .filter(|utxo| utxo.utxo.is_confirmed && !original_utxos.iter().any(|original| original.utxo.outpoint == utxo.utxo.outpoint))
+            .collect();
+        let unfunded = Transaction { version: Version::TWO, lock_time: LockTime::ZERO, input: vec![], output: outputs.clone() };
+        let target_amount = outgoing + fee_rate * unfunded.weight();
+        let coinselection = DefaultCoinSelectionAlgorithm::default().coin_select(original_utxos, optional_utxos, fee_rate, target_amount, Script::from_bytes(&change_script), &mut rand)?;
+
+        if let Excess::Change { amount, .. } = coinselection.excess {
+            self.mark_used(&change_script)?;
+            outputs.push(TxOut { script_pubkey: ScriptBuf::from(change_script), value: amount });
+        }
+
+        // It must also pay for its own relay at the minimum rate on top of the fee it replaces
+        let fee = coinselection.selected.iter().map(|utxo| utxo.txout.value).sum::<Amount>() - outputs.iter().map(|output| output.value).sum();
+        let weight = coinselection.selected.iter().fold(Transaction { output: outputs.clone(), ..unfunded }.weight(), |weight, utxo| {
+            weight + TxIn::default().segwit_weight() + self.keychain_descriptor(utxo.keychain).satisfaction_weight(0)
+        });
+        let required = original_fee + FeeRate::BROADCAST_MIN * weight;
+        if fee < required {
+            return Err(errors::Error::FeeTooLow { required });
+        }
+
+        self.build_psbt(coinselection.selected, outputs, None, 0)
+    }
+
+    /// Spends the outputs the unconfirmed `parent` pays the wallet back to its change, with a fee
+    /// bringing the package of both to `fee_rate`. The parent counts as paying no fee unless
+    /// every one of its inputs is a wallet output. Outputs frozen by coin control are left alone.
+    pub fn create_cpfp_psbt_tx<T: RngCore>(& mut self, parent: Transaction, fee_rate: FeeRate, mut rand: T) -> Result<Vec<u8>, errors::Error> {
+        let txid = parent.compute_txid();
+        let (frozen, parent_utxos): (Vec<_>, Vec<_>) = self.utxo_map.values()
+            .filter(|utxo| utxo.utxo.outpoint.txid == txid && self.is_unspent(&utxo.utxo))
+            .map(|utxo| WeightedUtxo { satisfaction_weight: self.keychain_descriptor(utxo.utxo.keychain).satisfaction_weight(0), utxo: utxo.utxo.clone() })
+            .partition(|utxo| self.frozen.contains(&utxo.utxo.outpoint));
+        if frozen.iter().chain(parent_utxos.iter()).any(|utxo| utxo.utxo.is_confirmed) {
+            return Err(errors::Error::TransactionConfirmed(txid));
+        }
+        if parent_utxos.is_empty() {
+            return Err(match frozen.first() {
+                Some(utxo) => errors::Error::FrozenUtxo(utxo.utxo.outpoint),
+                None => errors::Error::NoWalletOutputs(txid),
+            });
+        }
+
+        let parent_inputs: Option<Vec<Amount>> = parent.input.iter().map(|input| self.utxo_map.get(&input.previous_output).map(|utxo| utxo.utxo.txout.value)).collect();
+        let parent_fee = parent_inputs.map(|values| values.into_iter().sum::<Amount>())
+            .and_then(|value| value.checked_sub(parent.output.iter().map(|output| output.value).sum()))
+            .unwrap_or(Amount::ZERO);
+        if parent_fee / parent.weight() >= fee_rate {
+            return Err(errors::Error::FeeRateTooLow { required: FeeRate::from_sat_per_kwu((parent_fee / parent.weight()).to_sat_per_kwu() + 1) });
+        }
+
+        // The child makes up for what the parent pays short of the package rate, coin selection
+        // adds the fee of the child itself
+        let change_script = self.get_change_script()?;
+        let unfunded = Transaction { version: Version::TWO, lock_time: LockTime::ZERO, input: vec![], output: vec![] };
+        let target_amount = (fee_rate * parent.weight()).checked_sub(parent_fee).unwrap_or(Amount::ZERO) + fee_rate * unfunded.weight();
+        let optional_utxos: Vec<_> = self.spendable_utxos(None, 0)?.into_iter().filter(|utxo| utxo.utxo.is_confirmed).collect();
+        let available = parent_utxos.iter().chain(optional_utxos.iter()).map(|utxo| utxo.utxo.txout.value).sum::<Amount>();
+        let coinselection = DefaultCoinSelectionAlgorithm::default().coin_select(parent_utxos, optional_utxos, fee_rate, target_amount, Script::from_bytes(&change_script), &mut rand)?;
+
+        let amount = match coinselection.excess {
+            Excess::Change { amount, .. } => amount,
+            Excess::NoChange { dust_threshold, .. } => return Err(errors::Error::CoinSelection(InsufficientFunds { needed: target_amount + dust_threshold, available })),
+        };
+        self.mark_used(&change_script)?;
+
+        self.build_psbt(coinselection.selected, vec![TxOut { script_pubkey: ScriptBuf::from(change_script), value: amount }], None, 0)
+    }
+
+    /// Outputs a transaction in the next block can spend through `spending_path`, weighted by
+    /// what satisfying that path costs
+    fn spendable_utxos(&self, timelock: Option<Timelock>, spending_path: usize) -> Result<Vec<WeightedUtxo>, errors::Error> {
+        let spendable: Vec<_> = self.utxo_map.values().filter(|utxo| self.is_spendable(&utxo.utxo)).collect();
+        let utxos: Vec<_> = spendable.iter()
+            .filter(|utxo| self.is_unlocked(&utxo.utxo, timelock))
+            .map(|utxo| WeightedUtxo { satisfaction_weight: self.keychain_descriptor(utxo.utxo.keychain).satisfaction_weight(spending_path), utxo: utxo.utxo.clone() })
+            .collect();
+        if utxos.is_empty() && !spendable.is_empty() {
+            return Err(errors::Error::TimelockNotReached);
+        }
+        Ok(utxos)
+    }
+
+    /// Splits the outputs coin selection must spend off the ones it may, failing on an outpoint
+    /// that is frozen or not among `utxos`
+    fn split_required(&self, utxos: Vec<WeightedUtxo>, must_spend: &[OutPoint]) -> Result<(Vec<WeightedUtxo>, Vec<WeightedUtxo>), errors::Error> {
+        for outpoint in must_spend {
+            if self.frozen.contains(outpoint) {
+                return Err(errors::Error::FrozenUtxo(*outpoint));
+            }
+            if !utxos.iter().any(|utxo| utxo.utxo.outpoint == *outpoint) {
+                return Err(errors::Error::UnknownUtxo(*outpoint));
+            }
+        }
+        Ok(utxos.into_iter().partition(|utxo| must_spend.contains(&utxo.utxo.outpoint)))
+    }
+
+    /// Unsigned PSBT spending `selected` to `outputs`, with the sequence and lock time the
+    /// timelock of the spending path needs
+    fn build_psbt(&self, selected: Vec<Utxo>, outputs: Vec<TxOut>, timelock: Option<Timelock>, spending_path: usize) -> Result<Vec<u8>, errors::Error> {
+        // Every sequence below signals BIP125 replaceability, so a stuck payment can be bumped
+        let (sequence, lock_time) = match timelock {
+            None => (Sequence::ENABLE_RBF_NO_LOCKTIME, LockTime::ZERO),
+            Some(Timelock::Older(blocks)) => (Sequence::from_height(blocks as u16), LockTime::ZERO),
+            // A final sequence would disable the lock time
+            Some(Timelock::After(height)) => (Sequence::ENABLE_RBF_NO_LOCKTIME, LockTime::from_height(height).map_err(|_| errors::Error::InvalidSpendingPath(spending_path))?),
+        };
+        
+        let inputs = selected.iter().map(|utxo| TxIn {
+            previous_output: utxo.outpoint,
+            script_sig: Default::default(),
+            sequence,
+            witness: Default::default(),
+        }).collect();
+
         let transaction = Transaction {
             version:  Version::TWO,
-            lock_time: LockTime::ZERO,
+            lock_time,
             input: inputs,
-            output: recipients,
+            output: outputs,
         };
 
         let  mut psbt = Psbt::from_unsigned_tx(transaction).map_err(errors::Error::Psbt)?;
 
         let mut inputs=  Vec::new();
-        let ty = PsbtSighashType::from(EcdsaSighashType::All);
-        
-        
-        for utxo in coinselection.selected {
-            let child_pub = self.derive_pubkey(utxo.clone())?;
-            let mut map = BTreeMap::new();
-
-            let partial_derivation_path = DerivationPath::from(vec![ChildNumber::Normal{index: utxo.keychain.as_u32()}, ChildNumber::Normal{index: utxo.derivation_index }]);
-            let full_derivation_path = self.account_derivation.extend(partial_derivation_path);
-
-            map.insert(child_pub.0, (self.master_fingerprint, full_derivation_path ));
-
-            let wpkh = child_pub.wpubkey_hash();
-            let redeem_script = ScriptBuf::new_p2wpkh(&wpkh);
-
-            let input = Input { witness_utxo: Some(utxo.txout) ,witness_script: Some(redeem_script),bip32_derivation: map, sighash_type: Some(ty),  ..Default::default()};
+        for utxo in selected {
+            let mut input = Input { witness_utxo: Some(utxo.txout.clone()), ..Default::default() };
+            self.keychain_descriptor(utxo.keychain).update_input(utxo.derivation_index, &mut input)?;
             inputs.push(input);
-            
         };
 
         psbt.inputs = inputs;
 
         Ok(psbt.serialize())
+    }
 
+    /// Merges the signatures cosigners added to their copies of the same PSBT
+    pub fn combine_psbts(&self, mut psbt: Psbt, others: Vec<Psbt>) -> Result<Vec<u8>, errors::Error> {
+        for other in others {
+            psbt.combine(other)?;
+        }
+        Ok(psbt.serialize())
     }
 
     pub fn finalise_psbt_tx(& mut self, mut psbt: Psbt) -> Result<Vec<u8>, errors::Error> {
 
 
-        for (index, input) in psbt.inputs.clone().into_iter().enumerate() {
-            let (pubkey, signature) = input.partial_sigs.first_key_value().unwrap();
-            let mut script_witness: Witness = Witness::new();
-            script_witness.push(signature.serialize());
-            script_witness.push(pubkey.to_bytes());
-            psbt.inputs[index].final_script_witness = Some(script_witness);
+        for input in psbt.inputs.iter_mut() {
+            let script = input.witness_utxo.as_ref().ok_or(errors::Error::NoPubKey)?.script_pubkey.to_bytes();
+            let keychain = self.get_keychain(&script).ok_or(errors::Error::NoPubKey)?;
+            self.keychain_descriptor(keychain).finalize_input(input)?;
         }
 
-        // Clear all the data fields as per the spec.
-        psbt.inputs[0].partial_sigs = BTreeMap::new();
-        psbt.inputs[0].sighash_type = None;
-        psbt.inputs[0].redeem_script = None;
-        psbt.inputs[0].witness_script = None;
-        psbt.inputs[0].bip32_derivation = BTreeMap::new();
-
-        Ok(encode::serialize(&psbt.extract_tx().unwrap()))
+        Ok(encode::serialize(&psbt.extract_tx()?))
 
     }
 }
@@ -238,7 +656,7 @@ impl WatchOnly {
 mod tests {
     use std::str::FromStr;
 
-    use bitcoin::{hex::FromHex, OutPoint, Weight};
+    use bitcoin::{hex::FromHex, key::Secp256k1, CompressedPublicKey, OutPoint, Weight, Witness};
     use rand::rngs::mock::StepRng;
     use types::WeightedUtxo;
 
@@ -249,10 +667,16 @@ mod tests {
         return xpub.unwrap();
     }
 
+    fn get_wallet() -> WatchOnly {
+        let account_derivation = DerivationPath::from_str("m/84'/0'/0'").unwrap();
+        let master_fingerprint = Fingerprint::from_str("d34db33f").unwrap();
+        WatchOnly::from_account(get_xpub(), Network::Bitcoin, account_derivation, master_fingerprint, WalletType::P2WPKH, WalletOptions::default()).unwrap()
+    }
+
     #[test]
     fn test_derive_p2wpkh_receive_address() {
         
-        let mut wallet = WatchOnly::new(get_xpub(), Network::Bitcoin);
+        let mut wallet = get_wallet();
         let result = wallet.get_receive_address();
 
         assert!(result.is_ok());
@@ -263,7 +687,7 @@ mod tests {
 
     #[test]
     fn test_derive_p2wpkh_change_script() {
-        let mut wallet = WatchOnly::new(get_xpub(), Network::Bitcoin);
+        let mut wallet = get_wallet();
         let result = wallet.get_change_script();
 
         assert!(result.is_ok());
@@ -273,21 +697,685 @@ mod tests {
 
     #[test]
     fn test_create_psbt_tx() {
-        let mut wallet = WatchOnly::new(get_xpub(), Network::Bitcoin);
+        let mut wallet = get_wallet();
         wallet.get_receive_address().unwrap();
         let pubkey  = wallet.get_pubkeys().unwrap()[0].clone();
         let utxo = PartialUtxo{ outpoint: OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap(), amount: 100000, is_spent: false,
-            script: ScriptBuf::from_bytes(pubkey).into()  };
+            script: ScriptBuf::from_bytes(pubkey).into(), height: Some(1), is_coinbase: false };
         let _ = wallet.insert_utxos(vec![utxo]);
         let recipient = Vec::from_hex("0014c12e1ea122c2e2d8593948efede523652e0493cb").unwrap();
         let fee_rate = FeeRate::from_sat_per_vb(3).unwrap();
         let amount = Amount::from_sat(1000);
         let mut rng = StepRng::new(2, 1);
 
-        let result = wallet.create_psbt_tx(recipient, fee_rate, amount, &mut rng);
+        let result = wallet.create_psbt_tx(recipient, fee_rate, amount, 0, &mut rng);
 
         assert!(result.is_ok());
         assert!(wallet.pubkey_map.get(&Vec::from_hex("001478e81513288cb8697189df5aa8561bee7048e192").unwrap()).is_some());
 
     }
+
+    #[test]
+    fn test_create_batch_psbt_tx() {
+        let mut wallet = get_wallet();
+        let script  = wallet.get_pubkeys().unwrap()[0].clone();
+        let utxo = PartialUtxo{ outpoint: OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap(), amount: 100000, is_spent: false,
+            script, height: Some(1), is_coinbase: false };
+        wallet.insert_utxos(vec![utxo]).unwrap();
+        let recipient = ScriptBuf::from_hex("0014c12e1ea122c2e2d8593948efede523652e0493cb").unwrap();
+        let fee_rate = FeeRate::from_sat_per_vb(3).unwrap();
+        let recipients: Vec<_> = (1..=3).map(|i| (recipient.clone(), Amount::from_sat(10000 * i))).collect();
+
+        let psbt = Psbt::deserialize(&wallet.create_batch_psbt_tx(recipients.clone(), fee_rate, &[], 0, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        let outputs: Vec<_> = psbt.unsigned_tx.output.iter().map(|output| (output.script_pubkey.clone(), output.value)).collect();
+        assert_eq!(outputs[..3], recipients[..]);
+        assert_eq!(outputs.len(), 4);
+
+        // The fee covers every output, the segwit marker and the signed input
+        let signed_weight = psbt.unsigned_tx.weight() + Weight::from_wu(2 + 108);
+        assert!(psbt.fee().unwrap() >= fee_rate * signed_weight);
+
+        let dust = vec![(recipient.clone(), Amount::from_sat(1000)), (recipient, Amount::from_sat(293))];
+        assert!(matches!(wallet.create_batch_psbt_tx(dust, fee_rate, &[], 0, &mut StepRng::new(2, 1)), Err(errors::Error::OutputBelowDustLimit(1))));
+        assert!(matches!(wallet.create_batch_psbt_tx(vec![], fee_rate, &[], 0, &mut StepRng::new(2, 1)), Err(errors::Error::NoRecipients)));
+    }
+
+    #[test]
+    fn test_create_drain_psbt_tx() {
+        let mut wallet = get_wallet();
+        let script  = wallet.get_pubkeys().unwrap()[0].clone();
+        let txid = "90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da";
+        let utxo = |vout: u32, height| PartialUtxo{ outpoint: OutPoint::from_str(&format!("{}:{}", txid, vout)).unwrap(), amount: 100000, is_spent: false,
+            script: script.clone(), height, is_coinbase: false };
+        wallet.insert_utxos(vec![utxo(0, Some(1)), utxo(1, Some(1))]).unwrap();
+        wallet.insert_unconfirmed_utxos(vec![utxo(2, None)]).unwrap();
+        let destination = ScriptBuf::from_hex("0014c12e1ea122c2e2d8593948efede523652e0493cb").unwrap();
+        let fee_rate = FeeRate::from_sat_per_vb(3).unwrap();
+
+        // The unconfirmed output stays behind and no change is paid
+        let psbt = Psbt::deserialize(&wallet.create_drain_psbt_tx(destination.clone(), fee_rate, None, 0).unwrap()).unwrap();
+        assert_eq!(psbt.unsigned_tx.input.len(), 2);
+        assert_eq!(psbt.unsigned_tx.output.len(), 1);
+        assert_eq!(psbt.unsigned_tx.output[0].script_pubkey, destination);
+        assert_eq!(psbt.unsigned_tx.output[0].value + psbt.fee().unwrap(), Amount::from_sat(200000));
+        let signed_weight = psbt.unsigned_tx.weight() + Weight::from_wu(2 + 2 * 108);
+        assert!(psbt.fee().unwrap() >= fee_rate * signed_weight);
+
+        let selected = OutPoint::from_str(&format!("{}:1", txid)).unwrap();
+        let psbt = Psbt::deserialize(&wallet.create_drain_psbt_tx(destination.clone(), fee_rate, Some(vec![selected]), 0).unwrap()).unwrap();
+        assert_eq!(psbt.unsigned_tx.input[0].previous_output, selected);
+        assert_eq!(psbt.unsigned_tx.input.len(), 1);
+
+        let pending = OutPoint::from_str(&format!("{}:2", txid)).unwrap();
+        assert!(matches!(wallet.create_drain_psbt_tx(destination.clone(), fee_rate, Some(vec![pending]), 0), Err(errors::Error::UnknownUtxo(outpoint)) if outpoint == pending));
+        let fee_rate = FeeRate::from_sat_per_vb(2000).unwrap();
+        assert!(matches!(wallet.create_drain_psbt_tx(destination, fee_rate, None, 0), Err(errors::Error::CoinSelection(_))));
+    }
+
+    #[test]
+    fn test_coin_control() {
+        let mut wallet = get_wallet();
+        let script  = wallet.get_pubkeys().unwrap()[0].clone();
+        let outpoint = |vout: u32| OutPoint::from_str(&format!("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:{}", vout)).unwrap();
+        let utxo = |vout: u32| PartialUtxo{ outpoint: outpoint(vout), amount: 100000, is_spent: false,
+            script: script.clone(), height: Some(3 - vout), is_coinbase: false };
+        wallet.insert_utxos(vec![utxo(0), utxo(1), utxo(2)]).unwrap();
+        wallet.set_tip_height(3);
+        let recipient = ScriptBuf::from_hex("0014c12e1ea122c2e2d8593948efede523652e0493cb").unwrap();
+        let fee_rate = FeeRate::from_sat_per_vb(3).unwrap();
+
+        wallet.freeze_utxo(outpoint(0)).unwrap();
+        let unspent = wallet.list_unspent();
+        let listed: Vec<_> = unspent.iter().map(|utxo| (utxo.utxo.outpoint, utxo.is_frozen)).collect();
+        assert_eq!(listed, vec![(outpoint(2), false), (outpoint(1), false), (outpoint(0), true)]);
+        assert_eq!(unspent.iter().map(|utxo| utxo.confirmations).collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        // One input would do, the forced one is spent anyway and the frozen one never
+        let recipients = vec![(recipient.clone(), Amount::from_sat(150000))];
+        let psbt = Psbt::deserialize(&wallet.create_batch_psbt_tx(recipients, fee_rate, &[outpoint(2)], 0, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        let inputs: Vec<_> = psbt.unsigned_tx.input.iter().map(|input| input.previous_output).collect();
+        assert!(inputs.contains(&outpoint(2)) && inputs.contains(&outpoint(1)) && !inputs.contains(&outpoint(0)));
+
+        let recipients = vec![(recipient, Amount::from_sat(10000))];
+        assert!(matches!(wallet.create_batch_psbt_tx(recipients.clone(), fee_rate, &[outpoint(0)], 0, &mut StepRng::new(2, 1)), Err(errors::Error::FrozenUtxo(_))));
+        assert!(matches!(wallet.create_batch_psbt_tx(recipients.clone(), fee_rate, &[outpoint(3)], 0, &mut StepRng::new(2, 1)), Err(errors::Error::UnknownUtxo(_))));
+        assert!(matches!(wallet.freeze_utxo(outpoint(3)), Err(errors::Error::UnknownUtxo(_))));
+
+        wallet.unfreeze_utxo(outpoint(0)).unwrap();
+        assert!(matches!(wallet.unfreeze_utxo(outpoint(0)), Err(errors::Error::UnknownUtxo(_))));
+        let psbt = Psbt::deserialize(&wallet.create_batch_psbt_tx(recipients, fee_rate, &[outpoint(0)], 0, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        assert_eq!(psbt.unsigned_tx.input[0].previous_output, outpoint(0));
+    }
+
+    #[test]
+    fn test_create_bump_fee_psbt_tx() {
+        let mut wallet = get_wallet();
+        let script  = wallet.get_pubkeys().unwrap()[0].clone();
+        let utxo = |vout: u32| PartialUtxo{ outpoint: OutPoint::from_str(&format!("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:{}", vout)).unwrap(), amount: 100000, is_spent: false,
+            script: script.clone(), height: Some(1), is_coinbase: false };
+        wallet.insert_utxos(vec![utxo(0), utxo(1)]).unwrap();
+        let recipient = ScriptBuf::from_hex("0014c12e1ea122c2e2d8593948efede523652e0493cb").unwrap();
+        // Stands in for the signature and public key of a broadcast transaction
+        let broadcast = |psbt: Psbt| {
+            let mut transaction = psbt.unsigned_tx;
+            transaction.input.iter_mut().for_each(|input| input.witness = Witness::from_slice(&[vec![0; 72], vec![0; 33]]));
+            transaction
+        };
+
+        let original = Psbt::deserialize(&wallet.create_psbt_tx(recipient.to_bytes(), FeeRate::from_sat_per_vb(2).unwrap(), Amount::from_sat(50000), 0, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        assert_eq!(original.unsigned_tx.input[0].sequence, Sequence::ENABLE_RBF_NO_LOCKTIME);
+        let original_fee = original.fee().unwrap();
+        let original = broadcast(original);
+
+        assert!(matches!(wallet.create_bump_fee_psbt_tx(original.clone(), FeeRate::from_sat_per_vb(2).unwrap(), &mut StepRng::new(2, 1)), Err(errors::Error::FeeRateTooLow { .. })));
+
+        // The change pays for the bump
+        let fee_rate = FeeRate::from_sat_per_vb(10).unwrap();
+        let bumped = Psbt::deserialize(&wallet.create_bump_fee_psbt_tx(original.clone(), fee_rate, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        assert_eq!(bumped.unsigned_tx.input.len(), 1);
+        assert_eq!(bumped.unsigned_tx.input[0].previous_output, original.input[0].previous_output);
+        assert!(bumped.unsigned_tx.output.contains(&TxOut { script_pubkey: recipient.clone(), value: Amount::from_sat(50000) }));
+        assert_eq!(bumped.unsigned_tx.output.len(), 2);
+        assert!(bumped.fee().unwrap() >= fee_rate * broadcast(bumped.clone()).weight());
+        assert!(bumped.fee().unwrap() > original_fee);
+
+        // Without change to take from, another confirmed output joins
+        let original = Psbt::deserialize(&wallet.create_psbt_tx(recipient.to_bytes(), FeeRate::from_sat_per_vb(1).unwrap(), Amount::from_sat(99800), 0, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        assert_eq!(original.unsigned_tx.output.len(), 1);
+        let bumped = Psbt::deserialize(&wallet.create_bump_fee_psbt_tx(broadcast(original), fee_rate, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        assert_eq!(bumped.unsigned_tx.input.len(), 2);
+
+        let mut final_sequence = broadcast(Psbt::deserialize(&wallet.create_psbt_tx(recipient.to_bytes(), FeeRate::from_sat_per_vb(2).unwrap(), Amount::from_sat(50000), 0, &mut StepRng::new(2, 1)).unwrap()).unwrap());
+        final_sequence.input[0].sequence = Sequence::MAX;
+        assert!(matches!(wallet.create_bump_fee_psbt_tx(final_sequence, fee_rate, &mut StepRng::new(2, 1)), Err(errors::Error::IrreplaceableTransaction(_))));
+    }
+
+    #[test]
+    fn test_pending_spends() {
+        let mut wallet = get_wallet();
+        let script  = wallet.get_pubkeys().unwrap()[0].clone();
+        let funding = OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap();
+        wallet.insert_utxos(vec![PartialUtxo{ outpoint: funding, amount: 100000, is_spent: false, script: script.clone(), height: Some(1), is_coinbase: false }]).unwrap();
+        let recipient = ScriptBuf::from_hex("0014c12e1ea122c2e2d8593948efede523652e0493cb").unwrap();
+        let fee_rate = FeeRate::from_sat_per_vb(2).unwrap();
+
+        let original = Psbt::deserialize(&wallet.create_psbt_tx(recipient.to_bytes(), fee_rate, Amount::from_sat(50000), 0, &mut StepRng::new(2, 1)).unwrap()).unwrap().unsigned_tx;
+        wallet.mark_pending_spent(&original);
+
+        // The input is gone from the balance and a second send cannot pick it again
+        assert!(wallet.list_unspent().is_empty());
+        assert_eq!(wallet.balance().unwrap(), Amount::ZERO);
+        let second = wallet.create_psbt_tx(recipient.to_bytes(), fee_rate, Amount::from_sat(10000), 0, &mut StepRng::new(2, 1));
+        assert!(matches!(second, Err(errors::Error::CoinSelection(_))));
+
+        // The fee bump spends what the original holds, after that the original is replaced
+        let bumped = Psbt::deserialize(&wallet.create_bump_fee_psbt_tx(original.clone(), FeeRate::from_sat_per_vb(10).unwrap(), &mut StepRng::new(2, 1)).unwrap()).unwrap().unsigned_tx;
+        wallet.mark_pending_spent(&bumped);
+        wallet.release_pending_spent(original.compute_txid());
+        assert!(wallet.list_unspent().is_empty());
+        let again = wallet.create_bump_fee_psbt_tx(original, FeeRate::from_sat_per_vb(20).unwrap(), &mut StepRng::new(2, 1));
+        assert!(matches!(again, Err(errors::Error::IrreplaceableTransaction(_))));
+
+        // Dropping the replacement makes the input spendable again, mining it spends it for good
+        wallet.release_pending_spent(bumped.compute_txid());
+        assert_eq!(wallet.list_unspent().len(), 1);
+        wallet.mark_pending_spent(&bumped);
+        wallet.insert_utxos(vec![PartialUtxo{ outpoint: funding, amount: 100000, is_spent: true, script, height: Some(1), is_coinbase: false }]).unwrap();
+        wallet.release_pending_spent(bumped.compute_txid());
+        assert!(wallet.list_unspent().is_empty());
+    }
+
+    #[test]
+    fn test_create_cpfp_psbt_tx() {
+        let mut wallet = get_wallet();
+        let script  = wallet.get_pubkeys().unwrap()[0].clone();
+        let parent = Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn { previous_output: OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap(), witness: Witness::from_slice(&[vec![0; 72], vec![0; 33]]), ..Default::default() }],
+            output: vec![
+                TxOut { script_pubkey: ScriptBuf::from_hex("0014c12e1ea122c2e2d8593948efede523652e0493cb").unwrap(), value: Amount::from_sat(50000) },
+                TxOut { script_pubkey: ScriptBuf::from_bytes(script.clone()), value: Amount::from_sat(20000) },
+            ],
+        };
+        let fee_rate = FeeRate::from_sat_per_vb(20).unwrap();
+        assert!(matches!(wallet.create_cpfp_psbt_tx(parent.clone(), fee_rate, &mut StepRng::new(2, 1)), Err(errors::Error::NoWalletOutputs(_))));
+
+        let payment = OutPoint { txid: parent.compute_txid(), vout: 1 };
+        wallet.insert_unconfirmed_utxos(vec![PartialUtxo{ outpoint: payment, amount: 20000, is_spent: false, script: script.clone(), height: None, is_coinbase: false }]).unwrap();
+        wallet.freeze_utxo(payment).unwrap();
+        assert!(matches!(wallet.create_cpfp_psbt_tx(parent.clone(), fee_rate, &mut StepRng::new(2, 1)), Err(errors::Error::FrozenUtxo(outpoint)) if outpoint == payment));
+        wallet.unfreeze_utxo(payment).unwrap();
+        let child = Psbt::deserialize(&wallet.create_cpfp_psbt_tx(parent.clone(), fee_rate, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        assert_eq!(child.unsigned_tx.input.len(), 1);
+        assert_eq!(child.unsigned_tx.input[0].previous_output, payment);
+        assert_eq!(child.unsigned_tx.output.len(), 1);
+        assert_eq!(wallet.get_keychain(child.unsigned_tx.output[0].script_pubkey.as_bytes()), Some(KeychainKind::Internal));
+
+        // The parent pays nothing the wallet knows of, so the child pays for both
+        let child_weight = child.unsigned_tx.weight() + Weight::from_wu(2 + 108);
+        assert!(child.fee().unwrap() >= fee_rate * (parent.weight() + child_weight));
+
+        // Once mined there is nothing left to accelerate
+        wallet.insert_utxos(vec![PartialUtxo{ outpoint: payment, amount: 20000, is_spent: false, script, height: Some(1), is_coinbase: false }]).unwrap();
+        assert!(matches!(wallet.create_cpfp_psbt_tx(parent, fee_rate, &mut StepRng::new(2, 1)), Err(errors::Error::TransactionConfirmed(_))));
+    }
+
+    #[test]
+    fn test_unconfirmed_utxos_count_as_pending() {
+        let mut wallet = get_wallet();
+        wallet.get_receive_address().unwrap();
+        let script  = wallet.get_pubkeys().unwrap()[0].clone();
+        let outpoint = OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap();
+        let utxo = || PartialUtxo{ outpoint, amount: 100000, is_spent: false, script: script.clone(), height: None, is_coinbase: false };
+
+        wallet.insert_unconfirmed_utxos(vec![utxo()]).unwrap();
+        assert_eq!(wallet.balance().unwrap(), Amount::ZERO);
+        assert_eq!(wallet.pending_balance().unwrap(), Amount::from_sat(100000));
+
+        wallet.insert_utxos(vec![utxo()]).unwrap();
+        assert_eq!(wallet.balance().unwrap(), Amount::from_sat(100000));
+        assert_eq!(wallet.pending_balance().unwrap(), Amount::ZERO);
+
+        // Seeing the transaction again in the mempool does not unconfirm it
+        wallet.insert_unconfirmed_utxos(vec![utxo()]).unwrap();
+        assert_eq!(wallet.pending_balance().unwrap(), Amount::ZERO);
+    }
+    #[test]
+    fn test_lookahead_watches_unused_scripts() {
+        let wallet = get_wallet();
+        let pubkeys = wallet.get_pubkeys().unwrap();
+
+        assert_eq!(pubkeys.len(), 2 * DEFAULT_LOOKAHEAD as usize);
+        assert!(pubkeys.contains(&wallet.derive_script(KeychainKind::External, DEFAULT_LOOKAHEAD - 1).unwrap()));
+        assert!(pubkeys.contains(&Vec::from_hex("001478e81513288cb8697189df5aa8561bee7048e192").unwrap()));
+    }
+
+    #[test]
+    fn test_used_script_extends_lookahead() {
+        let mut wallet = get_wallet();
+        let last_watched = wallet.derive_script(KeychainKind::External, DEFAULT_LOOKAHEAD - 1).unwrap();
+        let outpoint = OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap();
+        wallet.insert_utxos(vec![PartialUtxo{ outpoint, amount: 100000, is_spent: false, script: last_watched, height: Some(1), is_coinbase: false }]).unwrap();
+
+        assert_eq!(wallet.get_pubkeys().unwrap().len(), 3 * DEFAULT_LOOKAHEAD as usize);
+        let next_unused = wallet.derive_script(KeychainKind::External, DEFAULT_LOOKAHEAD).unwrap();
+        let address = wallet.get_receive_address().unwrap();
+        assert_eq!(Address::from_str(&address).unwrap().assume_checked().script_pubkey().to_bytes(), next_unused);
+    }
+
+    #[test]
+    fn test_new_address_is_never_reused() {
+        let mut wallet = get_wallet();
+        let first = wallet.new_receive_address().unwrap();
+        let second = wallet.new_receive_address().unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(second, wallet.derive_address(KeychainKind::External, 1).unwrap().to_string());
+        let next = wallet.get_receive_address().unwrap();
+        assert_ne!(next, second);
+        assert_eq!(wallet.get_pubkeys().unwrap().len(), 2 * DEFAULT_LOOKAHEAD as usize + 2);
+    }
+
+    #[test]
+    fn test_first_new_address_is_index_zero() {
+        let mut wallet = get_wallet();
+
+        // m/84'/0'/0'/0/0
+        assert_eq!(wallet.new_receive_address().unwrap(), "bc1qcyhpagfzct3dskfefrh7mefrv5hqfy7txzhq24");
+    }
+
+    #[test]
+    fn test_change_script_is_internal() {
+        let mut wallet = get_wallet();
+        let change_script = wallet.get_change_script().unwrap();
+
+        assert_eq!(wallet.get_keychain(&change_script), Some(KeychainKind::Internal));
+        assert_eq!(wallet.pubkey_map.get(&change_script).unwrap().key_depth, 0);
+        let receive_script = wallet.derive_script(KeychainKind::External, 0).unwrap();
+        assert_eq!(wallet.get_keychain(&receive_script), Some(KeychainKind::External));
+        assert_eq!(wallet.get_keychain(&[vec![0x00, 0x14], vec![1; 20]].concat()), None);
+    }
+
+    #[test]
+    fn test_spend_change_output() {
+        let mut wallet = get_wallet();
+        let receive_script = wallet.derive_script(KeychainKind::External, 0).unwrap();
+        let funding = OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap();
+        wallet.insert_utxos(vec![PartialUtxo{ outpoint: funding, amount: 100000, is_spent: false, script: receive_script.clone(), height: Some(1), is_coinbase: false }]).unwrap();
+        let recipient = [vec![0x00, 0x14], vec![1; 20]].concat();
+        let fee_rate = FeeRate::from_sat_per_vb(3).unwrap();
+        let mut rng = StepRng::new(2, 1);
+
+        let first = Psbt::deserialize(&wallet.create_psbt_tx(recipient.clone(), fee_rate, Amount::from_sat(1000), 0, &mut rng).unwrap()).unwrap();
+        let change_vout = first.unsigned_tx.output.iter().position(|output| output.script_pubkey.as_bytes() != recipient.as_slice()).unwrap();
+        let change = &first.unsigned_tx.output[change_vout];
+        assert_eq!(wallet.get_keychain(change.script_pubkey.as_bytes()), Some(KeychainKind::Internal));
+
+        // The payment confirms, spending the funding output and creating the change
+        let change_outpoint = OutPoint { txid: first.unsigned_tx.compute_txid(), vout: change_vout as u32 };
+        wallet.insert_utxos(vec![
+            PartialUtxo{ outpoint: funding, amount: 100000, is_spent: true, script: receive_script, height: Some(1), is_coinbase: false },
+            PartialUtxo{ outpoint: change_outpoint, amount: change.value.to_sat(), is_spent: false, script: change.script_pubkey.to_bytes(), height: Some(2), is_coinbase: false },
+        ]).unwrap();
+
+        let second = Psbt::deserialize(&wallet.create_psbt_tx(recipient.clone(), fee_rate, Amount::from_sat(1000), 0, &mut rng).unwrap()).unwrap();
+        assert_eq!(second.unsigned_tx.input[0].previous_output, change_outpoint);
+        let (pubkey, (fingerprint, path)) = second.inputs[0].bip32_derivation.first_key_value().unwrap();
+        assert_eq!(*fingerprint, Fingerprint::from_str("d34db33f").unwrap());
+        assert_eq!(*path, DerivationPath::from_str("m/84'/0'/0'/1/0").unwrap());
+        let spent_script = ScriptBuf::new_p2wpkh(&CompressedPublicKey(*pubkey).wpubkey_hash());
+        assert_eq!(spent_script, change.script_pubkey);
+
+        // Change of the second payment goes to the next internal script
+        let next_change = second.unsigned_tx.output.iter().find(|output| output.script_pubkey.as_bytes() != recipient.as_slice()).unwrap();
+        assert_eq!(next_change.script_pubkey.to_bytes(), wallet.derive_script(KeychainKind::Internal, 1).unwrap());
+    }
+
+    #[test]
+    fn test_balance_breakdown() {
+        let mut wallet = get_wallet();
+        let receive_script = wallet.derive_script(KeychainKind::External, 0).unwrap();
+        let change_script = wallet.derive_script(KeychainKind::Internal, 0).unwrap();
+        let outpoint = |vout| OutPoint { txid: OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap().txid, vout };
+
+        wallet.insert_utxos(vec![
+            PartialUtxo{ outpoint: outpoint(0), amount: 100000, is_spent: false, script: receive_script.clone(), height: Some(1), is_coinbase: false },
+            PartialUtxo{ outpoint: outpoint(1), amount: 50000, is_spent: false, script: receive_script.clone(), height: Some(5), is_coinbase: true },
+        ]).unwrap();
+        wallet.insert_unconfirmed_utxos(vec![
+            PartialUtxo{ outpoint: outpoint(2), amount: 2000, is_spent: false, script: receive_script, height: None, is_coinbase: false },
+            PartialUtxo{ outpoint: outpoint(3), amount: 3000, is_spent: false, script: change_script, height: None, is_coinbase: false },
+        ]).unwrap();
+        wallet.set_tip_height(10);
+
+        let balance = wallet.balance_breakdown().unwrap();
+        assert_eq!(balance.confirmed, Amount::from_sat(100000));
+        assert_eq!(balance.immature, Amount::from_sat(50000));
+        assert_eq!(balance.untrusted_pending, Amount::from_sat(2000));
+        assert_eq!(balance.trusted_pending, Amount::from_sat(3000));
+        assert_eq!(wallet.pending_balance().unwrap(), Amount::from_sat(5000));
+
+        wallet.set_tip_height(5 + COINBASE_MATURITY - 1);
+        assert_eq!(wallet.balance().unwrap(), Amount::from_sat(150000));
+        assert_eq!(wallet.balance_breakdown().unwrap().immature, Amount::ZERO);
+    }
+
+    #[test]
+    fn test_balance_breakdown_after_send() {
+        let mut wallet = get_wallet();
+        let receive_script = wallet.derive_script(KeychainKind::External, 0).unwrap();
+        let outpoint = |vout| OutPoint { txid: OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap().txid, vout };
+        wallet.insert_utxos(vec![
+            PartialUtxo{ outpoint: outpoint(0), amount: 100000, is_spent: false, script: receive_script.clone(), height: Some(1), is_coinbase: false },
+            PartialUtxo{ outpoint: outpoint(1), amount: 20000, is_spent: false, script: receive_script, height: Some(1), is_coinbase: false },
+        ]).unwrap();
+        let recipient = ScriptBuf::from_hex("0014c12e1ea122c2e2d8593948efede523652e0493cb").unwrap();
+
+        let send = Psbt::deserialize(&wallet.create_psbt_tx(recipient.to_bytes(), FeeRate::from_sat_per_vb(2).unwrap(), Amount::from_sat(90000), 0, &mut StepRng::new(2, 1)).unwrap()).unwrap().unsigned_tx;
+        let (vout, change) = send.output.iter().enumerate()
+            .find(|(_, output)| wallet.get_keychain(output.script_pubkey.as_bytes()) == Some(KeychainKind::Internal)).unwrap();
+        let spent: Amount = send.input.iter().map(|input| wallet.utxo_map[&input.previous_output].utxo.txout.value).sum();
+
+        // What the node does once the send is broadcast
+        wallet.mark_pending_spent(&send);
+        wallet.insert_unconfirmed_utxos(vec![PartialUtxo{ outpoint: OutPoint { txid: send.compute_txid(), vout: vout as u32 }, amount: change.value.to_sat(), is_spent: false,
+            script: change.script_pubkey.to_bytes(), height: None, is_coinbase: false }]).unwrap();
+
+        let balance = wallet.balance_breakdown().unwrap();
+        assert_eq!(balance.confirmed, Amount::from_sat(120000) - spent);
+        assert_eq!(balance.trusted_pending, change.value);
+        assert_eq!(balance.untrusted_pending, Amount::ZERO);
+    }
+
+    #[test]
+    fn test_coin_selection_honours_min_confirmations() {
+        let account_derivation = DerivationPath::from_str("m/84'/0'/0'").unwrap();
+        let master_fingerprint = Fingerprint::from_str("d34db33f").unwrap();
+        let mut wallet = WatchOnly::from_account(get_xpub(), Network::Bitcoin, account_derivation, master_fingerprint, WalletType::P2WPKH, WalletOptions { min_confirmations: 6, ..Default::default() }).unwrap();
+        let receive_script = wallet.derive_script(KeychainKind::External, 0).unwrap();
+        let outpoint = OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap();
+        wallet.insert_utxos(vec![PartialUtxo{ outpoint, amount: 100000, is_spent: false, script: receive_script, height: Some(10), is_coinbase: false }]).unwrap();
+        let recipient = [vec![0x00, 0x14], vec![1; 20]].concat();
+        let fee_rate = FeeRate::from_sat_per_vb(3).unwrap();
+        let mut rng = StepRng::new(2, 1);
+
+        wallet.set_tip_height(14);
+        assert!(matches!(wallet.create_psbt_tx(recipient.clone(), fee_rate, Amount::from_sat(1000), 0, &mut rng), Err(errors::Error::CoinSelection(_))));
+
+        wallet.set_tip_height(15);
+        assert!(wallet.create_psbt_tx(recipient, fee_rate, Amount::from_sat(1000), 0, &mut rng).is_ok());
+    }
+
+    #[test]
+    fn test_zero_confirmations_spends_pending_change() {
+        let account_derivation = DerivationPath::from_str("m/84'/0'/0'").unwrap();
+        let master_fingerprint = Fingerprint::from_str("d34db33f").unwrap();
+        let mut wallet = WatchOnly::from_account(get_xpub(), Network::Bitcoin, account_derivation, master_fingerprint, WalletType::P2WPKH, WalletOptions { min_confirmations: 0, ..Default::default() }).unwrap();
+        let change_script = wallet.derive_script(KeychainKind::Internal, 0).unwrap();
+        let outpoint = OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap();
+        wallet.insert_unconfirmed_utxos(vec![PartialUtxo{ outpoint, amount: 100000, is_spent: false, script: change_script, height: None, is_coinbase: false }]).unwrap();
+        let recipient = [vec![0x00, 0x14], vec![1; 20]].concat();
+        let fee_rate = FeeRate::from_sat_per_vb(3).unwrap();
+
+        let psbt = Psbt::deserialize(&wallet.create_psbt_tx(recipient, fee_rate, Amount::from_sat(1000), 0, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        assert_eq!(psbt.unsigned_tx.input[0].previous_output, outpoint);
+    }
+
+    fn get_taproot_wallet() -> WatchOnly {
+        // BIP86 test vector account
+        let xpub = Xpub::from_str("xpub6BgBgsespWvERF3LHQu6CnqdvfEvtMcQjYrcRzx53QJjSxarj2afYWcLteoGVky7D3UKDP9QyrLprQ3VCECoY49yfdDEHGCtMMj92pReUsQ").unwrap();
+        let account_derivation = DerivationPath::from_str("m/86'/0'/0'").unwrap();
+        let master_fingerprint = Fingerprint::from_str("73c5da0a").unwrap();
+        WatchOnly::from_account(xpub, Network::Bitcoin, account_derivation, master_fingerprint, WalletType::P2TR, WalletOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn test_derive_p2tr_addresses() {
+        let mut wallet = get_taproot_wallet();
+
+        assert_eq!(wallet.get_receive_address().unwrap(), "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr");
+        let change_script = wallet.get_change_script().unwrap();
+        let change_address = Address::from_script(Script::from_bytes(&change_script), Network::Bitcoin).unwrap();
+        assert_eq!(change_address.to_string(), "bc1p3qkhfews2uk44qtvauqyr2ttdsw7svhkl9nkm9s9c3x4ax5h60wqwruhk7");
+    }
+
+    #[test]
+    fn test_create_p2tr_psbt_tx() {
+        let mut wallet = get_taproot_wallet();
+        let receive_script = wallet.derive_script(KeychainKind::External, 0).unwrap();
+        let outpoint = OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap();
+        wallet.insert_utxos(vec![PartialUtxo{ outpoint, amount: 100000, is_spent: false, script: receive_script.clone(), height: Some(1), is_coinbase: false }]).unwrap();
+        assert_eq!(wallet.utxo_map.get(&outpoint).unwrap().satisfaction_weight, Weight::from_wu(65));
+        let recipient = [vec![0x00, 0x14], vec![1; 20]].concat();
+        let fee_rate = FeeRate::from_sat_per_vb(3).unwrap();
+
+        let mut psbt = Psbt::deserialize(&wallet.create_psbt_tx(recipient, fee_rate, Amount::from_sat(1000), 0, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        let input = &psbt.inputs[0];
+        let internal_key = input.tap_internal_key.unwrap();
+        let (leaf_hashes, (fingerprint, path)) = input.tap_key_origins.get(&internal_key).unwrap();
+        assert!(leaf_hashes.is_empty());
+        assert_eq!(*fingerprint, Fingerprint::from_str("73c5da0a").unwrap());
+        assert_eq!(*path, DerivationPath::from_str("m/86'/0'/0'/0/0").unwrap());
+        assert!(input.bip32_derivation.is_empty());
+        assert_eq!(input.witness_utxo.as_ref().unwrap().script_pubkey.to_bytes(), receive_script);
+
+        // Key path spends finalise to the signature alone
+        psbt.inputs[0].tap_key_sig = Some(bitcoin::taproot::Signature::from_slice(&[1; 64]).unwrap());
+        let transaction: Transaction = encode::deserialize(&wallet.finalise_psbt_tx(psbt).unwrap()).unwrap();
+        assert_eq!(transaction.input[0].witness.len(), 1);
+        assert_eq!(transaction.input[0].witness.nth(0).unwrap(), &[1; 64]);
+    }
+
+    /// Master key of the "abandon ... about" test mnemonic, fingerprint 73c5da0a
+    const TEST_XPRV: &str = "xprv9s21ZrQH143K3GJpoapnV8SFfukcVBSfeCficPSGfubmSFDxo1kuHnLisriDvSnRRuL2Qrg5ggqHKNVpxR86QEC8w35uxmGoggxtQTPvfUu";
+
+    fn get_descriptor_wallet(descriptor: &str) -> WatchOnly {
+        let (descriptor, change_descriptor) = crate::descriptor::parse_keychains(descriptor, None).unwrap();
+        WatchOnly::new(descriptor, change_descriptor, Network::Bitcoin, WalletOptions::default()).unwrap()
+    }
+
+    /// Funds receive index 0, then pays 1000 sat and signs with the test master key
+    fn sign_payment(wallet: &mut WatchOnly) -> Psbt {
+        let receive_script = wallet.derive_script(KeychainKind::External, 0).unwrap();
+        let outpoint = OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap();
+        wallet.insert_utxos(vec![PartialUtxo{ outpoint, amount: 100000, is_spent: false, script: receive_script, height: Some(1), is_coinbase: false }]).unwrap();
+        let recipient = [vec![0x00, 0x14], vec![1; 20]].concat();
+        let fee_rate = FeeRate::from_sat_per_vb(3).unwrap();
+
+        let mut psbt = Psbt::deserialize(&wallet.create_psbt_tx(recipient, fee_rate, Amount::from_sat(1000), 0, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        let xpriv = bitcoin::bip32::Xpriv::from_str(TEST_XPRV).unwrap();
+        psbt.sign(&xpriv, &Secp256k1::new()).unwrap();
+        psbt
+    }
+
+    #[test]
+    fn test_export_descriptors() {
+        let wallet = get_wallet();
+        let (external, internal) = wallet.descriptors();
+        assert_eq!(external, "wpkh([d34db33f/84'/0'/0']xpub6BgqrNmJjjiaQASxUaBH9xLTBtnVpSoTzSBiGt12K572ofLub5U2rvZok5MJ5qFnBqPVi2HmnMhzQsAuZ1jG7ppoizmEzbuuCTtj9rm9Cpp/0/*)#zejyth28");
+
+        // The exported descriptors rebuild the same wallet
+        let restored = WatchOnly::new(Descriptor::from_str(&external).unwrap(), Descriptor::from_str(&internal).unwrap(), Network::Bitcoin, WalletOptions::default()).unwrap();
+        assert_eq!(restored.get_pubkeys().unwrap(), wallet.get_pubkeys().unwrap());
+    }
+
+    #[test]
+    fn test_spend_nested_segwit_descriptor() {
+        let mut wallet = get_descriptor_wallet("sh(wpkh([73c5da0a/49'/0'/0']xpub6C6nQwHaWbSrzs5tZ1q7m5R9cPK9eYpNMFesiXsYrgc1P8bvLLAet9JfHjYXKjToD8cBRswJXXbbFpXgwsswVPAZzKMa1jUp2kVkGVUaJa7/<0;1>/*))");
+        assert_eq!(wallet.get_receive_address().unwrap(), "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf");
+
+        let psbt = sign_payment(&mut wallet);
+        let transaction: Transaction = encode::deserialize(&wallet.finalise_psbt_tx(psbt).unwrap()).unwrap();
+        assert_eq!(transaction.input[0].witness.len(), 2);
+        // Push of the p2wpkh redeem script
+        assert_eq!(transaction.input[0].script_sig.len(), 23);
+    }
+
+    #[test]
+    fn test_spend_multisig_descriptor() {
+        let descriptor = "wsh(sortedmulti(2,[73c5da0a/48'/0'/0'/2']xpub6DkFAXWQ2dHxq2vatrt9qyA3bXYU4ToWQwCHbf5XB2mSTexcHZCeKS1VZYcPoBd5X8yVcbXFHJR9R8UCVpt82VX1VhR28mCyxUFL4r6KFrf/<0;1>/*,[73c5da0a/44'/0'/0']xpub6BosfCnifzxcFwrSzQiqu2DBVTshkCXacvNsWGYJVVhhawA7d4R5WSWGFNbi8Aw6ZRc1brxMyWMzG3DSSSSoekkudhUd9yLb6qx39T9nMdj/<0;1>/*))";
+        let mut wallet = get_descriptor_wallet(descriptor);
+
+        let psbt = sign_payment(&mut wallet);
+        assert_eq!(psbt.inputs[0].partial_sigs.len(), 2);
+        let mut unsigned = psbt.clone();
+        unsigned.inputs[0].partial_sigs.pop_first();
+        assert!(matches!(wallet.finalise_psbt_tx(unsigned), Err(errors::Error::MissingSignature)));
+
+        let transaction: Transaction = encode::deserialize(&wallet.finalise_psbt_tx(psbt).unwrap()).unwrap();
+        let witness = &transaction.input[0].witness;
+        assert_eq!(witness.len(), 4);
+        assert!(witness.nth(0).unwrap().is_empty());
+        assert_eq!(ScriptBuf::from_bytes(witness.last().unwrap().to_vec()).to_p2wsh().to_bytes(), wallet.derive_script(KeychainKind::External, 0).unwrap());
+    }
+
+    /// Cosigner master keys and their BIP48 accounts
+    fn get_cosigners(script_type: u32) -> (Vec<bitcoin::bip32::Xpriv>, Vec<(Xpub, Fingerprint, DerivationPath)>) {
+        let secp = Secp256k1::new();
+        let masters: Vec<_> = (1..=3).map(|seed| bitcoin::bip32::Xpriv::new_master(Network::Bitcoin, &[seed; 32]).unwrap()).collect();
+        let account_derivation = DerivationPath::from_str(&format!("m/48'/0'/0'/{}'", script_type)).unwrap();
+        let cosigners = masters.iter().map(|master| {
+            let account = master.derive_priv(&secp, &account_derivation).unwrap();
+            (Xpub::from_priv(&secp, &account), master.fingerprint(&secp), account_derivation.clone())
+        }).collect();
+        (masters, cosigners)
+    }
+
+    /// Funds receive index 0 and pays 1000 sat, each of `signers` signing its own copy
+    fn cosign_payment(wallet: &mut WatchOnly, signers: &[bitcoin::bip32::Xpriv]) -> Vec<Psbt> {
+        let receive_script = wallet.derive_script(KeychainKind::External, 0).unwrap();
+        let outpoint = OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap();
+        wallet.insert_utxos(vec![PartialUtxo{ outpoint, amount: 100000, is_spent: false, script: receive_script, height: Some(1), is_coinbase: false }]).unwrap();
+        let recipient = [vec![0x00, 0x14], vec![1; 20]].concat();
+        let fee_rate = FeeRate::from_sat_per_vb(3).unwrap();
+
+        let psbt = Psbt::deserialize(&wallet.create_psbt_tx(recipient, fee_rate, Amount::from_sat(1000), 0, &mut StepRng::new(2, 1)).unwrap()).unwrap();
+        signers.iter().map(|signer| {
+            let mut copy = psbt.clone();
+            copy.sign(signer, &Secp256k1::new()).unwrap();
+            copy
+        }).collect()
+    }
+
+    #[test]
+    fn test_p2wsh_multisig_combines_cosigner_psbts() {
+        let (masters, cosigners) = get_cosigners(2);
+        let mut wallet = WatchOnly::from_multisig(cosigners, 2, MultisigType::P2WSH, Network::Bitcoin, WalletOptions::default()).unwrap();
+        let mut signed = cosign_payment(&mut wallet, &masters[1..]);
+        let input = &signed[0].inputs[0];
+        assert_eq!(input.bip32_derivation.len(), 3);
+        assert!(input.witness_script.is_some());
+        assert_eq!(input.partial_sigs.len(), 1);
+        assert!(matches!(wallet.finalise_psbt_tx(signed[0].clone()), Err(errors::Error::MissingSignature)));
+
+        let first = signed.remove(0);
+        let combined = Psbt::deserialize(&wallet.combine_psbts(first, signed).unwrap()).unwrap();
+        assert_eq!(combined.inputs[0].partial_sigs.len(), 2);
+        let transaction: Transaction = encode::deserialize(&wallet.finalise_psbt_tx(combined).unwrap()).unwrap();
+        assert_eq!(transaction.input[0].witness.len(), 4);
+    }
+
+    #[test]
+    fn test_p2tr_multisig_combines_cosigner_psbts() {
+        use bitcoin::{sighash::{Prevouts, SighashCache}, taproot::{LeafVersion, TapLeafHash}, TapSighashType, XOnlyPublicKey};
+
+        let (masters, cosigners) = get_cosigners(3);
+        let mut wallet = WatchOnly::from_multisig(cosigners, 2, MultisigType::P2TR, Network::Bitcoin, WalletOptions::default()).unwrap();
+        assert!(wallet.descriptors().0.starts_with("tr(50929b74c1a04954b78b4b6035e97a5e078a5a0f28ec96d547bfee9ace803ac0,sortedmulti_a(2,"));
+        let mut signed = cosign_payment(&mut wallet, &[masters[0], masters[2]]);
+        let input = &signed[0].inputs[0];
+        assert_eq!(input.tap_key_origins.len(), 3);
+        assert_eq!(input.tap_script_sigs.len(), 1);
+        assert!(input.tap_key_sig.is_none());
+        let prevout = input.witness_utxo.clone().unwrap();
+
+        let first = signed.remove(0);
+        let combined = Psbt::deserialize(&wallet.combine_psbts(first, signed).unwrap()).unwrap();
+        let transaction: Transaction = encode::deserialize(&wallet.finalise_psbt_tx(combined).unwrap()).unwrap();
+
+        // Two signatures and an empty push, then the leaf and its control block
+        let witness = &transaction.input[0].witness;
+        assert_eq!(witness.len(), 5);
+        let leaf = ScriptBuf::from_bytes(witness.nth(3).unwrap().to_vec());
+        let leaf_hash = TapLeafHash::from_script(&leaf, LeafVersion::TapScript);
+        let sighash = SighashCache::new(&transaction).taproot_script_spend_signature_hash(0, &Prevouts::All(&[prevout]), leaf_hash, TapSighashType::Default).unwrap();
+        let keys: Vec<_> = leaf.instructions().filter_map(|instruction| match instruction {
+            Ok(bitcoin::script::Instruction::PushBytes(bytes)) => XOnlyPublicKey::from_slice(bytes.as_bytes()).ok(),
+            _ => None,
+        }).collect();
+        let secp = Secp256k1::new();
+        let mut verified = 0;
+        for (position, key) in keys.iter().enumerate() {
+            let item = witness.nth(2 - position).unwrap();
+            if !item.is_empty() {
+                let signature = bitcoin::taproot::Signature::from_slice(item).unwrap();
+                secp.verify_schnorr(&signature.signature, &sighash.into(), key).unwrap();
+                verified += 1;
+            }
+        }
+        assert_eq!(verified, 2);
+    }
+
+    /// Policy wallet with a primary key, a key after 144 blocks and a key after height 1000, the
+    /// key of each path from its own master
+    fn get_policy_wallet() -> (Vec<bitcoin::bip32::Xpriv>, WatchOnly) {
+        let (masters, cosigners) = get_cosigners(2);
+        let keys: Vec<_> = cosigners.iter().map(|(xpub, fingerprint, path)| format!("[{}/{}]{}/<0;1>/*", fingerprint, path, xpub)).collect();
+        let policy = format!("or(9@pk({}),or(and(pk({}),older(144)),and(after(1000),pk({}))))", keys[0], keys[1], keys[2]);
+        let (descriptor, change_descriptor) = crate::descriptor::policy_keychains(&policy).unwrap();
+        let wallet = WatchOnly::new(descriptor, change_descriptor, Network::Bitcoin, WalletOptions::default()).unwrap();
+        (masters, wallet)
+    }
+
+    #[test]
+    fn test_policy_spending_paths() {
+        let (masters, mut wallet) = get_policy_wallet();
+        assert!(wallet.descriptors().0.starts_with("wsh(or_d(pk(["));
+        let receive_script = wallet.derive_script(KeychainKind::External, 0).unwrap();
+        let outpoint = OutPoint::from_str("90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da:0").unwrap();
+        wallet.insert_utxos(vec![PartialUtxo{ outpoint, amount: 100000, is_spent: false, script: receive_script, height: Some(10), is_coinbase: false }]).unwrap();
+        let recipient = [vec![0x00, 0x14], vec![1; 20]].concat();
+        let fee_rate = FeeRate::from_sat_per_vb(3).unwrap();
+        let mut rng = StepRng::new(2, 1);
+        let secp = Secp256k1::new();
+        wallet.set_tip_height(20);
+
+        let mut spend = |wallet: &mut WatchOnly, path: usize, signer: &bitcoin::bip32::Xpriv| {
+            let mut psbt = Psbt::deserialize(&wallet.create_psbt_tx(recipient.clone(), fee_rate, Amount::from_sat(1000), path, &mut rng)?).unwrap();
+            psbt.sign(signer, &secp).unwrap();
+            let satisfaction_weight = wallet.descriptor.satisfaction_weight(path);
+            let transaction: Transaction = encode::deserialize(&wallet.finalise_psbt_tx(psbt)?).unwrap();
+            // Signatures can be a byte shorter than the estimate
+            let witness_weight = transaction.input[0].witness.size() as u64 - 1;
+            assert!(satisfaction_weight.to_wu() - witness_weight <= 1);
+            Ok::<_, errors::Error>(transaction)
+        };
+
+        // The primary key spends at once, only signalling replaceability
+        let primary = spend(&mut wallet, 0, &masters[0]).unwrap();
+        assert_eq!(primary.input[0].sequence, Sequence::ENABLE_RBF_NO_LOCKTIME);
+        assert_eq!(primary.input[0].witness.len(), 2);
+
+        assert!(matches!(spend(&mut wallet, 1, &masters[1]), Err(errors::Error::TimelockNotReached)));
+        wallet.set_tip_height(10 + 144 - 1);
+        let relative = spend(&mut wallet, 1, &masters[1]).unwrap();
+        assert_eq!(relative.input[0].sequence, Sequence::from_height(144));
+        assert_eq!(relative.lock_time, LockTime::ZERO);
+        // Signature, selector of the first recovery path, failed primary key check
+        assert_eq!(relative.input[0].witness.nth(1).unwrap(), &[1]);
+        assert!(relative.input[0].witness.nth(2).unwrap().is_empty());
+
+        assert!(matches!(spend(&mut wallet, 2, &masters[2]), Err(errors::Error::TimelockNotReached)));
+        wallet.set_tip_height(1000);
+        let absolute = spend(&mut wallet, 2, &masters[2]).unwrap();
+        assert_eq!(absolute.lock_time, LockTime::from_height(1000).unwrap());
+        assert_eq!(absolute.input[0].sequence, Sequence::ENABLE_RBF_NO_LOCKTIME);
+        assert_eq!(absolute.input[0].witness.len(), 4);
+        assert!(absolute.input[0].witness.nth(1).unwrap().is_empty());
+
+        assert!(matches!(spend(&mut wallet, 3, &masters[2]), Err(errors::Error::InvalidSpendingPath(3))));
+        assert!(matches!(get_wallet().create_psbt_tx(recipient.clone(), fee_rate, Amount::from_sat(1000), 1, &mut StepRng::new(2, 1)), Err(errors::Error::InvalidSpendingPath(1))));
+    }
 }
+
@@ -0,0 +1,275 @@
+//! Peer addresses learned from configuration, seeds and addr gossip
+//!
+//! The book keeps connection statistics per address so that peers which keep failing are
+//! retried less and less often, and is persisted in the key value store between runs.
+
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::db::{KeyValueDb, ADDRESS_BOOK_KEY};
+use crate::node::CustomIPV4SocketAddress;
+use crate::util::{Error, Result};
+
+/// Maximum number of addresses kept, the least useful ones are evicted first
+const MAX_ADDRESSES: usize = 1000;
+
+/// Learned addresses failing this many times in a row without ever connecting are dropped
+const MAX_ADDRESS_FAILURES: u32 = 10;
+
+/// Seconds to wait before retrying an address after its first failure, doubled on each failure
+const RETRY_BACKOFF_SECS: u64 = 60;
+
+/// Longest wait between two attempts on a failing address, in seconds
+const MAX_RETRY_BACKOFF_SECS: u64 = 24 * 60 * 60;
+
+/// Where an address was learned from
+#[derive(serde::Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+pub enum AddressSource {
+    /// Set in the node configuration, never evicted
+    Configured,
+    /// Resolved from the network's DNS seeds
+    Seed,
+    /// Received from a peer in an addr or addrv2 message
+    Gossip,
+}
+
+#[derive(serde::Deserialize, Serialize, Clone, Debug)]
+pub struct AddressEntry {
+    pub address: CustomIPV4SocketAddress,
+    pub services: u64,
+    pub source: AddressSource,
+    /// Last time the address was announced or connected to, in seconds since the unix epoch
+    pub last_seen: u64,
+    pub last_attempt: u64,
+    pub last_success: u64,
+    /// Failed connection attempts since the last success
+    pub failures: u32,
+}
+
+impl AddressEntry {
+    fn retry_at(&self) -> u64 {
+        if self.failures == 0 {
+            return 0;
+        }
+        let backoff = RETRY_BACKOFF_SECS.saturating_mul(1 << (self.failures - 1).min(16));
+        self.last_attempt + backoff.min(MAX_RETRY_BACKOFF_SECS)
+    }
+}
+
+#[derive(serde::Deserialize, Serialize, Clone, Debug, Default)]
+pub struct AddressBook {
+    entries: Vec<AddressEntry>,
+}
+
+impl AddressBook {
+
+    /// Restores the book persisted in the store, empty if none was saved yet
+    pub fn load(db: &Arc<KeyValueDb>) -> Result<Self> {
+        match db.get_optional(ADDRESS_BOOK_KEY.to_string())? {
+            Some(bytes) => bincode::deserialize(&bytes).map_err(|err| Error::SerializationError(err.to_string())),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, db: &Arc<KeyValueDb>) -> Result<()> {
+        let encoded = bincode::serialize(self).map_err(|err| Error::SerializationError(err.to_string()))?;
+        db.insert(ADDRESS_BOOK_KEY.to_string(), encoded)
+    }
+
+    /// Adds an address or refreshes what is known about it
+    ///
+    /// Announcements only move `last_seen` forward and never downgrade a configured address.
+    pub fn add(&mut self, address: CustomIPV4SocketAddress, services: u64, last_seen: u64, source: AddressSource) {
+        if address.port == 0 || address.ip == (0, 0, 0, 0) {
+            return;
+        }
+
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.address == address) {
+            entry.last_seen = entry.last_seen.max(last_seen);
+            if services != 0 {
+                entry.services = services;
+            }
+            if source == AddressSource::Configured {
+                entry.source = source;
+            }
+            return;
+        }
+
+        if self.entries.len() >= MAX_ADDRESSES && !self.evict() && source != AddressSource::Configured {
+            return;
+        }
+        self.entries.push(AddressEntry { address, services, source, last_seen, last_attempt: 0, last_success: 0, failures: 0 });
+    }
+
+    pub fn remove(&mut self, address: &CustomIPV4SocketAddress) {
+        self.entries.retain(|entry| entry.address != *address);
+    }
+
+    pub fn mark_attempt(&mut self, address: &CustomIPV4SocketAddress, now: u64) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.address == *address) {
+            entry.last_attempt = now;
+        }
+    }
+
+    /// Records a completed handshake and the services the peer advertised in it
+    pub fn mark_success(&mut self, address: &CustomIPV4SocketAddress, services: u64, now: u64) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.address == *address) {
+            entry.services = services;
+            entry.last_success = now;
+            entry.last_seen = now;
+            entry.failures = 0;
+        }
+    }
+
+    /// Records a failed connection, dropping learned addresses that never worked and keep failing
+    pub fn mark_failure(&mut self, address: &CustomIPV4SocketAddress, now: u64) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.address == *address) {
+            entry.last_attempt = now;
+            entry.failures += 1;
+        }
+        self.entries.retain(|entry| {
+            entry.source == AddressSource::Configured || entry.last_success != 0 || entry.failures < MAX_ADDRESS_FAILURES
+        });
+    }
+
+    /// Whether `address` is done backing off from its last failure, addresses not in the book are
+    pub fn ready(&self, address: &CustomIPV4SocketAddress, now: u64) -> bool {
+        self.entries.iter()
+            .find(|entry| entry.address == *address)
+            .is_none_or(|entry| entry.retry_at() <= now)
+    }
+
+    /// Best address to connect to next, skipping `exclude` and addresses still backing off
+    ///
+    /// Addresses with fewer failures come first, then the ones seen most recently.
+    pub fn pick(&self, exclude: &[CustomIPV4SocketAddress], now: u64) -> Option<CustomIPV4SocketAddress> {
+        self.entries.iter()
+            .filter(|entry| !exclude.contains(&entry.address))
+            .filter(|entry| entry.retry_at() <= now)
+            .min_by(|a, b| a.failures.cmp(&b.failures).then(b.last_seen.cmp(&a.last_seen)))
+            .map(|entry| entry.address.clone())
+    }
+
+    /// Frees a slot by dropping the learned address that failed most and was seen least recently
+    fn evict(&mut self) -> bool {
+        let worst = self.entries.iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.source != AddressSource::Configured)
+            .max_by(|(_, a), (_, b)| a.failures.cmp(&b.failures).then(b.last_seen.cmp(&a.last_seen)))
+            .map(|(position, _)| position);
+
+        match worst {
+            Some(position) => {
+                self.entries.remove(position);
+                true
+            },
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(last: u8) -> CustomIPV4SocketAddress {
+        CustomIPV4SocketAddress { ip: (10, 0, 0, last), port: 8333 }
+    }
+
+    fn entry<'a>(book: &'a AddressBook, address: &CustomIPV4SocketAddress) -> Option<&'a AddressEntry> {
+        book.entries.iter().find(|entry| entry.address == *address)
+    }
+
+    #[test]
+    fn add_refreshes_existing_entry() {
+        let mut book = AddressBook::default();
+        book.add(address(1), 0, 100, AddressSource::Configured);
+        book.add(address(1), 9, 200, AddressSource::Gossip);
+        book.add(address(1), 0, 150, AddressSource::Gossip);
+
+        assert_eq!(book.entries.len(), 1);
+        let entry = entry(&book, &address(1)).unwrap();
+        assert_eq!(entry.last_seen, 200);
+        assert_eq!(entry.services, 9);
+        assert_eq!(entry.source, AddressSource::Configured);
+    }
+
+    #[test]
+    fn ignores_unroutable_addresses() {
+        let mut book = AddressBook::default();
+        book.add(CustomIPV4SocketAddress { ip: (0, 0, 0, 0), port: 8333 }, 0, 100, AddressSource::Gossip);
+        book.add(CustomIPV4SocketAddress { ip: (10, 0, 0, 1), port: 0 }, 0, 100, AddressSource::Gossip);
+        assert_eq!(book.entries.len(), 0);
+    }
+
+    #[test]
+    fn pick_prefers_reliable_and_recent_addresses() {
+        let mut book = AddressBook::default();
+        book.add(address(1), 0, 100, AddressSource::Gossip);
+        book.add(address(2), 0, 300, AddressSource::Gossip);
+        book.add(address(3), 0, 500, AddressSource::Gossip);
+        book.mark_failure(&address(3), 1000);
+
+        assert!(book.pick(&[], 10_000) == Some(address(2)));
+        assert!(book.pick(&[address(2)], 10_000) == Some(address(1)));
+    }
+
+    #[test]
+    fn failing_addresses_back_off() {
+        let mut book = AddressBook::default();
+        book.add(address(1), 0, 100, AddressSource::Gossip);
+        book.mark_failure(&address(1), 1000);
+        assert!(book.pick(&[], 1000 + RETRY_BACKOFF_SECS - 1).is_none());
+        assert!(book.pick(&[], 1000 + RETRY_BACKOFF_SECS) == Some(address(1)));
+
+        book.mark_failure(&address(1), 2000);
+        assert!(book.pick(&[], 2000 + RETRY_BACKOFF_SECS).is_none());
+        assert!(book.pick(&[], 2000 + 2 * RETRY_BACKOFF_SECS) == Some(address(1)));
+
+        book.mark_success(&address(1), 0, 3000);
+        assert!(book.pick(&[], 3000) == Some(address(1)));
+    }
+
+    #[test]
+    fn configured_addresses_back_off_too() {
+        let mut book = AddressBook::default();
+        book.add(address(1), 0, 100, AddressSource::Configured);
+        assert!(book.ready(&address(1), 1000));
+        assert!(book.ready(&address(2), 1000));
+
+        book.mark_failure(&address(1), 1000);
+        assert!(!book.ready(&address(1), 1000 + RETRY_BACKOFF_SECS - 1));
+        assert!(book.ready(&address(1), 1000 + RETRY_BACKOFF_SECS));
+    }
+
+    #[test]
+    fn drops_learned_addresses_that_never_connect() {
+        let mut book = AddressBook::default();
+        book.add(address(1), 0, 100, AddressSource::Gossip);
+        book.add(address(2), 0, 100, AddressSource::Configured);
+        for attempt in 0..MAX_ADDRESS_FAILURES {
+            book.mark_failure(&address(1), attempt as u64);
+            book.mark_failure(&address(2), attempt as u64);
+        }
+        assert!(entry(&book, &address(1)).is_none());
+        assert!(entry(&book, &address(2)).is_some());
+    }
+
+    #[test]
+    fn evicts_worst_learned_address_when_full() {
+        let mut book = AddressBook::default();
+        book.add(address(0), 0, 0, AddressSource::Configured);
+        for i in 1..MAX_ADDRESSES {
+            let address = CustomIPV4SocketAddress { ip: (10, 1, (i >> 8) as u8, i as u8), port: 8333 };
+            book.add(address, 0, 1000 + i as u64, AddressSource::Gossip);
+        }
+        assert_eq!(book.entries.len(), MAX_ADDRESSES);
+
+        book.add(address(200), 0, 5000, AddressSource::Gossip);
+        assert_eq!(book.entries.len(), MAX_ADDRESSES);
+        assert!(entry(&book, &address(0)).is_some());
+        assert!(entry(&book, &address(200)).is_some());
+        assert!(entry(&book, &CustomIPV4SocketAddress { ip: (10, 1, 0, 1), port: 8333 }).is_none());
+    }
+}
@@ -375,6 +375,90 @@ pub mod component {
             }
 
             impl std::error::Error for Error {}
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, PartialEq)]
+            pub enum Network {
+                Bitcoin,
+                Testnet,
+                Testnet4,
+                Signet,
+                Regtest,
+            }
+            impl ::core::fmt::Debug for Network {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        Network::Bitcoin => f.debug_tuple("Network::Bitcoin").finish(),
+                        Network::Testnet => f.debug_tuple("Network::Testnet").finish(),
+                        Network::Testnet4 => f.debug_tuple("Network::Testnet4").finish(),
+                        Network::Signet => f.debug_tuple("Network::Signet").finish(),
+                        Network::Regtest => f.debug_tuple("Network::Regtest").finish(),
+                    }
+                }
+            }
+
+            impl Network {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> Network {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+
+                    match val {
+                        0 => Network::Bitcoin,
+                        1 => Network::Testnet,
+                        2 => Network::Testnet4,
+                        3 => Network::Signet,
+                        4 => Network::Regtest,
+
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, PartialEq)]
+            pub enum WalletType {
+                P2wpkh,
+                /// Single key taproot, BIP86
+                P2tr,
+                /// Cosigner of a p2wsh multisig, BIP48
+                P2wshMultisig,
+                /// Cosigner of a taproot multisig
+                P2trMultisig,
+            }
+            impl ::core::fmt::Debug for WalletType {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        WalletType::P2wpkh => f.debug_tuple("WalletType::P2wpkh").finish(),
+                        WalletType::P2tr => f.debug_tuple("WalletType::P2tr").finish(),
+                        WalletType::P2wshMultisig => {
+                            f.debug_tuple("WalletType::P2wshMultisig").finish()
+                        }
+                        WalletType::P2trMultisig => {
+                            f.debug_tuple("WalletType::P2trMultisig").finish()
+                        }
+                    }
+                }
+            }
+
+            impl WalletType {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> WalletType {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+
+                    match val {
+                        0 => WalletType::P2wpkh,
+                        1 => WalletType::P2tr,
+                        2 => WalletType::P2wshMultisig,
+                        3 => WalletType::P2trMultisig,
+
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+
             pub type AccountXpub = _rt::String;
             pub type MasterFingerprint = _rt::String;
             pub type AccountDerivation = _rt::String;
@@ -463,8 +547,12 @@ pub mod component {
             }
             impl SimpleSigner {
                 #[allow(unused_unsafe, clippy::all)]
+                /// Account 0 of the wallet type, at m/84h/0h/0h, m/86h/0h/0h, m/48h/0h/0h/2h or
+                /// m/48h/0h/0h/3h on mainnet, with coin type 1h instead of 0h on the other networks
                 pub fn derive_account(
                     &self,
+                    wallet_type: WalletType,
+                    network: Network,
                 ) -> Result<(AccountXpub, MasterFingerprint, AccountDerivation), Error>
                 {
                     unsafe {
@@ -476,14 +564,19 @@ pub mod component {
                         #[link(wasm_import_module = "component:signer/types@0.1.0")]
                         extern "C" {
                             #[link_name = "[method]simple-signer.derive-account"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            fn wit_import(_: i32, _: i32, _: i32, _: *mut u8);
                         }
 
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        fn wit_import(_: i32, _: i32, _: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
+                        wit_import(
+                            (self).handle() as i32,
+                            wallet_type.clone() as i32,
+                            network.clone() as i32,
+                            ptr0,
+                        );
                         let l1 = i32::from(*ptr0.add(0).cast::<u8>());
                         match l1 {
                             0 => {
@@ -671,48 +764,177 @@ pub mod component {
                 }
             }
 
-            #[derive(Clone, Copy)]
-            pub enum Error {
-                CoinSelection,
-                Psbt,
-                MissingNonWitnessUtxo,
-                NoPubkey,
-                PubkeyError,
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, PartialEq)]
+            pub enum WalletType {
+                P2wpkh,
+                /// Single key taproot, BIP86
+                P2tr,
             }
-            impl ::core::fmt::Debug for Error {
+            impl ::core::fmt::Debug for WalletType {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     match self {
-                        Error::CoinSelection => f.debug_tuple("Error::CoinSelection").finish(),
-                        Error::Psbt => f.debug_tuple("Error::Psbt").finish(),
-                        Error::MissingNonWitnessUtxo => {
-                            f.debug_tuple("Error::MissingNonWitnessUtxo").finish()
-                        }
-                        Error::NoPubkey => f.debug_tuple("Error::NoPubkey").finish(),
-                        Error::PubkeyError => f.debug_tuple("Error::PubkeyError").finish(),
+                        WalletType::P2wpkh => f.debug_tuple("WalletType::P2wpkh").finish(),
+                        WalletType::P2tr => f.debug_tuple("WalletType::P2tr").finish(),
                     }
                 }
             }
-            impl ::core::fmt::Display for Error {
+
+            impl WalletType {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> WalletType {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+
+                    match val {
+                        0 => WalletType::P2wpkh,
+                        1 => WalletType::P2tr,
+
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+
+            /// Single key account, receiving on `/0/*` and paying change to `/1/*`
+            #[derive(Clone)]
+            pub struct Account {
+                pub xpub: _rt::String,
+                pub account_derivation: _rt::String,
+                pub master_fingerprint: _rt::String,
+                /// Script type of the account, p2wpkh when not set
+                pub wallet_type: Option<WalletType>,
+            }
+            impl ::core::fmt::Debug for Account {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    write!(f, "{:?}", self)
+                    f.debug_struct("Account")
+                        .field("xpub", &self.xpub)
+                        .field("account-derivation", &self.account_derivation)
+                        .field("master-fingerprint", &self.master_fingerprint)
+                        .field("wallet-type", &self.wallet_type)
+                        .finish()
+                }
+            }
+            /// Output descriptors of the receive and change scripts, checksums optional
+            #[derive(Clone)]
+            pub struct Descriptors {
+                pub external: _rt::String,
+                /// Taken from a `<0;1>` multipath step of the external descriptor when not set
+                pub internal: Option<_rt::String>,
+            }
+            impl ::core::fmt::Debug for Descriptors {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Descriptors")
+                        .field("external", &self.external)
+                        .field("internal", &self.internal)
+                        .finish()
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, PartialEq)]
+            pub enum MultisigType {
+                /// `wsh(sortedmulti)`
+                P2wsh,
+                /// `sortedmulti_a` leaf under an unspendable internal key
+                P2tr,
+            }
+            impl ::core::fmt::Debug for MultisigType {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        MultisigType::P2wsh => f.debug_tuple("MultisigType::P2wsh").finish(),
+                        MultisigType::P2tr => f.debug_tuple("MultisigType::P2tr").finish(),
+                    }
                 }
             }
 
-            impl std::error::Error for Error {}
+            impl MultisigType {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> MultisigType {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+
+                    match val {
+                        0 => MultisigType::P2wsh,
+                        1 => MultisigType::P2tr,
+
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+
+            /// Account of one cosigner, receiving on `/0/*` and paying change to `/1/*`
             #[derive(Clone)]
-            pub struct Config {
+            pub struct Cosigner {
                 pub xpub: _rt::String,
                 pub account_derivation: _rt::String,
                 pub master_fingerprint: _rt::String,
-                pub network: BitcoinNetwork,
             }
-            impl ::core::fmt::Debug for Config {
+            impl ::core::fmt::Debug for Cosigner {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    f.debug_struct("Config")
+                    f.debug_struct("Cosigner")
                         .field("xpub", &self.xpub)
                         .field("account-derivation", &self.account_derivation)
                         .field("master-fingerprint", &self.master_fingerprint)
+                        .finish()
+                }
+            }
+            /// Sorted multisig needing `threshold` of the cosigners' signatures
+            #[derive(Clone)]
+            pub struct Multisig {
+                pub threshold: u32,
+                pub cosigners: _rt::Vec<Cosigner>,
+                pub multisig_type: MultisigType,
+            }
+            impl ::core::fmt::Debug for Multisig {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Multisig")
+                        .field("threshold", &self.threshold)
+                        .field("cosigners", &self.cosigners)
+                        .field("multisig-type", &self.multisig_type)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub enum Keys {
+                Account(Account),
+                Descriptors(Descriptors),
+                Multisig(Multisig),
+                /// Primary key or timelocked recovery keys, such as
+                /// `or(pk(A),and(pk(B),older(26280)))`, every key with a `<0;1>` multipath step
+                Policy(_rt::String),
+            }
+            impl ::core::fmt::Debug for Keys {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        Keys::Account(e) => f.debug_tuple("Keys::Account").field(e).finish(),
+                        Keys::Descriptors(e) => {
+                            f.debug_tuple("Keys::Descriptors").field(e).finish()
+                        }
+                        Keys::Multisig(e) => f.debug_tuple("Keys::Multisig").field(e).finish(),
+                        Keys::Policy(e) => f.debug_tuple("Keys::Policy").field(e).finish(),
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub struct Config {
+                pub keys: Keys,
+                pub network: BitcoinNetwork,
+                /// Unused receive scripts watched past the last used one, 20 when not set
+                pub lookahead: Option<u32>,
+                /// Unused change scripts watched past the last used one, lookahead when not set
+                pub change_lookahead: Option<u32>,
+                /// Confirmations an output needs before it is spent, 0 allows unconfirmed ones, 1 when not set
+                pub min_confirmations: Option<u32>,
+            }
+            impl ::core::fmt::Debug for Config {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Config")
+                        .field("keys", &self.keys)
                         .field("network", &self.network)
+                        .field("lookahead", &self.lookahead)
+                        .field("change-lookahead", &self.change_lookahead)
+                        .field("min-confirmations", &self.min_confirmations)
                         .finish()
                 }
             }
@@ -733,6 +955,62 @@ pub mod component {
                     }
                 }
             }
+            /// Wallet balance split by how final the outputs are
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Balance {
+                /// Mined outputs, immature coinbase left out
+                pub confirmed: u64,
+                /// Unconfirmed change of our own transactions
+                pub trusted_pending: u64,
+                /// Unconfirmed payments from others
+                pub untrusted_pending: u64,
+                /// Coinbase outputs that are not 100 blocks deep yet
+                pub immature: u64,
+            }
+            impl ::core::fmt::Debug for Balance {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Balance")
+                        .field("confirmed", &self.confirmed)
+                        .field("trusted-pending", &self.trusted_pending)
+                        .field("untrusted-pending", &self.untrusted_pending)
+                        .field("immature", &self.immature)
+                        .finish()
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, PartialEq)]
+            pub enum Keychain {
+                /// Receive addresses handed out to payers
+                External,
+                /// Change outputs of the wallet's own transactions
+                Internal,
+            }
+            impl ::core::fmt::Debug for Keychain {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        Keychain::External => f.debug_tuple("Keychain::External").finish(),
+                        Keychain::Internal => f.debug_tuple("Keychain::Internal").finish(),
+                    }
+                }
+            }
+
+            impl Keychain {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> Keychain {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+
+                    match val {
+                        0 => Keychain::External,
+                        1 => Keychain::Internal,
+
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+
             #[derive(Clone)]
             pub struct PartialUtxo {
                 pub txid: _rt::Vec<u8>,
@@ -740,6 +1018,9 @@ pub mod component {
                 pub amount: u64,
                 pub script: _rt::Vec<u8>,
                 pub is_spent: bool,
+                /// Height of the block that mined the output, none while unconfirmed
+                pub height: Option<u32>,
+                pub is_coinbase: bool,
             }
             impl ::core::fmt::Debug for PartialUtxo {
                 fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
@@ -749,10 +1030,162 @@ pub mod component {
                         .field("amount", &self.amount)
                         .field("script", &self.script)
                         .field("is-spent", &self.is_spent)
+                        .field("height", &self.height)
+                        .field("is-coinbase", &self.is_coinbase)
                         .finish()
                 }
             }
             pub type Pubkey = _rt::Vec<u8>;
+            #[derive(Clone)]
+            pub struct Outpoint {
+                pub txid: _rt::Vec<u8>,
+                pub vout: u32,
+            }
+            impl ::core::fmt::Debug for Outpoint {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Outpoint")
+                        .field("txid", &self.txid)
+                        .field("vout", &self.vout)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub enum Error {
+                CoinSelection,
+                Psbt,
+                MissingNonWitnessUtxo,
+                NoPubkey,
+                PubkeyError,
+                InvalidDescriptor(_rt::String),
+                MissingSignature,
+                InvalidSpendingPath(u32),
+                TimelockNotReached,
+                NoRecipients,
+                /// Index of the recipient paid less than its script's dust limit
+                OutputBelowDustLimit(u32),
+                /// Outpoint that is not a confirmed, spendable output of the wallet
+                UnknownUtxo(Outpoint),
+                /// Outpoint frozen by coin control that a transaction was asked to spend
+                FrozenUtxo(Outpoint),
+                /// Txid of a transaction that does not signal replaceability
+                IrreplaceableTransaction(_rt::Vec<u8>),
+                /// Txid of a transaction that was already mined
+                TransactionConfirmed(_rt::Vec<u8>),
+                /// Txid of a transaction paying nothing to the wallet
+                NoWalletOutputs(_rt::Vec<u8>),
+                /// Lowest fee rate in sat/vB the replacement or child may pay
+                FeeRateTooLow(u64),
+                /// Lowest absolute fee the replacement may pay
+                FeeTooLow(u64),
+                /// Fee rate in sat/vB too high to be represented
+                InvalidFeeRate(u64),
+            }
+            impl ::core::fmt::Debug for Error {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        Error::CoinSelection => f.debug_tuple("Error::CoinSelection").finish(),
+                        Error::Psbt => f.debug_tuple("Error::Psbt").finish(),
+                        Error::MissingNonWitnessUtxo => {
+                            f.debug_tuple("Error::MissingNonWitnessUtxo").finish()
+                        }
+                        Error::NoPubkey => f.debug_tuple("Error::NoPubkey").finish(),
+                        Error::PubkeyError => f.debug_tuple("Error::PubkeyError").finish(),
+                        Error::InvalidDescriptor(e) => {
+                            f.debug_tuple("Error::InvalidDescriptor").field(e).finish()
+                        }
+                        Error::MissingSignature => {
+                            f.debug_tuple("Error::MissingSignature").finish()
+                        }
+                        Error::InvalidSpendingPath(e) => f
+                            .debug_tuple("Error::InvalidSpendingPath")
+                            .field(e)
+                            .finish(),
+                        Error::TimelockNotReached => {
+                            f.debug_tuple("Error::TimelockNotReached").finish()
+                        }
+                        Error::NoRecipients => f.debug_tuple("Error::NoRecipients").finish(),
+                        Error::OutputBelowDustLimit(e) => f
+                            .debug_tuple("Error::OutputBelowDustLimit")
+                            .field(e)
+                            .finish(),
+                        Error::UnknownUtxo(e) => {
+                            f.debug_tuple("Error::UnknownUtxo").field(e).finish()
+                        }
+                        Error::FrozenUtxo(e) => {
+                            f.debug_tuple("Error::FrozenUtxo").field(e).finish()
+                        }
+                        Error::IrreplaceableTransaction(e) => f
+                            .debug_tuple("Error::IrreplaceableTransaction")
+                            .field(e)
+                            .finish(),
+                        Error::TransactionConfirmed(e) => f
+                            .debug_tuple("Error::TransactionConfirmed")
+                            .field(e)
+                            .finish(),
+                        Error::NoWalletOutputs(e) => {
+                            f.debug_tuple("Error::NoWalletOutputs").field(e).finish()
+                        }
+                        Error::FeeRateTooLow(e) => {
+                            f.debug_tuple("Error::FeeRateTooLow").field(e).finish()
+                        }
+                        Error::FeeTooLow(e) => f.debug_tuple("Error::FeeTooLow").field(e).finish(),
+                        Error::InvalidFeeRate(e) => {
+                            f.debug_tuple("Error::InvalidFeeRate").field(e).finish()
+                        }
+                    }
+                }
+            }
+            impl ::core::fmt::Display for Error {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "{:?}", self)
+                }
+            }
+
+            impl std::error::Error for Error {}
+            /// Unspent wallet output as coin control sees it
+            #[derive(Clone)]
+            pub struct UnspentUtxo {
+                pub outpoint: Outpoint,
+                pub amount: u64,
+                pub script: _rt::Vec<u8>,
+                pub keychain: Keychain,
+                pub derivation_index: u32,
+                /// Height of the block that mined the output, none while unconfirmed
+                pub height: Option<u32>,
+                pub confirmations: u32,
+                pub is_coinbase: bool,
+                /// Left out of coin selection until unfrozen
+                pub is_frozen: bool,
+            }
+            impl ::core::fmt::Debug for UnspentUtxo {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("UnspentUtxo")
+                        .field("outpoint", &self.outpoint)
+                        .field("amount", &self.amount)
+                        .field("script", &self.script)
+                        .field("keychain", &self.keychain)
+                        .field("derivation-index", &self.derivation_index)
+                        .field("height", &self.height)
+                        .field("confirmations", &self.confirmations)
+                        .field("is-coinbase", &self.is_coinbase)
+                        .field("is-frozen", &self.is_frozen)
+                        .finish()
+                }
+            }
+            /// Output of a transaction paying several recipients
+            #[derive(Clone)]
+            pub struct Recipient {
+                pub script: _rt::Vec<u8>,
+                pub amount: u64,
+            }
+            impl ::core::fmt::Debug for Recipient {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Recipient")
+                        .field("script", &self.script)
+                        .field("amount", &self.amount)
+                        .finish()
+                }
+            }
 
             #[derive(Debug)]
             #[repr(transparent)]
@@ -800,108 +1233,576 @@ pub mod component {
 
             impl WatchOnly {
                 #[allow(unused_unsafe, clippy::all)]
+                /// Traps on a config whose keys, descriptors or policy do not parse, `create` reports
+                /// them instead
                 pub fn new(init: &Initialization) -> Self {
                     unsafe {
-                        let (
-                            result5_0,
-                            result5_1,
-                            result5_2,
-                            result5_3,
-                            result5_4,
-                            result5_5,
-                            result5_6,
-                            result5_7,
-                        ) = match init {
+                        let mut cleanup_list = _rt::Vec::new();
+                        #[repr(align(4))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 64]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 64]);
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        match init {
                             Initialization::OldState(e) => {
-                                let vec0 = e;
-                                let ptr0 = vec0.as_ptr().cast::<u8>();
-                                let len0 = vec0.len();
-
-                                (
-                                    0i32,
-                                    ptr0.cast_mut(),
-                                    len0,
-                                    ::core::ptr::null_mut(),
-                                    0usize,
-                                    ::core::ptr::null_mut(),
-                                    0usize,
-                                    0i32,
-                                )
+                                *ptr0.add(0).cast::<u8>() = (0i32) as u8;
+                                let vec1 = e;
+                                let ptr1 = vec1.as_ptr().cast::<u8>();
+                                let len1 = vec1.len();
+                                *ptr0.add(8).cast::<usize>() = len1;
+                                *ptr0.add(4).cast::<*mut u8>() = ptr1.cast_mut();
                             }
                             Initialization::Config(e) => {
+                                *ptr0.add(0).cast::<u8>() = (1i32) as u8;
                                 let Config {
-                                    xpub: xpub1,
-                                    account_derivation: account_derivation1,
-                                    master_fingerprint: master_fingerprint1,
-                                    network: network1,
+                                    keys: keys2,
+                                    network: network2,
+                                    lookahead: lookahead2,
+                                    change_lookahead: change_lookahead2,
+                                    min_confirmations: min_confirmations2,
                                 } = e;
-                                let vec2 = xpub1;
-                                let ptr2 = vec2.as_ptr().cast::<u8>();
-                                let len2 = vec2.len();
-                                let vec3 = account_derivation1;
-                                let ptr3 = vec3.as_ptr().cast::<u8>();
-                                let len3 = vec3.len();
-                                let vec4 = master_fingerprint1;
-                                let ptr4 = vec4.as_ptr().cast::<u8>();
-                                let len4 = vec4.len();
-
-                                (
-                                    1i32,
-                                    ptr2.cast_mut(),
-                                    len2,
-                                    ptr3.cast_mut(),
-                                    len3,
-                                    ptr4.cast_mut(),
-                                    len4,
-                                    network1.clone() as i32,
-                                )
+                                match keys2 {
+                                    Keys::Account(e) => {
+                                        *ptr0.add(4).cast::<u8>() = (0i32) as u8;
+                                        let Account {
+                                            xpub: xpub3,
+                                            account_derivation: account_derivation3,
+                                            master_fingerprint: master_fingerprint3,
+                                            wallet_type: wallet_type3,
+                                        } = e;
+                                        let vec4 = xpub3;
+                                        let ptr4 = vec4.as_ptr().cast::<u8>();
+                                        let len4 = vec4.len();
+                                        *ptr0.add(12).cast::<usize>() = len4;
+                                        *ptr0.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                                        let vec5 = account_derivation3;
+                                        let ptr5 = vec5.as_ptr().cast::<u8>();
+                                        let len5 = vec5.len();
+                                        *ptr0.add(20).cast::<usize>() = len5;
+                                        *ptr0.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                        let vec6 = master_fingerprint3;
+                                        let ptr6 = vec6.as_ptr().cast::<u8>();
+                                        let len6 = vec6.len();
+                                        *ptr0.add(28).cast::<usize>() = len6;
+                                        *ptr0.add(24).cast::<*mut u8>() = ptr6.cast_mut();
+                                        match wallet_type3 {
+                                            Some(e) => {
+                                                *ptr0.add(32).cast::<u8>() = (1i32) as u8;
+                                                *ptr0.add(33).cast::<u8>() =
+                                                    (e.clone() as i32) as u8;
+                                            }
+                                            None => {
+                                                *ptr0.add(32).cast::<u8>() = (0i32) as u8;
+                                            }
+                                        };
+                                    }
+                                    Keys::Descriptors(e) => {
+                                        *ptr0.add(4).cast::<u8>() = (1i32) as u8;
+                                        let Descriptors {
+                                            external: external7,
+                                            internal: internal7,
+                                        } = e;
+                                        let vec8 = external7;
+                                        let ptr8 = vec8.as_ptr().cast::<u8>();
+                                        let len8 = vec8.len();
+                                        *ptr0.add(12).cast::<usize>() = len8;
+                                        *ptr0.add(8).cast::<*mut u8>() = ptr8.cast_mut();
+                                        match internal7 {
+                                            Some(e) => {
+                                                *ptr0.add(16).cast::<u8>() = (1i32) as u8;
+                                                let vec9 = e;
+                                                let ptr9 = vec9.as_ptr().cast::<u8>();
+                                                let len9 = vec9.len();
+                                                *ptr0.add(24).cast::<usize>() = len9;
+                                                *ptr0.add(20).cast::<*mut u8>() = ptr9.cast_mut();
+                                            }
+                                            None => {
+                                                *ptr0.add(16).cast::<u8>() = (0i32) as u8;
+                                            }
+                                        };
+                                    }
+                                    Keys::Multisig(e) => {
+                                        *ptr0.add(4).cast::<u8>() = (2i32) as u8;
+                                        let Multisig {
+                                            threshold: threshold10,
+                                            cosigners: cosigners10,
+                                            multisig_type: multisig_type10,
+                                        } = e;
+                                        *ptr0.add(8).cast::<i32>() = _rt::as_i32(threshold10);
+                                        let vec15 = cosigners10;
+                                        let len15 = vec15.len();
+                                        let layout15 =
+                                            _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec15.len() * 24,
+                                                4,
+                                            );
+                                        let result15 = if layout15.size() != 0 {
+                                            let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
+                                            if ptr.is_null() {
+                                                _rt::alloc::handle_alloc_error(layout15);
+                                            }
+                                            ptr
+                                        } else {
+                                            {
+                                                ::core::ptr::null_mut()
+                                            }
+                                        };
+                                        for (i, e) in vec15.into_iter().enumerate() {
+                                            let base = result15.add(i * 24);
+                                            {
+                                                let Cosigner {
+                                                    xpub: xpub11,
+                                                    account_derivation: account_derivation11,
+                                                    master_fingerprint: master_fingerprint11,
+                                                } = e;
+                                                let vec12 = xpub11;
+                                                let ptr12 = vec12.as_ptr().cast::<u8>();
+                                                let len12 = vec12.len();
+                                                *base.add(4).cast::<usize>() = len12;
+                                                *base.add(0).cast::<*mut u8>() = ptr12.cast_mut();
+                                                let vec13 = account_derivation11;
+                                                let ptr13 = vec13.as_ptr().cast::<u8>();
+                                                let len13 = vec13.len();
+                                                *base.add(12).cast::<usize>() = len13;
+                                                *base.add(8).cast::<*mut u8>() = ptr13.cast_mut();
+                                                let vec14 = master_fingerprint11;
+                                                let ptr14 = vec14.as_ptr().cast::<u8>();
+                                                let len14 = vec14.len();
+                                                *base.add(20).cast::<usize>() = len14;
+                                                *base.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                            }
+                                        }
+                                        *ptr0.add(16).cast::<usize>() = len15;
+                                        *ptr0.add(12).cast::<*mut u8>() = result15;
+                                        *ptr0.add(20).cast::<u8>() =
+                                            (multisig_type10.clone() as i32) as u8;
+                                        cleanup_list.extend_from_slice(&[(result15, layout15)]);
+                                    }
+                                    Keys::Policy(e) => {
+                                        *ptr0.add(4).cast::<u8>() = (3i32) as u8;
+                                        let vec16 = e;
+                                        let ptr16 = vec16.as_ptr().cast::<u8>();
+                                        let len16 = vec16.len();
+                                        *ptr0.add(12).cast::<usize>() = len16;
+                                        *ptr0.add(8).cast::<*mut u8>() = ptr16.cast_mut();
+                                    }
+                                }
+                                *ptr0.add(36).cast::<u8>() = (network2.clone() as i32) as u8;
+                                match lookahead2 {
+                                    Some(e) => {
+                                        *ptr0.add(40).cast::<u8>() = (1i32) as u8;
+                                        *ptr0.add(44).cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    None => {
+                                        *ptr0.add(40).cast::<u8>() = (0i32) as u8;
+                                    }
+                                };
+                                match change_lookahead2 {
+                                    Some(e) => {
+                                        *ptr0.add(48).cast::<u8>() = (1i32) as u8;
+                                        *ptr0.add(52).cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    None => {
+                                        *ptr0.add(48).cast::<u8>() = (0i32) as u8;
+                                    }
+                                };
+                                match min_confirmations2 {
+                                    Some(e) => {
+                                        *ptr0.add(56).cast::<u8>() = (1i32) as u8;
+                                        *ptr0.add(60).cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    None => {
+                                        *ptr0.add(56).cast::<u8>() = (0i32) as u8;
+                                    }
+                                };
                             }
-                        };
+                        }
 
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "component:wallet/types@0.1.0")]
                         extern "C" {
                             #[link_name = "[constructor]watch-only"]
-                            fn wit_import(
-                                _: i32,
-                                _: *mut u8,
-                                _: usize,
-                                _: *mut u8,
-                                _: usize,
-                                _: *mut u8,
-                                _: usize,
-                                _: i32,
-                            ) -> i32;
+                            fn wit_import(_: *mut u8) -> i32;
                         }
 
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(
-                            _: i32,
-                            _: *mut u8,
-                            _: usize,
-                            _: *mut u8,
-                            _: usize,
-                            _: *mut u8,
-                            _: usize,
-                            _: i32,
-                        ) -> i32 {
+                        fn wit_import(_: *mut u8) -> i32 {
                             unreachable!()
                         }
-                        let ret = wit_import(
-                            result5_0, result5_1, result5_2, result5_3, result5_4, result5_5,
-                            result5_6, result5_7,
-                        );
+                        let ret = wit_import(ptr0);
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
                         WatchOnly::from_handle(ret as u32)
                     }
                 }
             }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Fails with invalid-descriptor when an xpub, derivation, fingerprint, descriptor or
+                /// policy of the config does not parse
+                pub fn create(init: &Initialization) -> Result<WatchOnly, Error> {
+                    unsafe {
+                        let mut cleanup_list = _rt::Vec::new();
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 64]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 64]);
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        match init {
+                            Initialization::OldState(e) => {
+                                *ptr0.add(0).cast::<u8>() = (0i32) as u8;
+                                let vec1 = e;
+                                let ptr1 = vec1.as_ptr().cast::<u8>();
+                                let len1 = vec1.len();
+                                *ptr0.add(8).cast::<usize>() = len1;
+                                *ptr0.add(4).cast::<*mut u8>() = ptr1.cast_mut();
+                            }
+                            Initialization::Config(e) => {
+                                *ptr0.add(0).cast::<u8>() = (1i32) as u8;
+                                let Config {
+                                    keys: keys2,
+                                    network: network2,
+                                    lookahead: lookahead2,
+                                    change_lookahead: change_lookahead2,
+                                    min_confirmations: min_confirmations2,
+                                } = e;
+                                match keys2 {
+                                    Keys::Account(e) => {
+                                        *ptr0.add(4).cast::<u8>() = (0i32) as u8;
+                                        let Account {
+                                            xpub: xpub3,
+                                            account_derivation: account_derivation3,
+                                            master_fingerprint: master_fingerprint3,
+                                            wallet_type: wallet_type3,
+                                        } = e;
+                                        let vec4 = xpub3;
+                                        let ptr4 = vec4.as_ptr().cast::<u8>();
+                                        let len4 = vec4.len();
+                                        *ptr0.add(12).cast::<usize>() = len4;
+                                        *ptr0.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                                        let vec5 = account_derivation3;
+                                        let ptr5 = vec5.as_ptr().cast::<u8>();
+                                        let len5 = vec5.len();
+                                        *ptr0.add(20).cast::<usize>() = len5;
+                                        *ptr0.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                        let vec6 = master_fingerprint3;
+                                        let ptr6 = vec6.as_ptr().cast::<u8>();
+                                        let len6 = vec6.len();
+                                        *ptr0.add(28).cast::<usize>() = len6;
+                                        *ptr0.add(24).cast::<*mut u8>() = ptr6.cast_mut();
+                                        match wallet_type3 {
+                                            Some(e) => {
+                                                *ptr0.add(32).cast::<u8>() = (1i32) as u8;
+                                                *ptr0.add(33).cast::<u8>() =
+                                                    (e.clone() as i32) as u8;
+                                            }
+                                            None => {
+                                                *ptr0.add(32).cast::<u8>() = (0i32) as u8;
+                                            }
+                                        };
+                                    }
+                                    Keys::Descriptors(e) => {
+                                        *ptr0.add(4).cast::<u8>() = (1i32) as u8;
+                                        let Descriptors {
+                                            external: external7,
+                                            internal: internal7,
+                                        } = e;
+                                        let vec8 = external7;
+                                        let ptr8 = vec8.as_ptr().cast::<u8>();
+                                        let len8 = vec8.len();
+                                        *ptr0.add(12).cast::<usize>() = len8;
+                                        *ptr0.add(8).cast::<*mut u8>() = ptr8.cast_mut();
+                                        match internal7 {
+                                            Some(e) => {
+                                                *ptr0.add(16).cast::<u8>() = (1i32) as u8;
+                                                let vec9 = e;
+                                                let ptr9 = vec9.as_ptr().cast::<u8>();
+                                                let len9 = vec9.len();
+                                                *ptr0.add(24).cast::<usize>() = len9;
+                                                *ptr0.add(20).cast::<*mut u8>() = ptr9.cast_mut();
+                                            }
+                                            None => {
+                                                *ptr0.add(16).cast::<u8>() = (0i32) as u8;
+                                            }
+                                        };
+                                    }
+                                    Keys::Multisig(e) => {
+                                        *ptr0.add(4).cast::<u8>() = (2i32) as u8;
+                                        let Multisig {
+                                            threshold: threshold10,
+                                            cosigners: cosigners10,
+                                            multisig_type: multisig_type10,
+                                        } = e;
+                                        *ptr0.add(8).cast::<i32>() = _rt::as_i32(threshold10);
+                                        let vec15 = cosigners10;
+                                        let len15 = vec15.len();
+                                        let layout15 =
+                                            _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec15.len() * 24,
+                                                4,
+                                            );
+                                        let result15 = if layout15.size() != 0 {
+                                            let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
+                                            if ptr.is_null() {
+                                                _rt::alloc::handle_alloc_error(layout15);
+                                            }
+                                            ptr
+                                        } else {
+                                            {
+                                                ::core::ptr::null_mut()
+                                            }
+                                        };
+                                        for (i, e) in vec15.into_iter().enumerate() {
+                                            let base = result15.add(i * 24);
+                                            {
+                                                let Cosigner {
+                                                    xpub: xpub11,
+                                                    account_derivation: account_derivation11,
+                                                    master_fingerprint: master_fingerprint11,
+                                                } = e;
+                                                let vec12 = xpub11;
+                                                let ptr12 = vec12.as_ptr().cast::<u8>();
+                                                let len12 = vec12.len();
+                                                *base.add(4).cast::<usize>() = len12;
+                                                *base.add(0).cast::<*mut u8>() = ptr12.cast_mut();
+                                                let vec13 = account_derivation11;
+                                                let ptr13 = vec13.as_ptr().cast::<u8>();
+                                                let len13 = vec13.len();
+                                                *base.add(12).cast::<usize>() = len13;
+                                                *base.add(8).cast::<*mut u8>() = ptr13.cast_mut();
+                                                let vec14 = master_fingerprint11;
+                                                let ptr14 = vec14.as_ptr().cast::<u8>();
+                                                let len14 = vec14.len();
+                                                *base.add(20).cast::<usize>() = len14;
+                                                *base.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                            }
+                                        }
+                                        *ptr0.add(16).cast::<usize>() = len15;
+                                        *ptr0.add(12).cast::<*mut u8>() = result15;
+                                        *ptr0.add(20).cast::<u8>() =
+                                            (multisig_type10.clone() as i32) as u8;
+                                        cleanup_list.extend_from_slice(&[(result15, layout15)]);
+                                    }
+                                    Keys::Policy(e) => {
+                                        *ptr0.add(4).cast::<u8>() = (3i32) as u8;
+                                        let vec16 = e;
+                                        let ptr16 = vec16.as_ptr().cast::<u8>();
+                                        let len16 = vec16.len();
+                                        *ptr0.add(12).cast::<usize>() = len16;
+                                        *ptr0.add(8).cast::<*mut u8>() = ptr16.cast_mut();
+                                    }
+                                }
+                                *ptr0.add(36).cast::<u8>() = (network2.clone() as i32) as u8;
+                                match lookahead2 {
+                                    Some(e) => {
+                                        *ptr0.add(40).cast::<u8>() = (1i32) as u8;
+                                        *ptr0.add(44).cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    None => {
+                                        *ptr0.add(40).cast::<u8>() = (0i32) as u8;
+                                    }
+                                };
+                                match change_lookahead2 {
+                                    Some(e) => {
+                                        *ptr0.add(48).cast::<u8>() = (1i32) as u8;
+                                        *ptr0.add(52).cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    None => {
+                                        *ptr0.add(48).cast::<u8>() = (0i32) as u8;
+                                    }
+                                };
+                                match min_confirmations2 {
+                                    Some(e) => {
+                                        *ptr0.add(56).cast::<u8>() = (1i32) as u8;
+                                        *ptr0.add(60).cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    None => {
+                                        *ptr0.add(56).cast::<u8>() = (0i32) as u8;
+                                    }
+                                };
+                            }
+                        }
+                        let ptr17 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[static]watch-only.create"]
+                            fn wit_import(_: *mut u8, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: *mut u8, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import(ptr0, ptr17);
+                        let l18 = i32::from(*ptr17.add(0).cast::<u8>());
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
+                        match l18 {
+                            0 => {
+                                let e = {
+                                    let l19 = *ptr17.add(8).cast::<i32>();
+
+                                    WatchOnly::from_handle(l19 as u32)
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l20 = i32::from(*ptr17.add(8).cast::<u8>());
+                                    let v46 = match l20 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e46 = {
+                                                let l21 = *ptr17.add(16).cast::<*mut u8>();
+                                                let l22 = *ptr17.add(20).cast::<usize>();
+                                                let len23 = l22;
+                                                let bytes23 = _rt::Vec::from_raw_parts(
+                                                    l21.cast(),
+                                                    len23,
+                                                    len23,
+                                                );
+
+                                                _rt::string_lift(bytes23)
+                                            };
+                                            Error::InvalidDescriptor(e46)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e46 = {
+                                                let l24 = *ptr17.add(16).cast::<i32>();
+
+                                                l24 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e46)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e46 = {
+                                                let l25 = *ptr17.add(16).cast::<i32>();
+
+                                                l25 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e46)
+                                        }
+                                        11 => {
+                                            let e46 = {
+                                                let l26 = *ptr17.add(16).cast::<*mut u8>();
+                                                let l27 = *ptr17.add(20).cast::<usize>();
+                                                let len28 = l27;
+                                                let l29 = *ptr17.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l26.cast(),
+                                                        len28,
+                                                        len28,
+                                                    ),
+                                                    vout: l29 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e46)
+                                        }
+                                        12 => {
+                                            let e46 = {
+                                                let l30 = *ptr17.add(16).cast::<*mut u8>();
+                                                let l31 = *ptr17.add(20).cast::<usize>();
+                                                let len32 = l31;
+                                                let l33 = *ptr17.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l30.cast(),
+                                                        len32,
+                                                        len32,
+                                                    ),
+                                                    vout: l33 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e46)
+                                        }
+                                        13 => {
+                                            let e46 = {
+                                                let l34 = *ptr17.add(16).cast::<*mut u8>();
+                                                let l35 = *ptr17.add(20).cast::<usize>();
+                                                let len36 = l35;
+
+                                                _rt::Vec::from_raw_parts(l34.cast(), len36, len36)
+                                            };
+                                            Error::IrreplaceableTransaction(e46)
+                                        }
+                                        14 => {
+                                            let e46 = {
+                                                let l37 = *ptr17.add(16).cast::<*mut u8>();
+                                                let l38 = *ptr17.add(20).cast::<usize>();
+                                                let len39 = l38;
+
+                                                _rt::Vec::from_raw_parts(l37.cast(), len39, len39)
+                                            };
+                                            Error::TransactionConfirmed(e46)
+                                        }
+                                        15 => {
+                                            let e46 = {
+                                                let l40 = *ptr17.add(16).cast::<*mut u8>();
+                                                let l41 = *ptr17.add(20).cast::<usize>();
+                                                let len42 = l41;
+
+                                                _rt::Vec::from_raw_parts(l40.cast(), len42, len42)
+                                            };
+                                            Error::NoWalletOutputs(e46)
+                                        }
+                                        16 => {
+                                            let e46 = {
+                                                let l43 = *ptr17.add(16).cast::<i64>();
+
+                                                l43 as u64
+                                            };
+                                            Error::FeeRateTooLow(e46)
+                                        }
+                                        17 => {
+                                            let e46 = {
+                                                let l44 = *ptr17.add(16).cast::<i64>();
+
+                                                l44 as u64
+                                            };
+                                            Error::FeeTooLow(e46)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e46 = {
+                                                let l45 = *ptr17.add(16).cast::<i64>();
+
+                                                l45 as u64
+                                            };
+                                            Error::InvalidFeeRate(e46)
+                                        }
+                                    };
+
+                                    v46
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
             impl WatchOnly {
                 #[allow(unused_unsafe, clippy::all)]
                 pub fn new_address(&self) -> Result<_rt::String, Error> {
                     unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
                         let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "component:wallet/types@0.1.0")]
@@ -919,8 +1820,8 @@ pub mod component {
                         match l1 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(4).cast::<*mut u8>();
-                                    let l3 = *ptr0.add(8).cast::<usize>();
+                                    let l2 = *ptr0.add(8).cast::<*mut u8>();
+                                    let l3 = *ptr0.add(12).cast::<usize>();
                                     let len4 = l3;
                                     let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
 
@@ -930,19 +1831,138 @@ pub mod component {
                             }
                             1 => {
                                 let e = {
-                                    let l5 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    let v6 = match l5 {
+                                    let l5 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v31 = match l5 {
                                         0 => Error::CoinSelection,
                                         1 => Error::Psbt,
                                         2 => Error::MissingNonWitnessUtxo,
                                         3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e31 = {
+                                                let l6 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l7 = *ptr0.add(20).cast::<usize>();
+                                                let len8 = l7;
+                                                let bytes8 =
+                                                    _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
+
+                                                _rt::string_lift(bytes8)
+                                            };
+                                            Error::InvalidDescriptor(e31)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e31 = {
+                                                let l9 = *ptr0.add(16).cast::<i32>();
+
+                                                l9 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e31)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e31 = {
+                                                let l10 = *ptr0.add(16).cast::<i32>();
+
+                                                l10 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e31)
+                                        }
+                                        11 => {
+                                            let e31 = {
+                                                let l11 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l12 = *ptr0.add(20).cast::<usize>();
+                                                let len13 = l12;
+                                                let l14 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l11.cast(),
+                                                        len13,
+                                                        len13,
+                                                    ),
+                                                    vout: l14 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e31)
+                                        }
+                                        12 => {
+                                            let e31 = {
+                                                let l15 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l16 = *ptr0.add(20).cast::<usize>();
+                                                let len17 = l16;
+                                                let l18 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l15.cast(),
+                                                        len17,
+                                                        len17,
+                                                    ),
+                                                    vout: l18 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e31)
+                                        }
+                                        13 => {
+                                            let e31 = {
+                                                let l19 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l20 = *ptr0.add(20).cast::<usize>();
+                                                let len21 = l20;
+
+                                                _rt::Vec::from_raw_parts(l19.cast(), len21, len21)
+                                            };
+                                            Error::IrreplaceableTransaction(e31)
+                                        }
+                                        14 => {
+                                            let e31 = {
+                                                let l22 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l23 = *ptr0.add(20).cast::<usize>();
+                                                let len24 = l23;
+
+                                                _rt::Vec::from_raw_parts(l22.cast(), len24, len24)
+                                            };
+                                            Error::TransactionConfirmed(e31)
+                                        }
+                                        15 => {
+                                            let e31 = {
+                                                let l25 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l26 = *ptr0.add(20).cast::<usize>();
+                                                let len27 = l26;
+
+                                                _rt::Vec::from_raw_parts(l25.cast(), len27, len27)
+                                            };
+                                            Error::NoWalletOutputs(e31)
+                                        }
+                                        16 => {
+                                            let e31 = {
+                                                let l28 = *ptr0.add(16).cast::<i64>();
+
+                                                l28 as u64
+                                            };
+                                            Error::FeeRateTooLow(e31)
+                                        }
+                                        17 => {
+                                            let e31 = {
+                                                let l29 = *ptr0.add(16).cast::<i64>();
+
+                                                l29 as u64
+                                            };
+                                            Error::FeeTooLow(e31)
+                                        }
                                         n => {
-                                            debug_assert_eq!(n, 4, "invalid enum discriminant");
-                                            Error::PubkeyError
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e31 = {
+                                                let l30 = *ptr0.add(16).cast::<i64>();
+
+                                                l30 as u64
+                                            };
+                                            Error::InvalidFeeRate(e31)
                                         }
                                     };
 
-                                    v6
+                                    v31
                                 };
                                 Err(e)
                             }
@@ -953,29 +1973,54 @@ pub mod component {
             }
             impl WatchOnly {
                 #[allow(unused_unsafe, clippy::all)]
+                /// Spends through the primary key of a policy unless `spending-path` picks its nth
+                /// recovery path, setting the sequence or lock time that path needs
                 pub fn create_transaction(
                     &self,
                     recepient: &[u8],
                     amount: u64,
                     fee_rate: u64,
+                    spending_path: Option<u32>,
                 ) -> Result<_rt::Vec<u8>, Error> {
                     unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
                         let vec0 = recepient;
                         let ptr0 = vec0.as_ptr().cast::<u8>();
                         let len0 = vec0.len();
-                        let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        let (result1_0, result1_1) = match spending_path {
+                            Some(e) => (1i32, _rt::as_i32(e)),
+                            None => (0i32, 0i32),
+                        };
+                        let ptr2 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "component:wallet/types@0.1.0")]
                         extern "C" {
                             #[link_name = "[method]watch-only.create-transaction"]
-                            fn wit_import(_: i32, _: *mut u8, _: usize, _: i64, _: i64, _: *mut u8);
+                            fn wit_import(
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: i64,
+                                _: i64,
+                                _: i32,
+                                _: i32,
+                                _: *mut u8,
+                            );
                         }
 
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8, _: usize, _: i64, _: i64, _: *mut u8) {
+                        fn wit_import(
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: i64,
+                            _: i64,
+                            _: i32,
+                            _: i32,
+                            _: *mut u8,
+                        ) {
                             unreachable!()
                         }
                         wit_import(
@@ -984,35 +2029,159 @@ pub mod component {
                             len0,
                             _rt::as_i64(&amount),
                             _rt::as_i64(&fee_rate),
-                            ptr1,
+                            result1_0,
+                            result1_1,
+                            ptr2,
                         );
-                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
-                        match l2 {
+                        let l3 = i32::from(*ptr2.add(0).cast::<u8>());
+                        match l3 {
                             0 => {
                                 let e = {
-                                    let l3 = *ptr1.add(4).cast::<*mut u8>();
-                                    let l4 = *ptr1.add(8).cast::<usize>();
-                                    let len5 = l4;
+                                    let l4 = *ptr2.add(8).cast::<*mut u8>();
+                                    let l5 = *ptr2.add(12).cast::<usize>();
+                                    let len6 = l5;
 
-                                    _rt::Vec::from_raw_parts(l3.cast(), len5, len5)
+                                    _rt::Vec::from_raw_parts(l4.cast(), len6, len6)
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l6 = i32::from(*ptr1.add(4).cast::<u8>());
-                                    let v7 = match l6 {
+                                    let l7 = i32::from(*ptr2.add(8).cast::<u8>());
+                                    let v33 = match l7 {
                                         0 => Error::CoinSelection,
                                         1 => Error::Psbt,
                                         2 => Error::MissingNonWitnessUtxo,
                                         3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e33 = {
+                                                let l8 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l9 = *ptr2.add(20).cast::<usize>();
+                                                let len10 = l9;
+                                                let bytes10 = _rt::Vec::from_raw_parts(
+                                                    l8.cast(),
+                                                    len10,
+                                                    len10,
+                                                );
+
+                                                _rt::string_lift(bytes10)
+                                            };
+                                            Error::InvalidDescriptor(e33)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e33 = {
+                                                let l11 = *ptr2.add(16).cast::<i32>();
+
+                                                l11 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e33)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e33 = {
+                                                let l12 = *ptr2.add(16).cast::<i32>();
+
+                                                l12 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e33)
+                                        }
+                                        11 => {
+                                            let e33 = {
+                                                let l13 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l14 = *ptr2.add(20).cast::<usize>();
+                                                let len15 = l14;
+                                                let l16 = *ptr2.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l13.cast(),
+                                                        len15,
+                                                        len15,
+                                                    ),
+                                                    vout: l16 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e33)
+                                        }
+                                        12 => {
+                                            let e33 = {
+                                                let l17 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l18 = *ptr2.add(20).cast::<usize>();
+                                                let len19 = l18;
+                                                let l20 = *ptr2.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l17.cast(),
+                                                        len19,
+                                                        len19,
+                                                    ),
+                                                    vout: l20 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e33)
+                                        }
+                                        13 => {
+                                            let e33 = {
+                                                let l21 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l22 = *ptr2.add(20).cast::<usize>();
+                                                let len23 = l22;
+
+                                                _rt::Vec::from_raw_parts(l21.cast(), len23, len23)
+                                            };
+                                            Error::IrreplaceableTransaction(e33)
+                                        }
+                                        14 => {
+                                            let e33 = {
+                                                let l24 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l25 = *ptr2.add(20).cast::<usize>();
+                                                let len26 = l25;
+
+                                                _rt::Vec::from_raw_parts(l24.cast(), len26, len26)
+                                            };
+                                            Error::TransactionConfirmed(e33)
+                                        }
+                                        15 => {
+                                            let e33 = {
+                                                let l27 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l28 = *ptr2.add(20).cast::<usize>();
+                                                let len29 = l28;
+
+                                                _rt::Vec::from_raw_parts(l27.cast(), len29, len29)
+                                            };
+                                            Error::NoWalletOutputs(e33)
+                                        }
+                                        16 => {
+                                            let e33 = {
+                                                let l30 = *ptr2.add(16).cast::<i64>();
+
+                                                l30 as u64
+                                            };
+                                            Error::FeeRateTooLow(e33)
+                                        }
+                                        17 => {
+                                            let e33 = {
+                                                let l31 = *ptr2.add(16).cast::<i64>();
+
+                                                l31 as u64
+                                            };
+                                            Error::FeeTooLow(e33)
+                                        }
                                         n => {
-                                            debug_assert_eq!(n, 4, "invalid enum discriminant");
-                                            Error::PubkeyError
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e33 = {
+                                                let l32 = *ptr2.add(16).cast::<i64>();
+
+                                                l32 as u64
+                                            };
+                                            Error::InvalidFeeRate(e33)
                                         }
                                     };
 
-                                    v7
+                                    v33
                                 };
                                 Err(e)
                             }
@@ -1023,108 +2192,27 @@ pub mod component {
             }
             impl WatchOnly {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn get_utxos(&self) -> Result<_rt::Vec<PartialUtxo>, Error> {
-                    unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
-                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
-                        #[cfg(target_arch = "wasm32")]
-                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
-                        extern "C" {
-                            #[link_name = "[method]watch-only.get-utxos"]
-                            fn wit_import(_: i32, _: *mut u8);
-                        }
-
-                        #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
-                            unreachable!()
-                        }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
-                            0 => {
-                                let e = {
-                                    let l2 = *ptr0.add(4).cast::<*mut u8>();
-                                    let l3 = *ptr0.add(8).cast::<usize>();
-                                    let base13 = l2;
-                                    let len13 = l3;
-                                    let mut result13 = _rt::Vec::with_capacity(len13);
-                                    for i in 0..len13 {
-                                        let base = base13.add(i * 40);
-                                        let e13 = {
-                                            let l4 = *base.add(0).cast::<*mut u8>();
-                                            let l5 = *base.add(4).cast::<usize>();
-                                            let len6 = l5;
-                                            let l7 = *base.add(8).cast::<i32>();
-                                            let l8 = *base.add(16).cast::<i64>();
-                                            let l9 = *base.add(24).cast::<*mut u8>();
-                                            let l10 = *base.add(28).cast::<usize>();
-                                            let len11 = l10;
-                                            let l12 = i32::from(*base.add(32).cast::<u8>());
-
-                                            PartialUtxo {
-                                                txid: _rt::Vec::from_raw_parts(
-                                                    l4.cast(),
-                                                    len6,
-                                                    len6,
-                                                ),
-                                                vout: l7 as u32,
-                                                amount: l8 as u64,
-                                                script: _rt::Vec::from_raw_parts(
-                                                    l9.cast(),
-                                                    len11,
-                                                    len11,
-                                                ),
-                                                is_spent: _rt::bool_lift(l12 as u8),
-                                            }
-                                        };
-                                        result13.push(e13);
-                                    }
-                                    _rt::cabi_dealloc(base13, len13 * 40, 8);
-
-                                    result13
-                                };
-                                Ok(e)
-                            }
-                            1 => {
-                                let e = {
-                                    let l14 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    let v15 = match l14 {
-                                        0 => Error::CoinSelection,
-                                        1 => Error::Psbt,
-                                        2 => Error::MissingNonWitnessUtxo,
-                                        3 => Error::NoPubkey,
-                                        n => {
-                                            debug_assert_eq!(n, 4, "invalid enum discriminant");
-                                            Error::PubkeyError
-                                        }
-                                    };
-
-                                    v15
-                                };
-                                Err(e)
-                            }
-                            _ => _rt::invalid_enum_discriminant(),
-                        }
-                    }
-                }
-            }
-            impl WatchOnly {
-                #[allow(unused_unsafe, clippy::all)]
-                pub fn insert_utxos(&self, utxos: &[PartialUtxo]) -> Result<(), Error> {
+                /// Pays every recipient from one transaction, with a single change output. Every
+                /// `must-spend` output is an input whether coin selection needs it or not.
+                pub fn create_batch_transaction(
+                    &self,
+                    recipients: &[Recipient],
+                    fee_rate: u64,
+                    must_spend: &[Outpoint],
+                    spending_path: Option<u32>,
+                ) -> Result<_rt::Vec<u8>, Error> {
                     unsafe {
-                        #[repr(align(1))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
-                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
-                        let vec3 = utxos;
-                        let len3 = vec3.len();
-                        let layout3 =
-                            _rt::alloc::Layout::from_size_align_unchecked(vec3.len() * 40, 8);
-                        let result3 = if layout3.size() != 0 {
-                            let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let vec2 = recipients;
+                        let len2 = vec2.len();
+                        let layout2 =
+                            _rt::alloc::Layout::from_size_align_unchecked(vec2.len() * 16, 8);
+                        let result2 = if layout2.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout2).cast::<u8>();
                             if ptr.is_null() {
-                                _rt::alloc::handle_alloc_error(layout3);
+                                _rt::alloc::handle_alloc_error(layout2);
                             }
                             ptr
                         } else {
@@ -1132,72 +2220,253 @@ pub mod component {
                                 ::core::ptr::null_mut()
                             }
                         };
-                        for (i, e) in vec3.into_iter().enumerate() {
-                            let base = result3.add(i * 40);
+                        for (i, e) in vec2.into_iter().enumerate() {
+                            let base = result2.add(i * 16);
                             {
-                                let PartialUtxo {
-                                    txid: txid0,
-                                    vout: vout0,
-                                    amount: amount0,
+                                let Recipient {
                                     script: script0,
-                                    is_spent: is_spent0,
+                                    amount: amount0,
                                 } = e;
-                                let vec1 = txid0;
+                                let vec1 = script0;
                                 let ptr1 = vec1.as_ptr().cast::<u8>();
                                 let len1 = vec1.len();
                                 *base.add(4).cast::<usize>() = len1;
                                 *base.add(0).cast::<*mut u8>() = ptr1.cast_mut();
-                                *base.add(8).cast::<i32>() = _rt::as_i32(vout0);
-                                *base.add(16).cast::<i64>() = _rt::as_i64(amount0);
-                                let vec2 = script0;
-                                let ptr2 = vec2.as_ptr().cast::<u8>();
-                                let len2 = vec2.len();
-                                *base.add(28).cast::<usize>() = len2;
-                                *base.add(24).cast::<*mut u8>() = ptr2.cast_mut();
-                                *base.add(32).cast::<u8>() = (match is_spent0 {
-                                    true => 1,
-                                    false => 0,
-                                })
-                                    as u8;
+                                *base.add(8).cast::<i64>() = _rt::as_i64(amount0);
                             }
                         }
-                        let ptr4 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        let vec5 = must_spend;
+                        let len5 = vec5.len();
+                        let layout5 =
+                            _rt::alloc::Layout::from_size_align_unchecked(vec5.len() * 12, 4);
+                        let result5 = if layout5.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout5);
+                            }
+                            ptr
+                        } else {
+                            {
+                                ::core::ptr::null_mut()
+                            }
+                        };
+                        for (i, e) in vec5.into_iter().enumerate() {
+                            let base = result5.add(i * 12);
+                            {
+                                let Outpoint {
+                                    txid: txid3,
+                                    vout: vout3,
+                                } = e;
+                                let vec4 = txid3;
+                                let ptr4 = vec4.as_ptr().cast::<u8>();
+                                let len4 = vec4.len();
+                                *base.add(4).cast::<usize>() = len4;
+                                *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                                *base.add(8).cast::<i32>() = _rt::as_i32(vout3);
+                            }
+                        }
+                        let (result6_0, result6_1) = match spending_path {
+                            Some(e) => (1i32, _rt::as_i32(e)),
+                            None => (0i32, 0i32),
+                        };
+                        let ptr7 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "component:wallet/types@0.1.0")]
                         extern "C" {
-                            #[link_name = "[method]watch-only.insert-utxos"]
-                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                            #[link_name = "[method]watch-only.create-batch-transaction"]
+                            fn wit_import(
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: i64,
+                                _: *mut u8,
+                                _: usize,
+                                _: i32,
+                                _: i32,
+                                _: *mut u8,
+                            );
                         }
 
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                        fn wit_import(
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                            _: i32,
+                            _: i32,
+                            _: *mut u8,
+                        ) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, result3, len3, ptr4);
-                        let l5 = i32::from(*ptr4.add(0).cast::<u8>());
-                        if layout3.size() != 0 {
-                            _rt::alloc::dealloc(result3.cast(), layout3);
+                        wit_import(
+                            (self).handle() as i32,
+                            result2,
+                            len2,
+                            _rt::as_i64(&fee_rate),
+                            result5,
+                            len5,
+                            result6_0,
+                            result6_1,
+                            ptr7,
+                        );
+                        let l8 = i32::from(*ptr7.add(0).cast::<u8>());
+                        if layout2.size() != 0 {
+                            _rt::alloc::dealloc(result2.cast(), layout2);
                         }
-                        match l5 {
+                        if layout5.size() != 0 {
+                            _rt::alloc::dealloc(result5.cast(), layout5);
+                        }
+                        match l8 {
                             0 => {
-                                let e = ();
+                                let e = {
+                                    let l9 = *ptr7.add(8).cast::<*mut u8>();
+                                    let l10 = *ptr7.add(12).cast::<usize>();
+                                    let len11 = l10;
+
+                                    _rt::Vec::from_raw_parts(l9.cast(), len11, len11)
+                                };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l6 = i32::from(*ptr4.add(1).cast::<u8>());
-                                    let v7 = match l6 {
+                                    let l12 = i32::from(*ptr7.add(8).cast::<u8>());
+                                    let v38 = match l12 {
                                         0 => Error::CoinSelection,
                                         1 => Error::Psbt,
                                         2 => Error::MissingNonWitnessUtxo,
                                         3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e38 = {
+                                                let l13 = *ptr7.add(16).cast::<*mut u8>();
+                                                let l14 = *ptr7.add(20).cast::<usize>();
+                                                let len15 = l14;
+                                                let bytes15 = _rt::Vec::from_raw_parts(
+                                                    l13.cast(),
+                                                    len15,
+                                                    len15,
+                                                );
+
+                                                _rt::string_lift(bytes15)
+                                            };
+                                            Error::InvalidDescriptor(e38)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e38 = {
+                                                let l16 = *ptr7.add(16).cast::<i32>();
+
+                                                l16 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e38)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e38 = {
+                                                let l17 = *ptr7.add(16).cast::<i32>();
+
+                                                l17 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e38)
+                                        }
+                                        11 => {
+                                            let e38 = {
+                                                let l18 = *ptr7.add(16).cast::<*mut u8>();
+                                                let l19 = *ptr7.add(20).cast::<usize>();
+                                                let len20 = l19;
+                                                let l21 = *ptr7.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l18.cast(),
+                                                        len20,
+                                                        len20,
+                                                    ),
+                                                    vout: l21 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e38)
+                                        }
+                                        12 => {
+                                            let e38 = {
+                                                let l22 = *ptr7.add(16).cast::<*mut u8>();
+                                                let l23 = *ptr7.add(20).cast::<usize>();
+                                                let len24 = l23;
+                                                let l25 = *ptr7.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l22.cast(),
+                                                        len24,
+                                                        len24,
+                                                    ),
+                                                    vout: l25 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e38)
+                                        }
+                                        13 => {
+                                            let e38 = {
+                                                let l26 = *ptr7.add(16).cast::<*mut u8>();
+                                                let l27 = *ptr7.add(20).cast::<usize>();
+                                                let len28 = l27;
+
+                                                _rt::Vec::from_raw_parts(l26.cast(), len28, len28)
+                                            };
+                                            Error::IrreplaceableTransaction(e38)
+                                        }
+                                        14 => {
+                                            let e38 = {
+                                                let l29 = *ptr7.add(16).cast::<*mut u8>();
+                                                let l30 = *ptr7.add(20).cast::<usize>();
+                                                let len31 = l30;
+
+                                                _rt::Vec::from_raw_parts(l29.cast(), len31, len31)
+                                            };
+                                            Error::TransactionConfirmed(e38)
+                                        }
+                                        15 => {
+                                            let e38 = {
+                                                let l32 = *ptr7.add(16).cast::<*mut u8>();
+                                                let l33 = *ptr7.add(20).cast::<usize>();
+                                                let len34 = l33;
+
+                                                _rt::Vec::from_raw_parts(l32.cast(), len34, len34)
+                                            };
+                                            Error::NoWalletOutputs(e38)
+                                        }
+                                        16 => {
+                                            let e38 = {
+                                                let l35 = *ptr7.add(16).cast::<i64>();
+
+                                                l35 as u64
+                                            };
+                                            Error::FeeRateTooLow(e38)
+                                        }
+                                        17 => {
+                                            let e38 = {
+                                                let l36 = *ptr7.add(16).cast::<i64>();
+
+                                                l36 as u64
+                                            };
+                                            Error::FeeTooLow(e38)
+                                        }
                                         n => {
-                                            debug_assert_eq!(n, 4, "invalid enum discriminant");
-                                            Error::PubkeyError
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e38 = {
+                                                let l37 = *ptr7.add(16).cast::<i64>();
+
+                                                l37 as u64
+                                            };
+                                            Error::InvalidFeeRate(e38)
                                         }
                                     };
 
-                                    v7
+                                    v38
                                 };
                                 Err(e)
                             }
@@ -1208,65 +2477,267 @@ pub mod component {
             }
             impl WatchOnly {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn get_pubkeys(&self) -> Result<_rt::Vec<Pubkey>, Error> {
+                /// Spends every confirmed output, or only `outpoints`, to `destination` with the fee
+                /// taken from that output and no change
+                pub fn create_drain_transaction(
+                    &self,
+                    destination: &[u8],
+                    fee_rate: u64,
+                    outpoints: Option<&[Outpoint]>,
+                    spending_path: Option<u32>,
+                ) -> Result<_rt::Vec<u8>, Error> {
                     unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
-                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        let mut cleanup_list = _rt::Vec::new();
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let vec0 = destination;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        let (result4_0, result4_1, result4_2) = match outpoints {
+                            Some(e) => {
+                                let vec3 = e;
+                                let len3 = vec3.len();
+                                let layout3 = _rt::alloc::Layout::from_size_align_unchecked(
+                                    vec3.len() * 12,
+                                    4,
+                                );
+                                let result3 = if layout3.size() != 0 {
+                                    let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                                    if ptr.is_null() {
+                                        _rt::alloc::handle_alloc_error(layout3);
+                                    }
+                                    ptr
+                                } else {
+                                    {
+                                        ::core::ptr::null_mut()
+                                    }
+                                };
+                                for (i, e) in vec3.into_iter().enumerate() {
+                                    let base = result3.add(i * 12);
+                                    {
+                                        let Outpoint {
+                                            txid: txid1,
+                                            vout: vout1,
+                                        } = e;
+                                        let vec2 = txid1;
+                                        let ptr2 = vec2.as_ptr().cast::<u8>();
+                                        let len2 = vec2.len();
+                                        *base.add(4).cast::<usize>() = len2;
+                                        *base.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                                        *base.add(8).cast::<i32>() = _rt::as_i32(vout1);
+                                    }
+                                }
+                                cleanup_list.extend_from_slice(&[(result3, layout3)]);
+
+                                (1i32, result3, len3)
+                            }
+                            None => (0i32, ::core::ptr::null_mut(), 0usize),
+                        };
+                        let (result5_0, result5_1) = match spending_path {
+                            Some(e) => (1i32, _rt::as_i32(e)),
+                            None => (0i32, 0i32),
+                        };
+                        let ptr6 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "component:wallet/types@0.1.0")]
                         extern "C" {
-                            #[link_name = "[method]watch-only.get-pubkeys"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            #[link_name = "[method]watch-only.create-drain-transaction"]
+                            fn wit_import(
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: i64,
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: i32,
+                                _: i32,
+                                _: *mut u8,
+                            );
                         }
 
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        fn wit_import(
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: i64,
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: i32,
+                            _: i32,
+                            _: *mut u8,
+                        ) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        wit_import(
+                            (self).handle() as i32,
+                            ptr0.cast_mut(),
+                            len0,
+                            _rt::as_i64(&fee_rate),
+                            result4_0,
+                            result4_1,
+                            result4_2,
+                            result5_0,
+                            result5_1,
+                            ptr6,
+                        );
+                        let l7 = i32::from(*ptr6.add(0).cast::<u8>());
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
+                        match l7 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(4).cast::<*mut u8>();
-                                    let l3 = *ptr0.add(8).cast::<usize>();
-                                    let base7 = l2;
-                                    let len7 = l3;
-                                    let mut result7 = _rt::Vec::with_capacity(len7);
-                                    for i in 0..len7 {
-                                        let base = base7.add(i * 8);
-                                        let e7 = {
-                                            let l4 = *base.add(0).cast::<*mut u8>();
-                                            let l5 = *base.add(4).cast::<usize>();
-                                            let len6 = l5;
-
-                                            _rt::Vec::from_raw_parts(l4.cast(), len6, len6)
-                                        };
-                                        result7.push(e7);
-                                    }
-                                    _rt::cabi_dealloc(base7, len7 * 8, 4);
+                                    let l8 = *ptr6.add(8).cast::<*mut u8>();
+                                    let l9 = *ptr6.add(12).cast::<usize>();
+                                    let len10 = l9;
 
-                                    result7
+                                    _rt::Vec::from_raw_parts(l8.cast(), len10, len10)
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l8 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    let v9 = match l8 {
+                                    let l11 = i32::from(*ptr6.add(8).cast::<u8>());
+                                    let v37 = match l11 {
                                         0 => Error::CoinSelection,
                                         1 => Error::Psbt,
                                         2 => Error::MissingNonWitnessUtxo,
                                         3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e37 = {
+                                                let l12 = *ptr6.add(16).cast::<*mut u8>();
+                                                let l13 = *ptr6.add(20).cast::<usize>();
+                                                let len14 = l13;
+                                                let bytes14 = _rt::Vec::from_raw_parts(
+                                                    l12.cast(),
+                                                    len14,
+                                                    len14,
+                                                );
+
+                                                _rt::string_lift(bytes14)
+                                            };
+                                            Error::InvalidDescriptor(e37)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e37 = {
+                                                let l15 = *ptr6.add(16).cast::<i32>();
+
+                                                l15 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e37)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e37 = {
+                                                let l16 = *ptr6.add(16).cast::<i32>();
+
+                                                l16 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e37)
+                                        }
+                                        11 => {
+                                            let e37 = {
+                                                let l17 = *ptr6.add(16).cast::<*mut u8>();
+                                                let l18 = *ptr6.add(20).cast::<usize>();
+                                                let len19 = l18;
+                                                let l20 = *ptr6.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l17.cast(),
+                                                        len19,
+                                                        len19,
+                                                    ),
+                                                    vout: l20 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e37)
+                                        }
+                                        12 => {
+                                            let e37 = {
+                                                let l21 = *ptr6.add(16).cast::<*mut u8>();
+                                                let l22 = *ptr6.add(20).cast::<usize>();
+                                                let len23 = l22;
+                                                let l24 = *ptr6.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l21.cast(),
+                                                        len23,
+                                                        len23,
+                                                    ),
+                                                    vout: l24 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e37)
+                                        }
+                                        13 => {
+                                            let e37 = {
+                                                let l25 = *ptr6.add(16).cast::<*mut u8>();
+                                                let l26 = *ptr6.add(20).cast::<usize>();
+                                                let len27 = l26;
+
+                                                _rt::Vec::from_raw_parts(l25.cast(), len27, len27)
+                                            };
+                                            Error::IrreplaceableTransaction(e37)
+                                        }
+                                        14 => {
+                                            let e37 = {
+                                                let l28 = *ptr6.add(16).cast::<*mut u8>();
+                                                let l29 = *ptr6.add(20).cast::<usize>();
+                                                let len30 = l29;
+
+                                                _rt::Vec::from_raw_parts(l28.cast(), len30, len30)
+                                            };
+                                            Error::TransactionConfirmed(e37)
+                                        }
+                                        15 => {
+                                            let e37 = {
+                                                let l31 = *ptr6.add(16).cast::<*mut u8>();
+                                                let l32 = *ptr6.add(20).cast::<usize>();
+                                                let len33 = l32;
+
+                                                _rt::Vec::from_raw_parts(l31.cast(), len33, len33)
+                                            };
+                                            Error::NoWalletOutputs(e37)
+                                        }
+                                        16 => {
+                                            let e37 = {
+                                                let l34 = *ptr6.add(16).cast::<i64>();
+
+                                                l34 as u64
+                                            };
+                                            Error::FeeRateTooLow(e37)
+                                        }
+                                        17 => {
+                                            let e37 = {
+                                                let l35 = *ptr6.add(16).cast::<i64>();
+
+                                                l35 as u64
+                                            };
+                                            Error::FeeTooLow(e37)
+                                        }
                                         n => {
-                                            debug_assert_eq!(n, 4, "invalid enum discriminant");
-                                            Error::PubkeyError
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e37 = {
+                                                let l36 = *ptr6.add(16).cast::<i64>();
+
+                                                l36 as u64
+                                            };
+                                            Error::InvalidFeeRate(e37)
                                         }
                                     };
 
-                                    v9
+                                    v37
                                 };
                                 Err(e)
                             }
@@ -1277,76 +2748,185 @@ pub mod component {
             }
             impl WatchOnly {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn get_state(&self) -> _rt::Vec<u8> {
-                    unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
-                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
-                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
-                        #[cfg(target_arch = "wasm32")]
-                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
-                        extern "C" {
-                            #[link_name = "[method]watch-only.get-state"]
-                            fn wit_import(_: i32, _: *mut u8);
-                        }
-
-                        #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
-                            unreachable!()
-                        }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = *ptr0.add(0).cast::<*mut u8>();
-                        let l2 = *ptr0.add(4).cast::<usize>();
-                        let len3 = l2;
-                        _rt::Vec::from_raw_parts(l1.cast(), len3, len3)
-                    }
-                }
-            }
-            impl WatchOnly {
-                #[allow(unused_unsafe, clippy::all)]
-                pub fn balance(&self) -> Result<u64, Error> {
+                /// Replaces the unconfirmed `transaction` by one paying the same recipients at `fee-rate`,
+                /// out of its change or extra confirmed inputs
+                pub fn create_bump_fee_transaction(
+                    &self,
+                    transaction: &[u8],
+                    fee_rate: u64,
+                ) -> Result<_rt::Vec<u8>, Error> {
                     unsafe {
                         #[repr(align(8))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
-                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
-                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let vec0 = transaction;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "component:wallet/types@0.1.0")]
                         extern "C" {
-                            #[link_name = "[method]watch-only.balance"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            #[link_name = "[method]watch-only.create-bump-fee-transaction"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: i64, _: *mut u8);
                         }
 
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        wit_import(
+                            (self).handle() as i32,
+                            ptr0.cast_mut(),
+                            len0,
+                            _rt::as_i64(&fee_rate),
+                            ptr1,
+                        );
+                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
+                        match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(8).cast::<i64>();
+                                    let l3 = *ptr1.add(8).cast::<*mut u8>();
+                                    let l4 = *ptr1.add(12).cast::<usize>();
+                                    let len5 = l4;
 
-                                    l2 as u64
+                                    _rt::Vec::from_raw_parts(l3.cast(), len5, len5)
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l3 = i32::from(*ptr0.add(8).cast::<u8>());
-                                    let v4 = match l3 {
+                                    let l6 = i32::from(*ptr1.add(8).cast::<u8>());
+                                    let v32 = match l6 {
                                         0 => Error::CoinSelection,
                                         1 => Error::Psbt,
                                         2 => Error::MissingNonWitnessUtxo,
                                         3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e32 = {
+                                                let l7 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l8 = *ptr1.add(20).cast::<usize>();
+                                                let len9 = l8;
+                                                let bytes9 =
+                                                    _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+
+                                                _rt::string_lift(bytes9)
+                                            };
+                                            Error::InvalidDescriptor(e32)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e32 = {
+                                                let l10 = *ptr1.add(16).cast::<i32>();
+
+                                                l10 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e32)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e32 = {
+                                                let l11 = *ptr1.add(16).cast::<i32>();
+
+                                                l11 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e32)
+                                        }
+                                        11 => {
+                                            let e32 = {
+                                                let l12 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l13 = *ptr1.add(20).cast::<usize>();
+                                                let len14 = l13;
+                                                let l15 = *ptr1.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l12.cast(),
+                                                        len14,
+                                                        len14,
+                                                    ),
+                                                    vout: l15 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e32)
+                                        }
+                                        12 => {
+                                            let e32 = {
+                                                let l16 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l17 = *ptr1.add(20).cast::<usize>();
+                                                let len18 = l17;
+                                                let l19 = *ptr1.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l16.cast(),
+                                                        len18,
+                                                        len18,
+                                                    ),
+                                                    vout: l19 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e32)
+                                        }
+                                        13 => {
+                                            let e32 = {
+                                                let l20 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l21 = *ptr1.add(20).cast::<usize>();
+                                                let len22 = l21;
+
+                                                _rt::Vec::from_raw_parts(l20.cast(), len22, len22)
+                                            };
+                                            Error::IrreplaceableTransaction(e32)
+                                        }
+                                        14 => {
+                                            let e32 = {
+                                                let l23 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l24 = *ptr1.add(20).cast::<usize>();
+                                                let len25 = l24;
+
+                                                _rt::Vec::from_raw_parts(l23.cast(), len25, len25)
+                                            };
+                                            Error::TransactionConfirmed(e32)
+                                        }
+                                        15 => {
+                                            let e32 = {
+                                                let l26 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l27 = *ptr1.add(20).cast::<usize>();
+                                                let len28 = l27;
+
+                                                _rt::Vec::from_raw_parts(l26.cast(), len28, len28)
+                                            };
+                                            Error::NoWalletOutputs(e32)
+                                        }
+                                        16 => {
+                                            let e32 = {
+                                                let l29 = *ptr1.add(16).cast::<i64>();
+
+                                                l29 as u64
+                                            };
+                                            Error::FeeRateTooLow(e32)
+                                        }
+                                        17 => {
+                                            let e32 = {
+                                                let l30 = *ptr1.add(16).cast::<i64>();
+
+                                                l30 as u64
+                                            };
+                                            Error::FeeTooLow(e32)
+                                        }
                                         n => {
-                                            debug_assert_eq!(n, 4, "invalid enum discriminant");
-                                            Error::PubkeyError
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e32 = {
+                                                let l31 = *ptr1.add(16).cast::<i64>();
+
+                                                l31 as u64
+                                            };
+                                            Error::InvalidFeeRate(e32)
                                         }
                                     };
 
-                                    v4
+                                    v32
                                 };
                                 Err(e)
                             }
@@ -1357,52 +2937,185 @@ pub mod component {
             }
             impl WatchOnly {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn get_receive_address(&self) -> Result<_rt::String, Error> {
+                /// Child spending what the unconfirmed `parent` pays the wallet, bringing the fee rate of
+                /// both to `fee-rate`. The parent counts as paying no fee unless every input is ours.
+                pub fn create_cpfp_transaction(
+                    &self,
+                    parent: &[u8],
+                    fee_rate: u64,
+                ) -> Result<_rt::Vec<u8>, Error> {
                     unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
-                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let vec0 = parent;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "component:wallet/types@0.1.0")]
                         extern "C" {
-                            #[link_name = "[method]watch-only.get-receive-address"]
-                            fn wit_import(_: i32, _: *mut u8);
+                            #[link_name = "[method]watch-only.create-cpfp-transaction"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: i64, _: *mut u8);
                         }
 
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8) {
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: i64, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0);
-                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
-                        match l1 {
+                        wit_import(
+                            (self).handle() as i32,
+                            ptr0.cast_mut(),
+                            len0,
+                            _rt::as_i64(&fee_rate),
+                            ptr1,
+                        );
+                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
+                        match l2 {
                             0 => {
                                 let e = {
-                                    let l2 = *ptr0.add(4).cast::<*mut u8>();
-                                    let l3 = *ptr0.add(8).cast::<usize>();
-                                    let len4 = l3;
-                                    let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+                                    let l3 = *ptr1.add(8).cast::<*mut u8>();
+                                    let l4 = *ptr1.add(12).cast::<usize>();
+                                    let len5 = l4;
 
-                                    _rt::string_lift(bytes4)
+                                    _rt::Vec::from_raw_parts(l3.cast(), len5, len5)
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l5 = i32::from(*ptr0.add(4).cast::<u8>());
-                                    let v6 = match l5 {
+                                    let l6 = i32::from(*ptr1.add(8).cast::<u8>());
+                                    let v32 = match l6 {
                                         0 => Error::CoinSelection,
                                         1 => Error::Psbt,
                                         2 => Error::MissingNonWitnessUtxo,
                                         3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e32 = {
+                                                let l7 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l8 = *ptr1.add(20).cast::<usize>();
+                                                let len9 = l8;
+                                                let bytes9 =
+                                                    _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+
+                                                _rt::string_lift(bytes9)
+                                            };
+                                            Error::InvalidDescriptor(e32)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e32 = {
+                                                let l10 = *ptr1.add(16).cast::<i32>();
+
+                                                l10 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e32)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e32 = {
+                                                let l11 = *ptr1.add(16).cast::<i32>();
+
+                                                l11 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e32)
+                                        }
+                                        11 => {
+                                            let e32 = {
+                                                let l12 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l13 = *ptr1.add(20).cast::<usize>();
+                                                let len14 = l13;
+                                                let l15 = *ptr1.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l12.cast(),
+                                                        len14,
+                                                        len14,
+                                                    ),
+                                                    vout: l15 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e32)
+                                        }
+                                        12 => {
+                                            let e32 = {
+                                                let l16 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l17 = *ptr1.add(20).cast::<usize>();
+                                                let len18 = l17;
+                                                let l19 = *ptr1.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l16.cast(),
+                                                        len18,
+                                                        len18,
+                                                    ),
+                                                    vout: l19 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e32)
+                                        }
+                                        13 => {
+                                            let e32 = {
+                                                let l20 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l21 = *ptr1.add(20).cast::<usize>();
+                                                let len22 = l21;
+
+                                                _rt::Vec::from_raw_parts(l20.cast(), len22, len22)
+                                            };
+                                            Error::IrreplaceableTransaction(e32)
+                                        }
+                                        14 => {
+                                            let e32 = {
+                                                let l23 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l24 = *ptr1.add(20).cast::<usize>();
+                                                let len25 = l24;
+
+                                                _rt::Vec::from_raw_parts(l23.cast(), len25, len25)
+                                            };
+                                            Error::TransactionConfirmed(e32)
+                                        }
+                                        15 => {
+                                            let e32 = {
+                                                let l26 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l27 = *ptr1.add(20).cast::<usize>();
+                                                let len28 = l27;
+
+                                                _rt::Vec::from_raw_parts(l26.cast(), len28, len28)
+                                            };
+                                            Error::NoWalletOutputs(e32)
+                                        }
+                                        16 => {
+                                            let e32 = {
+                                                let l29 = *ptr1.add(16).cast::<i64>();
+
+                                                l29 as u64
+                                            };
+                                            Error::FeeRateTooLow(e32)
+                                        }
+                                        17 => {
+                                            let e32 = {
+                                                let l30 = *ptr1.add(16).cast::<i64>();
+
+                                                l30 as u64
+                                            };
+                                            Error::FeeTooLow(e32)
+                                        }
                                         n => {
-                                            debug_assert_eq!(n, 4, "invalid enum discriminant");
-                                            Error::PubkeyError
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e32 = {
+                                                let l31 = *ptr1.add(16).cast::<i64>();
+
+                                                l31 as u64
+                                            };
+                                            Error::InvalidFeeRate(e32)
                                         }
                                     };
 
-                                    v6
+                                    v32
                                 };
                                 Err(e)
                             }
@@ -1413,54 +3126,222 @@ pub mod component {
             }
             impl WatchOnly {
                 #[allow(unused_unsafe, clippy::all)]
-                pub fn finalise_transaction(&self, psbt: &[u8]) -> Result<_rt::Vec<u8>, Error> {
+                pub fn get_utxos(&self) -> Result<_rt::Vec<PartialUtxo>, Error> {
                     unsafe {
-                        #[repr(align(4))]
-                        struct RetArea([::core::mem::MaybeUninit<u8>; 12]);
-                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 12]);
-                        let vec0 = psbt;
-                        let ptr0 = vec0.as_ptr().cast::<u8>();
-                        let len0 = vec0.len();
-                        let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
                         #[cfg(target_arch = "wasm32")]
                         #[link(wasm_import_module = "component:wallet/types@0.1.0")]
                         extern "C" {
-                            #[link_name = "[method]watch-only.finalise-transaction"]
-                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                            #[link_name = "[method]watch-only.get-utxos"]
+                            fn wit_import(_: i32, _: *mut u8);
                         }
 
                         #[cfg(not(target_arch = "wasm32"))]
-                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                        fn wit_import(_: i32, _: *mut u8) {
                             unreachable!()
                         }
-                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0, ptr1);
-                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
-                        match l2 {
+                        wit_import((self).handle() as i32, ptr0);
+                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+                        match l1 {
                             0 => {
                                 let e = {
-                                    let l3 = *ptr1.add(4).cast::<*mut u8>();
-                                    let l4 = *ptr1.add(8).cast::<usize>();
-                                    let len5 = l4;
+                                    let l2 = *ptr0.add(8).cast::<*mut u8>();
+                                    let l3 = *ptr0.add(12).cast::<usize>();
+                                    let base16 = l2;
+                                    let len16 = l3;
+                                    let mut result16 = _rt::Vec::with_capacity(len16);
+                                    for i in 0..len16 {
+                                        let base = base16.add(i * 48);
+                                        let e16 = {
+                                            let l4 = *base.add(0).cast::<*mut u8>();
+                                            let l5 = *base.add(4).cast::<usize>();
+                                            let len6 = l5;
+                                            let l7 = *base.add(8).cast::<i32>();
+                                            let l8 = *base.add(16).cast::<i64>();
+                                            let l9 = *base.add(24).cast::<*mut u8>();
+                                            let l10 = *base.add(28).cast::<usize>();
+                                            let len11 = l10;
+                                            let l12 = i32::from(*base.add(32).cast::<u8>());
+                                            let l13 = i32::from(*base.add(36).cast::<u8>());
+                                            let l15 = i32::from(*base.add(44).cast::<u8>());
 
-                                    _rt::Vec::from_raw_parts(l3.cast(), len5, len5)
+                                            PartialUtxo {
+                                                txid: _rt::Vec::from_raw_parts(
+                                                    l4.cast(),
+                                                    len6,
+                                                    len6,
+                                                ),
+                                                vout: l7 as u32,
+                                                amount: l8 as u64,
+                                                script: _rt::Vec::from_raw_parts(
+                                                    l9.cast(),
+                                                    len11,
+                                                    len11,
+                                                ),
+                                                is_spent: _rt::bool_lift(l12 as u8),
+                                                height: match l13 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l14 = *base.add(40).cast::<i32>();
+
+                                                            l14 as u32
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                                is_coinbase: _rt::bool_lift(l15 as u8),
+                                            }
+                                        };
+                                        result16.push(e16);
+                                    }
+                                    _rt::cabi_dealloc(base16, len16 * 48, 8);
+
+                                    result16
                                 };
                                 Ok(e)
                             }
                             1 => {
                                 let e = {
-                                    let l6 = i32::from(*ptr1.add(4).cast::<u8>());
-                                    let v7 = match l6 {
+                                    let l17 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v43 = match l17 {
                                         0 => Error::CoinSelection,
                                         1 => Error::Psbt,
                                         2 => Error::MissingNonWitnessUtxo,
                                         3 => Error::NoPubkey,
-                                        n => {
-                                            debug_assert_eq!(n, 4, "invalid enum discriminant");
-                                            Error::PubkeyError
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e43 = {
+                                                let l18 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l19 = *ptr0.add(20).cast::<usize>();
+                                                let len20 = l19;
+                                                let bytes20 = _rt::Vec::from_raw_parts(
+                                                    l18.cast(),
+                                                    len20,
+                                                    len20,
+                                                );
+
+                                                _rt::string_lift(bytes20)
+                                            };
+                                            Error::InvalidDescriptor(e43)
                                         }
-                                    };
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e43 = {
+                                                let l21 = *ptr0.add(16).cast::<i32>();
 
-                                    v7
+                                                l21 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e43)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e43 = {
+                                                let l22 = *ptr0.add(16).cast::<i32>();
+
+                                                l22 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e43)
+                                        }
+                                        11 => {
+                                            let e43 = {
+                                                let l23 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l24 = *ptr0.add(20).cast::<usize>();
+                                                let len25 = l24;
+                                                let l26 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l23.cast(),
+                                                        len25,
+                                                        len25,
+                                                    ),
+                                                    vout: l26 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e43)
+                                        }
+                                        12 => {
+                                            let e43 = {
+                                                let l27 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l28 = *ptr0.add(20).cast::<usize>();
+                                                let len29 = l28;
+                                                let l30 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l27.cast(),
+                                                        len29,
+                                                        len29,
+                                                    ),
+                                                    vout: l30 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e43)
+                                        }
+                                        13 => {
+                                            let e43 = {
+                                                let l31 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l32 = *ptr0.add(20).cast::<usize>();
+                                                let len33 = l32;
+
+                                                _rt::Vec::from_raw_parts(l31.cast(), len33, len33)
+                                            };
+                                            Error::IrreplaceableTransaction(e43)
+                                        }
+                                        14 => {
+                                            let e43 = {
+                                                let l34 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l35 = *ptr0.add(20).cast::<usize>();
+                                                let len36 = l35;
+
+                                                _rt::Vec::from_raw_parts(l34.cast(), len36, len36)
+                                            };
+                                            Error::TransactionConfirmed(e43)
+                                        }
+                                        15 => {
+                                            let e43 = {
+                                                let l37 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l38 = *ptr0.add(20).cast::<usize>();
+                                                let len39 = l38;
+
+                                                _rt::Vec::from_raw_parts(l37.cast(), len39, len39)
+                                            };
+                                            Error::NoWalletOutputs(e43)
+                                        }
+                                        16 => {
+                                            let e43 = {
+                                                let l40 = *ptr0.add(16).cast::<i64>();
+
+                                                l40 as u64
+                                            };
+                                            Error::FeeRateTooLow(e43)
+                                        }
+                                        17 => {
+                                            let e43 = {
+                                                let l41 = *ptr0.add(16).cast::<i64>();
+
+                                                l41 as u64
+                                            };
+                                            Error::FeeTooLow(e43)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e43 = {
+                                                let l42 = *ptr0.add(16).cast::<i64>();
+
+                                                l42 as u64
+                                            };
+                                            Error::InvalidFeeRate(e43)
+                                        }
+                                    };
+
+                                    v43
                                 };
                                 Err(e)
                             }
@@ -1469,341 +3350,4916 @@ pub mod component {
                     }
                 }
             }
-        }
-    }
-}
-#[allow(dead_code)]
-pub mod exports {
-    #[allow(dead_code)]
-    pub mod component {
-        #[allow(dead_code)]
-        pub mod node {
-            #[allow(dead_code, clippy::all)]
-            pub mod types {
-                #[used]
-                #[doc(hidden)]
-                #[cfg(target_arch = "wasm32")]
-                static __FORCE_SECTION_REF: fn() =
-                    super::super::super::super::__link_custom_section_describing_imports;
-                use super::super::super::super::_rt;
-                #[repr(C)]
-                #[derive(Clone, Copy)]
-                pub struct Ipv4SocketAdress {
-                    pub address: (u8, u8, u8, u8),
-                    pub port: u16,
-                }
-                impl ::core::fmt::Debug for Ipv4SocketAdress {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                        f.debug_struct("Ipv4SocketAdress")
-                            .field("address", &self.address)
-                            .field("port", &self.port)
-                            .finish()
-                    }
-                }
-                #[repr(u8)]
-                #[derive(Clone, Copy, Eq, PartialEq)]
-                pub enum BitcoinNetwork {
-                    Bitcoin,
-                    Testnet,
-                    Testnet4,
-                    Signet,
-                    Regtest,
-                }
-                impl ::core::fmt::Debug for BitcoinNetwork {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                        match self {
-                            BitcoinNetwork::Bitcoin => {
-                                f.debug_tuple("BitcoinNetwork::Bitcoin").finish()
-                            }
-                            BitcoinNetwork::Testnet => {
-                                f.debug_tuple("BitcoinNetwork::Testnet").finish()
-                            }
-                            BitcoinNetwork::Testnet4 => {
-                                f.debug_tuple("BitcoinNetwork::Testnet4").finish()
-                            }
-                            BitcoinNetwork::Signet => {
-                                f.debug_tuple("BitcoinNetwork::Signet").finish()
-                            }
-                            BitcoinNetwork::Regtest => {
-                                f.debug_tuple("BitcoinNetwork::Regtest").finish()
-                            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Unspent outputs, oldest first
+                pub fn list_unspent(&self) -> _rt::Vec<UnspentUtxo> {
+                    unsafe {
+                        #[repr(align(4))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.list-unspent"]
+                            fn wit_import(_: i32, _: *mut u8);
                         }
-                    }
-                }
 
-                impl BitcoinNetwork {
-                    #[doc(hidden)]
-                    pub unsafe fn _lift(val: u8) -> BitcoinNetwork {
-                        if !cfg!(debug_assertions) {
-                            return ::core::mem::transmute(val);
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8) {
+                            unreachable!()
                         }
-
-                        match val {
-                            0 => BitcoinNetwork::Bitcoin,
-                            1 => BitcoinNetwork::Testnet,
-                            2 => BitcoinNetwork::Testnet4,
-                            3 => BitcoinNetwork::Signet,
-                            4 => BitcoinNetwork::Regtest,
-
-                            _ => panic!("invalid enum discriminant"),
+                        wit_import((self).handle() as i32, ptr0);
+                        let l1 = *ptr0.add(0).cast::<*mut u8>();
+                        let l2 = *ptr0.add(4).cast::<usize>();
+                        let base18 = l1;
+                        let len18 = l2;
+                        let mut result18 = _rt::Vec::with_capacity(len18);
+                        for i in 0..len18 {
+                            let base = base18.add(i * 56);
+                            let e18 = {
+                                let l3 = *base.add(0).cast::<*mut u8>();
+                                let l4 = *base.add(4).cast::<usize>();
+                                let len5 = l4;
+                                let l6 = *base.add(8).cast::<i32>();
+                                let l7 = *base.add(16).cast::<i64>();
+                                let l8 = *base.add(24).cast::<*mut u8>();
+                                let l9 = *base.add(28).cast::<usize>();
+                                let len10 = l9;
+                                let l11 = i32::from(*base.add(32).cast::<u8>());
+                                let l12 = *base.add(36).cast::<i32>();
+                                let l13 = i32::from(*base.add(40).cast::<u8>());
+                                let l15 = *base.add(48).cast::<i32>();
+                                let l16 = i32::from(*base.add(52).cast::<u8>());
+                                let l17 = i32::from(*base.add(53).cast::<u8>());
+
+                                UnspentUtxo {
+                                    outpoint: Outpoint {
+                                        txid: _rt::Vec::from_raw_parts(l3.cast(), len5, len5),
+                                        vout: l6 as u32,
+                                    },
+                                    amount: l7 as u64,
+                                    script: _rt::Vec::from_raw_parts(l8.cast(), len10, len10),
+                                    keychain: Keychain::_lift(l11 as u8),
+                                    derivation_index: l12 as u32,
+                                    height: match l13 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l14 = *base.add(44).cast::<i32>();
+
+                                                l14 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    confirmations: l15 as u32,
+                                    is_coinbase: _rt::bool_lift(l16 as u8),
+                                    is_frozen: _rt::bool_lift(l17 as u8),
+                                }
+                            };
+                            result18.push(e18);
                         }
+                        _rt::cabi_dealloc(base18, len18 * 56, 8);
+                        result18
                     }
                 }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Keeps coin selection from spending the unspent output until it is unfrozen
+                pub fn freeze_utxo(&self, outpoint: &Outpoint) -> Result<(), Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let Outpoint {
+                            txid: txid0,
+                            vout: vout0,
+                        } = outpoint;
+                        let vec1 = txid0;
+                        let ptr1 = vec1.as_ptr().cast::<u8>();
+                        let len1 = vec1.len();
+                        let ptr2 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.freeze-utxo"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: i32, _: *mut u8);
+                        }
 
-                #[derive(Clone)]
-                pub struct NodeConfig {
-                    pub network: BitcoinNetwork,
-                    pub socket_address: Ipv4SocketAdress,
-                    pub xpriv: _rt::String,
-                }
-                impl ::core::fmt::Debug for NodeConfig {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                        f.debug_struct("NodeConfig")
-                            .field("network", &self.network)
-                            .field("socket-address", &self.socket_address)
-                            .field("xpriv", &self.xpriv)
-                            .finish()
-                    }
-                }
-                #[derive(Clone)]
-                pub enum Initialization {
-                    OldState,
-                    Config(NodeConfig),
-                }
-                impl ::core::fmt::Debug for Initialization {
-                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                        match self {
-                            Initialization::OldState => {
-                                f.debug_tuple("Initialization::OldState").finish()
-                            }
-                            Initialization::Config(e) => {
-                                f.debug_tuple("Initialization::Config").field(e).finish()
-                            }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: i32, _: *mut u8) {
+                            unreachable!()
                         }
-                    }
-                }
+                        wit_import(
+                            (self).handle() as i32,
+                            ptr1.cast_mut(),
+                            len1,
+                            _rt::as_i32(vout0),
+                            ptr2,
+                        );
+                        let l3 = i32::from(*ptr2.add(0).cast::<u8>());
+                        match l3 {
+                            0 => {
+                                let e = ();
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l4 = i32::from(*ptr2.add(8).cast::<u8>());
+                                    let v30 = match l4 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e30 = {
+                                                let l5 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l6 = *ptr2.add(20).cast::<usize>();
+                                                let len7 = l6;
+                                                let bytes7 =
+                                                    _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+
+                                                _rt::string_lift(bytes7)
+                                            };
+                                            Error::InvalidDescriptor(e30)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e30 = {
+                                                let l8 = *ptr2.add(16).cast::<i32>();
 
-                #[derive(Debug)]
-                #[repr(transparent)]
-                pub struct ClientNode {
-                    handle: _rt::Resource<ClientNode>,
-                }
+                                                l8 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e30)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e30 = {
+                                                let l9 = *ptr2.add(16).cast::<i32>();
 
-                type _ClientNodeRep<T> = Option<T>;
+                                                l9 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e30)
+                                        }
+                                        11 => {
+                                            let e30 = {
+                                                let l10 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l11 = *ptr2.add(20).cast::<usize>();
+                                                let len12 = l11;
+                                                let l13 = *ptr2.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l10.cast(),
+                                                        len12,
+                                                        len12,
+                                                    ),
+                                                    vout: l13 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e30)
+                                        }
+                                        12 => {
+                                            let e30 = {
+                                                let l14 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l15 = *ptr2.add(20).cast::<usize>();
+                                                let len16 = l15;
+                                                let l17 = *ptr2.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l14.cast(),
+                                                        len16,
+                                                        len16,
+                                                    ),
+                                                    vout: l17 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e30)
+                                        }
+                                        13 => {
+                                            let e30 = {
+                                                let l18 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l19 = *ptr2.add(20).cast::<usize>();
+                                                let len20 = l19;
 
-                impl ClientNode {
-                    /// Creates a new resource from the specified representation.
-                    ///
-                    /// This function will create a new resource handle by moving `val` onto
-                    /// the heap and then passing that heap pointer to the component model to
-                    /// create a handle. The owned handle is then returned as `ClientNode`.
-                    pub fn new<T: GuestClientNode>(val: T) -> Self {
-                        Self::type_guard::<T>();
-                        let val: _ClientNodeRep<T> = Some(val);
-                        let ptr: *mut _ClientNodeRep<T> = _rt::Box::into_raw(_rt::Box::new(val));
-                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
-                    }
+                                                _rt::Vec::from_raw_parts(l18.cast(), len20, len20)
+                                            };
+                                            Error::IrreplaceableTransaction(e30)
+                                        }
+                                        14 => {
+                                            let e30 = {
+                                                let l21 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l22 = *ptr2.add(20).cast::<usize>();
+                                                let len23 = l22;
 
-                    /// Gets access to the underlying `T` which represents this resource.
-                    pub fn get<T: GuestClientNode>(&self) -> &T {
-                        let ptr = unsafe { &*self.as_ptr::<T>() };
-                        ptr.as_ref().unwrap()
-                    }
+                                                _rt::Vec::from_raw_parts(l21.cast(), len23, len23)
+                                            };
+                                            Error::TransactionConfirmed(e30)
+                                        }
+                                        15 => {
+                                            let e30 = {
+                                                let l24 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l25 = *ptr2.add(20).cast::<usize>();
+                                                let len26 = l25;
 
-                    /// Gets mutable access to the underlying `T` which represents this
-                    /// resource.
-                    pub fn get_mut<T: GuestClientNode>(&mut self) -> &mut T {
-                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
-                        ptr.as_mut().unwrap()
-                    }
+                                                _rt::Vec::from_raw_parts(l24.cast(), len26, len26)
+                                            };
+                                            Error::NoWalletOutputs(e30)
+                                        }
+                                        16 => {
+                                            let e30 = {
+                                                let l27 = *ptr2.add(16).cast::<i64>();
 
-                    /// Consumes this resource and returns the underlying `T`.
-                    pub fn into_inner<T: GuestClientNode>(self) -> T {
-                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
-                        ptr.take().unwrap()
-                    }
+                                                l27 as u64
+                                            };
+                                            Error::FeeRateTooLow(e30)
+                                        }
+                                        17 => {
+                                            let e30 = {
+                                                let l28 = *ptr2.add(16).cast::<i64>();
 
-                    #[doc(hidden)]
-                    pub unsafe fn from_handle(handle: u32) -> Self {
-                        Self {
-                            handle: _rt::Resource::from_handle(handle),
+                                                l28 as u64
+                                            };
+                                            Error::FeeTooLow(e30)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e30 = {
+                                                let l29 = *ptr2.add(16).cast::<i64>();
+
+                                                l29 as u64
+                                            };
+                                            Error::InvalidFeeRate(e30)
+                                        }
+                                    };
+
+                                    v30
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn unfreeze_utxo(&self, outpoint: &Outpoint) -> Result<(), Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let Outpoint {
+                            txid: txid0,
+                            vout: vout0,
+                        } = outpoint;
+                        let vec1 = txid0;
+                        let ptr1 = vec1.as_ptr().cast::<u8>();
+                        let len1 = vec1.len();
+                        let ptr2 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.unfreeze-utxo"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: i32, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import(
+                            (self).handle() as i32,
+                            ptr1.cast_mut(),
+                            len1,
+                            _rt::as_i32(vout0),
+                            ptr2,
+                        );
+                        let l3 = i32::from(*ptr2.add(0).cast::<u8>());
+                        match l3 {
+                            0 => {
+                                let e = ();
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l4 = i32::from(*ptr2.add(8).cast::<u8>());
+                                    let v30 = match l4 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e30 = {
+                                                let l5 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l6 = *ptr2.add(20).cast::<usize>();
+                                                let len7 = l6;
+                                                let bytes7 =
+                                                    _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+
+                                                _rt::string_lift(bytes7)
+                                            };
+                                            Error::InvalidDescriptor(e30)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e30 = {
+                                                let l8 = *ptr2.add(16).cast::<i32>();
+
+                                                l8 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e30)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e30 = {
+                                                let l9 = *ptr2.add(16).cast::<i32>();
+
+                                                l9 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e30)
+                                        }
+                                        11 => {
+                                            let e30 = {
+                                                let l10 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l11 = *ptr2.add(20).cast::<usize>();
+                                                let len12 = l11;
+                                                let l13 = *ptr2.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l10.cast(),
+                                                        len12,
+                                                        len12,
+                                                    ),
+                                                    vout: l13 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e30)
+                                        }
+                                        12 => {
+                                            let e30 = {
+                                                let l14 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l15 = *ptr2.add(20).cast::<usize>();
+                                                let len16 = l15;
+                                                let l17 = *ptr2.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l14.cast(),
+                                                        len16,
+                                                        len16,
+                                                    ),
+                                                    vout: l17 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e30)
+                                        }
+                                        13 => {
+                                            let e30 = {
+                                                let l18 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l19 = *ptr2.add(20).cast::<usize>();
+                                                let len20 = l19;
+
+                                                _rt::Vec::from_raw_parts(l18.cast(), len20, len20)
+                                            };
+                                            Error::IrreplaceableTransaction(e30)
+                                        }
+                                        14 => {
+                                            let e30 = {
+                                                let l21 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l22 = *ptr2.add(20).cast::<usize>();
+                                                let len23 = l22;
+
+                                                _rt::Vec::from_raw_parts(l21.cast(), len23, len23)
+                                            };
+                                            Error::TransactionConfirmed(e30)
+                                        }
+                                        15 => {
+                                            let e30 = {
+                                                let l24 = *ptr2.add(16).cast::<*mut u8>();
+                                                let l25 = *ptr2.add(20).cast::<usize>();
+                                                let len26 = l25;
+
+                                                _rt::Vec::from_raw_parts(l24.cast(), len26, len26)
+                                            };
+                                            Error::NoWalletOutputs(e30)
+                                        }
+                                        16 => {
+                                            let e30 = {
+                                                let l27 = *ptr2.add(16).cast::<i64>();
+
+                                                l27 as u64
+                                            };
+                                            Error::FeeRateTooLow(e30)
+                                        }
+                                        17 => {
+                                            let e30 = {
+                                                let l28 = *ptr2.add(16).cast::<i64>();
+
+                                                l28 as u64
+                                            };
+                                            Error::FeeTooLow(e30)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e30 = {
+                                                let l29 = *ptr2.add(16).cast::<i64>();
+
+                                                l29 as u64
+                                            };
+                                            Error::InvalidFeeRate(e30)
+                                        }
+                                    };
+
+                                    v30
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn insert_utxos(&self, utxos: &[PartialUtxo]) -> Result<(), Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let vec3 = utxos;
+                        let len3 = vec3.len();
+                        let layout3 =
+                            _rt::alloc::Layout::from_size_align_unchecked(vec3.len() * 48, 8);
+                        let result3 = if layout3.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout3);
+                            }
+                            ptr
+                        } else {
+                            {
+                                ::core::ptr::null_mut()
+                            }
+                        };
+                        for (i, e) in vec3.into_iter().enumerate() {
+                            let base = result3.add(i * 48);
+                            {
+                                let PartialUtxo {
+                                    txid: txid0,
+                                    vout: vout0,
+                                    amount: amount0,
+                                    script: script0,
+                                    is_spent: is_spent0,
+                                    height: height0,
+                                    is_coinbase: is_coinbase0,
+                                } = e;
+                                let vec1 = txid0;
+                                let ptr1 = vec1.as_ptr().cast::<u8>();
+                                let len1 = vec1.len();
+                                *base.add(4).cast::<usize>() = len1;
+                                *base.add(0).cast::<*mut u8>() = ptr1.cast_mut();
+                                *base.add(8).cast::<i32>() = _rt::as_i32(vout0);
+                                *base.add(16).cast::<i64>() = _rt::as_i64(amount0);
+                                let vec2 = script0;
+                                let ptr2 = vec2.as_ptr().cast::<u8>();
+                                let len2 = vec2.len();
+                                *base.add(28).cast::<usize>() = len2;
+                                *base.add(24).cast::<*mut u8>() = ptr2.cast_mut();
+                                *base.add(32).cast::<u8>() = (match is_spent0 {
+                                    true => 1,
+                                    false => 0,
+                                })
+                                    as u8;
+                                match height0 {
+                                    Some(e) => {
+                                        *base.add(36).cast::<u8>() = (1i32) as u8;
+                                        *base.add(40).cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    None => {
+                                        *base.add(36).cast::<u8>() = (0i32) as u8;
+                                    }
+                                };
+                                *base.add(44).cast::<u8>() = (match is_coinbase0 {
+                                    true => 1,
+                                    false => 0,
+                                })
+                                    as u8;
+                            }
+                        }
+                        let ptr4 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.insert-utxos"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, result3, len3, ptr4);
+                        let l5 = i32::from(*ptr4.add(0).cast::<u8>());
+                        if layout3.size() != 0 {
+                            _rt::alloc::dealloc(result3.cast(), layout3);
+                        }
+                        match l5 {
+                            0 => {
+                                let e = ();
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l6 = i32::from(*ptr4.add(8).cast::<u8>());
+                                    let v32 = match l6 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e32 = {
+                                                let l7 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l8 = *ptr4.add(20).cast::<usize>();
+                                                let len9 = l8;
+                                                let bytes9 =
+                                                    _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+
+                                                _rt::string_lift(bytes9)
+                                            };
+                                            Error::InvalidDescriptor(e32)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e32 = {
+                                                let l10 = *ptr4.add(16).cast::<i32>();
+
+                                                l10 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e32)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e32 = {
+                                                let l11 = *ptr4.add(16).cast::<i32>();
+
+                                                l11 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e32)
+                                        }
+                                        11 => {
+                                            let e32 = {
+                                                let l12 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l13 = *ptr4.add(20).cast::<usize>();
+                                                let len14 = l13;
+                                                let l15 = *ptr4.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l12.cast(),
+                                                        len14,
+                                                        len14,
+                                                    ),
+                                                    vout: l15 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e32)
+                                        }
+                                        12 => {
+                                            let e32 = {
+                                                let l16 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l17 = *ptr4.add(20).cast::<usize>();
+                                                let len18 = l17;
+                                                let l19 = *ptr4.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l16.cast(),
+                                                        len18,
+                                                        len18,
+                                                    ),
+                                                    vout: l19 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e32)
+                                        }
+                                        13 => {
+                                            let e32 = {
+                                                let l20 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l21 = *ptr4.add(20).cast::<usize>();
+                                                let len22 = l21;
+
+                                                _rt::Vec::from_raw_parts(l20.cast(), len22, len22)
+                                            };
+                                            Error::IrreplaceableTransaction(e32)
+                                        }
+                                        14 => {
+                                            let e32 = {
+                                                let l23 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l24 = *ptr4.add(20).cast::<usize>();
+                                                let len25 = l24;
+
+                                                _rt::Vec::from_raw_parts(l23.cast(), len25, len25)
+                                            };
+                                            Error::TransactionConfirmed(e32)
+                                        }
+                                        15 => {
+                                            let e32 = {
+                                                let l26 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l27 = *ptr4.add(20).cast::<usize>();
+                                                let len28 = l27;
+
+                                                _rt::Vec::from_raw_parts(l26.cast(), len28, len28)
+                                            };
+                                            Error::NoWalletOutputs(e32)
+                                        }
+                                        16 => {
+                                            let e32 = {
+                                                let l29 = *ptr4.add(16).cast::<i64>();
+
+                                                l29 as u64
+                                            };
+                                            Error::FeeRateTooLow(e32)
+                                        }
+                                        17 => {
+                                            let e32 = {
+                                                let l30 = *ptr4.add(16).cast::<i64>();
+
+                                                l30 as u64
+                                            };
+                                            Error::FeeTooLow(e32)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e32 = {
+                                                let l31 = *ptr4.add(16).cast::<i64>();
+
+                                                l31 as u64
+                                            };
+                                            Error::InvalidFeeRate(e32)
+                                        }
+                                    };
+
+                                    v32
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn insert_unconfirmed_utxos(&self, utxos: &[PartialUtxo]) -> Result<(), Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let vec3 = utxos;
+                        let len3 = vec3.len();
+                        let layout3 =
+                            _rt::alloc::Layout::from_size_align_unchecked(vec3.len() * 48, 8);
+                        let result3 = if layout3.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout3);
+                            }
+                            ptr
+                        } else {
+                            {
+                                ::core::ptr::null_mut()
+                            }
+                        };
+                        for (i, e) in vec3.into_iter().enumerate() {
+                            let base = result3.add(i * 48);
+                            {
+                                let PartialUtxo {
+                                    txid: txid0,
+                                    vout: vout0,
+                                    amount: amount0,
+                                    script: script0,
+                                    is_spent: is_spent0,
+                                    height: height0,
+                                    is_coinbase: is_coinbase0,
+                                } = e;
+                                let vec1 = txid0;
+                                let ptr1 = vec1.as_ptr().cast::<u8>();
+                                let len1 = vec1.len();
+                                *base.add(4).cast::<usize>() = len1;
+                                *base.add(0).cast::<*mut u8>() = ptr1.cast_mut();
+                                *base.add(8).cast::<i32>() = _rt::as_i32(vout0);
+                                *base.add(16).cast::<i64>() = _rt::as_i64(amount0);
+                                let vec2 = script0;
+                                let ptr2 = vec2.as_ptr().cast::<u8>();
+                                let len2 = vec2.len();
+                                *base.add(28).cast::<usize>() = len2;
+                                *base.add(24).cast::<*mut u8>() = ptr2.cast_mut();
+                                *base.add(32).cast::<u8>() = (match is_spent0 {
+                                    true => 1,
+                                    false => 0,
+                                })
+                                    as u8;
+                                match height0 {
+                                    Some(e) => {
+                                        *base.add(36).cast::<u8>() = (1i32) as u8;
+                                        *base.add(40).cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    None => {
+                                        *base.add(36).cast::<u8>() = (0i32) as u8;
+                                    }
+                                };
+                                *base.add(44).cast::<u8>() = (match is_coinbase0 {
+                                    true => 1,
+                                    false => 0,
+                                })
+                                    as u8;
+                            }
+                        }
+                        let ptr4 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.insert-unconfirmed-utxos"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, result3, len3, ptr4);
+                        let l5 = i32::from(*ptr4.add(0).cast::<u8>());
+                        if layout3.size() != 0 {
+                            _rt::alloc::dealloc(result3.cast(), layout3);
+                        }
+                        match l5 {
+                            0 => {
+                                let e = ();
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l6 = i32::from(*ptr4.add(8).cast::<u8>());
+                                    let v32 = match l6 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e32 = {
+                                                let l7 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l8 = *ptr4.add(20).cast::<usize>();
+                                                let len9 = l8;
+                                                let bytes9 =
+                                                    _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+
+                                                _rt::string_lift(bytes9)
+                                            };
+                                            Error::InvalidDescriptor(e32)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e32 = {
+                                                let l10 = *ptr4.add(16).cast::<i32>();
+
+                                                l10 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e32)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e32 = {
+                                                let l11 = *ptr4.add(16).cast::<i32>();
+
+                                                l11 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e32)
+                                        }
+                                        11 => {
+                                            let e32 = {
+                                                let l12 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l13 = *ptr4.add(20).cast::<usize>();
+                                                let len14 = l13;
+                                                let l15 = *ptr4.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l12.cast(),
+                                                        len14,
+                                                        len14,
+                                                    ),
+                                                    vout: l15 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e32)
+                                        }
+                                        12 => {
+                                            let e32 = {
+                                                let l16 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l17 = *ptr4.add(20).cast::<usize>();
+                                                let len18 = l17;
+                                                let l19 = *ptr4.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l16.cast(),
+                                                        len18,
+                                                        len18,
+                                                    ),
+                                                    vout: l19 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e32)
+                                        }
+                                        13 => {
+                                            let e32 = {
+                                                let l20 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l21 = *ptr4.add(20).cast::<usize>();
+                                                let len22 = l21;
+
+                                                _rt::Vec::from_raw_parts(l20.cast(), len22, len22)
+                                            };
+                                            Error::IrreplaceableTransaction(e32)
+                                        }
+                                        14 => {
+                                            let e32 = {
+                                                let l23 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l24 = *ptr4.add(20).cast::<usize>();
+                                                let len25 = l24;
+
+                                                _rt::Vec::from_raw_parts(l23.cast(), len25, len25)
+                                            };
+                                            Error::TransactionConfirmed(e32)
+                                        }
+                                        15 => {
+                                            let e32 = {
+                                                let l26 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l27 = *ptr4.add(20).cast::<usize>();
+                                                let len28 = l27;
+
+                                                _rt::Vec::from_raw_parts(l26.cast(), len28, len28)
+                                            };
+                                            Error::NoWalletOutputs(e32)
+                                        }
+                                        16 => {
+                                            let e32 = {
+                                                let l29 = *ptr4.add(16).cast::<i64>();
+
+                                                l29 as u64
+                                            };
+                                            Error::FeeRateTooLow(e32)
+                                        }
+                                        17 => {
+                                            let e32 = {
+                                                let l30 = *ptr4.add(16).cast::<i64>();
+
+                                                l30 as u64
+                                            };
+                                            Error::FeeTooLow(e32)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e32 = {
+                                                let l31 = *ptr4.add(16).cast::<i64>();
+
+                                                l31 as u64
+                                            };
+                                            Error::InvalidFeeRate(e32)
+                                        }
+                                    };
+
+                                    v32
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn remove_utxos(&self, utxos: &[PartialUtxo]) -> Result<(), Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let vec3 = utxos;
+                        let len3 = vec3.len();
+                        let layout3 =
+                            _rt::alloc::Layout::from_size_align_unchecked(vec3.len() * 48, 8);
+                        let result3 = if layout3.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout3);
+                            }
+                            ptr
+                        } else {
+                            {
+                                ::core::ptr::null_mut()
+                            }
+                        };
+                        for (i, e) in vec3.into_iter().enumerate() {
+                            let base = result3.add(i * 48);
+                            {
+                                let PartialUtxo {
+                                    txid: txid0,
+                                    vout: vout0,
+                                    amount: amount0,
+                                    script: script0,
+                                    is_spent: is_spent0,
+                                    height: height0,
+                                    is_coinbase: is_coinbase0,
+                                } = e;
+                                let vec1 = txid0;
+                                let ptr1 = vec1.as_ptr().cast::<u8>();
+                                let len1 = vec1.len();
+                                *base.add(4).cast::<usize>() = len1;
+                                *base.add(0).cast::<*mut u8>() = ptr1.cast_mut();
+                                *base.add(8).cast::<i32>() = _rt::as_i32(vout0);
+                                *base.add(16).cast::<i64>() = _rt::as_i64(amount0);
+                                let vec2 = script0;
+                                let ptr2 = vec2.as_ptr().cast::<u8>();
+                                let len2 = vec2.len();
+                                *base.add(28).cast::<usize>() = len2;
+                                *base.add(24).cast::<*mut u8>() = ptr2.cast_mut();
+                                *base.add(32).cast::<u8>() = (match is_spent0 {
+                                    true => 1,
+                                    false => 0,
+                                })
+                                    as u8;
+                                match height0 {
+                                    Some(e) => {
+                                        *base.add(36).cast::<u8>() = (1i32) as u8;
+                                        *base.add(40).cast::<i32>() = _rt::as_i32(e);
+                                    }
+                                    None => {
+                                        *base.add(36).cast::<u8>() = (0i32) as u8;
+                                    }
+                                };
+                                *base.add(44).cast::<u8>() = (match is_coinbase0 {
+                                    true => 1,
+                                    false => 0,
+                                })
+                                    as u8;
+                            }
+                        }
+                        let ptr4 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.remove-utxos"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, result3, len3, ptr4);
+                        let l5 = i32::from(*ptr4.add(0).cast::<u8>());
+                        if layout3.size() != 0 {
+                            _rt::alloc::dealloc(result3.cast(), layout3);
+                        }
+                        match l5 {
+                            0 => {
+                                let e = ();
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l6 = i32::from(*ptr4.add(8).cast::<u8>());
+                                    let v32 = match l6 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e32 = {
+                                                let l7 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l8 = *ptr4.add(20).cast::<usize>();
+                                                let len9 = l8;
+                                                let bytes9 =
+                                                    _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+
+                                                _rt::string_lift(bytes9)
+                                            };
+                                            Error::InvalidDescriptor(e32)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e32 = {
+                                                let l10 = *ptr4.add(16).cast::<i32>();
+
+                                                l10 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e32)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e32 = {
+                                                let l11 = *ptr4.add(16).cast::<i32>();
+
+                                                l11 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e32)
+                                        }
+                                        11 => {
+                                            let e32 = {
+                                                let l12 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l13 = *ptr4.add(20).cast::<usize>();
+                                                let len14 = l13;
+                                                let l15 = *ptr4.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l12.cast(),
+                                                        len14,
+                                                        len14,
+                                                    ),
+                                                    vout: l15 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e32)
+                                        }
+                                        12 => {
+                                            let e32 = {
+                                                let l16 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l17 = *ptr4.add(20).cast::<usize>();
+                                                let len18 = l17;
+                                                let l19 = *ptr4.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l16.cast(),
+                                                        len18,
+                                                        len18,
+                                                    ),
+                                                    vout: l19 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e32)
+                                        }
+                                        13 => {
+                                            let e32 = {
+                                                let l20 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l21 = *ptr4.add(20).cast::<usize>();
+                                                let len22 = l21;
+
+                                                _rt::Vec::from_raw_parts(l20.cast(), len22, len22)
+                                            };
+                                            Error::IrreplaceableTransaction(e32)
+                                        }
+                                        14 => {
+                                            let e32 = {
+                                                let l23 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l24 = *ptr4.add(20).cast::<usize>();
+                                                let len25 = l24;
+
+                                                _rt::Vec::from_raw_parts(l23.cast(), len25, len25)
+                                            };
+                                            Error::TransactionConfirmed(e32)
+                                        }
+                                        15 => {
+                                            let e32 = {
+                                                let l26 = *ptr4.add(16).cast::<*mut u8>();
+                                                let l27 = *ptr4.add(20).cast::<usize>();
+                                                let len28 = l27;
+
+                                                _rt::Vec::from_raw_parts(l26.cast(), len28, len28)
+                                            };
+                                            Error::NoWalletOutputs(e32)
+                                        }
+                                        16 => {
+                                            let e32 = {
+                                                let l29 = *ptr4.add(16).cast::<i64>();
+
+                                                l29 as u64
+                                            };
+                                            Error::FeeRateTooLow(e32)
+                                        }
+                                        17 => {
+                                            let e32 = {
+                                                let l30 = *ptr4.add(16).cast::<i64>();
+
+                                                l30 as u64
+                                            };
+                                            Error::FeeTooLow(e32)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e32 = {
+                                                let l31 = *ptr4.add(16).cast::<i64>();
+
+                                                l31 as u64
+                                            };
+                                            Error::InvalidFeeRate(e32)
+                                        }
+                                    };
+
+                                    v32
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Holds the wallet outputs a broadcast transaction spends until a block mines it
+                pub fn mark_pending_spent(&self, transaction: &[u8]) -> Result<(), Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let vec0 = transaction;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.mark-pending-spent"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0, ptr1);
+                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
+                        match l2 {
+                            0 => {
+                                let e = ();
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l3 = i32::from(*ptr1.add(8).cast::<u8>());
+                                    let v29 = match l3 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e29 = {
+                                                let l4 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l5 = *ptr1.add(20).cast::<usize>();
+                                                let len6 = l5;
+                                                let bytes6 =
+                                                    _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+
+                                                _rt::string_lift(bytes6)
+                                            };
+                                            Error::InvalidDescriptor(e29)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e29 = {
+                                                let l7 = *ptr1.add(16).cast::<i32>();
+
+                                                l7 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e29)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e29 = {
+                                                let l8 = *ptr1.add(16).cast::<i32>();
+
+                                                l8 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e29)
+                                        }
+                                        11 => {
+                                            let e29 = {
+                                                let l9 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l10 = *ptr1.add(20).cast::<usize>();
+                                                let len11 = l10;
+                                                let l12 = *ptr1.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l9.cast(),
+                                                        len11,
+                                                        len11,
+                                                    ),
+                                                    vout: l12 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e29)
+                                        }
+                                        12 => {
+                                            let e29 = {
+                                                let l13 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l14 = *ptr1.add(20).cast::<usize>();
+                                                let len15 = l14;
+                                                let l16 = *ptr1.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l13.cast(),
+                                                        len15,
+                                                        len15,
+                                                    ),
+                                                    vout: l16 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e29)
+                                        }
+                                        13 => {
+                                            let e29 = {
+                                                let l17 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l18 = *ptr1.add(20).cast::<usize>();
+                                                let len19 = l18;
+
+                                                _rt::Vec::from_raw_parts(l17.cast(), len19, len19)
+                                            };
+                                            Error::IrreplaceableTransaction(e29)
+                                        }
+                                        14 => {
+                                            let e29 = {
+                                                let l20 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l21 = *ptr1.add(20).cast::<usize>();
+                                                let len22 = l21;
+
+                                                _rt::Vec::from_raw_parts(l20.cast(), len22, len22)
+                                            };
+                                            Error::TransactionConfirmed(e29)
+                                        }
+                                        15 => {
+                                            let e29 = {
+                                                let l23 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l24 = *ptr1.add(20).cast::<usize>();
+                                                let len25 = l24;
+
+                                                _rt::Vec::from_raw_parts(l23.cast(), len25, len25)
+                                            };
+                                            Error::NoWalletOutputs(e29)
+                                        }
+                                        16 => {
+                                            let e29 = {
+                                                let l26 = *ptr1.add(16).cast::<i64>();
+
+                                                l26 as u64
+                                            };
+                                            Error::FeeRateTooLow(e29)
+                                        }
+                                        17 => {
+                                            let e29 = {
+                                                let l27 = *ptr1.add(16).cast::<i64>();
+
+                                                l27 as u64
+                                            };
+                                            Error::FeeTooLow(e29)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e29 = {
+                                                let l28 = *ptr1.add(16).cast::<i64>();
+
+                                                l28 as u64
+                                            };
+                                            Error::InvalidFeeRate(e29)
+                                        }
+                                    };
+
+                                    v29
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Frees the outputs the unconfirmed transaction `txid` still holds, after it was
+                /// replaced or dropped
+                pub fn release_pending_spent(&self, txid: &[u8]) {
+                    unsafe {
+                        let vec0 = txid;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.release-pending-spent"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0);
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get_pubkeys(&self) -> Result<_rt::Vec<Pubkey>, Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.get-pubkeys"]
+                            fn wit_import(_: i32, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0);
+                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+                        match l1 {
+                            0 => {
+                                let e = {
+                                    let l2 = *ptr0.add(8).cast::<*mut u8>();
+                                    let l3 = *ptr0.add(12).cast::<usize>();
+                                    let base7 = l2;
+                                    let len7 = l3;
+                                    let mut result7 = _rt::Vec::with_capacity(len7);
+                                    for i in 0..len7 {
+                                        let base = base7.add(i * 8);
+                                        let e7 = {
+                                            let l4 = *base.add(0).cast::<*mut u8>();
+                                            let l5 = *base.add(4).cast::<usize>();
+                                            let len6 = l5;
+
+                                            _rt::Vec::from_raw_parts(l4.cast(), len6, len6)
+                                        };
+                                        result7.push(e7);
+                                    }
+                                    _rt::cabi_dealloc(base7, len7 * 8, 4);
+
+                                    result7
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l8 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v34 = match l8 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e34 = {
+                                                let l9 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l10 = *ptr0.add(20).cast::<usize>();
+                                                let len11 = l10;
+                                                let bytes11 = _rt::Vec::from_raw_parts(
+                                                    l9.cast(),
+                                                    len11,
+                                                    len11,
+                                                );
+
+                                                _rt::string_lift(bytes11)
+                                            };
+                                            Error::InvalidDescriptor(e34)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e34 = {
+                                                let l12 = *ptr0.add(16).cast::<i32>();
+
+                                                l12 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e34)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e34 = {
+                                                let l13 = *ptr0.add(16).cast::<i32>();
+
+                                                l13 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e34)
+                                        }
+                                        11 => {
+                                            let e34 = {
+                                                let l14 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l15 = *ptr0.add(20).cast::<usize>();
+                                                let len16 = l15;
+                                                let l17 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l14.cast(),
+                                                        len16,
+                                                        len16,
+                                                    ),
+                                                    vout: l17 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e34)
+                                        }
+                                        12 => {
+                                            let e34 = {
+                                                let l18 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l19 = *ptr0.add(20).cast::<usize>();
+                                                let len20 = l19;
+                                                let l21 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l18.cast(),
+                                                        len20,
+                                                        len20,
+                                                    ),
+                                                    vout: l21 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e34)
+                                        }
+                                        13 => {
+                                            let e34 = {
+                                                let l22 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l23 = *ptr0.add(20).cast::<usize>();
+                                                let len24 = l23;
+
+                                                _rt::Vec::from_raw_parts(l22.cast(), len24, len24)
+                                            };
+                                            Error::IrreplaceableTransaction(e34)
+                                        }
+                                        14 => {
+                                            let e34 = {
+                                                let l25 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l26 = *ptr0.add(20).cast::<usize>();
+                                                let len27 = l26;
+
+                                                _rt::Vec::from_raw_parts(l25.cast(), len27, len27)
+                                            };
+                                            Error::TransactionConfirmed(e34)
+                                        }
+                                        15 => {
+                                            let e34 = {
+                                                let l28 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l29 = *ptr0.add(20).cast::<usize>();
+                                                let len30 = l29;
+
+                                                _rt::Vec::from_raw_parts(l28.cast(), len30, len30)
+                                            };
+                                            Error::NoWalletOutputs(e34)
+                                        }
+                                        16 => {
+                                            let e34 = {
+                                                let l31 = *ptr0.add(16).cast::<i64>();
+
+                                                l31 as u64
+                                            };
+                                            Error::FeeRateTooLow(e34)
+                                        }
+                                        17 => {
+                                            let e34 = {
+                                                let l32 = *ptr0.add(16).cast::<i64>();
+
+                                                l32 as u64
+                                            };
+                                            Error::FeeTooLow(e34)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e34 = {
+                                                let l33 = *ptr0.add(16).cast::<i64>();
+
+                                                l33 as u64
+                                            };
+                                            Error::InvalidFeeRate(e34)
+                                        }
+                                    };
+
+                                    v34
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Keychain the script belongs to, none when it is not a wallet script
+                pub fn get_keychain(&self, script: &Pubkey) -> Option<Keychain> {
+                    unsafe {
+                        #[repr(align(1))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 2]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 2]);
+                        let vec0 = script;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.get-keychain"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0, ptr1);
+                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
+                        match l2 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let l3 = i32::from(*ptr1.add(1).cast::<u8>());
+
+                                    Keychain::_lift(l3 as u8)
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get_state(&self) -> _rt::Vec<u8> {
+                    unsafe {
+                        #[repr(align(4))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 8]);
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.get-state"]
+                            fn wit_import(_: i32, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0);
+                        let l1 = *ptr0.add(0).cast::<*mut u8>();
+                        let l2 = *ptr0.add(4).cast::<usize>();
+                        let len3 = l2;
+                        _rt::Vec::from_raw_parts(l1.cast(), len3, len3)
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Receive and change descriptors with their checksums
+                pub fn get_descriptors(&self) -> Descriptors {
+                    unsafe {
+                        #[repr(align(4))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 20]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 20]);
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.get-descriptors"]
+                            fn wit_import(_: i32, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0);
+                        let l1 = *ptr0.add(0).cast::<*mut u8>();
+                        let l2 = *ptr0.add(4).cast::<usize>();
+                        let len3 = l2;
+                        let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                        let l4 = i32::from(*ptr0.add(8).cast::<u8>());
+                        Descriptors {
+                            external: _rt::string_lift(bytes3),
+                            internal: match l4 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let l5 = *ptr0.add(12).cast::<*mut u8>();
+                                        let l6 = *ptr0.add(16).cast::<usize>();
+                                        let len7 = l6;
+                                        let bytes7 =
+                                            _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+
+                                        _rt::string_lift(bytes7)
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn balance(&self) -> Result<u64, Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.balance"]
+                            fn wit_import(_: i32, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0);
+                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+                        match l1 {
+                            0 => {
+                                let e = {
+                                    let l2 = *ptr0.add(8).cast::<i64>();
+
+                                    l2 as u64
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l3 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v29 = match l3 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e29 = {
+                                                let l4 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l5 = *ptr0.add(20).cast::<usize>();
+                                                let len6 = l5;
+                                                let bytes6 =
+                                                    _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+
+                                                _rt::string_lift(bytes6)
+                                            };
+                                            Error::InvalidDescriptor(e29)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e29 = {
+                                                let l7 = *ptr0.add(16).cast::<i32>();
+
+                                                l7 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e29)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e29 = {
+                                                let l8 = *ptr0.add(16).cast::<i32>();
+
+                                                l8 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e29)
+                                        }
+                                        11 => {
+                                            let e29 = {
+                                                let l9 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l10 = *ptr0.add(20).cast::<usize>();
+                                                let len11 = l10;
+                                                let l12 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l9.cast(),
+                                                        len11,
+                                                        len11,
+                                                    ),
+                                                    vout: l12 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e29)
+                                        }
+                                        12 => {
+                                            let e29 = {
+                                                let l13 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l14 = *ptr0.add(20).cast::<usize>();
+                                                let len15 = l14;
+                                                let l16 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l13.cast(),
+                                                        len15,
+                                                        len15,
+                                                    ),
+                                                    vout: l16 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e29)
+                                        }
+                                        13 => {
+                                            let e29 = {
+                                                let l17 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l18 = *ptr0.add(20).cast::<usize>();
+                                                let len19 = l18;
+
+                                                _rt::Vec::from_raw_parts(l17.cast(), len19, len19)
+                                            };
+                                            Error::IrreplaceableTransaction(e29)
+                                        }
+                                        14 => {
+                                            let e29 = {
+                                                let l20 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l21 = *ptr0.add(20).cast::<usize>();
+                                                let len22 = l21;
+
+                                                _rt::Vec::from_raw_parts(l20.cast(), len22, len22)
+                                            };
+                                            Error::TransactionConfirmed(e29)
+                                        }
+                                        15 => {
+                                            let e29 = {
+                                                let l23 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l24 = *ptr0.add(20).cast::<usize>();
+                                                let len25 = l24;
+
+                                                _rt::Vec::from_raw_parts(l23.cast(), len25, len25)
+                                            };
+                                            Error::NoWalletOutputs(e29)
+                                        }
+                                        16 => {
+                                            let e29 = {
+                                                let l26 = *ptr0.add(16).cast::<i64>();
+
+                                                l26 as u64
+                                            };
+                                            Error::FeeRateTooLow(e29)
+                                        }
+                                        17 => {
+                                            let e29 = {
+                                                let l27 = *ptr0.add(16).cast::<i64>();
+
+                                                l27 as u64
+                                            };
+                                            Error::FeeTooLow(e29)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e29 = {
+                                                let l28 = *ptr0.add(16).cast::<i64>();
+
+                                                l28 as u64
+                                            };
+                                            Error::InvalidFeeRate(e29)
+                                        }
+                                    };
+
+                                    v29
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn pending_balance(&self) -> Result<u64, Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.pending-balance"]
+                            fn wit_import(_: i32, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0);
+                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+                        match l1 {
+                            0 => {
+                                let e = {
+                                    let l2 = *ptr0.add(8).cast::<i64>();
+
+                                    l2 as u64
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l3 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v29 = match l3 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e29 = {
+                                                let l4 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l5 = *ptr0.add(20).cast::<usize>();
+                                                let len6 = l5;
+                                                let bytes6 =
+                                                    _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+
+                                                _rt::string_lift(bytes6)
+                                            };
+                                            Error::InvalidDescriptor(e29)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e29 = {
+                                                let l7 = *ptr0.add(16).cast::<i32>();
+
+                                                l7 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e29)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e29 = {
+                                                let l8 = *ptr0.add(16).cast::<i32>();
+
+                                                l8 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e29)
+                                        }
+                                        11 => {
+                                            let e29 = {
+                                                let l9 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l10 = *ptr0.add(20).cast::<usize>();
+                                                let len11 = l10;
+                                                let l12 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l9.cast(),
+                                                        len11,
+                                                        len11,
+                                                    ),
+                                                    vout: l12 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e29)
+                                        }
+                                        12 => {
+                                            let e29 = {
+                                                let l13 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l14 = *ptr0.add(20).cast::<usize>();
+                                                let len15 = l14;
+                                                let l16 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l13.cast(),
+                                                        len15,
+                                                        len15,
+                                                    ),
+                                                    vout: l16 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e29)
+                                        }
+                                        13 => {
+                                            let e29 = {
+                                                let l17 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l18 = *ptr0.add(20).cast::<usize>();
+                                                let len19 = l18;
+
+                                                _rt::Vec::from_raw_parts(l17.cast(), len19, len19)
+                                            };
+                                            Error::IrreplaceableTransaction(e29)
+                                        }
+                                        14 => {
+                                            let e29 = {
+                                                let l20 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l21 = *ptr0.add(20).cast::<usize>();
+                                                let len22 = l21;
+
+                                                _rt::Vec::from_raw_parts(l20.cast(), len22, len22)
+                                            };
+                                            Error::TransactionConfirmed(e29)
+                                        }
+                                        15 => {
+                                            let e29 = {
+                                                let l23 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l24 = *ptr0.add(20).cast::<usize>();
+                                                let len25 = l24;
+
+                                                _rt::Vec::from_raw_parts(l23.cast(), len25, len25)
+                                            };
+                                            Error::NoWalletOutputs(e29)
+                                        }
+                                        16 => {
+                                            let e29 = {
+                                                let l26 = *ptr0.add(16).cast::<i64>();
+
+                                                l26 as u64
+                                            };
+                                            Error::FeeRateTooLow(e29)
+                                        }
+                                        17 => {
+                                            let e29 = {
+                                                let l27 = *ptr0.add(16).cast::<i64>();
+
+                                                l27 as u64
+                                            };
+                                            Error::FeeTooLow(e29)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e29 = {
+                                                let l28 = *ptr0.add(16).cast::<i64>();
+
+                                                l28 as u64
+                                            };
+                                            Error::InvalidFeeRate(e29)
+                                        }
+                                    };
+
+                                    v29
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn balance_breakdown(&self) -> Result<Balance, Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 40]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 40]);
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.balance-breakdown"]
+                            fn wit_import(_: i32, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0);
+                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+                        match l1 {
+                            0 => {
+                                let e = {
+                                    let l2 = *ptr0.add(8).cast::<i64>();
+                                    let l3 = *ptr0.add(16).cast::<i64>();
+                                    let l4 = *ptr0.add(24).cast::<i64>();
+                                    let l5 = *ptr0.add(32).cast::<i64>();
+
+                                    Balance {
+                                        confirmed: l2 as u64,
+                                        trusted_pending: l3 as u64,
+                                        untrusted_pending: l4 as u64,
+                                        immature: l5 as u64,
+                                    }
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l6 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v32 = match l6 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e32 = {
+                                                let l7 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l8 = *ptr0.add(20).cast::<usize>();
+                                                let len9 = l8;
+                                                let bytes9 =
+                                                    _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+
+                                                _rt::string_lift(bytes9)
+                                            };
+                                            Error::InvalidDescriptor(e32)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e32 = {
+                                                let l10 = *ptr0.add(16).cast::<i32>();
+
+                                                l10 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e32)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e32 = {
+                                                let l11 = *ptr0.add(16).cast::<i32>();
+
+                                                l11 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e32)
+                                        }
+                                        11 => {
+                                            let e32 = {
+                                                let l12 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l13 = *ptr0.add(20).cast::<usize>();
+                                                let len14 = l13;
+                                                let l15 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l12.cast(),
+                                                        len14,
+                                                        len14,
+                                                    ),
+                                                    vout: l15 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e32)
+                                        }
+                                        12 => {
+                                            let e32 = {
+                                                let l16 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l17 = *ptr0.add(20).cast::<usize>();
+                                                let len18 = l17;
+                                                let l19 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l16.cast(),
+                                                        len18,
+                                                        len18,
+                                                    ),
+                                                    vout: l19 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e32)
+                                        }
+                                        13 => {
+                                            let e32 = {
+                                                let l20 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l21 = *ptr0.add(20).cast::<usize>();
+                                                let len22 = l21;
+
+                                                _rt::Vec::from_raw_parts(l20.cast(), len22, len22)
+                                            };
+                                            Error::IrreplaceableTransaction(e32)
+                                        }
+                                        14 => {
+                                            let e32 = {
+                                                let l23 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l24 = *ptr0.add(20).cast::<usize>();
+                                                let len25 = l24;
+
+                                                _rt::Vec::from_raw_parts(l23.cast(), len25, len25)
+                                            };
+                                            Error::TransactionConfirmed(e32)
+                                        }
+                                        15 => {
+                                            let e32 = {
+                                                let l26 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l27 = *ptr0.add(20).cast::<usize>();
+                                                let len28 = l27;
+
+                                                _rt::Vec::from_raw_parts(l26.cast(), len28, len28)
+                                            };
+                                            Error::NoWalletOutputs(e32)
+                                        }
+                                        16 => {
+                                            let e32 = {
+                                                let l29 = *ptr0.add(16).cast::<i64>();
+
+                                                l29 as u64
+                                            };
+                                            Error::FeeRateTooLow(e32)
+                                        }
+                                        17 => {
+                                            let e32 = {
+                                                let l30 = *ptr0.add(16).cast::<i64>();
+
+                                                l30 as u64
+                                            };
+                                            Error::FeeTooLow(e32)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e32 = {
+                                                let l31 = *ptr0.add(16).cast::<i64>();
+
+                                                l31 as u64
+                                            };
+                                            Error::InvalidFeeRate(e32)
+                                        }
+                                    };
+
+                                    v32
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Height of the last block scanned, confirmations are counted from it
+                pub fn set_tip_height(&self, height: u32) {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.set-tip-height"]
+                            fn wit_import(_: i32, _: i32);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: i32) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, _rt::as_i32(&height));
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get_receive_address(&self) -> Result<_rt::String, Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.get-receive-address"]
+                            fn wit_import(_: i32, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0);
+                        let l1 = i32::from(*ptr0.add(0).cast::<u8>());
+                        match l1 {
+                            0 => {
+                                let e = {
+                                    let l2 = *ptr0.add(8).cast::<*mut u8>();
+                                    let l3 = *ptr0.add(12).cast::<usize>();
+                                    let len4 = l3;
+                                    let bytes4 = _rt::Vec::from_raw_parts(l2.cast(), len4, len4);
+
+                                    _rt::string_lift(bytes4)
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l5 = i32::from(*ptr0.add(8).cast::<u8>());
+                                    let v31 = match l5 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e31 = {
+                                                let l6 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l7 = *ptr0.add(20).cast::<usize>();
+                                                let len8 = l7;
+                                                let bytes8 =
+                                                    _rt::Vec::from_raw_parts(l6.cast(), len8, len8);
+
+                                                _rt::string_lift(bytes8)
+                                            };
+                                            Error::InvalidDescriptor(e31)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e31 = {
+                                                let l9 = *ptr0.add(16).cast::<i32>();
+
+                                                l9 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e31)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e31 = {
+                                                let l10 = *ptr0.add(16).cast::<i32>();
+
+                                                l10 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e31)
+                                        }
+                                        11 => {
+                                            let e31 = {
+                                                let l11 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l12 = *ptr0.add(20).cast::<usize>();
+                                                let len13 = l12;
+                                                let l14 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l11.cast(),
+                                                        len13,
+                                                        len13,
+                                                    ),
+                                                    vout: l14 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e31)
+                                        }
+                                        12 => {
+                                            let e31 = {
+                                                let l15 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l16 = *ptr0.add(20).cast::<usize>();
+                                                let len17 = l16;
+                                                let l18 = *ptr0.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l15.cast(),
+                                                        len17,
+                                                        len17,
+                                                    ),
+                                                    vout: l18 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e31)
+                                        }
+                                        13 => {
+                                            let e31 = {
+                                                let l19 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l20 = *ptr0.add(20).cast::<usize>();
+                                                let len21 = l20;
+
+                                                _rt::Vec::from_raw_parts(l19.cast(), len21, len21)
+                                            };
+                                            Error::IrreplaceableTransaction(e31)
+                                        }
+                                        14 => {
+                                            let e31 = {
+                                                let l22 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l23 = *ptr0.add(20).cast::<usize>();
+                                                let len24 = l23;
+
+                                                _rt::Vec::from_raw_parts(l22.cast(), len24, len24)
+                                            };
+                                            Error::TransactionConfirmed(e31)
+                                        }
+                                        15 => {
+                                            let e31 = {
+                                                let l25 = *ptr0.add(16).cast::<*mut u8>();
+                                                let l26 = *ptr0.add(20).cast::<usize>();
+                                                let len27 = l26;
+
+                                                _rt::Vec::from_raw_parts(l25.cast(), len27, len27)
+                                            };
+                                            Error::NoWalletOutputs(e31)
+                                        }
+                                        16 => {
+                                            let e31 = {
+                                                let l28 = *ptr0.add(16).cast::<i64>();
+
+                                                l28 as u64
+                                            };
+                                            Error::FeeRateTooLow(e31)
+                                        }
+                                        17 => {
+                                            let e31 = {
+                                                let l29 = *ptr0.add(16).cast::<i64>();
+
+                                                l29 as u64
+                                            };
+                                            Error::FeeTooLow(e31)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e31 = {
+                                                let l30 = *ptr0.add(16).cast::<i64>();
+
+                                                l30 as u64
+                                            };
+                                            Error::InvalidFeeRate(e31)
+                                        }
+                                    };
+
+                                    v31
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Merges the signatures of cosigners who signed their own copy of `psbt`
+                pub fn combine_psbts(
+                    &self,
+                    psbt: &[u8],
+                    others: &[_rt::Vec<u8>],
+                ) -> Result<_rt::Vec<u8>, Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let vec0 = psbt;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        let vec2 = others;
+                        let len2 = vec2.len();
+                        let layout2 =
+                            _rt::alloc::Layout::from_size_align_unchecked(vec2.len() * 8, 4);
+                        let result2 = if layout2.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout2).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout2);
+                            }
+                            ptr
+                        } else {
+                            {
+                                ::core::ptr::null_mut()
+                            }
+                        };
+                        for (i, e) in vec2.into_iter().enumerate() {
+                            let base = result2.add(i * 8);
+                            {
+                                let vec1 = e;
+                                let ptr1 = vec1.as_ptr().cast::<u8>();
+                                let len1 = vec1.len();
+                                *base.add(4).cast::<usize>() = len1;
+                                *base.add(0).cast::<*mut u8>() = ptr1.cast_mut();
+                            }
+                        }
+                        let ptr3 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.combine-psbts"]
+                            fn wit_import(
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                            );
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        ) {
+                            unreachable!()
+                        }
+                        wit_import(
+                            (self).handle() as i32,
+                            ptr0.cast_mut(),
+                            len0,
+                            result2,
+                            len2,
+                            ptr3,
+                        );
+                        let l4 = i32::from(*ptr3.add(0).cast::<u8>());
+                        if layout2.size() != 0 {
+                            _rt::alloc::dealloc(result2.cast(), layout2);
+                        }
+                        match l4 {
+                            0 => {
+                                let e = {
+                                    let l5 = *ptr3.add(8).cast::<*mut u8>();
+                                    let l6 = *ptr3.add(12).cast::<usize>();
+                                    let len7 = l6;
+
+                                    _rt::Vec::from_raw_parts(l5.cast(), len7, len7)
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l8 = i32::from(*ptr3.add(8).cast::<u8>());
+                                    let v34 = match l8 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e34 = {
+                                                let l9 = *ptr3.add(16).cast::<*mut u8>();
+                                                let l10 = *ptr3.add(20).cast::<usize>();
+                                                let len11 = l10;
+                                                let bytes11 = _rt::Vec::from_raw_parts(
+                                                    l9.cast(),
+                                                    len11,
+                                                    len11,
+                                                );
+
+                                                _rt::string_lift(bytes11)
+                                            };
+                                            Error::InvalidDescriptor(e34)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e34 = {
+                                                let l12 = *ptr3.add(16).cast::<i32>();
+
+                                                l12 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e34)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e34 = {
+                                                let l13 = *ptr3.add(16).cast::<i32>();
+
+                                                l13 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e34)
+                                        }
+                                        11 => {
+                                            let e34 = {
+                                                let l14 = *ptr3.add(16).cast::<*mut u8>();
+                                                let l15 = *ptr3.add(20).cast::<usize>();
+                                                let len16 = l15;
+                                                let l17 = *ptr3.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l14.cast(),
+                                                        len16,
+                                                        len16,
+                                                    ),
+                                                    vout: l17 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e34)
+                                        }
+                                        12 => {
+                                            let e34 = {
+                                                let l18 = *ptr3.add(16).cast::<*mut u8>();
+                                                let l19 = *ptr3.add(20).cast::<usize>();
+                                                let len20 = l19;
+                                                let l21 = *ptr3.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l18.cast(),
+                                                        len20,
+                                                        len20,
+                                                    ),
+                                                    vout: l21 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e34)
+                                        }
+                                        13 => {
+                                            let e34 = {
+                                                let l22 = *ptr3.add(16).cast::<*mut u8>();
+                                                let l23 = *ptr3.add(20).cast::<usize>();
+                                                let len24 = l23;
+
+                                                _rt::Vec::from_raw_parts(l22.cast(), len24, len24)
+                                            };
+                                            Error::IrreplaceableTransaction(e34)
+                                        }
+                                        14 => {
+                                            let e34 = {
+                                                let l25 = *ptr3.add(16).cast::<*mut u8>();
+                                                let l26 = *ptr3.add(20).cast::<usize>();
+                                                let len27 = l26;
+
+                                                _rt::Vec::from_raw_parts(l25.cast(), len27, len27)
+                                            };
+                                            Error::TransactionConfirmed(e34)
+                                        }
+                                        15 => {
+                                            let e34 = {
+                                                let l28 = *ptr3.add(16).cast::<*mut u8>();
+                                                let l29 = *ptr3.add(20).cast::<usize>();
+                                                let len30 = l29;
+
+                                                _rt::Vec::from_raw_parts(l28.cast(), len30, len30)
+                                            };
+                                            Error::NoWalletOutputs(e34)
+                                        }
+                                        16 => {
+                                            let e34 = {
+                                                let l31 = *ptr3.add(16).cast::<i64>();
+
+                                                l31 as u64
+                                            };
+                                            Error::FeeRateTooLow(e34)
+                                        }
+                                        17 => {
+                                            let e34 = {
+                                                let l32 = *ptr3.add(16).cast::<i64>();
+
+                                                l32 as u64
+                                            };
+                                            Error::FeeTooLow(e34)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e34 = {
+                                                let l33 = *ptr3.add(16).cast::<i64>();
+
+                                                l33 as u64
+                                            };
+                                            Error::InvalidFeeRate(e34)
+                                        }
+                                    };
+
+                                    v34
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+            impl WatchOnly {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Builds the transaction once every input has the signatures its script needs
+                pub fn finalise_transaction(&self, psbt: &[u8]) -> Result<_rt::Vec<u8>, Error> {
+                    unsafe {
+                        #[repr(align(8))]
+                        struct RetArea([::core::mem::MaybeUninit<u8>; 32]);
+                        let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 32]);
+                        let vec0 = psbt;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "component:wallet/types@0.1.0")]
+                        extern "C" {
+                            #[link_name = "[method]watch-only.finalise-transaction"]
+                            fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8);
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        fn wit_import(_: i32, _: *mut u8, _: usize, _: *mut u8) {
+                            unreachable!()
+                        }
+                        wit_import((self).handle() as i32, ptr0.cast_mut(), len0, ptr1);
+                        let l2 = i32::from(*ptr1.add(0).cast::<u8>());
+                        match l2 {
+                            0 => {
+                                let e = {
+                                    let l3 = *ptr1.add(8).cast::<*mut u8>();
+                                    let l4 = *ptr1.add(12).cast::<usize>();
+                                    let len5 = l4;
+
+                                    _rt::Vec::from_raw_parts(l3.cast(), len5, len5)
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l6 = i32::from(*ptr1.add(8).cast::<u8>());
+                                    let v32 = match l6 {
+                                        0 => Error::CoinSelection,
+                                        1 => Error::Psbt,
+                                        2 => Error::MissingNonWitnessUtxo,
+                                        3 => Error::NoPubkey,
+                                        4 => Error::PubkeyError,
+                                        5 => {
+                                            let e32 = {
+                                                let l7 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l8 = *ptr1.add(20).cast::<usize>();
+                                                let len9 = l8;
+                                                let bytes9 =
+                                                    _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+
+                                                _rt::string_lift(bytes9)
+                                            };
+                                            Error::InvalidDescriptor(e32)
+                                        }
+                                        6 => Error::MissingSignature,
+                                        7 => {
+                                            let e32 = {
+                                                let l10 = *ptr1.add(16).cast::<i32>();
+
+                                                l10 as u32
+                                            };
+                                            Error::InvalidSpendingPath(e32)
+                                        }
+                                        8 => Error::TimelockNotReached,
+                                        9 => Error::NoRecipients,
+                                        10 => {
+                                            let e32 = {
+                                                let l11 = *ptr1.add(16).cast::<i32>();
+
+                                                l11 as u32
+                                            };
+                                            Error::OutputBelowDustLimit(e32)
+                                        }
+                                        11 => {
+                                            let e32 = {
+                                                let l12 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l13 = *ptr1.add(20).cast::<usize>();
+                                                let len14 = l13;
+                                                let l15 = *ptr1.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l12.cast(),
+                                                        len14,
+                                                        len14,
+                                                    ),
+                                                    vout: l15 as u32,
+                                                }
+                                            };
+                                            Error::UnknownUtxo(e32)
+                                        }
+                                        12 => {
+                                            let e32 = {
+                                                let l16 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l17 = *ptr1.add(20).cast::<usize>();
+                                                let len18 = l17;
+                                                let l19 = *ptr1.add(24).cast::<i32>();
+
+                                                Outpoint {
+                                                    txid: _rt::Vec::from_raw_parts(
+                                                        l16.cast(),
+                                                        len18,
+                                                        len18,
+                                                    ),
+                                                    vout: l19 as u32,
+                                                }
+                                            };
+                                            Error::FrozenUtxo(e32)
+                                        }
+                                        13 => {
+                                            let e32 = {
+                                                let l20 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l21 = *ptr1.add(20).cast::<usize>();
+                                                let len22 = l21;
+
+                                                _rt::Vec::from_raw_parts(l20.cast(), len22, len22)
+                                            };
+                                            Error::IrreplaceableTransaction(e32)
+                                        }
+                                        14 => {
+                                            let e32 = {
+                                                let l23 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l24 = *ptr1.add(20).cast::<usize>();
+                                                let len25 = l24;
+
+                                                _rt::Vec::from_raw_parts(l23.cast(), len25, len25)
+                                            };
+                                            Error::TransactionConfirmed(e32)
+                                        }
+                                        15 => {
+                                            let e32 = {
+                                                let l26 = *ptr1.add(16).cast::<*mut u8>();
+                                                let l27 = *ptr1.add(20).cast::<usize>();
+                                                let len28 = l27;
+
+                                                _rt::Vec::from_raw_parts(l26.cast(), len28, len28)
+                                            };
+                                            Error::NoWalletOutputs(e32)
+                                        }
+                                        16 => {
+                                            let e32 = {
+                                                let l29 = *ptr1.add(16).cast::<i64>();
+
+                                                l29 as u64
+                                            };
+                                            Error::FeeRateTooLow(e32)
+                                        }
+                                        17 => {
+                                            let e32 = {
+                                                let l30 = *ptr1.add(16).cast::<i64>();
+
+                                                l30 as u64
+                                            };
+                                            Error::FeeTooLow(e32)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 18, "invalid enum discriminant");
+                                            let e32 = {
+                                                let l31 = *ptr1.add(16).cast::<i64>();
+
+                                                l31 as u64
+                                            };
+                                            Error::InvalidFeeRate(e32)
+                                        }
+                                    };
+
+                                    v32
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+#[allow(dead_code)]
+pub mod exports {
+    #[allow(dead_code)]
+    pub mod component {
+        #[allow(dead_code)]
+        pub mod node {
+            #[allow(dead_code, clippy::all)]
+            pub mod types {
+                #[used]
+                #[doc(hidden)]
+                #[cfg(target_arch = "wasm32")]
+                static __FORCE_SECTION_REF: fn() =
+                    super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct Ipv4SocketAdress {
+                    pub address: (u8, u8, u8, u8),
+                    pub port: u16,
+                }
+                impl ::core::fmt::Debug for Ipv4SocketAdress {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Ipv4SocketAdress")
+                            .field("address", &self.address)
+                            .field("port", &self.port)
+                            .finish()
+                    }
+                }
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum BitcoinNetwork {
+                    Bitcoin,
+                    Testnet,
+                    Testnet4,
+                    Signet,
+                    Regtest,
+                }
+                impl ::core::fmt::Debug for BitcoinNetwork {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            BitcoinNetwork::Bitcoin => {
+                                f.debug_tuple("BitcoinNetwork::Bitcoin").finish()
+                            }
+                            BitcoinNetwork::Testnet => {
+                                f.debug_tuple("BitcoinNetwork::Testnet").finish()
+                            }
+                            BitcoinNetwork::Testnet4 => {
+                                f.debug_tuple("BitcoinNetwork::Testnet4").finish()
+                            }
+                            BitcoinNetwork::Signet => {
+                                f.debug_tuple("BitcoinNetwork::Signet").finish()
+                            }
+                            BitcoinNetwork::Regtest => {
+                                f.debug_tuple("BitcoinNetwork::Regtest").finish()
+                            }
+                        }
+                    }
+                }
+
+                impl BitcoinNetwork {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> BitcoinNetwork {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+
+                        match val {
+                            0 => BitcoinNetwork::Bitcoin,
+                            1 => BitcoinNetwork::Testnet,
+                            2 => BitcoinNetwork::Testnet4,
+                            3 => BitcoinNetwork::Signet,
+                            4 => BitcoinNetwork::Regtest,
+
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+
+                /// Earliest point the wallet may have received funds
+                #[derive(Clone, Copy)]
+                pub enum WalletBirthday {
+                    Height(u64),
+                    /// Seconds since the unix epoch
+                    Timestamp(u64),
+                }
+                impl ::core::fmt::Debug for WalletBirthday {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            WalletBirthday::Height(e) => {
+                                f.debug_tuple("WalletBirthday::Height").field(e).finish()
+                            }
+                            WalletBirthday::Timestamp(e) => {
+                                f.debug_tuple("WalletBirthday::Timestamp").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum Direction {
+                    /// Pays the wallet without spending any of its outputs
+                    Incoming,
+                    /// Spends wallet outputs to pay someone else
+                    Outgoing,
+                    /// Every output pays back to the wallet
+                    SelfTransfer,
+                }
+                impl ::core::fmt::Debug for Direction {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            Direction::Incoming => f.debug_tuple("Direction::Incoming").finish(),
+                            Direction::Outgoing => f.debug_tuple("Direction::Outgoing").finish(),
+                            Direction::SelfTransfer => {
+                                f.debug_tuple("Direction::SelfTransfer").finish()
+                            }
+                        }
+                    }
+                }
+
+                impl Direction {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> Direction {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+
+                        match val {
+                            0 => Direction::Incoming,
+                            1 => Direction::Outgoing,
+                            2 => Direction::SelfTransfer,
+
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+
+                /// Wallet output spent by a transaction
+                #[derive(Clone)]
+                pub struct WalletInput {
+                    pub txid: _rt::String,
+                    pub vout: u32,
+                    pub amount: u64,
+                }
+                impl ::core::fmt::Debug for WalletInput {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("WalletInput")
+                            .field("txid", &self.txid)
+                            .field("vout", &self.vout)
+                            .field("amount", &self.amount)
+                            .finish()
+                    }
+                }
+                /// Transaction output paying the wallet
+                #[derive(Clone)]
+                pub struct WalletOutput {
+                    pub vout: u32,
+                    pub amount: u64,
+                    pub script: _rt::Vec<u8>,
+                    /// Paid back to the wallet's change keychain
+                    pub is_change: bool,
+                }
+                impl ::core::fmt::Debug for WalletOutput {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("WalletOutput")
+                            .field("vout", &self.vout)
+                            .field("amount", &self.amount)
+                            .field("script", &self.script)
+                            .field("is-change", &self.is_change)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct TransactionDetails {
+                    pub txid: _rt::String,
+                    /// Not set while the transaction is unconfirmed
+                    pub block_height: Option<u64>,
+                    pub block_hash: Option<_rt::String>,
+                    /// Block time once mined, when it was first seen before that
+                    pub timestamp: u64,
+                    /// Change of the wallet balance, the fee included when sending
+                    pub net_amount: i64,
+                    /// Only known when every input belongs to the wallet
+                    pub fee: Option<u64>,
+                    pub direction: Direction,
+                    pub inputs: _rt::Vec<WalletInput>,
+                    pub outputs: _rt::Vec<WalletOutput>,
+                }
+                impl ::core::fmt::Debug for TransactionDetails {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("TransactionDetails")
+                            .field("txid", &self.txid)
+                            .field("block-height", &self.block_height)
+                            .field("block-hash", &self.block_hash)
+                            .field("timestamp", &self.timestamp)
+                            .field("net-amount", &self.net_amount)
+                            .field("fee", &self.fee)
+                            .field("direction", &self.direction)
+                            .field("inputs", &self.inputs)
+                            .field("outputs", &self.outputs)
+                            .finish()
+                    }
+                }
+                /// Wallet balance split by how final the outputs are
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct Balance {
+                    /// Mined outputs, immature coinbase left out
+                    pub confirmed: u64,
+                    /// Unconfirmed change of our own transactions
+                    pub trusted_pending: u64,
+                    /// Unconfirmed payments from others
+                    pub untrusted_pending: u64,
+                    /// Coinbase outputs that are not 100 blocks deep yet
+                    pub immature: u64,
+                }
+                impl ::core::fmt::Debug for Balance {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Balance")
+                            .field("confirmed", &self.confirmed)
+                            .field("trusted-pending", &self.trusted_pending)
+                            .field("untrusted-pending", &self.untrusted_pending)
+                            .field("immature", &self.immature)
+                            .finish()
+                    }
+                }
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, PartialEq)]
+                pub enum WalletType {
+                    P2wpkh,
+                    /// Single key taproot, BIP86
+                    P2tr,
+                }
+                impl ::core::fmt::Debug for WalletType {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            WalletType::P2wpkh => f.debug_tuple("WalletType::P2wpkh").finish(),
+                            WalletType::P2tr => f.debug_tuple("WalletType::P2tr").finish(),
+                        }
+                    }
+                }
+
+                impl WalletType {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> WalletType {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+
+                        match val {
+                            0 => WalletType::P2wpkh,
+                            1 => WalletType::P2tr,
+
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+
+                /// Account of a wallet whose keys are held elsewhere
+                #[derive(Clone)]
+                pub struct WatchAccount {
+                    pub xpub: _rt::String,
+                    pub account_derivation: _rt::String,
+                    pub master_fingerprint: _rt::String,
+                }
+                impl ::core::fmt::Debug for WatchAccount {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("WatchAccount")
+                            .field("xpub", &self.xpub)
+                            .field("account-derivation", &self.account_derivation)
+                            .field("master-fingerprint", &self.master_fingerprint)
+                            .finish()
+                    }
+                }
+                /// Output descriptors of a wallet whose keys are held elsewhere
+                #[derive(Clone)]
+                pub struct WatchDescriptors {
+                    pub external: _rt::String,
+                    /// Taken from a `<0;1>` multipath step of the external descriptor when not set
+                    pub internal: Option<_rt::String>,
+                }
+                impl ::core::fmt::Debug for WatchDescriptors {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("WatchDescriptors")
+                            .field("external", &self.external)
+                            .field("internal", &self.internal)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub enum NodeKeys {
+                    /// The node derives its account from this key and signs its own transactions
+                    Xpriv(_rt::String),
+                    /// Watch-only, spending goes through create-psbt and broadcast-psbt
+                    Account(WatchAccount),
+                    /// Watch-only, spending goes through create-psbt and broadcast-psbt
+                    Descriptors(WatchDescriptors),
+                }
+                impl ::core::fmt::Debug for NodeKeys {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            NodeKeys::Xpriv(e) => {
+                                f.debug_tuple("NodeKeys::Xpriv").field(e).finish()
+                            }
+                            NodeKeys::Account(e) => {
+                                f.debug_tuple("NodeKeys::Account").field(e).finish()
+                            }
+                            NodeKeys::Descriptors(e) => {
+                                f.debug_tuple("NodeKeys::Descriptors").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                #[derive(Clone)]
+                pub struct NodeConfig {
+                    pub network: BitcoinNetwork,
+                    /// Script type of the wallet account, p2wpkh when not set
+                    pub wallet_type: Option<WalletType>,
+                    pub socket_address: Ipv4SocketAdress,
+                    /// Additional peers, connected after socket-address
+                    pub peers: _rt::Vec<Ipv4SocketAdress>,
+                    /// Number of outbound connections kept open, 4 when not set
+                    pub max_connections: Option<u32>,
+                    /// Look up peers from the network's DNS seeds when no known peer is reachable
+                    pub use_seeds: bool,
+                    /// Blocks before the birthday are not scanned, every block is when not set. Headers are
+                    /// still synced from genesis either way.
+                    pub birthday: Option<WalletBirthday>,
+                    /// Unused scripts watched past the last used one on each keychain, 20 when not set
+                    pub lookahead: Option<u32>,
+                    /// Confirmations an output needs before it is spent, 0 allows unconfirmed ones, 1 when not set
+                    pub min_confirmations: Option<u32>,
+                    pub keys: NodeKeys,
+                }
+                impl ::core::fmt::Debug for NodeConfig {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("NodeConfig")
+                            .field("network", &self.network)
+                            .field("wallet-type", &self.wallet_type)
+                            .field("socket-address", &self.socket_address)
+                            .field("peers", &self.peers)
+                            .field("max-connections", &self.max_connections)
+                            .field("use-seeds", &self.use_seeds)
+                            .field("birthday", &self.birthday)
+                            .field("lookahead", &self.lookahead)
+                            .field("min-confirmations", &self.min_confirmations)
+                            .field("keys", &self.keys)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub enum Initialization {
+                    OldState,
+                    Config(NodeConfig),
+                }
+                impl ::core::fmt::Debug for Initialization {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            Initialization::OldState => {
+                                f.debug_tuple("Initialization::OldState").finish()
+                            }
+                            Initialization::Config(e) => {
+                                f.debug_tuple("Initialization::Config").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                /// Payment asked for by a BIP21 `bitcoin:` URI
+                #[derive(Clone)]
+                pub struct PaymentRequest {
+                    pub address: _rt::String,
+                    pub amount: Option<u64>,
+                    pub label: Option<_rt::String>,
+                    pub message: Option<_rt::String>,
+                }
+                impl ::core::fmt::Debug for PaymentRequest {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("PaymentRequest")
+                            .field("address", &self.address)
+                            .field("amount", &self.amount)
+                            .field("label", &self.label)
+                            .field("message", &self.message)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct Recipient {
+                    pub address: _rt::String,
+                    pub amount: u64,
+                }
+                impl ::core::fmt::Debug for Recipient {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Recipient")
+                            .field("address", &self.address)
+                            .field("amount", &self.amount)
+                            .finish()
+                    }
+                }
+                #[derive(Clone)]
+                pub struct Outpoint {
+                    pub txid: _rt::String,
+                    pub vout: u32,
+                }
+                impl ::core::fmt::Debug for Outpoint {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("Outpoint")
+                            .field("txid", &self.txid)
+                            .field("vout", &self.vout)
+                            .finish()
+                    }
+                }
+                /// Unspent wallet output, for choosing by hand what a send spends
+                #[derive(Clone)]
+                pub struct UnspentOutput {
+                    pub txid: _rt::String,
+                    pub vout: u32,
+                    pub amount: u64,
+                    pub script: _rt::Vec<u8>,
+                    /// Paid back to the wallet's change keychain
+                    pub is_change: bool,
+                    /// Not set while the output is unconfirmed
+                    pub block_height: Option<u64>,
+                    pub confirmations: u32,
+                    pub is_coinbase: bool,
+                    /// Sends leave it alone until it is unfrozen
+                    pub is_frozen: bool,
+                }
+                impl ::core::fmt::Debug for UnspentOutput {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.debug_struct("UnspentOutput")
+                            .field("txid", &self.txid)
+                            .field("vout", &self.vout)
+                            .field("amount", &self.amount)
+                            .field("script", &self.script)
+                            .field("is-change", &self.is_change)
+                            .field("block-height", &self.block_height)
+                            .field("confirmations", &self.confirmations)
+                            .field("is-coinbase", &self.is_coinbase)
+                            .field("is-frozen", &self.is_frozen)
+                            .finish()
+                    }
+                }
+                /// Methods taking an address fail with 35 when it cannot be parsed, with 36 when it is
+                /// for another network than the node's and with 37 on a malformed or unpayable URI
+                ///
+                /// Methods that broadcast fail with 38 when the fee rate is below the fee filter of every
+                /// peer, so none would relay it, and with 39 when the signer cannot sign it
+                ///
+                /// Methods that build a transaction fail with 40 when a recipient would be paid less than
+                /// its script's dust limit, holding the recipient's index, and with 41 when asked to spend an
+                /// outpoint that is not a confirmed output of the wallet
+                ///
+                /// Bump-fee fails with 42 or 43 when the replacement pays less than the lowest fee rate or
+                /// absolute fee it needs, with 44 when the original does not signal replaceability and with
+                /// 45 when it was already mined
+                ///
+                /// Cpfp fails with 42 when the fee rate is not above the parent's own, with 45 when the
+                /// parent was already mined and with 46 when the parent pays nothing to the wallet
+                ///
+                /// Sends asked to spend a frozen output fail with 47, and freezing or unfreezing an outpoint
+                /// the wallet does not hold fails with 41
+
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct ClientNode {
+                    handle: _rt::Resource<ClientNode>,
+                }
+
+                type _ClientNodeRep<T> = Option<T>;
+
+                impl ClientNode {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `ClientNode`.
+                    pub fn new<T: GuestClientNode>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _ClientNodeRep<T> = Some(val);
+                        let ptr: *mut _ClientNodeRep<T> = _rt::Box::into_raw(_rt::Box::new(val));
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestClientNode>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestClientNode>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestClientNode>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: _rt::Resource::from_handle(handle),
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+
+                    // It's theoretically possible to implement the `GuestClientNode` trait twice
+                    // so guard against using it with two different types here.
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(!cfg!(target_feature = "threads"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => assert!(
+                                    ty == id,
+                                    "cannot use two types with this resource type"
+                                ),
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = _rt::Box::from_raw(handle as *mut _ClientNodeRep<T>);
+                    }
+
+                    fn as_ptr<T: GuestClientNode>(&self) -> *mut _ClientNodeRep<T> {
+                        ClientNode::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+
+                /// A borrowed version of [`ClientNode`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct ClientNodeBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a ClientNode>,
+                }
+
+                impl<'a> ClientNodeBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestClientNode>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+
+                    // NB: mutable access is not allowed due to the component model allowing
+                    // multiple borrows of the same resource.
+
+                    fn as_ptr<T: 'static>(&self) -> *mut _ClientNodeRep<T> {
+                        ClientNode::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+
+                unsafe impl _rt::WasmResource for ClientNode {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(wasm_import_module = "[export]component:node/types@0.1.0")]
+                            extern "C" {
+                                #[link_name = "[resource-drop]client-node"]
+                                fn drop(_: u32);
+                            }
+
+                            drop(_handle);
+                        }
+                    }
+                }
+
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_constructor_client_node_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    let v45 = match l0 {
+                        0 => Initialization::OldState,
+                        n => {
+                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                            let e45 = {
+                                let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                                let l2 = i32::from(*arg0.add(9).cast::<u8>());
+                                let l4 = i32::from(*arg0.add(12).cast::<u8>());
+                                let l5 = i32::from(*arg0.add(13).cast::<u8>());
+                                let l6 = i32::from(*arg0.add(14).cast::<u8>());
+                                let l7 = i32::from(*arg0.add(15).cast::<u8>());
+                                let l8 = i32::from(*arg0.add(16).cast::<u16>());
+                                let l9 = *arg0.add(20).cast::<*mut u8>();
+                                let l10 = *arg0.add(24).cast::<usize>();
+                                let len11 = l10;
+                                let l12 = i32::from(*arg0.add(28).cast::<u8>());
+                                let l14 = i32::from(*arg0.add(36).cast::<u8>());
+                                let l15 = i32::from(*arg0.add(40).cast::<u8>());
+                                let l20 = i32::from(*arg0.add(64).cast::<u8>());
+                                let l22 = i32::from(*arg0.add(72).cast::<u8>());
+                                let l24 = i32::from(*arg0.add(80).cast::<u8>());
+                                let v44 = match l24 {
+                                    0 => {
+                                        let e44 = {
+                                            let l25 = *arg0.add(84).cast::<*mut u8>();
+                                            let l26 = *arg0.add(88).cast::<usize>();
+                                            let len27 = l26;
+                                            let bytes27 =
+                                                _rt::Vec::from_raw_parts(l25.cast(), len27, len27);
+
+                                            _rt::string_lift(bytes27)
+                                        };
+                                        NodeKeys::Xpriv(e44)
+                                    }
+                                    1 => {
+                                        let e44 = {
+                                            let l28 = *arg0.add(84).cast::<*mut u8>();
+                                            let l29 = *arg0.add(88).cast::<usize>();
+                                            let len30 = l29;
+                                            let bytes30 =
+                                                _rt::Vec::from_raw_parts(l28.cast(), len30, len30);
+                                            let l31 = *arg0.add(92).cast::<*mut u8>();
+                                            let l32 = *arg0.add(96).cast::<usize>();
+                                            let len33 = l32;
+                                            let bytes33 =
+                                                _rt::Vec::from_raw_parts(l31.cast(), len33, len33);
+                                            let l34 = *arg0.add(100).cast::<*mut u8>();
+                                            let l35 = *arg0.add(104).cast::<usize>();
+                                            let len36 = l35;
+                                            let bytes36 =
+                                                _rt::Vec::from_raw_parts(l34.cast(), len36, len36);
+
+                                            WatchAccount {
+                                                xpub: _rt::string_lift(bytes30),
+                                                account_derivation: _rt::string_lift(bytes33),
+                                                master_fingerprint: _rt::string_lift(bytes36),
+                                            }
+                                        };
+                                        NodeKeys::Account(e44)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 2, "invalid enum discriminant");
+                                        let e44 = {
+                                            let l37 = *arg0.add(84).cast::<*mut u8>();
+                                            let l38 = *arg0.add(88).cast::<usize>();
+                                            let len39 = l38;
+                                            let bytes39 =
+                                                _rt::Vec::from_raw_parts(l37.cast(), len39, len39);
+                                            let l40 = i32::from(*arg0.add(92).cast::<u8>());
+
+                                            WatchDescriptors {
+                                                external: _rt::string_lift(bytes39),
+                                                internal: match l40 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l41 =
+                                                                *arg0.add(96).cast::<*mut u8>();
+                                                            let l42 =
+                                                                *arg0.add(100).cast::<usize>();
+                                                            let len43 = l42;
+                                                            let bytes43 = _rt::Vec::from_raw_parts(
+                                                                l41.cast(),
+                                                                len43,
+                                                                len43,
+                                                            );
+
+                                                            _rt::string_lift(bytes43)
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                            }
+                                        };
+                                        NodeKeys::Descriptors(e44)
+                                    }
+                                };
+
+                                NodeConfig {
+                                    network: BitcoinNetwork::_lift(l1 as u8),
+                                    wallet_type: match l2 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l3 = i32::from(*arg0.add(10).cast::<u8>());
+
+                                                WalletType::_lift(l3 as u8)
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    socket_address: Ipv4SocketAdress {
+                                        address: (l4 as u8, l5 as u8, l6 as u8, l7 as u8),
+                                        port: l8 as u16,
+                                    },
+                                    peers: _rt::Vec::from_raw_parts(l9.cast(), len11, len11),
+                                    max_connections: match l12 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l13 = *arg0.add(32).cast::<i32>();
+
+                                                l13 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    use_seeds: _rt::bool_lift(l14 as u8),
+                                    birthday: match l15 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l16 = i32::from(*arg0.add(48).cast::<u8>());
+                                                let v19 = match l16 {
+                                                    0 => {
+                                                        let e19 = {
+                                                            let l17 = *arg0.add(56).cast::<i64>();
+
+                                                            l17 as u64
+                                                        };
+                                                        WalletBirthday::Height(e19)
+                                                    }
+                                                    n => {
+                                                        debug_assert_eq!(
+                                                            n, 1,
+                                                            "invalid enum discriminant"
+                                                        );
+                                                        let e19 = {
+                                                            let l18 = *arg0.add(56).cast::<i64>();
+
+                                                            l18 as u64
+                                                        };
+                                                        WalletBirthday::Timestamp(e19)
+                                                    }
+                                                };
+
+                                                v19
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    lookahead: match l20 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l21 = *arg0.add(68).cast::<i32>();
+
+                                                l21 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    min_confirmations: match l22 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l23 = *arg0.add(76).cast::<i32>();
+
+                                                l23 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    keys: v44,
+                                }
+                            };
+                            Initialization::Config(e45)
+                        }
+                    };
+                    let result46 = ClientNode::new(T::new(v45));
+                    _rt::cabi_dealloc(arg0, 112, 8);
+                    (result46).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_static_client_node_create_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    let v45 = match l0 {
+                        0 => Initialization::OldState,
+                        n => {
+                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                            let e45 = {
+                                let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                                let l2 = i32::from(*arg0.add(9).cast::<u8>());
+                                let l4 = i32::from(*arg0.add(12).cast::<u8>());
+                                let l5 = i32::from(*arg0.add(13).cast::<u8>());
+                                let l6 = i32::from(*arg0.add(14).cast::<u8>());
+                                let l7 = i32::from(*arg0.add(15).cast::<u8>());
+                                let l8 = i32::from(*arg0.add(16).cast::<u16>());
+                                let l9 = *arg0.add(20).cast::<*mut u8>();
+                                let l10 = *arg0.add(24).cast::<usize>();
+                                let len11 = l10;
+                                let l12 = i32::from(*arg0.add(28).cast::<u8>());
+                                let l14 = i32::from(*arg0.add(36).cast::<u8>());
+                                let l15 = i32::from(*arg0.add(40).cast::<u8>());
+                                let l20 = i32::from(*arg0.add(64).cast::<u8>());
+                                let l22 = i32::from(*arg0.add(72).cast::<u8>());
+                                let l24 = i32::from(*arg0.add(80).cast::<u8>());
+                                let v44 = match l24 {
+                                    0 => {
+                                        let e44 = {
+                                            let l25 = *arg0.add(84).cast::<*mut u8>();
+                                            let l26 = *arg0.add(88).cast::<usize>();
+                                            let len27 = l26;
+                                            let bytes27 =
+                                                _rt::Vec::from_raw_parts(l25.cast(), len27, len27);
+
+                                            _rt::string_lift(bytes27)
+                                        };
+                                        NodeKeys::Xpriv(e44)
+                                    }
+                                    1 => {
+                                        let e44 = {
+                                            let l28 = *arg0.add(84).cast::<*mut u8>();
+                                            let l29 = *arg0.add(88).cast::<usize>();
+                                            let len30 = l29;
+                                            let bytes30 =
+                                                _rt::Vec::from_raw_parts(l28.cast(), len30, len30);
+                                            let l31 = *arg0.add(92).cast::<*mut u8>();
+                                            let l32 = *arg0.add(96).cast::<usize>();
+                                            let len33 = l32;
+                                            let bytes33 =
+                                                _rt::Vec::from_raw_parts(l31.cast(), len33, len33);
+                                            let l34 = *arg0.add(100).cast::<*mut u8>();
+                                            let l35 = *arg0.add(104).cast::<usize>();
+                                            let len36 = l35;
+                                            let bytes36 =
+                                                _rt::Vec::from_raw_parts(l34.cast(), len36, len36);
+
+                                            WatchAccount {
+                                                xpub: _rt::string_lift(bytes30),
+                                                account_derivation: _rt::string_lift(bytes33),
+                                                master_fingerprint: _rt::string_lift(bytes36),
+                                            }
+                                        };
+                                        NodeKeys::Account(e44)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 2, "invalid enum discriminant");
+                                        let e44 = {
+                                            let l37 = *arg0.add(84).cast::<*mut u8>();
+                                            let l38 = *arg0.add(88).cast::<usize>();
+                                            let len39 = l38;
+                                            let bytes39 =
+                                                _rt::Vec::from_raw_parts(l37.cast(), len39, len39);
+                                            let l40 = i32::from(*arg0.add(92).cast::<u8>());
+
+                                            WatchDescriptors {
+                                                external: _rt::string_lift(bytes39),
+                                                internal: match l40 {
+                                                    0 => None,
+                                                    1 => {
+                                                        let e = {
+                                                            let l41 =
+                                                                *arg0.add(96).cast::<*mut u8>();
+                                                            let l42 =
+                                                                *arg0.add(100).cast::<usize>();
+                                                            let len43 = l42;
+                                                            let bytes43 = _rt::Vec::from_raw_parts(
+                                                                l41.cast(),
+                                                                len43,
+                                                                len43,
+                                                            );
+
+                                                            _rt::string_lift(bytes43)
+                                                        };
+                                                        Some(e)
+                                                    }
+                                                    _ => _rt::invalid_enum_discriminant(),
+                                                },
+                                            }
+                                        };
+                                        NodeKeys::Descriptors(e44)
+                                    }
+                                };
+
+                                NodeConfig {
+                                    network: BitcoinNetwork::_lift(l1 as u8),
+                                    wallet_type: match l2 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l3 = i32::from(*arg0.add(10).cast::<u8>());
+
+                                                WalletType::_lift(l3 as u8)
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    socket_address: Ipv4SocketAdress {
+                                        address: (l4 as u8, l5 as u8, l6 as u8, l7 as u8),
+                                        port: l8 as u16,
+                                    },
+                                    peers: _rt::Vec::from_raw_parts(l9.cast(), len11, len11),
+                                    max_connections: match l12 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l13 = *arg0.add(32).cast::<i32>();
+
+                                                l13 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    use_seeds: _rt::bool_lift(l14 as u8),
+                                    birthday: match l15 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l16 = i32::from(*arg0.add(48).cast::<u8>());
+                                                let v19 = match l16 {
+                                                    0 => {
+                                                        let e19 = {
+                                                            let l17 = *arg0.add(56).cast::<i64>();
+
+                                                            l17 as u64
+                                                        };
+                                                        WalletBirthday::Height(e19)
+                                                    }
+                                                    n => {
+                                                        debug_assert_eq!(
+                                                            n, 1,
+                                                            "invalid enum discriminant"
+                                                        );
+                                                        let e19 = {
+                                                            let l18 = *arg0.add(56).cast::<i64>();
+
+                                                            l18 as u64
+                                                        };
+                                                        WalletBirthday::Timestamp(e19)
+                                                    }
+                                                };
+
+                                                v19
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    lookahead: match l20 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l21 = *arg0.add(68).cast::<i32>();
+
+                                                l21 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    min_confirmations: match l22 {
+                                        0 => None,
+                                        1 => {
+                                            let e = {
+                                                let l23 = *arg0.add(76).cast::<i32>();
+
+                                                l23 as u32
+                                            };
+                                            Some(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    },
+                                    keys: v44,
+                                }
+                            };
+                            Initialization::Config(e45)
+                        }
+                    };
+                    let result46 = T::create(v45);
+                    _rt::cabi_dealloc(arg0, 112, 8);
+                    let ptr47 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result46 {
+                        Ok(e) => {
+                            *ptr47.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr47.add(4).cast::<i32>() = (e).take_handle() as i32;
+                        }
+                        Err(e) => {
+                            *ptr47.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr47.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr47
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_get_balance_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 =
+                        T::get_balance(ClientNodeBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr1.add(8).cast::<i64>() = _rt::as_i64(e);
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr1.add(8).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_get_pending_balance_cabi<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 =
+                        T::get_pending_balance(ClientNodeBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            *ptr1.add(8).cast::<i64>() = _rt::as_i64(e);
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr1.add(8).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_get_balance_breakdown_cabi<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::get_balance_breakdown(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                    );
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let Balance {
+                                confirmed: confirmed2,
+                                trusted_pending: trusted_pending2,
+                                untrusted_pending: untrusted_pending2,
+                                immature: immature2,
+                            } = e;
+                            *ptr1.add(8).cast::<i64>() = _rt::as_i64(confirmed2);
+                            *ptr1.add(16).cast::<i64>() = _rt::as_i64(trusted_pending2);
+                            *ptr1.add(24).cast::<i64>() = _rt::as_i64(untrusted_pending2);
+                            *ptr1.add(32).cast::<i64>() = _rt::as_i64(immature2);
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr1.add(8).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_get_receive_address_cabi<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 =
+                        T::get_receive_address(ClientNodeBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec2 = (e.into_bytes()).into_boxed_slice();
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            ::core::mem::forget(vec2);
+                            *ptr1.add(8).cast::<usize>() = len2;
+                            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr1.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_node_get_receive_address<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                        _ => (),
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_send_to_address_cabi<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                    arg4: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::send_to_address(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        arg3 as u64,
+                        arg4 as u64,
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_send_many_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                    arg4: *mut u8,
+                    arg5: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let base4 = arg1;
+                    let len4 = arg2;
+                    let mut result4 = _rt::Vec::with_capacity(len4);
+                    for i in 0..len4 {
+                        let base = base4.add(i * 16);
+                        let e4 = {
+                            let l0 = *base.add(0).cast::<*mut u8>();
+                            let l1 = *base.add(4).cast::<usize>();
+                            let len2 = l1;
+                            let bytes2 = _rt::Vec::from_raw_parts(l0.cast(), len2, len2);
+                            let l3 = *base.add(8).cast::<i64>();
+
+                            Recipient {
+                                address: _rt::string_lift(bytes2),
+                                amount: l3 as u64,
+                            }
+                        };
+                        result4.push(e4);
+                    }
+                    _rt::cabi_dealloc(base4, len4 * 16, 8);
+                    let base9 = arg4;
+                    let len9 = arg5;
+                    let mut result9 = _rt::Vec::with_capacity(len9);
+                    for i in 0..len9 {
+                        let base = base9.add(i * 12);
+                        let e9 = {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base.add(4).cast::<usize>();
+                            let len7 = l6;
+                            let bytes7 = _rt::Vec::from_raw_parts(l5.cast(), len7, len7);
+                            let l8 = *base.add(8).cast::<i32>();
+
+                            Outpoint {
+                                txid: _rt::string_lift(bytes7),
+                                vout: l8 as u32,
+                            }
+                        };
+                        result9.push(e9);
+                    }
+                    _rt::cabi_dealloc(base9, len9 * 12, 4);
+                    let result10 = T::send_many(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        result4,
+                        arg3 as u64,
+                        result9,
+                    );
+                    let ptr11 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result10 {
+                        Ok(_) => {
+                            *ptr11.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr11.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr11.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr11
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_drain_to_address_cabi<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                    arg4: i32,
+                    arg5: *mut u8,
+                    arg6: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result6 = T::drain_to_address(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        arg3 as u64,
+                        match arg4 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let base5 = arg5;
+                                    let len5 = arg6;
+                                    let mut result5 = _rt::Vec::with_capacity(len5);
+                                    for i in 0..len5 {
+                                        let base = base5.add(i * 12);
+                                        let e5 = {
+                                            let l1 = *base.add(0).cast::<*mut u8>();
+                                            let l2 = *base.add(4).cast::<usize>();
+                                            let len3 = l2;
+                                            let bytes3 =
+                                                _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                                            let l4 = *base.add(8).cast::<i32>();
+
+                                            Outpoint {
+                                                txid: _rt::string_lift(bytes3),
+                                                vout: l4 as u32,
+                                            }
+                                        };
+                                        result5.push(e5);
+                                    }
+                                    _rt::cabi_dealloc(base5, len5 * 12, 4);
+
+                                    result5
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr7 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result6 {
+                        Ok(_) => {
+                            *ptr7.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr7.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr7.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr7
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_bump_fee_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::bump_fee(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        arg3 as u64,
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec3 = (e.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *ptr2.add(8).cast::<usize>() = len3;
+                            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_node_bump_fee<T: GuestClientNode>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                        _ => (),
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_cpfp_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::cpfp(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        arg3 as u64,
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec3 = (e.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *ptr2.add(8).cast::<usize>() = len3;
+                            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_node_cpfp<T: GuestClientNode>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                        _ => (),
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_send_to_uri_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::send_to_uri(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        arg3 as u64,
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_parse_payment_uri_cabi<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::parse_payment_uri(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let PaymentRequest {
+                                address: address3,
+                                amount: amount3,
+                                label: label3,
+                                message: message3,
+                            } = e;
+                            let vec4 = (address3.into_bytes()).into_boxed_slice();
+                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                            let len4 = vec4.len();
+                            ::core::mem::forget(vec4);
+                            *ptr2.add(12).cast::<usize>() = len4;
+                            *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                            match amount3 {
+                                Some(e) => {
+                                    *ptr2.add(16).cast::<u8>() = (1i32) as u8;
+                                    *ptr2.add(24).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                None => {
+                                    *ptr2.add(16).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match label3 {
+                                Some(e) => {
+                                    *ptr2.add(32).cast::<u8>() = (1i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr2.add(40).cast::<usize>() = len5;
+                                    *ptr2.add(36).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                None => {
+                                    *ptr2.add(32).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match message3 {
+                                Some(e) => {
+                                    *ptr2.add(44).cast::<u8>() = (1i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2.add(52).cast::<usize>() = len6;
+                                    *ptr2.add(48).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                None => {
+                                    *ptr2.add(44).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(8).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_node_parse_payment_uri<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0.add(12).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = i32::from(*arg0.add(32).cast::<u8>());
+                            match l3 {
+                                0 => (),
+                                _ => {
+                                    let l4 = *arg0.add(36).cast::<*mut u8>();
+                                    let l5 = *arg0.add(40).cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                            }
+                            let l6 = i32::from(*arg0.add(44).cast::<u8>());
+                            match l6 {
+                                0 => (),
+                                _ => {
+                                    let l7 = *arg0.add(48).cast::<*mut u8>();
+                                    let l8 = *arg0.add(52).cast::<usize>();
+                                    _rt::cabi_dealloc(l7, l8, 1);
+                                }
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_create_psbt_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                    arg4: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::create_psbt(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        arg3 as u64,
+                        arg4 as u64,
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec3 = (e).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *ptr2.add(8).cast::<usize>() = len3;
+                            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_node_create_psbt<T: GuestClientNode>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 1, 1);
                         }
+                        _ => (),
                     }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_create_drain_psbt_cabi<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i64,
+                    arg4: i32,
+                    arg5: *mut u8,
+                    arg6: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result6 = T::create_drain_psbt(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::string_lift(bytes0),
+                        arg3 as u64,
+                        match arg4 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let base5 = arg5;
+                                    let len5 = arg6;
+                                    let mut result5 = _rt::Vec::with_capacity(len5);
+                                    for i in 0..len5 {
+                                        let base = base5.add(i * 12);
+                                        let e5 = {
+                                            let l1 = *base.add(0).cast::<*mut u8>();
+                                            let l2 = *base.add(4).cast::<usize>();
+                                            let len3 = l2;
+                                            let bytes3 =
+                                                _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                                            let l4 = *base.add(8).cast::<i32>();
+
+                                            Outpoint {
+                                                txid: _rt::string_lift(bytes3),
+                                                vout: l4 as u32,
+                                            }
+                                        };
+                                        result5.push(e5);
+                                    }
+                                    _rt::cabi_dealloc(base5, len5 * 12, 4);
 
-                    #[doc(hidden)]
-                    pub fn take_handle(&self) -> u32 {
-                        _rt::Resource::take_handle(&self.handle)
-                    }
-
-                    #[doc(hidden)]
-                    pub fn handle(&self) -> u32 {
-                        _rt::Resource::handle(&self.handle)
-                    }
-
-                    // It's theoretically possible to implement the `GuestClientNode` trait twice
-                    // so guard against using it with two different types here.
-                    #[doc(hidden)]
-                    fn type_guard<T: 'static>() {
-                        use core::any::TypeId;
-                        static mut LAST_TYPE: Option<TypeId> = None;
-                        unsafe {
-                            assert!(!cfg!(target_feature = "threads"));
-                            let id = TypeId::of::<T>();
-                            match LAST_TYPE {
-                                Some(ty) => assert!(
-                                    ty == id,
-                                    "cannot use two types with this resource type"
-                                ),
-                                None => LAST_TYPE = Some(id),
+                                    result5
+                                };
+                                Some(e)
                             }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr7 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result6 {
+                        Ok(e) => {
+                            *ptr7.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec8 = (e).into_boxed_slice();
+                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                            let len8 = vec8.len();
+                            ::core::mem::forget(vec8);
+                            *ptr7.add(8).cast::<usize>() = len8;
+                            *ptr7.add(4).cast::<*mut u8>() = ptr8.cast_mut();
                         }
-                    }
-
-                    #[doc(hidden)]
-                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
-                        Self::type_guard::<T>();
-                        let _ = _rt::Box::from_raw(handle as *mut _ClientNodeRep<T>);
-                    }
-
-                    fn as_ptr<T: GuestClientNode>(&self) -> *mut _ClientNodeRep<T> {
-                        ClientNode::type_guard::<T>();
-                        T::_resource_rep(self.handle()).cast()
+                        Err(e) => {
+                            *ptr7.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr7.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr7
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_node_create_drain_psbt<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 1, 1);
+                        }
+                        _ => (),
                     }
                 }
-
-                /// A borrowed version of [`ClientNode`] which represents a borrowed value
-                /// with the lifetime `'a`.
-                #[derive(Debug)]
-                #[repr(transparent)]
-                pub struct ClientNodeBorrow<'a> {
-                    rep: *mut u8,
-                    _marker: core::marker::PhantomData<&'a ClientNode>,
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_broadcast_psbt_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let result1 = T::broadcast_psbt(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec3 = (e.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *ptr2.add(8).cast::<usize>() = len3;
+                            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr2
                 }
-
-                impl<'a> ClientNodeBorrow<'a> {
-                    #[doc(hidden)]
-                    pub unsafe fn lift(rep: usize) -> Self {
-                        Self {
-                            rep: rep as *mut u8,
-                            _marker: core::marker::PhantomData,
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_node_broadcast_psbt<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(4).cast::<*mut u8>();
+                            let l2 = *arg0.add(8).cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
                         }
-                    }
-
-                    /// Gets access to the underlying `T` in this resource.
-                    pub fn get<T: GuestClientNode>(&self) -> &T {
-                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
-                        ptr.as_ref().unwrap()
-                    }
-
-                    // NB: mutable access is not allowed due to the component model allowing
-                    // multiple borrows of the same resource.
-
-                    fn as_ptr<T: 'static>(&self) -> *mut _ClientNodeRep<T> {
-                        ClientNode::type_guard::<T>();
-                        self.rep.cast()
+                        _ => (),
                     }
                 }
-
-                unsafe impl _rt::WasmResource for ClientNode {
-                    #[inline]
-                    unsafe fn drop(_handle: u32) {
-                        #[cfg(not(target_arch = "wasm32"))]
-                        unreachable!();
-
-                        #[cfg(target_arch = "wasm32")]
-                        {
-                            #[link(wasm_import_module = "[export]component:node/types@0.1.0")]
-                            extern "C" {
-                                #[link_name = "[resource-drop]client-node"]
-                                fn drop(_: u32);
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_list_unspent_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 =
+                        T::list_unspent(ClientNodeBorrow::lift(arg0 as u32 as usize).get());
+                    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec5 = e;
+                            let len5 = vec5.len();
+                            let layout5 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec5.len() * 64, 8);
+                            let result5 = if layout5.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout5);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec5.into_iter().enumerate() {
+                                let base = result5.add(i * 64);
+                                {
+                                    let UnspentOutput {
+                                        txid: txid2,
+                                        vout: vout2,
+                                        amount: amount2,
+                                        script: script2,
+                                        is_change: is_change2,
+                                        block_height: block_height2,
+                                        confirmations: confirmations2,
+                                        is_coinbase: is_coinbase2,
+                                        is_frozen: is_frozen2,
+                                    } = e;
+                                    let vec3 = (txid2.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *base.add(4).cast::<usize>() = len3;
+                                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(vout2);
+                                    *base.add(16).cast::<i64>() = _rt::as_i64(amount2);
+                                    let vec4 = (script2).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *base.add(28).cast::<usize>() = len4;
+                                    *base.add(24).cast::<*mut u8>() = ptr4.cast_mut();
+                                    *base.add(32).cast::<u8>() = (match is_change2 {
+                                        true => 1,
+                                        false => 0,
+                                    })
+                                        as u8;
+                                    match block_height2 {
+                                        Some(e) => {
+                                            *base.add(40).cast::<u8>() = (1i32) as u8;
+                                            *base.add(48).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *base.add(40).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base.add(56).cast::<i32>() = _rt::as_i32(confirmations2);
+                                    *base.add(60).cast::<u8>() = (match is_coinbase2 {
+                                        true => 1,
+                                        false => 0,
+                                    })
+                                        as u8;
+                                    *base.add(61).cast::<u8>() = (match is_frozen2 {
+                                        true => 1,
+                                        false => 0,
+                                    })
+                                        as u8;
+                                }
                             }
-
-                            drop(_handle);
+                            *ptr1.add(8).cast::<usize>() = len5;
+                            *ptr1.add(4).cast::<*mut u8>() = result5;
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr1.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_node_list_unspent<T: GuestClientNode>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l6 = *arg0.add(4).cast::<*mut u8>();
+                            let l7 = *arg0.add(8).cast::<usize>();
+                            let base8 = l6;
+                            let len8 = l7;
+                            for i in 0..len8 {
+                                let base = base8.add(i * 64);
+                                {
+                                    let l1 = *base.add(0).cast::<*mut u8>();
+                                    let l2 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l1, l2, 1);
+                                    let l3 = *base.add(24).cast::<*mut u8>();
+                                    let l4 = *base.add(28).cast::<usize>();
+                                    let base5 = l3;
+                                    let len5 = l4;
+                                    _rt::cabi_dealloc(base5, len5 * 1, 1);
+                                }
+                            }
+                            _rt::cabi_dealloc(base8, len8 * 64, 8);
                         }
+                        _ => (),
                     }
                 }
-
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_constructor_client_node_cabi<T: GuestClientNode>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
+                pub unsafe fn _export_method_client_node_freeze_utxo_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
                     arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
-                    arg6: i32,
-                    arg7: *mut u8,
-                    arg8: usize,
-                ) -> i32 {
+                ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let v1 = match arg0 {
-                        0 => Initialization::OldState,
-                        n => {
-                            debug_assert_eq!(n, 1, "invalid enum discriminant");
-                            let e1 = {
-                                let len0 = arg8;
-                                let bytes0 = _rt::Vec::from_raw_parts(arg7.cast(), len0, len0);
-
-                                NodeConfig {
-                                    network: BitcoinNetwork::_lift(arg1 as u8),
-                                    socket_address: Ipv4SocketAdress {
-                                        address: (arg2 as u8, arg3 as u8, arg4 as u8, arg5 as u8),
-                                        port: arg6 as u16,
-                                    },
-                                    xpriv: _rt::string_lift(bytes0),
-                                }
-                            };
-                            Initialization::Config(e1)
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::freeze_utxo(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        Outpoint {
+                            txid: _rt::string_lift(bytes0),
+                            vout: arg3 as u32,
+                        },
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(4).cast::<i32>() = _rt::as_i32(e);
                         }
                     };
-                    let result2 = ClientNode::new(T::new(v1));
-                    (result2).take_handle() as i32
+                    ptr2
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_client_node_get_balance_cabi<T: GuestClientNode>(
+                pub unsafe fn _export_method_client_node_unfreeze_utxo_cabi<T: GuestClientNode>(
                     arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let result0 =
-                        T::get_balance(ClientNodeBorrow::lift(arg0 as u32 as usize).get());
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::unfreeze_utxo(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        Outpoint {
+                            txid: _rt::string_lift(bytes0),
+                            vout: arg3 as u32,
+                        },
+                    );
+                    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+                    match result1 {
+                        Ok(_) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr2.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_node_rescan_cabi<T: GuestClientNode>(
+                    arg0: *mut u8,
+                    arg1: i64,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")]
+                    _rt::run_ctors_once();
+                    let result0 = T::rescan(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u64,
+                    );
                     let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
                     match result0 {
-                        Ok(e) => {
+                        Ok(_) => {
                             *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                            *ptr1.add(8).cast::<i64>() = _rt::as_i64(e);
                         }
                         Err(e) => {
                             *ptr1.add(0).cast::<u8>() = (1i32) as u8;
-                            *ptr1.add(8).cast::<i32>() = _rt::as_i32(e);
+                            *ptr1.add(4).cast::<i32>() = _rt::as_i32(e);
                         }
                     };
                     ptr1
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_client_node_get_receive_address_cabi<
+                pub unsafe fn _export_method_client_node_list_transactions_cabi<
                     T: GuestClientNode,
                 >(
                     arg0: *mut u8,
+                    arg1: i32,
+                    arg2: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
-                    let result0 =
-                        T::get_receive_address(ClientNodeBorrow::lift(arg0 as u32 as usize).get());
+                    let result0 = T::list_transactions(
+                        ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
+                        arg1 as u32,
+                        arg2 as u32,
+                    );
                     let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
                     match result0 {
                         Ok(e) => {
                             *ptr1.add(0).cast::<u8>() = (0i32) as u8;
-                            let vec2 = (e.into_bytes()).into_boxed_slice();
-                            let ptr2 = vec2.as_ptr().cast::<u8>();
-                            let len2 = vec2.len();
-                            ::core::mem::forget(vec2);
-                            *ptr1.add(8).cast::<usize>() = len2;
-                            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+                            let vec11 = e;
+                            let len11 = vec11.len();
+                            let layout11 =
+                                _rt::alloc::Layout::from_size_align_unchecked(vec11.len() * 96, 8);
+                            let result11 = if layout11.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout11);
+                                }
+                                ptr
+                            } else {
+                                {
+                                    ::core::ptr::null_mut()
+                                }
+                            };
+                            for (i, e) in vec11.into_iter().enumerate() {
+                                let base = result11.add(i * 96);
+                                {
+                                    let TransactionDetails {
+                                        txid: txid2,
+                                        block_height: block_height2,
+                                        block_hash: block_hash2,
+                                        timestamp: timestamp2,
+                                        net_amount: net_amount2,
+                                        fee: fee2,
+                                        direction: direction2,
+                                        inputs: inputs2,
+                                        outputs: outputs2,
+                                    } = e;
+                                    let vec3 = (txid2.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *base.add(4).cast::<usize>() = len3;
+                                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                                    match block_height2 {
+                                        Some(e) => {
+                                            *base.add(8).cast::<u8>() = (1i32) as u8;
+                                            *base.add(16).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *base.add(8).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match block_hash2 {
+                                        Some(e) => {
+                                            *base.add(24).cast::<u8>() = (1i32) as u8;
+                                            let vec4 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                                            let len4 = vec4.len();
+                                            ::core::mem::forget(vec4);
+                                            *base.add(32).cast::<usize>() = len4;
+                                            *base.add(28).cast::<*mut u8>() = ptr4.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base.add(40).cast::<i64>() = _rt::as_i64(timestamp2);
+                                    *base.add(48).cast::<i64>() = _rt::as_i64(net_amount2);
+                                    match fee2 {
+                                        Some(e) => {
+                                            *base.add(56).cast::<u8>() = (1i32) as u8;
+                                            *base.add(64).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *base.add(56).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *base.add(72).cast::<u8>() = (direction2.clone() as i32) as u8;
+                                    let vec7 = inputs2;
+                                    let len7 = vec7.len();
+                                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec7.len() * 24,
+                                        8,
+                                    );
+                                    let result7 = if layout7.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout7);
+                                        }
+                                        ptr
+                                    } else {
+                                        {
+                                            ::core::ptr::null_mut()
+                                        }
+                                    };
+                                    for (i, e) in vec7.into_iter().enumerate() {
+                                        let base = result7.add(i * 24);
+                                        {
+                                            let WalletInput {
+                                                txid: txid5,
+                                                vout: vout5,
+                                                amount: amount5,
+                                            } = e;
+                                            let vec6 = (txid5.into_bytes()).into_boxed_slice();
+                                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                                            let len6 = vec6.len();
+                                            ::core::mem::forget(vec6);
+                                            *base.add(4).cast::<usize>() = len6;
+                                            *base.add(0).cast::<*mut u8>() = ptr6.cast_mut();
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(vout5);
+                                            *base.add(16).cast::<i64>() = _rt::as_i64(amount5);
+                                        }
+                                    }
+                                    *base.add(80).cast::<usize>() = len7;
+                                    *base.add(76).cast::<*mut u8>() = result7;
+                                    let vec10 = outputs2;
+                                    let len10 = vec10.len();
+                                    let layout10 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec10.len() * 32,
+                                        8,
+                                    );
+                                    let result10 = if layout10.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout10).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout10);
+                                        }
+                                        ptr
+                                    } else {
+                                        {
+                                            ::core::ptr::null_mut()
+                                        }
+                                    };
+                                    for (i, e) in vec10.into_iter().enumerate() {
+                                        let base = result10.add(i * 32);
+                                        {
+                                            let WalletOutput {
+                                                vout: vout8,
+                                                amount: amount8,
+                                                script: script8,
+                                                is_change: is_change8,
+                                            } = e;
+                                            *base.add(0).cast::<i32>() = _rt::as_i32(vout8);
+                                            *base.add(8).cast::<i64>() = _rt::as_i64(amount8);
+                                            let vec9 = (script8).into_boxed_slice();
+                                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                                            let len9 = vec9.len();
+                                            ::core::mem::forget(vec9);
+                                            *base.add(20).cast::<usize>() = len9;
+                                            *base.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                            *base.add(24).cast::<u8>() = (match is_change8 {
+                                                true => 1,
+                                                false => 0,
+                                            })
+                                                as u8;
+                                        }
+                                    }
+                                    *base.add(88).cast::<usize>() = len10;
+                                    *base.add(84).cast::<*mut u8>() = result10;
+                                }
+                            }
+                            *ptr1.add(8).cast::<usize>() = len11;
+                            *ptr1.add(4).cast::<*mut u8>() = result11;
                         }
                         Err(e) => {
                             *ptr1.add(0).cast::<u8>() = (1i32) as u8;
@@ -1814,7 +8270,7 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn __post_return_method_client_node_get_receive_address<
+                pub unsafe fn __post_return_method_client_node_list_transactions<
                     T: GuestClientNode,
                 >(
                     arg0: *mut u8,
@@ -1822,45 +8278,289 @@ pub mod exports {
                     let l0 = i32::from(*arg0.add(0).cast::<u8>());
                     match l0 {
                         0 => {
-                            let l1 = *arg0.add(4).cast::<*mut u8>();
-                            let l2 = *arg0.add(8).cast::<usize>();
-                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l17 = *arg0.add(4).cast::<*mut u8>();
+                            let l18 = *arg0.add(8).cast::<usize>();
+                            let base19 = l17;
+                            let len19 = l18;
+                            for i in 0..len19 {
+                                let base = base19.add(i * 96);
+                                {
+                                    let l1 = *base.add(0).cast::<*mut u8>();
+                                    let l2 = *base.add(4).cast::<usize>();
+                                    _rt::cabi_dealloc(l1, l2, 1);
+                                    let l3 = i32::from(*base.add(24).cast::<u8>());
+                                    match l3 {
+                                        0 => (),
+                                        _ => {
+                                            let l4 = *base.add(28).cast::<*mut u8>();
+                                            let l5 = *base.add(32).cast::<usize>();
+                                            _rt::cabi_dealloc(l4, l5, 1);
+                                        }
+                                    }
+                                    let l8 = *base.add(76).cast::<*mut u8>();
+                                    let l9 = *base.add(80).cast::<usize>();
+                                    let base10 = l8;
+                                    let len10 = l9;
+                                    for i in 0..len10 {
+                                        let base = base10.add(i * 24);
+                                        {
+                                            let l6 = *base.add(0).cast::<*mut u8>();
+                                            let l7 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l6, l7, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base10, len10 * 24, 8);
+                                    let l14 = *base.add(84).cast::<*mut u8>();
+                                    let l15 = *base.add(88).cast::<usize>();
+                                    let base16 = l14;
+                                    let len16 = l15;
+                                    for i in 0..len16 {
+                                        let base = base16.add(i * 32);
+                                        {
+                                            let l11 = *base.add(16).cast::<*mut u8>();
+                                            let l12 = *base.add(20).cast::<usize>();
+                                            let base13 = l11;
+                                            let len13 = l12;
+                                            _rt::cabi_dealloc(base13, len13 * 1, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base16, len16 * 32, 8);
+                                }
+                            }
+                            _rt::cabi_dealloc(base19, len19 * 96, 8);
                         }
                         _ => (),
                     }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_method_client_node_send_to_address_cabi<
+                pub unsafe fn _export_method_client_node_get_transaction_cabi<
                     T: GuestClientNode,
                 >(
                     arg0: *mut u8,
                     arg1: *mut u8,
                     arg2: usize,
-                    arg3: i64,
-                    arg4: i64,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")]
                     _rt::run_ctors_once();
                     let len0 = arg2;
-                    let result1 = T::send_to_address(
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::get_transaction(
                         ClientNodeBorrow::lift(arg0 as u32 as usize).get(),
-                        _rt::Vec::from_raw_parts(arg1.cast(), len0, len0),
-                        arg3 as u64,
-                        arg4 as u64,
+                        _rt::string_lift(bytes0),
                     );
                     let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
                     match result1 {
-                        Ok(_) => {
+                        Ok(e) => {
                             *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            match e {
+                                Some(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                    let TransactionDetails {
+                                        txid: txid3,
+                                        block_height: block_height3,
+                                        block_hash: block_hash3,
+                                        timestamp: timestamp3,
+                                        net_amount: net_amount3,
+                                        fee: fee3,
+                                        direction: direction3,
+                                        inputs: inputs3,
+                                        outputs: outputs3,
+                                    } = e;
+                                    let vec4 = (txid3.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr2.add(20).cast::<usize>() = len4;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                    match block_height3 {
+                                        Some(e) => {
+                                            *ptr2.add(24).cast::<u8>() = (1i32) as u8;
+                                            *ptr2.add(32).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr2.add(24).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match block_hash3 {
+                                        Some(e) => {
+                                            *ptr2.add(40).cast::<u8>() = (1i32) as u8;
+                                            let vec5 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                                            let len5 = vec5.len();
+                                            ::core::mem::forget(vec5);
+                                            *ptr2.add(48).cast::<usize>() = len5;
+                                            *ptr2.add(44).cast::<*mut u8>() = ptr5.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2.add(40).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *ptr2.add(56).cast::<i64>() = _rt::as_i64(timestamp3);
+                                    *ptr2.add(64).cast::<i64>() = _rt::as_i64(net_amount3);
+                                    match fee3 {
+                                        Some(e) => {
+                                            *ptr2.add(72).cast::<u8>() = (1i32) as u8;
+                                            *ptr2.add(80).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr2.add(72).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    *ptr2.add(88).cast::<u8>() = (direction3.clone() as i32) as u8;
+                                    let vec8 = inputs3;
+                                    let len8 = vec8.len();
+                                    let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec8.len() * 24,
+                                        8,
+                                    );
+                                    let result8 = if layout8.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout8);
+                                        }
+                                        ptr
+                                    } else {
+                                        {
+                                            ::core::ptr::null_mut()
+                                        }
+                                    };
+                                    for (i, e) in vec8.into_iter().enumerate() {
+                                        let base = result8.add(i * 24);
+                                        {
+                                            let WalletInput {
+                                                txid: txid6,
+                                                vout: vout6,
+                                                amount: amount6,
+                                            } = e;
+                                            let vec7 = (txid6.into_bytes()).into_boxed_slice();
+                                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                                            let len7 = vec7.len();
+                                            ::core::mem::forget(vec7);
+                                            *base.add(4).cast::<usize>() = len7;
+                                            *base.add(0).cast::<*mut u8>() = ptr7.cast_mut();
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(vout6);
+                                            *base.add(16).cast::<i64>() = _rt::as_i64(amount6);
+                                        }
+                                    }
+                                    *ptr2.add(96).cast::<usize>() = len8;
+                                    *ptr2.add(92).cast::<*mut u8>() = result8;
+                                    let vec11 = outputs3;
+                                    let len11 = vec11.len();
+                                    let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec11.len() * 32,
+                                        8,
+                                    );
+                                    let result11 = if layout11.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout11);
+                                        }
+                                        ptr
+                                    } else {
+                                        {
+                                            ::core::ptr::null_mut()
+                                        }
+                                    };
+                                    for (i, e) in vec11.into_iter().enumerate() {
+                                        let base = result11.add(i * 32);
+                                        {
+                                            let WalletOutput {
+                                                vout: vout9,
+                                                amount: amount9,
+                                                script: script9,
+                                                is_change: is_change9,
+                                            } = e;
+                                            *base.add(0).cast::<i32>() = _rt::as_i32(vout9);
+                                            *base.add(8).cast::<i64>() = _rt::as_i64(amount9);
+                                            let vec10 = (script9).into_boxed_slice();
+                                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                                            let len10 = vec10.len();
+                                            ::core::mem::forget(vec10);
+                                            *base.add(20).cast::<usize>() = len10;
+                                            *base.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                            *base.add(24).cast::<u8>() = (match is_change9 {
+                                                true => 1,
+                                                false => 0,
+                                            })
+                                                as u8;
+                                        }
+                                    }
+                                    *ptr2.add(104).cast::<usize>() = len11;
+                                    *ptr2.add(100).cast::<*mut u8>() = result11;
+                                }
+                                None => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
                         }
                         Err(e) => {
                             *ptr2.add(0).cast::<u8>() = (1i32) as u8;
-                            *ptr2.add(4).cast::<i32>() = _rt::as_i32(e);
+                            *ptr2.add(8).cast::<i32>() = _rt::as_i32(e);
                         }
                     };
                     ptr2
                 }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_node_get_transaction<
+                    T: GuestClientNode,
+                >(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => (),
+                                _ => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0.add(20).cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                    let l4 = i32::from(*arg0.add(40).cast::<u8>());
+                                    match l4 {
+                                        0 => (),
+                                        _ => {
+                                            let l5 = *arg0.add(44).cast::<*mut u8>();
+                                            let l6 = *arg0.add(48).cast::<usize>();
+                                            _rt::cabi_dealloc(l5, l6, 1);
+                                        }
+                                    }
+                                    let l9 = *arg0.add(92).cast::<*mut u8>();
+                                    let l10 = *arg0.add(96).cast::<usize>();
+                                    let base11 = l9;
+                                    let len11 = l10;
+                                    for i in 0..len11 {
+                                        let base = base11.add(i * 24);
+                                        {
+                                            let l7 = *base.add(0).cast::<*mut u8>();
+                                            let l8 = *base.add(4).cast::<usize>();
+                                            _rt::cabi_dealloc(l7, l8, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base11, len11 * 24, 8);
+                                    let l15 = *arg0.add(100).cast::<*mut u8>();
+                                    let l16 = *arg0.add(104).cast::<usize>();
+                                    let base17 = l15;
+                                    let len17 = l16;
+                                    for i in 0..len17 {
+                                        let base = base17.add(i * 32);
+                                        {
+                                            let l12 = *base.add(16).cast::<*mut u8>();
+                                            let l13 = *base.add(20).cast::<usize>();
+                                            let base14 = l12;
+                                            let len14 = l13;
+                                            _rt::cabi_dealloc(base14, len14 * 1, 1);
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(base17, len17 * 32, 8);
+                                }
+                            }
+                        }
+                        _ => (),
+                    }
+                }
                 pub trait Guest {
                     type ClientNode: GuestClientNode;
                 }
@@ -1909,15 +8609,92 @@ pub mod exports {
                         }
                     }
 
+                    /// Traps on keys or descriptors the wallet cannot parse, `create` reports them instead
                     fn new(init: Initialization) -> Self;
+                    /// Fails with 48 when the xpriv, wallet keys, derivations or descriptors do not parse
+                    fn create(init: Initialization) -> Result<ClientNode, u32>;
                     fn get_balance(&self) -> Result<u64, u32>;
+                    /// Incoming payments seen in the mempool that are not mined yet
+                    fn get_pending_balance(&self) -> Result<u64, u32>;
+                    fn get_balance_breakdown(&self) -> Result<Balance, u32>;
                     fn get_receive_address(&self) -> Result<_rt::String, u32>;
+                    /// Fails on a watch-only node, which has no key to sign with
                     fn send_to_address(
                         &self,
-                        recepient: _rt::Vec<u8>,
+                        address: _rt::String,
                         amount: u64,
                         fee_rate: u64,
                     ) -> Result<(), u32>;
+                    /// Pays every recipient from one transaction, failing when any amount is below the
+                    /// dust limit of its address. Every `must-spend` output is an input, even when not needed.
+                    fn send_many(
+                        &self,
+                        recipients: _rt::Vec<Recipient>,
+                        fee_rate: u64,
+                        must_spend: _rt::Vec<Outpoint>,
+                    ) -> Result<(), u32>;
+                    /// Sends every confirmed output, or only `outpoints`, to `address` with the fee taken
+                    /// from the amount sent, to empty the wallet
+                    fn drain_to_address(
+                        &self,
+                        address: _rt::String,
+                        fee_rate: u64,
+                        outpoints: Option<_rt::Vec<Outpoint>>,
+                    ) -> Result<(), u32>;
+                    /// Replaces the wallet's unconfirmed transaction `txid` by one paying `fee-rate`, out of
+                    /// its change or extra inputs, and returns the txid of the replacement. Sends signal
+                    /// BIP125 replaceability.
+                    fn bump_fee(
+                        &self,
+                        txid: _rt::String,
+                        new_fee_rate: u64,
+                    ) -> Result<_rt::String, u32>;
+                    /// Speeds up the unconfirmed transaction `txid` paying the wallet by spending our output
+                    /// of it back to the wallet, so parent and child pay `target-fee-rate` together. A parent
+                    /// with inputs of others counts as paying no fee. Returns the txid of the child.
+                    fn cpfp(
+                        &self,
+                        txid: _rt::String,
+                        target_fee_rate: u64,
+                    ) -> Result<_rt::String, u32>;
+                    /// Pays the amount the URI asks for, failing when it names none
+                    fn send_to_uri(&self, uri: _rt::String, fee_rate: u64) -> Result<(), u32>;
+                    fn parse_payment_uri(&self, uri: _rt::String) -> Result<PaymentRequest, u32>;
+                    /// Unsigned psbt paying `address`, to be signed by the holder of the keys
+                    fn create_psbt(
+                        &self,
+                        address: _rt::String,
+                        amount: u64,
+                        fee_rate: u64,
+                    ) -> Result<_rt::Vec<u8>, u32>;
+                    /// Unsigned psbt draining the wallet, or only `outpoints`, to `address`
+                    fn create_drain_psbt(
+                        &self,
+                        address: _rt::String,
+                        fee_rate: u64,
+                        outpoints: Option<_rt::Vec<Outpoint>>,
+                    ) -> Result<_rt::Vec<u8>, u32>;
+                    /// Finalises a psbt signed elsewhere and broadcasts it, returning its txid
+                    fn broadcast_psbt(&self, psbt: _rt::Vec<u8>) -> Result<_rt::String, u32>;
+                    /// Unspent wallet outputs, oldest first
+                    fn list_unspent(&self) -> Result<_rt::Vec<UnspentOutput>, u32>;
+                    /// Keeps sends from spending the output, such as dust sent to track the wallet, until it
+                    /// is unfrozen. Survives restarts.
+                    fn freeze_utxo(&self, outpoint: Outpoint) -> Result<(), u32>;
+                    fn unfreeze_utxo(&self, outpoint: Outpoint) -> Result<(), u32>;
+                    /// Scans the blocks from this height again, after importing keys or to find missed payments
+                    fn rescan(&self, from_height: u64) -> Result<(), u32>;
+                    /// Wallet transactions, unconfirmed ones first then the most recently mined
+                    fn list_transactions(
+                        &self,
+                        offset: u32,
+                        limit: u32,
+                    ) -> Result<_rt::Vec<TransactionDetails>, u32>;
+                    /// Wallet transaction with this hex txid, none when the wallet has no such transaction
+                    fn get_transaction(
+                        &self,
+                        txid: _rt::String,
+                    ) -> Result<Option<TransactionDetails>, u32>;
                 }
                 #[doc(hidden)]
 
@@ -1925,13 +8702,25 @@ pub mod exports {
   ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
     #[export_name = "component:node/types@0.1.0#[constructor]client-node"]
-    unsafe extern "C" fn export_constructor_client_node(arg0: i32,arg1: i32,arg2: i32,arg3: i32,arg4: i32,arg5: i32,arg6: i32,arg7: *mut u8,arg8: usize,) -> i32 {
-      $($path_to_types)*::_export_constructor_client_node_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8)
+    unsafe extern "C" fn export_constructor_client_node(arg0: *mut u8,) -> i32 {
+      $($path_to_types)*::_export_constructor_client_node_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
+    #[export_name = "component:node/types@0.1.0#[static]client-node.create"]
+    unsafe extern "C" fn export_static_client_node_create(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_static_client_node_create_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
     }
     #[export_name = "component:node/types@0.1.0#[method]client-node.get-balance"]
     unsafe extern "C" fn export_method_client_node_get_balance(arg0: *mut u8,) -> *mut u8 {
       $($path_to_types)*::_export_method_client_node_get_balance_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
     }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.get-pending-balance"]
+    unsafe extern "C" fn export_method_client_node_get_pending_balance(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_get_pending_balance_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.get-balance-breakdown"]
+    unsafe extern "C" fn export_method_client_node_get_balance_breakdown(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_get_balance_breakdown_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
     #[export_name = "component:node/types@0.1.0#[method]client-node.get-receive-address"]
     unsafe extern "C" fn export_method_client_node_get_receive_address(arg0: *mut u8,) -> *mut u8 {
       $($path_to_types)*::_export_method_client_node_get_receive_address_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
@@ -1944,6 +8733,102 @@ pub mod exports {
     unsafe extern "C" fn export_method_client_node_send_to_address(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,arg4: i64,) -> *mut u8 {
       $($path_to_types)*::_export_method_client_node_send_to_address_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2, arg3, arg4)
     }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.send-many"]
+    unsafe extern "C" fn export_method_client_node_send_many(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,arg4: *mut u8,arg5: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_send_many_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2, arg3, arg4, arg5)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.drain-to-address"]
+    unsafe extern "C" fn export_method_client_node_drain_to_address(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,arg4: i32,arg5: *mut u8,arg6: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_drain_to_address_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2, arg3, arg4, arg5, arg6)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.bump-fee"]
+    unsafe extern "C" fn export_method_client_node_bump_fee(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_bump_fee_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "cabi_post_component:node/types@0.1.0#[method]client-node.bump-fee"]
+    unsafe extern "C" fn _post_return_method_client_node_bump_fee(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_client_node_bump_fee::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.cpfp"]
+    unsafe extern "C" fn export_method_client_node_cpfp(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_cpfp_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "cabi_post_component:node/types@0.1.0#[method]client-node.cpfp"]
+    unsafe extern "C" fn _post_return_method_client_node_cpfp(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_client_node_cpfp::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.send-to-uri"]
+    unsafe extern "C" fn export_method_client_node_send_to_uri(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_send_to_uri_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.parse-payment-uri"]
+    unsafe extern "C" fn export_method_client_node_parse_payment_uri(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_parse_payment_uri_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_component:node/types@0.1.0#[method]client-node.parse-payment-uri"]
+    unsafe extern "C" fn _post_return_method_client_node_parse_payment_uri(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_client_node_parse_payment_uri::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.create-psbt"]
+    unsafe extern "C" fn export_method_client_node_create_psbt(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,arg4: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_create_psbt_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[export_name = "cabi_post_component:node/types@0.1.0#[method]client-node.create-psbt"]
+    unsafe extern "C" fn _post_return_method_client_node_create_psbt(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_client_node_create_psbt::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.create-drain-psbt"]
+    unsafe extern "C" fn export_method_client_node_create_drain_psbt(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i64,arg4: i32,arg5: *mut u8,arg6: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_create_drain_psbt_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2, arg3, arg4, arg5, arg6)
+    }
+    #[export_name = "cabi_post_component:node/types@0.1.0#[method]client-node.create-drain-psbt"]
+    unsafe extern "C" fn _post_return_method_client_node_create_drain_psbt(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_client_node_create_drain_psbt::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.broadcast-psbt"]
+    unsafe extern "C" fn export_method_client_node_broadcast_psbt(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_broadcast_psbt_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_component:node/types@0.1.0#[method]client-node.broadcast-psbt"]
+    unsafe extern "C" fn _post_return_method_client_node_broadcast_psbt(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_client_node_broadcast_psbt::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.list-unspent"]
+    unsafe extern "C" fn export_method_client_node_list_unspent(arg0: *mut u8,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_list_unspent_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
+    #[export_name = "cabi_post_component:node/types@0.1.0#[method]client-node.list-unspent"]
+    unsafe extern "C" fn _post_return_method_client_node_list_unspent(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_client_node_list_unspent::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.freeze-utxo"]
+    unsafe extern "C" fn export_method_client_node_freeze_utxo(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_freeze_utxo_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.unfreeze-utxo"]
+    unsafe extern "C" fn export_method_client_node_unfreeze_utxo(arg0: *mut u8,arg1: *mut u8,arg2: usize,arg3: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_unfreeze_utxo_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2, arg3)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.rescan"]
+    unsafe extern "C" fn export_method_client_node_rescan(arg0: *mut u8,arg1: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_rescan_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.list-transactions"]
+    unsafe extern "C" fn export_method_client_node_list_transactions(arg0: *mut u8,arg1: i32,arg2: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_list_transactions_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_component:node/types@0.1.0#[method]client-node.list-transactions"]
+    unsafe extern "C" fn _post_return_method_client_node_list_transactions(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_client_node_list_transactions::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
+    #[export_name = "component:node/types@0.1.0#[method]client-node.get-transaction"]
+    unsafe extern "C" fn export_method_client_node_get_transaction(arg0: *mut u8,arg1: *mut u8,arg2: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_method_client_node_get_transaction_cabi::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0, arg1, arg2)
+    }
+    #[export_name = "cabi_post_component:node/types@0.1.0#[method]client-node.get-transaction"]
+    unsafe extern "C" fn _post_return_method_client_node_get_transaction(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_method_client_node_get_transaction::<<$ty as $($path_to_types)*::Guest>::ClientNode>(arg0)
+    }
 
     const _: () = {
       #[doc(hidden)]
@@ -1961,8 +8846,9 @@ pub mod exports {
                 #[doc(hidden)]
                 pub(crate) use __export_component_node_types_0_1_0_cabi;
                 #[repr(align(8))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 16]);
-                static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 16]);
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 112]);
+                static mut _RET_AREA: _RetArea =
+                    _RetArea([::core::mem::MaybeUninit::uninit(); 112]);
             }
         }
     }
@@ -2072,59 +8958,6 @@ mod _rt {
     }
     pub use alloc_crate::string::String;
     pub use alloc_crate::vec::Vec;
-    pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
-        if cfg!(debug_assertions) {
-            String::from_utf8(bytes).unwrap()
-        } else {
-            String::from_utf8_unchecked(bytes)
-        }
-    }
-
-    pub fn as_i64<T: AsI64>(t: T) -> i64 {
-        t.as_i64()
-    }
-
-    pub trait AsI64 {
-        fn as_i64(self) -> i64;
-    }
-
-    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
-        fn as_i64(self) -> i64 {
-            (*self).as_i64()
-        }
-    }
-
-    impl AsI64 for i64 {
-        #[inline]
-        fn as_i64(self) -> i64 {
-            self as i64
-        }
-    }
-
-    impl AsI64 for u64 {
-        #[inline]
-        fn as_i64(self) -> i64 {
-            self as i64
-        }
-    }
-    pub unsafe fn bool_lift(val: u8) -> bool {
-        if cfg!(debug_assertions) {
-            match val {
-                0 => false,
-                1 => true,
-                _ => panic!("invalid bool discriminant"),
-            }
-        } else {
-            val != 0
-        }
-    }
-    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
-        if size == 0 {
-            return;
-        }
-        let layout = alloc::Layout::from_size_align_unchecked(size, align);
-        alloc::dealloc(ptr as *mut u8, layout);
-    }
 
     pub fn as_i32<T: AsI32>(t: T) -> i32 {
         t.as_i32()
@@ -2196,6 +9029,59 @@ mod _rt {
         }
     }
     pub use alloc_crate::alloc;
+    pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
+        if cfg!(debug_assertions) {
+            String::from_utf8(bytes).unwrap()
+        } else {
+            String::from_utf8_unchecked(bytes)
+        }
+    }
+
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    pub unsafe fn bool_lift(val: u8) -> bool {
+        if cfg!(debug_assertions) {
+            match val {
+                0 => false,
+                1 => true,
+                _ => panic!("invalid bool discriminant"),
+            }
+        } else {
+            val != 0
+        }
+    }
+    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+        if size == 0 {
+            return;
+        }
+        let layout = alloc::Layout::from_size_align_unchecked(size, align);
+        alloc::dealloc(ptr as *mut u8, layout);
+    }
     pub use alloc_crate::boxed::Box;
 
     #[cfg(target_arch = "wasm32")]
@@ -2236,64 +9122,143 @@ pub(crate) use __export_nodeworld_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[link_section = "component-type:wit-bindgen:0.25.0:nodeworld:encoded world"]
 #[doc(hidden)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2745] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xb9\x14\x01A\x02\x01\
-A\x08\x01B\x10\x01q\x06\x0fopen-file-error\0\0\x0cstream-error\0\0\x0efile-not-f\
-ound\x01w\0\x0cinvalid-data\0\0\x0bparse-error\0\0\x0fentry-not-found\0\0\x04\0\x05\
-error\x03\0\0\x04\0\x07kvstore\x03\x01\x01i\x02\x01@\0\0\x03\x04\0\x14[construct\
-or]kvstore\x01\x04\x01h\x02\x01p}\x01j\0\x01\x01\x01@\x03\x04self\x05\x03keys\x05\
-value\x06\0\x07\x04\0\x16[method]kvstore.insert\x01\x08\x01j\x01\x06\x01\x01\x01\
-@\x02\x04self\x05\x03keys\0\x09\x04\0\x13[method]kvstore.get\x01\x0a\x01@\x02\x04\
-self\x05\x03keys\0\x07\x04\0\x16[method]kvstore.delete\x01\x0b\x03\x01\x18compon\
-ent:kv/types@0.1.0\x05\0\x01B+\x01m\x05\x07bitcoin\x07testnet\x08testnet4\x06sig\
-net\x07regtest\x04\0\x0fbitcoin-network\x03\0\0\x01q\x05\x0ecoin-selection\0\0\x04\
-psbt\0\0\x18missing-non-witness-utxo\0\0\x09no-pubkey\0\0\x0cpubkey-error\0\0\x04\
-\0\x05error\x03\0\x02\x01r\x04\x04xpubs\x12account-derivations\x12master-fingerp\
-rints\x07network\x01\x04\0\x06config\x03\0\x04\x01p}\x01q\x02\x09old-state\x01\x06\
-\0\x06config\x01\x05\0\x04\0\x0einitialization\x03\0\x07\x01r\x05\x04txid\x06\x04\
-vouty\x06amountw\x06script\x06\x08is-spent\x7f\x04\0\x0cpartial-utxo\x03\0\x09\x01\
-p}\x04\0\x06pubkey\x03\0\x0b\x04\0\x0awatch-only\x03\x01\x01i\x0d\x01@\x01\x04in\
-it\x08\0\x0e\x04\0\x17[constructor]watch-only\x01\x0f\x01h\x0d\x01j\x01s\x01\x03\
-\x01@\x01\x04self\x10\0\x11\x04\0\x1e[method]watch-only.new-address\x01\x12\x01j\
-\x01\x06\x01\x03\x01@\x04\x04self\x10\x09recepient\x06\x06amountw\x08fee-ratew\0\
-\x13\x04\0%[method]watch-only.create-transaction\x01\x14\x01p\x0a\x01j\x01\x15\x01\
-\x03\x01@\x01\x04self\x10\0\x16\x04\0\x1c[method]watch-only.get-utxos\x01\x17\x01\
-j\0\x01\x03\x01@\x02\x04self\x10\x05utxos\x15\0\x18\x04\0\x1f[method]watch-only.\
-insert-utxos\x01\x19\x01p\x0c\x01j\x01\x1a\x01\x03\x01@\x01\x04self\x10\0\x1b\x04\
-\0\x1e[method]watch-only.get-pubkeys\x01\x1c\x01@\x01\x04self\x10\0\x06\x04\0\x1c\
-[method]watch-only.get-state\x01\x1d\x01j\x01w\x01\x03\x01@\x01\x04self\x10\0\x1e\
-\x04\0\x1a[method]watch-only.balance\x01\x1f\x04\0&[method]watch-only.get-receiv\
-e-address\x01\x12\x01@\x02\x04self\x10\x04psbt\x06\0\x13\x04\0'[method]watch-onl\
-y.finalise-transaction\x01\x20\x03\x01\x1ccomponent:wallet/types@0.1.0\x05\x01\x01\
-B\x1b\x01r\x01\x04xpivs\x04\0\x06config\x03\0\0\x01p}\x01q\x02\x09old-state\x01\x02\
-\0\x06config\x01\x01\0\x04\0\x0einitialization\x03\0\x03\x01q\x02\x10derivation-\
-error\0\0\x0dsigning-error\0\0\x04\0\x05error\x03\0\x05\x01s\x04\0\x0caccount-xp\
-ub\x03\0\x07\x01s\x04\0\x12master-fingerprint\x03\0\x09\x01s\x04\0\x12account-de\
-rivation\x03\0\x0b\x04\0\x0dsimple-signer\x03\x01\x01i\x0d\x01@\x01\x04init\x04\0\
-\x0e\x04\0\x1a[constructor]simple-signer\x01\x0f\x01h\x0d\x01o\x03\x08\x0a\x0c\x01\
-j\x01\x11\x01\x06\x01@\x01\x04self\x10\0\x12\x04\0$[method]simple-signer.derive-\
-account\x01\x13\x01j\x01\x02\x01\x06\x01@\x02\x04self\x10\x04psbt\x02\0\x14\x04\0\
-\x1f[method]simple-signer.sign-psbt\x01\x15\x01@\x01\x04self\x10\0\x02\x04\0\x1f\
-[method]simple-signer.get-state\x01\x16\x03\x01\x1ccomponent:signer/types@0.1.0\x05\
-\x02\x01B\x1f\x01r\x02\x03keys\x05values\x04\0\x0ekey-value-pair\x03\0\0\x01q\x0a\
-\x0dkey-not-found\0\0\x12key-already-exists\0\0\x10unexpected-error\0\0\x12key-o\
-verflow-error\0\0\x14value-overflow-error\0\0\x14try-from-slice-error\0\0\x0autf\
-8-error\0\0\x10filesystem-error\x01}\0\x13invalid-magic-bytes\0\0\x0cstream-erro\
-r\0\0\x04\0\x0bstore-error\x03\0\x02\x01o\x04}}}}\x01r\x02\x07address\x04\x04por\
-t{\x04\0\x12ipv4-socket-adress\x03\0\x05\x01m\x05\x07bitcoin\x07testnet\x08testn\
-et4\x06signet\x07regtest\x04\0\x0fbitcoin-network\x03\0\x07\x01ks\x01r\x04\x03fe\
-e\x09\x19estimated-settlement-timew\x02ids\x04rates\x04\0\x10offering-bargain\x03\
-\0\x0a\x01r\x03\x07network\x08\x0esocket-address\x06\x05xprivs\x04\0\x0bnode-con\
-fig\x03\0\x0c\x01q\x02\x09old-state\0\0\x06config\x01\x0d\0\x04\0\x0einitializat\
-ion\x03\0\x0e\x04\0\x0bclient-node\x03\x01\x01i\x10\x01@\x01\x04init\x0f\0\x11\x04\
-\0\x18[constructor]client-node\x01\x12\x01h\x10\x01j\x01w\x01y\x01@\x01\x04self\x13\
-\0\x14\x04\0\x1f[method]client-node.get-balance\x01\x15\x01j\x01s\x01y\x01@\x01\x04\
-self\x13\0\x16\x04\0'[method]client-node.get-receive-address\x01\x17\x01p}\x01j\0\
-\x01y\x01@\x04\x04self\x13\x09recepient\x18\x06amountw\x08fee-ratew\0\x19\x04\0#\
-[method]client-node.send-to-address\x01\x1a\x04\x01\x1acomponent:node/types@0.1.\
-0\x05\x03\x04\x01\x1ecomponent:node/nodeworld@0.1.0\x04\0\x0b\x0f\x01\0\x09nodew\
-orld\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.208.1\
-\x10wit-bindgen-rust\x060.25.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 7096] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xb86\x01A\x02\x01A\x08\
+\x01B\x10\x01q\x06\x0fopen-file-error\0\0\x0cstream-error\0\0\x0efile-not-found\x01\
+w\0\x0cinvalid-data\0\0\x0bparse-error\0\0\x0fentry-not-found\0\0\x04\0\x05error\
+\x03\0\0\x04\0\x07kvstore\x03\x01\x01i\x02\x01@\0\0\x03\x04\0\x14[constructor]kv\
+store\x01\x04\x01h\x02\x01p}\x01j\0\x01\x01\x01@\x03\x04self\x05\x03keys\x05valu\
+e\x06\0\x07\x04\0\x16[method]kvstore.insert\x01\x08\x01j\x01\x06\x01\x01\x01@\x02\
+\x04self\x05\x03keys\0\x09\x04\0\x13[method]kvstore.get\x01\x0a\x01@\x02\x04self\
+\x05\x03keys\0\x07\x04\0\x16[method]kvstore.delete\x01\x0b\x03\x01\x18component:\
+kv/types@0.1.0\x05\0\x01Bo\x01m\x05\x07bitcoin\x07testnet\x08testnet4\x06signet\x07\
+regtest\x04\0\x0fbitcoin-network\x03\0\0\x01m\x02\x06p2wpkh\x04p2tr\x04\0\x0bwal\
+let-type\x03\0\x02\x01k\x03\x01r\x04\x04xpubs\x12account-derivations\x12master-f\
+ingerprints\x0bwallet-type\x04\x04\0\x07account\x03\0\x05\x01ks\x01r\x02\x08exte\
+rnals\x08internal\x07\x04\0\x0bdescriptors\x03\0\x08\x01m\x02\x05p2wsh\x04p2tr\x04\
+\0\x0dmultisig-type\x03\0\x0a\x01r\x03\x04xpubs\x12account-derivations\x12master\
+-fingerprints\x04\0\x08cosigner\x03\0\x0c\x01p\x0d\x01r\x03\x09thresholdy\x09cos\
+igners\x0e\x0dmultisig-type\x0b\x04\0\x08multisig\x03\0\x0f\x01q\x04\x07account\x01\
+\x06\0\x0bdescriptors\x01\x09\0\x08multisig\x01\x10\0\x06policy\x01s\0\x04\0\x04\
+keys\x03\0\x11\x01ky\x01r\x05\x04keys\x12\x07network\x01\x09lookahead\x13\x10cha\
+nge-lookahead\x13\x11min-confirmations\x13\x04\0\x06config\x03\0\x14\x01p}\x01q\x02\
+\x09old-state\x01\x16\0\x06config\x01\x15\0\x04\0\x0einitialization\x03\0\x17\x01\
+r\x04\x09confirmedw\x0ftrusted-pendingw\x11untrusted-pendingw\x08immaturew\x04\0\
+\x07balance\x03\0\x19\x01m\x02\x08external\x08internal\x04\0\x08keychain\x03\0\x1b\
+\x01r\x07\x04txid\x16\x04vouty\x06amountw\x06script\x16\x08is-spent\x7f\x06heigh\
+t\x13\x0bis-coinbase\x7f\x04\0\x0cpartial-utxo\x03\0\x1d\x01p}\x04\0\x06pubkey\x03\
+\0\x1f\x01r\x02\x04txid\x16\x04vouty\x04\0\x08outpoint\x03\0!\x01q\x13\x0ecoin-s\
+election\0\0\x04psbt\0\0\x18missing-non-witness-utxo\0\0\x09no-pubkey\0\0\x0cpub\
+key-error\0\0\x12invalid-descriptor\x01s\0\x11missing-signature\0\0\x15invalid-s\
+pending-path\x01y\0\x14timelock-not-reached\0\0\x0dno-recipients\0\0\x17output-b\
+elow-dust-limit\x01y\0\x0cunknown-utxo\x01\"\0\x0bfrozen-utxo\x01\"\0\x19irrepla\
+ceable-transaction\x01\x16\0\x15transaction-confirmed\x01\x16\0\x11no-wallet-out\
+puts\x01\x16\0\x10fee-rate-too-low\x01w\0\x0bfee-too-low\x01w\0\x10invalid-fee-r\
+ate\x01w\0\x04\0\x05error\x03\0#\x01r\x09\x08outpoint\"\x06amountw\x06script\x16\
+\x08keychain\x1c\x10derivation-indexy\x06height\x13\x0dconfirmationsy\x0bis-coin\
+base\x7f\x09is-frozen\x7f\x04\0\x0cunspent-utxo\x03\0%\x01r\x02\x06script\x16\x06\
+amountw\x04\0\x09recipient\x03\0'\x04\0\x0awatch-only\x03\x01\x01i)\x01@\x01\x04\
+init\x18\0*\x04\0\x17[constructor]watch-only\x01+\x01j\x01*\x01$\x01@\x01\x04ini\
+t\x18\0,\x04\0\x19[static]watch-only.create\x01-\x01h)\x01j\x01s\x01$\x01@\x01\x04\
+self.\0/\x04\0\x1e[method]watch-only.new-address\x010\x01j\x01\x16\x01$\x01@\x05\
+\x04self.\x09recepient\x16\x06amountw\x08fee-ratew\x0dspending-path\x13\01\x04\0\
+%[method]watch-only.create-transaction\x012\x01p(\x01p\"\x01@\x05\x04self.\x0are\
+cipients3\x08fee-ratew\x0amust-spend4\x0dspending-path\x13\01\x04\0+[method]watc\
+h-only.create-batch-transaction\x015\x01k4\x01@\x05\x04self.\x0bdestination\x16\x08\
+fee-ratew\x09outpoints6\x0dspending-path\x13\01\x04\0+[method]watch-only.create-\
+drain-transaction\x017\x01@\x03\x04self.\x0btransaction\x16\x08fee-ratew\01\x04\0\
+.[method]watch-only.create-bump-fee-transaction\x018\x01@\x03\x04self.\x06parent\
+\x16\x08fee-ratew\01\x04\0*[method]watch-only.create-cpfp-transaction\x019\x01p\x1e\
+\x01j\x01:\x01$\x01@\x01\x04self.\0;\x04\0\x1c[method]watch-only.get-utxos\x01<\x01\
+p&\x01@\x01\x04self.\0=\x04\0\x1f[method]watch-only.list-unspent\x01>\x01j\0\x01\
+$\x01@\x02\x04self.\x08outpoint\"\0?\x04\0\x1e[method]watch-only.freeze-utxo\x01\
+@\x04\0\x20[method]watch-only.unfreeze-utxo\x01@\x01@\x02\x04self.\x05utxos:\0?\x04\
+\0\x1f[method]watch-only.insert-utxos\x01A\x04\0+[method]watch-only.insert-uncon\
+firmed-utxos\x01A\x04\0\x1f[method]watch-only.remove-utxos\x01A\x01@\x02\x04self\
+.\x0btransaction\x16\0?\x04\0%[method]watch-only.mark-pending-spent\x01B\x01@\x02\
+\x04self.\x04txid\x16\x01\0\x04\0([method]watch-only.release-pending-spent\x01C\x01\
+p\x20\x01j\x01\xc4\0\x01$\x01@\x01\x04self.\0\xc5\0\x04\0\x1e[method]watch-only.\
+get-pubkeys\x01F\x01k\x1c\x01@\x02\x04self.\x06script\x20\0\xc7\0\x04\0\x1f[meth\
+od]watch-only.get-keychain\x01H\x01@\x01\x04self.\0\x16\x04\0\x1c[method]watch-o\
+nly.get-state\x01I\x01@\x01\x04self.\0\x09\x04\0\"[method]watch-only.get-descrip\
+tors\x01J\x01j\x01w\x01$\x01@\x01\x04self.\0\xcb\0\x04\0\x1a[method]watch-only.b\
+alance\x01L\x04\0\"[method]watch-only.pending-balance\x01L\x01j\x01\x1a\x01$\x01\
+@\x01\x04self.\0\xcd\0\x04\0$[method]watch-only.balance-breakdown\x01N\x01@\x02\x04\
+self.\x06heighty\x01\0\x04\0![method]watch-only.set-tip-height\x01O\x04\0&[metho\
+d]watch-only.get-receive-address\x010\x01p\x16\x01@\x03\x04self.\x04psbt\x16\x06\
+others\xd0\0\01\x04\0\x20[method]watch-only.combine-psbts\x01Q\x01@\x02\x04self.\
+\x04psbt\x16\01\x04\0'[method]watch-only.finalise-transaction\x01R\x03\x01\x1cco\
+mponent:wallet/types@0.1.0\x05\x01\x01B\x1f\x01r\x01\x04xpivs\x04\0\x06config\x03\
+\0\0\x01p}\x01q\x02\x09old-state\x01\x02\0\x06config\x01\x01\0\x04\0\x0einitiali\
+zation\x03\0\x03\x01q\x02\x10derivation-error\0\0\x0dsigning-error\0\0\x04\0\x05\
+error\x03\0\x05\x01m\x05\x07bitcoin\x07testnet\x08testnet4\x06signet\x07regtest\x04\
+\0\x07network\x03\0\x07\x01m\x04\x06p2wpkh\x04p2tr\x0ep2wsh-multisig\x0dp2tr-mul\
+tisig\x04\0\x0bwallet-type\x03\0\x09\x01s\x04\0\x0caccount-xpub\x03\0\x0b\x01s\x04\
+\0\x12master-fingerprint\x03\0\x0d\x01s\x04\0\x12account-derivation\x03\0\x0f\x04\
+\0\x0dsimple-signer\x03\x01\x01i\x11\x01@\x01\x04init\x04\0\x12\x04\0\x1a[constr\
+uctor]simple-signer\x01\x13\x01h\x11\x01o\x03\x0c\x0e\x10\x01j\x01\x15\x01\x06\x01\
+@\x03\x04self\x14\x0bwallet-type\x0a\x07network\x08\0\x16\x04\0$[method]simple-s\
+igner.derive-account\x01\x17\x01j\x01\x02\x01\x06\x01@\x02\x04self\x14\x04psbt\x02\
+\0\x18\x04\0\x1f[method]simple-signer.sign-psbt\x01\x19\x01@\x01\x04self\x14\0\x02\
+\x04\0\x1f[method]simple-signer.get-state\x01\x1a\x03\x01\x1ccomponent:signer/ty\
+pes@0.1.0\x05\x02\x01Bq\x01r\x02\x03keys\x05values\x04\0\x0ekey-value-pair\x03\0\
+\0\x01q\x0a\x0dkey-not-found\0\0\x12key-already-exists\0\0\x10unexpected-error\0\
+\0\x12key-overflow-error\0\0\x14value-overflow-error\0\0\x14try-from-slice-error\
+\0\0\x0autf8-error\0\0\x10filesystem-error\x01}\0\x13invalid-magic-bytes\0\0\x0c\
+stream-error\0\0\x04\0\x0bstore-error\x03\0\x02\x01o\x04}}}}\x01r\x02\x07address\
+\x04\x04port{\x04\0\x12ipv4-socket-adress\x03\0\x05\x01m\x05\x07bitcoin\x07testn\
+et\x08testnet4\x06signet\x07regtest\x04\0\x0fbitcoin-network\x03\0\x07\x01ks\x01\
+r\x04\x03fee\x09\x19estimated-settlement-timew\x02ids\x04rates\x04\0\x10offering\
+-bargain\x03\0\x0a\x01q\x02\x06height\x01w\0\x09timestamp\x01w\0\x04\0\x0fwallet\
+-birthday\x03\0\x0c\x01m\x03\x08incoming\x08outgoing\x0dself-transfer\x04\0\x09d\
+irection\x03\0\x0e\x01r\x03\x04txids\x04vouty\x06amountw\x04\0\x0cwallet-input\x03\
+\0\x10\x01p}\x01r\x04\x04vouty\x06amountw\x06script\x12\x09is-change\x7f\x04\0\x0d\
+wallet-output\x03\0\x13\x01kw\x01p\x11\x01p\x14\x01r\x09\x04txids\x0cblock-heigh\
+t\x15\x0ablock-hash\x09\x09timestampw\x0anet-amountx\x03fee\x15\x09direction\x0f\
+\x06inputs\x16\x07outputs\x17\x04\0\x13transaction-details\x03\0\x18\x01r\x04\x09\
+confirmedw\x0ftrusted-pendingw\x11untrusted-pendingw\x08immaturew\x04\0\x07balan\
+ce\x03\0\x1a\x01m\x02\x06p2wpkh\x04p2tr\x04\0\x0bwallet-type\x03\0\x1c\x01r\x03\x04\
+xpubs\x12account-derivations\x12master-fingerprints\x04\0\x0dwatch-account\x03\0\
+\x1e\x01r\x02\x08externals\x08internal\x09\x04\0\x11watch-descriptors\x03\0\x20\x01\
+q\x03\x05xpriv\x01s\0\x07account\x01\x1f\0\x0bdescriptors\x01!\0\x04\0\x09node-k\
+eys\x03\0\"\x01k\x1d\x01p\x06\x01ky\x01k\x0d\x01r\x0a\x07network\x08\x0bwallet-t\
+ype$\x0esocket-address\x06\x05peers%\x0fmax-connections&\x09use-seeds\x7f\x08bir\
+thday'\x09lookahead&\x11min-confirmations&\x04keys#\x04\0\x0bnode-config\x03\0(\x01\
+q\x02\x09old-state\0\0\x06config\x01)\0\x04\0\x0einitialization\x03\0*\x01r\x04\x07\
+addresss\x06amount\x15\x05label\x09\x07message\x09\x04\0\x0fpayment-request\x03\0\
+,\x01r\x02\x07addresss\x06amountw\x04\0\x09recipient\x03\0.\x01r\x02\x04txids\x04\
+vouty\x04\0\x08outpoint\x03\00\x01r\x09\x04txids\x04vouty\x06amountw\x06script\x12\
+\x09is-change\x7f\x0cblock-height\x15\x0dconfirmationsy\x0bis-coinbase\x7f\x09is\
+-frozen\x7f\x04\0\x0eunspent-output\x03\02\x04\0\x0bclient-node\x03\x01\x01i4\x01\
+@\x01\x04init+\05\x04\0\x18[constructor]client-node\x016\x01j\x015\x01y\x01@\x01\
+\x04init+\07\x04\0\x1a[static]client-node.create\x018\x01h4\x01j\x01w\x01y\x01@\x01\
+\x04self9\0:\x04\0\x1f[method]client-node.get-balance\x01;\x04\0'[method]client-\
+node.get-pending-balance\x01;\x01j\x01\x1b\x01y\x01@\x01\x04self9\0<\x04\0)[meth\
+od]client-node.get-balance-breakdown\x01=\x01j\x01s\x01y\x01@\x01\x04self9\0>\x04\
+\0'[method]client-node.get-receive-address\x01?\x01j\0\x01y\x01@\x04\x04self9\x07\
+addresss\x06amountw\x08fee-ratew\0\xc0\0\x04\0#[method]client-node.send-to-addre\
+ss\x01A\x01p/\x01p1\x01@\x04\x04self9\x0arecipients\xc2\0\x08fee-ratew\x0amust-s\
+pend\xc3\0\0\xc0\0\x04\0\x1d[method]client-node.send-many\x01D\x01k\xc3\0\x01@\x04\
+\x04self9\x07addresss\x08fee-ratew\x09outpoints\xc5\0\0\xc0\0\x04\0$[method]clie\
+nt-node.drain-to-address\x01F\x01@\x03\x04self9\x04txids\x0cnew-fee-ratew\0>\x04\
+\0\x1c[method]client-node.bump-fee\x01G\x01@\x03\x04self9\x04txids\x0ftarget-fee\
+-ratew\0>\x04\0\x18[method]client-node.cpfp\x01H\x01@\x03\x04self9\x03uris\x08fe\
+e-ratew\0\xc0\0\x04\0\x1f[method]client-node.send-to-uri\x01I\x01j\x01-\x01y\x01\
+@\x02\x04self9\x03uris\0\xca\0\x04\0%[method]client-node.parse-payment-uri\x01K\x01\
+j\x01\x12\x01y\x01@\x04\x04self9\x07addresss\x06amountw\x08fee-ratew\0\xcc\0\x04\
+\0\x1f[method]client-node.create-psbt\x01M\x01@\x04\x04self9\x07addresss\x08fee-\
+ratew\x09outpoints\xc5\0\0\xcc\0\x04\0%[method]client-node.create-drain-psbt\x01\
+N\x01@\x02\x04self9\x04psbt\x12\0>\x04\0\"[method]client-node.broadcast-psbt\x01\
+O\x01p3\x01j\x01\xd0\0\x01y\x01@\x01\x04self9\0\xd1\0\x04\0\x20[method]client-no\
+de.list-unspent\x01R\x01@\x02\x04self9\x08outpoint1\0\xc0\0\x04\0\x1f[method]cli\
+ent-node.freeze-utxo\x01S\x04\0![method]client-node.unfreeze-utxo\x01S\x01@\x02\x04\
+self9\x0bfrom-heightw\0\xc0\0\x04\0\x1a[method]client-node.rescan\x01T\x01p\x19\x01\
+j\x01\xd5\0\x01y\x01@\x03\x04self9\x06offsety\x05limity\0\xd6\0\x04\0%[method]cl\
+ient-node.list-transactions\x01W\x01k\x19\x01j\x01\xd8\0\x01y\x01@\x02\x04self9\x04\
+txids\0\xd9\0\x04\0#[method]client-node.get-transaction\x01Z\x04\x01\x1acomponen\
+t:node/types@0.1.0\x05\x03\x04\x01\x1ecomponent:node/nodeworld@0.1.0\x04\0\x0b\x0f\
+\x01\0\x09nodeworld\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-compo\
+nent\x070.208.1\x10wit-bindgen-rust\x060.25.0";
 
 #[inline(never)]
 #[doc(hidden)]
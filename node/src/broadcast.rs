@@ -0,0 +1,271 @@
+//! Transactions the wallet sent, followed until a block confirms them
+//!
+//! Sends are re-announced periodically while unconfirmed, peers drop transactions from their
+//! mempool and a single announcement can get lost. Once a peer announces one of them back to us
+//! it is known to have propagated.
+
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::db::{KeyValueDb, BROADCAST_STATE_KEY};
+use crate::messages::reject::RejectCode;
+use crate::messages::tx::Tx;
+use crate::util::{Error, Hash256, Result, Serializable};
+
+/// Seconds between two announcements of a transaction that is not confirmed yet
+const REBROADCAST_INTERVAL_SECS: u64 = 60 * 60;
+
+/// Confirmed transactions are forgotten once buried this deep
+const MAX_CONFIRMED_DEPTH: u64 = 100;
+
+#[derive(serde::Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub enum BroadcastStatus {
+    /// Sent to peers, no peer announced it back yet
+    Pending,
+    /// A peer announced it, so it made it into other mempools
+    Relayed,
+    /// Every peer refused it on the last attempt, holds the reject code and reason. Only
+    /// peers still sending BIP61 rejects are heard refusing, Bitcoin Core 0.20 and later never are.
+    Rejected(RejectCode, String),
+    /// Mined in the block at this height
+    Confirmed(u64),
+    /// A fee bump with this txid took its place
+    Replaced(Hash256),
+}
+
+#[derive(serde::Deserialize, Serialize, Clone)]
+struct BroadcastEntry {
+    raw_tx: Vec<u8>,
+    fee_rate: u64,
+    last_broadcast: u64,
+    status: BroadcastStatus,
+}
+
+#[derive(serde::Deserialize, Serialize, Default)]
+pub struct BroadcastTracker {
+    transactions: BTreeMap<Hash256, BroadcastEntry>,
+}
+
+impl BroadcastTracker {
+
+    pub fn load(db: &Arc<KeyValueDb>) -> Result<Self> {
+        match db.get_optional(BROADCAST_STATE_KEY.to_string())? {
+            Some(bytes) => bincode::deserialize(&bytes).map_err(|err| Error::SerializationError(err.to_string())),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, db: &Arc<KeyValueDb>) -> Result<()> {
+        let encoded = bincode::serialize(self).map_err(|err| Error::SerializationError(err.to_string()))?;
+        db.insert(BROADCAST_STATE_KEY.to_string(), encoded)
+    }
+
+    /// Starts following a transaction that was just sent
+    pub fn track(&mut self, tx: &Tx, fee_rate: u64, now: u64) -> Result<()> {
+        let mut raw_tx = Vec::new();
+        tx.write(&mut raw_tx)?;
+        self.transactions.insert(tx.txid(), BroadcastEntry { raw_tx, fee_rate, last_broadcast: now, status: BroadcastStatus::Pending });
+        Ok(())
+    }
+
+    /// Records that a peer announced the transaction, returns whether it is one of ours
+    pub fn mark_relayed(&mut self, txid: &Hash256) -> bool {
+        match self.transactions.get_mut(txid) {
+            Some(entry) => {
+                if entry.status == BroadcastStatus::Pending {
+                    entry.status = BroadcastStatus::Relayed;
+                }
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Stops rebroadcasting a transaction every peer refused
+    pub fn mark_rejected(&mut self, txid: &Hash256, code: RejectCode, reason: String) {
+        if let Some(entry) = self.transactions.get_mut(txid) {
+            entry.status = BroadcastStatus::Rejected(code, reason);
+        }
+    }
+
+    /// Whether our unconfirmed send with this txid is still being rebroadcast
+    pub fn is_broadcasting(&self, txid: &Hash256) -> bool {
+        self.transactions.get(txid).is_some_and(|entry| matches!(entry.status, BroadcastStatus::Pending | BroadcastStatus::Relayed))
+    }
+
+    /// Our unconfirmed send with this txid, which a fee bump may still replace
+    pub fn replaceable(&self, txid: &Hash256) -> Option<&[u8]> {
+        self.transactions.get(txid)
+            .filter(|entry| matches!(entry.status, BroadcastStatus::Pending | BroadcastStatus::Relayed | BroadcastStatus::Rejected(_, _)))
+            .map(|entry| entry.raw_tx.as_slice())
+    }
+
+    /// Stops rebroadcasting a transaction once its fee bump `replacement` was sent
+    pub fn mark_replaced(&mut self, txid: &Hash256, replacement: Hash256) {
+        if let Some(entry) = self.transactions.get_mut(txid) {
+            entry.status = BroadcastStatus::Replaced(replacement);
+        }
+    }
+
+    /// Marks the transaction mined at `height`, returns whether it is one of ours
+    pub fn confirm(&mut self, txid: &Hash256, height: u64) -> bool {
+        match self.transactions.get_mut(txid) {
+            Some(entry) => {
+                entry.status = BroadcastStatus::Confirmed(height);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Puts transactions confirmed in blocks above `fork_height` back to pending after a reorg
+    /// and returns them
+    pub fn unconfirm_above(&mut self, fork_height: u64) -> Result<Vec<Tx>> {
+        let mut unconfirmed = Vec::new();
+        for entry in self.transactions.values_mut() {
+            if matches!(entry.status, BroadcastStatus::Confirmed(height) if height > fork_height) {
+                entry.status = BroadcastStatus::Pending;
+                entry.last_broadcast = 0;
+                unconfirmed.push(Tx::read(&mut Cursor::new(&entry.raw_tx))?);
+            }
+        }
+        Ok(unconfirmed)
+    }
+
+    /// Unconfirmed transactions not announced for a while, with their fee rate
+    pub fn due_for_rebroadcast(&self, now: u64) -> Result<Vec<(Tx, u64)>> {
+        self.transactions.values()
+            .filter(|entry| matches!(entry.status, BroadcastStatus::Pending | BroadcastStatus::Relayed))
+            .filter(|entry| entry.last_broadcast + REBROADCAST_INTERVAL_SECS <= now)
+            .map(|entry| Ok((Tx::read(&mut Cursor::new(&entry.raw_tx))?, entry.fee_rate)))
+            .collect()
+    }
+
+    pub fn mark_broadcast(&mut self, txid: &Hash256, now: u64) {
+        if let Some(entry) = self.transactions.get_mut(txid) {
+            entry.last_broadcast = now;
+        }
+    }
+
+    /// Forgets transactions confirmed deep enough below `tip_height` not to be reorged out,
+    /// and the ones replaced by a transaction already forgotten
+    pub fn prune(&mut self, tip_height: u64) {
+        self.transactions.retain(|_, entry| match entry.status {
+            BroadcastStatus::Confirmed(height) => height + MAX_CONFIRMED_DEPTH > tip_height,
+            _ => true,
+        });
+        let tracked: Vec<Hash256> = self.transactions.keys().cloned().collect();
+        self.transactions.retain(|_, entry| match entry.status {
+            BroadcastStatus::Replaced(replacement) => tracked.contains(&replacement),
+            _ => true,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{tx_in::TxIn, tx_out::TxOut, OutPoint};
+
+    fn status<'a>(tracker: &'a BroadcastTracker, txid: &Hash256) -> Option<&'a BroadcastStatus> {
+        tracker.transactions.get(txid).map(|entry| &entry.status)
+    }
+
+    fn transaction(amount: i64) -> Tx {
+        Tx {
+            version: 2,
+            inputs: vec![TxIn { prev_output: OutPoint { hash: Hash256([7; 32]), index: 0 }, unlock_script: vec![], sequence: 0xffffffff }],
+            outputs: vec![TxOut { satoshis: amount, lock_script: vec![0x00, 0x14, 1, 2, 3] }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rebroadcasts_unconfirmed_transactions() {
+        let mut tracker = BroadcastTracker::default();
+        let tx = transaction(1000);
+        tracker.track(&tx, 2, 100).unwrap();
+
+        assert!(tracker.due_for_rebroadcast(100 + REBROADCAST_INTERVAL_SECS - 1).unwrap().is_empty());
+        let due = tracker.due_for_rebroadcast(100 + REBROADCAST_INTERVAL_SECS).unwrap();
+        assert_eq!(due.len(), 1);
+        assert!(due[0].0.txid() == tx.txid());
+        assert_eq!(due[0].1, 2);
+
+        tracker.mark_broadcast(&tx.txid(), 100 + REBROADCAST_INTERVAL_SECS);
+        assert!(tracker.due_for_rebroadcast(100 + REBROADCAST_INTERVAL_SECS).unwrap().is_empty());
+    }
+
+    #[test]
+    fn follows_transaction_status() {
+        let mut tracker = BroadcastTracker::default();
+        let tx = transaction(1000);
+        tracker.track(&tx, 2, 100).unwrap();
+        assert!(!tracker.mark_relayed(&transaction(5).txid()));
+
+        assert!(tracker.mark_relayed(&tx.txid()));
+        assert!(status(&tracker, &tx.txid()) == Some(&BroadcastStatus::Relayed));
+        assert!(tracker.is_broadcasting(&tx.txid()));
+
+        assert!(tracker.confirm(&tx.txid(), 50));
+        assert!(tracker.mark_relayed(&tx.txid()));
+        assert!(status(&tracker, &tx.txid()) == Some(&BroadcastStatus::Confirmed(50)));
+        assert!(tracker.due_for_rebroadcast(u64::MAX / 2).unwrap().is_empty());
+
+        assert!(tracker.unconfirm_above(50).unwrap().is_empty());
+        assert_eq!(tracker.unconfirm_above(49).unwrap().len(), 1);
+        assert!(status(&tracker, &tx.txid()) == Some(&BroadcastStatus::Pending));
+        assert_eq!(tracker.due_for_rebroadcast(REBROADCAST_INTERVAL_SECS).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn rejected_transactions_are_not_rebroadcast() {
+        let mut tracker = BroadcastTracker::default();
+        let tx = transaction(1000);
+        tracker.track(&tx, 2, 100).unwrap();
+        assert!(tracker.is_broadcasting(&tx.txid()));
+        tracker.mark_rejected(&tx.txid(), RejectCode::InsufficientFee, "min relay fee not met".to_string());
+        assert!(!tracker.is_broadcasting(&tx.txid()));
+
+        assert!(status(&tracker, &tx.txid()) == Some(&BroadcastStatus::Rejected(RejectCode::InsufficientFee, "min relay fee not met".to_string())));
+        assert!(tracker.due_for_rebroadcast(u64::MAX / 2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn replaced_transactions_are_not_rebroadcast() {
+        let mut tracker = BroadcastTracker::default();
+        let tx = transaction(1000);
+        let replacement = transaction(900);
+        tracker.track(&tx, 2, 100).unwrap();
+        assert!(tracker.replaceable(&tx.txid()).is_some());
+
+        tracker.track(&replacement, 4, 200).unwrap();
+        tracker.mark_replaced(&tx.txid(), replacement.txid());
+        assert!(tracker.replaceable(&tx.txid()).is_none());
+        let due = tracker.due_for_rebroadcast(u64::MAX / 2).unwrap();
+        assert_eq!(due.len(), 1);
+        assert!(due[0].0.txid() == replacement.txid());
+
+        // Both are forgotten once the replacement is buried
+        tracker.confirm(&replacement.txid(), 10);
+        assert!(tracker.replaceable(&replacement.txid()).is_none());
+        tracker.prune(10 + MAX_CONFIRMED_DEPTH);
+        assert!(status(&tracker, &tx.txid()).is_none());
+    }
+
+    #[test]
+    fn prunes_deeply_confirmed_transactions() {
+        let mut tracker = BroadcastTracker::default();
+        let tx = transaction(1000);
+        tracker.track(&tx, 2, 100).unwrap();
+        tracker.confirm(&tx.txid(), 10);
+
+        tracker.prune(10 + MAX_CONFIRMED_DEPTH - 1);
+        assert!(status(&tracker, &tx.txid()).is_some());
+        tracker.prune(10 + MAX_CONFIRMED_DEPTH);
+        assert!(status(&tracker, &tx.txid()).is_none());
+    }
+}
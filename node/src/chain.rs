@@ -1,24 +1,164 @@
-use std::{iter::zip, sync::Arc};
-use crate::{bindings::component::wallet::types::{PartialUtxo, WatchOnly}, messages::{block_locator::NO_HASH_STOP, compact_filter::CompactFilter, tx::Tx, tx_out::TxOut, Inv, InvVect}, util::{self, sha256d, Error}};
+use std::{cmp::min, collections::BTreeMap, sync::Arc};
+use crate::{bindings::component::wallet::types::{Keychain, PartialUtxo, WatchOnly}, db::{KeyValueDb, BLOCK_UNDO_KEY_PREFIX}, filter_headers::{chain_filter_headers, filter_header, filter_matches_block, FilterHeaderStore}, broadcast::BroadcastTracker, header_store::{HeaderStore, Reorg}, history::{TransactionHistory, TransactionRecord, WalletOutput}, mempool::MempoolState, messages::{block::Block, block_locator::NO_HASH_STOP, compact_filter::CompactFilter, compact_filter_checkpoint::CHECKPOINT_INTERVAL, inv_vect::{INV_VECT_BLOCK, INV_VECT_TX}, tx::Tx, tx_out::TxOut, BlockHeader, Inv, InvVect}, p2p::PeerId, util::{self, sha256d, Error}};
 
 use bitcoin::network as bitcoin_network;
+use wasi::clocks::wall_clock;
 use serde::Serialize;
 
-use crate::{node::CustomIPV4SocketAddress, p2p::{P2PControl, P2P}, util::Hash256};
+use crate::{node::CustomIPV4SocketAddress, p2p::P2P, util::{Hash256, Serializable}};
 
 pub struct CompactChain {
     p2p: P2P,
     chain_state: ChainState,
+    headers: HeaderStore,
+    filter_headers: FilterHeaderStore,
+    mempool: MempoolState,
+    broadcasts: BroadcastTracker,
+    history: TransactionHistory,
+    birthday: Option<WalletBirthday>,
     wallet: Arc<WatchOnly>,
+    db: Arc<KeyValueDb>,
 }
 
 
+/// Last block whose compact filter was scanned for wallet outputs
 #[derive(serde::Deserialize, Serialize, Clone)]
 pub struct ChainState {
     last_block_hash: Hash256,
     last_block_height: u64,
 }
 
+/// Earliest point the wallet may have received funds, blocks before it are never scanned
+#[derive(serde::Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+pub enum WalletBirthday {
+    Height(u64),
+    /// Seconds since the unix epoch
+    Timestamp(u64),
+}
+
+/// Wallet output as recorded in a block undo entry
+#[derive(serde::Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct UtxoRecord {
+    pub txid: Vec<u8>,
+    pub vout: u32,
+    pub amount: u64,
+    pub script: Vec<u8>,
+    /// Height of the block that mined it, `None` while unconfirmed
+    pub height: Option<u32>,
+    pub is_coinbase: bool,
+}
+
+impl From<&PartialUtxo> for UtxoRecord {
+    fn from(utxo: &PartialUtxo) -> Self {
+        Self { txid: utxo.txid.clone(), vout: utxo.vout, amount: utxo.amount, script: utxo.script.clone(), height: utxo.height, is_coinbase: utxo.is_coinbase }
+    }
+}
+
+impl UtxoRecord {
+    pub fn to_partial_utxo(&self, is_spent: bool) -> PartialUtxo {
+        PartialUtxo { txid: self.txid.clone(), vout: self.vout, amount: self.amount, script: self.script.clone(), is_spent, height: self.height, is_coinbase: self.is_coinbase }
+    }
+
+    pub fn txid_hash(&self) -> Hash256 {
+        let mut hash = Hash256::default();
+        hash.0.copy_from_slice(&self.txid);
+        hash
+    }
+}
+
+/// Wallet changes made by a block, kept so they can be reverted if the block is disconnected
+#[derive(serde::Deserialize, Serialize, Clone, Default, Debug, PartialEq)]
+struct BlockUndo {
+    created: Vec<UtxoRecord>,
+    spent: Vec<UtxoRecord>,
+}
+
+impl BlockUndo {
+    fn key(block_hash: &Hash256) -> String {
+        format!("{}{}", BLOCK_UNDO_KEY_PREFIX, block_hash.encode())
+    }
+
+    fn save(&self, db: &KeyValueDb, block_hash: &Hash256) -> Result<(), Error> {
+        let encoded_undo = bincode::serialize(self).map_err(|err| Error::SerializationError(err.to_string()))?;
+        db.insert(Self::key(block_hash), encoded_undo)
+    }
+
+    fn load(db: &KeyValueDb, block_hash: &Hash256) -> Result<Option<Self>, Error> {
+        match db.get_optional(Self::key(block_hash))? {
+            Some(encoded_undo) => bincode::deserialize(&encoded_undo).map(Some).map_err(|err| Error::SerializationError(err.to_string())),
+            None => Ok(None),
+        }
+    }
+
+    fn delete(db: &KeyValueDb, block_hash: &Hash256) -> Result<(), Error> {
+        db.delete(Self::key(block_hash))
+    }
+
+    /// Outputs the block created, which the wallet has to forget
+    fn created_utxos(&self) -> Vec<PartialUtxo> {
+        self.created.iter().map(|record| record.to_partial_utxo(false)).collect()
+    }
+
+    /// Outputs the block spent, which are unspent again without it
+    fn spent_utxos(&self) -> Vec<PartialUtxo> {
+        self.spent.iter().map(|record| record.to_partial_utxo(false)).collect()
+    }
+}
+
+/// Wallet outputs a block creates and spends, collected transaction by transaction
+struct BlockScan {
+    height: u64,
+    utxos: Vec<PartialUtxo>,
+    undo: BlockUndo,
+}
+
+impl BlockScan {
+    fn new(height: u64) -> Self {
+        Self { height, utxos: Vec::new(), undo: BlockUndo::default() }
+    }
+
+    /// Marks the outputs `txn` spends, of the wallet or created earlier in the block, returning them
+    fn spend_inputs(&mut self, txn: &Tx, wallet_utxos: &[PartialUtxo]) -> Vec<UtxoRecord> {
+        let mut spent: Vec<UtxoRecord> = vec![];
+        for input in txn.inputs.iter() {
+            if input.prev_output.hash == NO_HASH_STOP {
+                continue;
+            }
+            let prev_txid = input.prev_output.hash.0.to_vec();
+
+            // Output created earlier in this block
+            if let Some(utxo) = self.utxos.iter_mut().find(|utxo| utxo.txid == prev_txid && utxo.vout == input.prev_output.index) {
+                utxo.is_spent = true;
+                spent.push((&*utxo).into());
+                continue;
+            }
+
+            if let Some(utxo) = wallet_utxos.iter().find(|utxo| !utxo.is_spent && utxo.txid == prev_txid && utxo.vout == input.prev_output.index) {
+                spent.push(utxo.into());
+                self.undo.spent.push(utxo.into());
+                let mut utxo = utxo.clone();
+                utxo.is_spent = true;
+                self.utxos.push(utxo);
+            }
+        }
+        spent
+    }
+
+    /// Records the outputs of `txn` paying one of `pub_keys`, returning their indexes
+    fn add_outputs(&mut self, txn: &Tx, txid: &Hash256, is_coinbase: bool, pub_keys: &[Vec<u8>]) -> Vec<usize> {
+        let mut created = vec![];
+        for (index, output) in txn.outputs.iter().enumerate() {
+            if pub_keys.contains(&output.lock_script) {
+                let utxo = PartialUtxo { amount: output.satoshis as u64, txid: txid.0.to_vec(), vout: index as u32, script: output.lock_script.clone(), is_spent: false, height: Some(self.height as u32), is_coinbase };
+                self.undo.created.push((&utxo).into());
+                self.utxos.push(utxo);
+                created.push(index);
+            }
+        }
+        created
+    }
+}
+
 #[derive(serde::Deserialize, Serialize, Clone)]
 pub struct Utxo  {
     pub tx_out: TxOut,
@@ -28,174 +168,853 @@ pub struct Utxo  {
 }
 
 
+/// Filter headers of one peer for a range of blocks
+struct FilterHeaderCandidate {
+    peer: PeerId,
+    filter_hashes: Vec<Hash256>,
+    filter_headers: Vec<Hash256>,
+}
+
+impl FilterHeaderCandidate {
+    /// Whether the range ends on `checkpoint`, so it can be taken without asking other peers
+    fn ends_on(&self, checkpoint: Option<&Hash256>) -> bool {
+        checkpoint.is_some() && self.filter_headers.last() == checkpoint
+    }
+}
+
+/// Number of peers among `served` that serve exactly `filter_headers`
+fn support(served: &[Vec<Hash256>], filter_headers: &[Hash256]) -> usize {
+    served.iter().filter(|headers| headers.as_slice() == filter_headers).count()
+}
+
+/// Checkpoint at `height` that every peer serving checkpoints agrees on, if any
+fn agreed_checkpoint(checkpoints: &BTreeMap<PeerId, Vec<Hash256>>, height: u64) -> Option<Hash256> {
+    let index = (height / CHECKPOINT_INTERVAL) as usize;
+    if !height.is_multiple_of(CHECKPOINT_INTERVAL) || index == 0 || checkpoints.is_empty() {
+        return None;
+    }
+    let values: Vec<_> = checkpoints.values().map(|headers| headers.get(index - 1)).collect();
+    match values.iter().all(|value| value.is_some() && *value == values[0]) {
+        true => values[0].cloned(),
+        false => None,
+    }
+}
+
 const MAX_HEADER_LEN: usize = 2000;
 const FILTER_SIZE: usize = 500;
+const MAX_FILTER_HEADERS_LEN: u64 = 2000;
+/// Announced transactions fetched per sync, the rest are skipped
+const MAX_MEMPOOL_FETCH: usize = 1000;
+/// Blocks timestamped this long before a timestamp birthday are still scanned, block times are
+/// only loosely tied to the time the block was found
+const BIRTHDAY_MARGIN_SECS: u64 = 24 * 60 * 60;
 
 
 impl CompactChain {
 
-    pub fn new(socket: CustomIPV4SocketAddress, network: bitcoin_network::Network, genesis_block: Hash256,  wallet: Arc<WatchOnly>  ) -> Self {
-        let mut p2p = P2P::new();
-        p2p.connect_peer(socket, network).expect("Failed to connect to peer");
+    pub fn new(peers: Vec<CustomIPV4SocketAddress>, max_connections: usize, use_seeds: bool, network: bitcoin_network::Network, genesis_header: BlockHeader, birthday: Option<WalletBirthday>, wallet: Arc<WatchOnly>, db: Arc<KeyValueDb>) -> Self {
+        let mut p2p = P2P::new(network, peers, max_connections, use_seeds, db.clone());
+        p2p.maintain_connections().expect("Failed to connect to peer");
 
-        let last_block_hash = genesis_block;
-        let last_block_height = 0;
-        Self{ p2p, chain_state: ChainState{ last_block_hash, last_block_height }, wallet }
+        let chain_state = ChainState { last_block_hash: genesis_header.hash(), last_block_height: 0 };
+        let headers = HeaderStore::new(db.clone(), network, genesis_header).expect("Failed to create header store");
+        let filter_headers = FilterHeaderStore::new(db.clone()).expect("Failed to create filter header store");
+        let mempool = MempoolState::default();
+        let broadcasts = BroadcastTracker::default();
+        let history = TransactionHistory::default();
+        Self{ p2p, chain_state, headers, filter_headers, mempool, broadcasts, history, birthday, wallet, db }
 
     }
 
-    pub fn restore(socket: CustomIPV4SocketAddress, network: bitcoin_network::Network, wallet: Arc<WatchOnly>, state: Vec<u8> ) -> Self {
-        let mut p2p = P2P::new();
-        p2p.connect_peer(socket, network).expect("Failed to connect to peer");
+    pub fn restore(peers: Vec<CustomIPV4SocketAddress>, max_connections: usize, use_seeds: bool, network: bitcoin_network::Network, birthday: Option<WalletBirthday>, wallet: Arc<WatchOnly>, state: Vec<u8>, db: Arc<KeyValueDb>) -> Self {
+        let mut p2p = P2P::new(network, peers, max_connections, use_seeds, db.clone());
+        p2p.maintain_connections().expect("Failed to connect to peer");
 
         let chain_state: ChainState = bincode::deserialize(&state).expect("Failed to deserialise Chain State");
-        Self{ p2p, chain_state: chain_state, wallet }
+        let headers = HeaderStore::restore(db.clone(), network).expect("Failed to restore header store");
+        let filter_headers = FilterHeaderStore::restore(db.clone()).expect("Failed to restore filter header store");
+        let mempool = MempoolState::load(&db).expect("Failed to restore mempool state");
+        let broadcasts = BroadcastTracker::load(&db).expect("Failed to restore broadcast tracker");
+        let history = TransactionHistory::load(&db).expect("Failed to restore transaction history");
+        Self{ p2p, chain_state: chain_state, headers, filter_headers, mempool, broadcasts, history, birthday, wallet, db }
     }
 
     pub fn get_state(& self) -> ChainState {
         return self.chain_state.clone()
     }
 
+    pub fn birthday(&self) -> Option<WalletBirthday> {
+        self.birthday
+    }
 
+    pub fn history(&self) -> &TransactionHistory {
+        &self.history
+    }
+
+
+    /// Downloads the filters of a range and checks them against the verified filter headers
     fn get_and_verify_compact_filters(& mut self, start_height: u32, last_block_hash: Hash256) -> Result<Vec<CompactFilter>, Error> {
-        let filter_header = self.p2p.get_compact_filter_headers(start_height, last_block_hash).map_err(|err| Error::FetchCompactFilterHeader(err.to_error_code()))?;
-        let filters = self.p2p.get_compact_filters(start_height, last_block_hash).map_err(|err| Error::FetchCompactFilter(err.to_error_code()))?;
+        let (peer, filters) = self.p2p.get_compact_filters(start_height, last_block_hash).map_err(|err| Error::FetchCompactFilter(err.to_error_code()))?;
+
+        let mut prev_hash = self.headers.hash_at(start_height as u64 - 1)?
+            .ok_or(Error::IllegalState(format!("No header at height {}", start_height - 1)))?;
+        for (offset, compact_filter) in filters.iter().enumerate() {
+            let height = start_height as u64 + offset as u64;
+            let block_hash = self.headers.hash_at(height)?;
+            if block_hash != Some(compact_filter.block_hash) {
+                return Err(Error::InvalidFilterHeader(format!("Unexpected filter for block {}", compact_filter.block_hash.encode())));
+            }
 
-        
-        for (filter_hash, compact_filter) in zip(filter_header.filter_hashes, filters.clone()) {
-            let computed_hash = sha256d(&compact_filter.filter_bytes);
-            if computed_hash != filter_hash {
+            let prev_header = self.filter_headers.get(&prev_hash)?
+                .ok_or(Error::IllegalState(format!("No filter header at height {}", height - 1)))?;
+            let expected_header = self.filter_headers.get(&compact_filter.block_hash)?
+                .ok_or(Error::IllegalState(format!("No filter header at height {}", height)))?;
+
+            // The filter headers are cross-checked, so a filter that does not match them is a lie
+            if filter_header(&sha256d(&compact_filter.filter_bytes), &prev_header) != expected_header {
+                self.p2p.ban_peer(peer);
                 return Err(Error::FilterMatchEror)
             }
+            prev_hash = compact_filter.block_hash;
         }
         return Ok(filters);
     }
 
-    fn fetch_and_save_utxos(&mut self, filters: Vec<CompactFilter>) -> Result<(), Error> {
-        let pub_keys = &self.wallet.get_pubkeys().map_err(|_| Error::WalletError(1))?;
-
-        let blockhash_present: Vec<_> = filters.into_iter().filter_map(|filter| {
-            let filter_algo = util::block_filter::BlockFilter::new(&filter.filter_bytes);
-            
-            let result = filter_algo.match_any(&filter.block_hash, pub_keys.clone().into_iter()).expect("error matching Filters");
-            match result {
-                true => Some(filter.block_hash),
-                false => None,
+    /// Filter header checkpoints of every peer that serves them, up to `stop_hash`
+    fn fetch_checkpoints(&mut self, stop_hash: Hash256) -> BTreeMap<PeerId, Vec<Hash256>> {
+        let mut checkpoints = BTreeMap::new();
+        for peer in self.p2p.peer_ids() {
+            match self.p2p.get_compact_filter_checkpoints_from(peer, stop_hash) {
+                Ok(response) if response.stop_hash == stop_hash => {
+                    checkpoints.insert(peer, response.filter_headers);
+                },
+                _ => continue,
             }
-        }).collect();
+        }
+        checkpoints
+    }
 
-        if blockhash_present.is_empty() {
-            return Ok(());
+    /// Filter headers served by `peer` for the blocks from `start_height` to `stop_hash`
+    ///
+    /// Answers that do not build on our verified filter header chain or contradict the peer's
+    /// own checkpoints get the peer banned. `None` is returned when the peer could not answer.
+    fn fetch_filter_header_candidate(&mut self, peer: PeerId, start_height: u64, stop_hash: Hash256, checkpoints: &BTreeMap<PeerId, Vec<Hash256>>) -> Result<Option<FilterHeaderCandidate>, Error> {
+        let response = match self.p2p.get_compact_filter_headers_from(peer, start_height as u32, stop_hash) {
+            Ok(response) => response,
+            Err(_) => return Ok(None),
+        };
+
+        let stop_height = self.headers.get_header(&stop_hash)?
+            .ok_or(Error::IllegalState("Stop header not found".to_string()))?.height;
+        let prev_header = self.filter_headers.prev_header();
+        let consistent = response.stop_hash == stop_hash
+            && response.previous_filter_header == prev_header
+            && response.filter_hashes.len() as u64 == stop_height - start_height + 1;
+        if !consistent {
+            self.p2p.ban_peer(peer);
+            return Ok(None);
         }
 
+        let filter_headers = chain_filter_headers(prev_header, &response.filter_hashes);
+
+        // Every checkpoint inside the range has to match the chain the peer just served
+        let peer_checkpoints = checkpoints.get(&peer).cloned().unwrap_or_default();
+        for (index, checkpoint) in peer_checkpoints.iter().enumerate() {
+            let height = (index as u64 + 1) * CHECKPOINT_INTERVAL;
+            if height < start_height || height > stop_height {
+                continue;
+            }
+            if filter_headers[(height - start_height) as usize] != *checkpoint {
+                self.p2p.ban_peer(peer);
+                return Ok(None);
+            }
+        }
 
-        let block_inv: Vec<_> = blockhash_present.into_iter().map(|hash| {
-            InvVect{ obj_type: 2, hash }
-        }).collect();
+        Ok(Some(FilterHeaderCandidate { peer, filter_hashes: response.filter_hashes, filter_headers }))
+    }
 
-        let blocks = self.p2p.get_block(Inv{ objects: block_inv}).map_err(|err| Error::FetchBlock(err.to_error_code()))?;
+    /// Decides which of two peers disagreeing on the filter of the block at `height` lies
+    ///
+    /// The block is downloaded and checked against our header, then the filter of each peer is
+    /// checked against its own filter hash and the block outputs. Peers proven wrong are banned.
+    fn adjudicate(&mut self, height: u64, first: &FilterHeaderCandidate, second: &FilterHeaderCandidate, start_height: u64) -> Result<Vec<PeerId>, Error> {
+        let block_hash = self.headers.hash_at(height)?
+            .ok_or(Error::IllegalState(format!("No header at height {}", height)))?;
+        let header = self.headers.get_header(&block_hash)?
+            .ok_or(Error::IllegalState(format!("No header at height {}", height)))?;
+
+        let block = self.fetch_verified_block(block_hash, &header.header)?;
+
+        let mut liars = Vec::new();
+        for candidate in [first, second] {
+            let filter_hash = candidate.filter_hashes[(height - start_height) as usize];
+            let filters = self.p2p.get_compact_filters_from(candidate.peer, height as u32, block_hash).unwrap_or_default();
+
+            let honest = match filters.first() {
+                Some(filter) => filter.block_hash == block_hash
+                    && sha256d(&filter.filter_bytes) == filter_hash
+                    && filter_matches_block(filter, &block).unwrap_or(false),
+                None => false,
+            };
+            if !honest {
+                liars.push(candidate.peer);
+            }
+        }
 
-        let utxos: Vec<PartialUtxo> = self.wallet.get_utxos().map_err(|_| Error::WalletError(1))?;
-        let mut new_utxos: Vec<PartialUtxo> = vec![];
-        for block in blocks {
-             for txn in block.txns {
-                 for (index, output) in txn.outputs.iter().enumerate() {
-                    if pub_keys.contains(&output.lock_script) {
-                        new_utxos.push(PartialUtxo { amount: output.satoshis as u64, txid:  txn.hash().0.to_vec(), vout: index as u32, script: output.lock_script.clone(), is_spent: false });
+        for peer in liars.iter() {
+            self.p2p.ban_peer(*peer);
+        }
+        Ok(liars)
+    }
+
+    /// Downloads a block from any peer serving one that matches `header`
+    fn fetch_verified_block(&mut self, block_hash: Hash256, header: &BlockHeader) -> Result<Block, Error> {
+        for peer in self.p2p.peer_ids() {
+            let inv = Inv { objects: vec![InvVect { obj_type: 2, hash: block_hash }] };
+            let block = match self.p2p.get_block_from(peer, inv) {
+                Ok(mut blocks) if !blocks.is_empty() => blocks.remove(0),
+                _ => continue,
+            };
+            if block.header == *header && block.merkle_root() == header.merkle_root {
+                return Ok(block);
+            }
+        }
+        Err(Error::FetchBlock(Error::PeerNotFound.to_error_code()))
+    }
+
+    /// Downloads and cross-checks filter headers until they cover our best chain
+    ///
+    /// Ranges ending on a checkpoint every peer agrees on are taken from the first peer whose
+    /// headers reach it, anything else is requested from all peers and conflicts are settled
+    /// with the block, or by the headers most peers serve when no filter is provably wrong.
+    fn sync_filter_headers(&mut self) -> Result<(), Error> {
+        let tip = self.headers.tip().clone();
+        if self.filter_headers.next_height() > tip.height {
+            return Ok(());
+        }
+
+        let checkpoints = self.fetch_checkpoints(tip.hash());
+
+        while self.filter_headers.next_height() <= tip.height {
+            let start_height = self.filter_headers.next_height();
+            let next_checkpoint = (start_height / CHECKPOINT_INTERVAL + 1) * CHECKPOINT_INTERVAL;
+            let stop_height = min(min(next_checkpoint, start_height + MAX_FILTER_HEADERS_LEN - 1), tip.height);
+            let stop_hash = self.headers.hash_at(stop_height)?
+                .ok_or(Error::IllegalState(format!("No header at height {}", stop_height)))?;
+
+            // A peer that served no checkpoints is only trusted alone when its range ends on the
+            // checkpoint the others agree on
+            let agreed_checkpoint = agreed_checkpoint(&checkpoints, stop_height);
+            let mut candidates = Vec::new();
+            for peer in self.p2p.peer_ids() {
+                if let Some(candidate) = self.fetch_filter_header_candidate(peer, start_height, stop_hash, &checkpoints)? {
+                    let settled = candidate.ends_on(agreed_checkpoint.as_ref());
+                    candidates.push(candidate);
+                    if settled {
+                        break;
                     }
-                 }
-
-                for input in txn.inputs {
-                   if input.prev_output.hash == NO_HASH_STOP {
-                       continue;
-                   } 
-
-                   //TODO: Fix this use get
-                   for (index,utxo) in  utxos.clone().iter().enumerate() {
-                        if utxo.txid == input.prev_output.hash.0.to_vec() && utxo.vout == input.prev_output.index {
-                            let mut utxo = utxos[index].clone();
-                            utxo.is_spent = true;
-                            new_utxos.push(utxo);
-                        }       
-                   } 
                 }
-             }
+            }
+
+            let served: Vec<_> = candidates.iter().map(|candidate| candidate.filter_headers.clone()).collect();
+            let mut accepted = candidates.pop().ok_or(Error::PeerNotFound)?;
+            while let Some(mut other) = candidates.pop() {
+                let difference = accepted.filter_headers.iter().zip(other.filter_headers.iter())
+                    .position(|(a, b)| a != b);
+                let offset = match difference {
+                    Some(offset) => offset as u64,
+                    None => continue,
+                };
+
+                let height = start_height + offset;
+                let liars = self.adjudicate(height, &accepted, &other, start_height)?;
+                match (liars.contains(&accepted.peer), liars.contains(&other.peer)) {
+                    (true, false) => accepted = other,
+                    (false, true) => {},
+                    (true, true) => accepted = candidates.pop().ok_or(Error::PeerNotFound)?,
+                    // Neither filter is provably wrong, the headers most peers serve are kept and the
+                    // other peer makes room for another one so the conflict does not come back
+                    (false, false) => {
+                        if support(&served, &other.filter_headers) > support(&served, &accepted.filter_headers) {
+                            std::mem::swap(&mut accepted, &mut other);
+                        }
+                        self.p2p.rotate_out(other.peer);
+                    },
+                }
+            }
+
+            let block_hashes = (start_height..=stop_height)
+                .map(|height| self.headers.hash_at(height)?.ok_or(Error::IllegalState(format!("No header at height {}", height))))
+                .collect::<Result<Vec<_>, Error>>()?;
+            self.filter_headers.connect(&block_hashes, &accepted.filter_headers)?;
         }
 
-        self.wallet.insert_utxos(&new_utxos).map_err(|_| Error::WalletError(1))?;
         Ok(())
+    }
+
+    fn fetch_and_save_utxos(&mut self, filters: Vec<CompactFilter>) -> Result<(), Error> {
+        let mut pub_keys = self.wallet.get_pubkeys().map_err(|_| Error::WalletError(1))?;
+        let mut start = 0;
+
+        // A wallet output moves the lookahead window, the filters after its block are matched
+        // again with the scripts added to the window
+        while start < filters.len() {
+            let blockhash_present: Vec<_> = filters[start..].iter().filter_map(|filter| {
+                let filter_algo = util::block_filter::BlockFilter::new(&filter.filter_bytes);
+
+                let result = filter_algo.match_any(&filter.block_hash, pub_keys.clone().into_iter()).expect("error matching Filters");
+                match result {
+                    true => Some(filter.block_hash),
+                    false => None,
+                }
+            }).collect();
+
+            if blockhash_present.is_empty() {
+                break;
+            }
+
+            let block_inv: Vec<_> = blockhash_present.into_iter().map(|hash| {
+                InvVect{ obj_type: 2, hash }
+            }).collect();
+
+            let blocks = self.p2p.get_block(Inv{ objects: block_inv}).map_err(|err| Error::FetchBlock(err.to_error_code()))?;
+
+            // Blocks are applied one by one so each gets its own undo record
+            let mut restart_at = None;
+            for block in blocks {
+                let block_hash = block.header.hash();
+                self.apply_block(block, &pub_keys)?;
+
+                let window = self.wallet.get_pubkeys().map_err(|_| Error::WalletError(1))?;
+                if window.len() != pub_keys.len() {
+                    pub_keys = window;
+                    restart_at = filters.iter().position(|filter| filter.block_hash == block_hash).map(|position| position + 1);
+                    break;
+                }
+            }
+
+            match restart_at {
+                Some(position) => start = position,
+                None => break,
+            }
+        }
+
+        self.mempool.save(&self.db)?;
+        self.broadcasts.save(&self.db)?;
+        self.history.save(&self.db)
 
     }
 
-    pub fn sync_state(& mut self) -> Result<(),Error> {
-        self.p2p.keep_alive().map_err(|_| Error::NetworkError)?;
+    /// Records the wallet outputs `block` creates and spends, along with their undo entry
+    fn apply_block(&mut self, block: Block, pub_keys: &[Vec<u8>]) -> Result<(), Error> {
+        let block_hash = block.header.hash();
+        let block_time = block.header.timestamp as u64;
+        let height = self.headers.get_header(&block_hash)?
+            .ok_or(Error::IllegalState("Matched block header not found".to_string()))?.height;
+        let utxos: Vec<PartialUtxo> = self.wallet.get_utxos().map_err(|_| Error::WalletError(1))?;
+        let mut scan = BlockScan::new(height);
 
-        let mut is_sync = true;
+        for (position, txn) in block.txns.into_iter().enumerate() {
+            let txid = txn.txid();
+            self.mempool.confirm(&txid);
+            if self.broadcasts.confirm(&txid, height) {
+                println!("sent transaction {} confirmed at height {}", txid.encode(), height);
+            }
 
-        println!("syncing");
+            let spent = scan.spend_inputs(&txn, &utxos);
+            let created: Vec<WalletOutput> = scan.add_outputs(&txn, &txid, position == 0, pub_keys).into_iter()
+                .map(|index| self.wallet_output(index, &txn.outputs[index]))
+                .collect();
 
-        while is_sync {
+            if let Some(record) = TransactionRecord::new(&txn, spent, created, block_time) {
+                self.history.add_confirmed(record, height, block_hash, block_time);
+            }
+        }
 
-            let fetched_block_headers = self.p2p.fetch_headers(self.chain_state.last_block_hash)
-            .map_err(|err| Error::FetchHeader(err.to_error_code()))?;
-            if fetched_block_headers.len() == 0 {
-                return Ok(());
+        if scan.utxos.is_empty() {
+            return Ok(());
+        }
+
+        self.wallet.insert_utxos(&scan.utxos).map_err(|_| Error::WalletError(1))?;
+        scan.undo.save(&self.db, &block_hash)
+    }
+
+    fn wallet_output(&self, vout: usize, output: &TxOut) -> WalletOutput {
+        let is_change = self.wallet.get_keychain(&output.lock_script) == Some(Keychain::Internal);
+        WalletOutput { vout: vout as u32, amount: output.satoshis as u64, script: output.lock_script.clone(), is_change }
+    }
+
+    /// History record of a transaction outside a block, `None` when it does not touch the wallet
+    fn unconfirmed_record(&self, tx: &Tx, utxos: &[PartialUtxo], scripts: &[Vec<u8>], now: u64) -> Option<TransactionRecord> {
+        let inputs = tx.inputs.iter()
+            .filter_map(|input| utxos.iter().find(|utxo| !utxo.is_spent && utxo.txid == input.prev_output.hash.0.to_vec() && utxo.vout == input.prev_output.index))
+            .map(|utxo| utxo.into())
+            .collect();
+        let outputs = tx.outputs.iter().enumerate()
+            .filter(|(_, output)| scripts.contains(&output.lock_script))
+            .map(|(vout, output)| self.wallet_output(vout, output))
+            .collect();
+        TransactionRecord::new(tx, inputs, outputs, now)
+    }
+
+    /// Reverts the wallet changes of the blocks disconnected by `reorg`
+    fn rollback(&mut self, reorg: Reorg) -> Result<(), Error> {
+        self.revert_blocks(&reorg.disconnected)?;
+        self.filter_headers.rewind(reorg.fork_height, reorg.fork_hash)?;
+
+        // Our sends the reorg took out of the chain hold their inputs again until mined anew
+        let unconfirmed = self.broadcasts.unconfirm_above(reorg.fork_height)?;
+        self.broadcasts.save(&self.db)?;
+        if !unconfirmed.is_empty() {
+            let scripts = self.wallet.get_pubkeys().map_err(|_| Error::WalletError(1))?;
+            let now = wall_clock::now().seconds;
+            for transaction in unconfirmed.iter() {
+                self.hold_unconfirmed_send(transaction, &scripts, now)?;
             }
+        }
 
-            let last_block_hash = fetched_block_headers.last()
-                .expect("No block headers found")
-                .hash();
-
-            // Calculate the range for the for loop
-            let start_block = self.chain_state.last_block_height + 1;
-            let end_block = start_block + (fetched_block_headers.len() - 1) as u64;
-
-            let mut block_numbers = vec![start_block];
-            //ensure that at least one loop is run
-            if start_block < end_block {
-                block_numbers = (start_block..end_block).step_by(FILTER_SIZE).collect();
-            }
-            // Generate ranges for block numbers and block headers counter
-            let block_headers_counters = (FILTER_SIZE..).step_by(FILTER_SIZE as usize);
-           
-            // Use zip to iterate over both ranges simultaneously
-            for (current_block_num, block_headers_counter) in block_numbers.into_iter().zip(block_headers_counters) {
-                // Get the last known block hash
-                let last_known_block_hash = fetched_block_headers
-                    .get(block_headers_counter as usize - 1)
-                    .map(|header| header.hash())
-                    .unwrap_or(last_block_hash);
-                // Fetch and verify compact filters for the current range
-                let block_filters = self.get_and_verify_compact_filters(
-                    current_block_num as u32,
-                    last_known_block_hash,
-                )?;
-
-                if block_filters.is_empty() {
-                    continue;
-                }
-                // Fetch and save UTXOs for the verified block filters
-                self.fetch_and_save_utxos(block_filters)?;
+        // The new branch gets scanned from the fork point
+        if self.chain_state.last_block_height > reorg.fork_height {
+            self.chain_state = ChainState { last_block_hash: reorg.fork_hash, last_block_height: reorg.fork_height };
+        }
+
+        Ok(())
+    }
+
+    /// Undoes the wallet changes recorded for `hashes`, which must be ordered highest first
+    fn revert_blocks(&mut self, hashes: &[Hash256]) -> Result<(), Error> {
+        for hash in hashes.iter() {
+            self.history.unconfirm_block(hash);
+            let undo = match BlockUndo::load(&self.db, hash)? {
+                Some(undo) => undo,
+                None => continue,
+            };
+
+            self.wallet.remove_utxos(&undo.created_utxos()).map_err(|_| Error::WalletError(1))?;
+            self.wallet.insert_utxos(&undo.spent_utxos()).map_err(|_| Error::WalletError(1))?;
+
+            BlockUndo::delete(&self.db, hash)?;
+        }
+
+        self.history.save(&self.db)
+    }
+
+    /// Forgets what was learned from the blocks from `from_height` on so the next sync scans them again
+    ///
+    /// A birthday after `from_height` is moved back to it, otherwise the scan would skip the
+    /// blocks again.
+    pub fn rescan(&mut self, from_height: u64) -> Result<(), Error> {
+        let from_height = from_height.max(1);
+        if self.scan_start_height()? > from_height {
+            self.birthday = Some(WalletBirthday::Height(from_height));
+        }
+        if from_height > self.chain_state.last_block_height {
+            return Ok(());
+        }
+        println!("rescanning from height {}", from_height);
+
+        let hashes = (from_height..=self.chain_state.last_block_height).rev()
+            .map(|height| self.headers.hash_at(height)?.ok_or(Error::IllegalState(format!("No header at height {}", height))))
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.revert_blocks(&hashes)?;
+
+        let last_block_hash = self.headers.hash_at(from_height - 1)?
+            .ok_or(Error::IllegalState(format!("No header at height {}", from_height - 1)))?;
+        self.chain_state = ChainState { last_block_hash, last_block_height: from_height - 1 };
+        Ok(())
+    }
+
+    /// First block height that has to be scanned for the wallet birthday
+    ///
+    /// A timestamp birthday later than every known header resolves past the tip.
+    fn scan_start_height(&self) -> Result<u64, Error> {
+        match self.birthday {
+            None => Ok(1),
+            Some(WalletBirthday::Height(height)) => Ok(height.max(1)),
+            Some(WalletBirthday::Timestamp(timestamp)) => {
+                let height = self.headers.first_height_after(timestamp.saturating_sub(BIRTHDAY_MARGIN_SECS))?;
+                Ok(height.unwrap_or(self.headers.tip().height + 1).max(1))
+            },
+        }
+    }
+
+    /// Downloads headers from the peer until our best chain matches its tip
+    fn sync_headers(&mut self) -> Result<(), Error> {
+        // Set when a batch extended a side branch without overtaking the best chain
+        let mut branch_tip: Option<Hash256> = None;
+
+        loop {
+            let mut locator = self.headers.locator()?;
+            if let Some(hash) = branch_tip {
+                locator.insert(0, hash);
             }
-    
+
+            let previous_tip = self.headers.tip().hash();
+            let fetched_block_headers = self.p2p.fetch_headers(locator)
+                .map_err(|err| Error::FetchHeader(err.to_error_code()))?;
+            let last_header = match fetched_block_headers.last() {
+                Some(header) => header.hash(),
+                None => return Ok(()),
+            };
+
+            if let Some(reorg) = self.headers.connect(&fetched_block_headers)? {
+                self.rollback(reorg)?;
+            }
+
             if fetched_block_headers.len() < MAX_HEADER_LEN {
-                is_sync = false;
+                return Ok(());
             }
 
-            self.chain_state.last_block_height = end_block;
-            self.chain_state.last_block_hash = last_block_hash;
-        }  
-        
+            branch_tip = match self.headers.tip().hash() == previous_tip {
+                true => Some(last_header),
+                false => None,
+            };
+        }
+    }
+
+    /// Scans the compact filters of the best chain blocks that were not scanned yet
+    fn sync_filters(&mut self) -> Result<(), Error> {
+        let tip_height = min(self.headers.tip().height, self.filter_headers.next_height().saturating_sub(1));
+
+        // Blocks before the wallet birthday cannot hold wallet outputs
+        let skip_to = min(self.scan_start_height()? - 1, tip_height);
+        if self.chain_state.last_block_height < skip_to {
+            let last_block_hash = self.headers.hash_at(skip_to)?
+                .ok_or(Error::IllegalState(format!("No header at height {}", skip_to)))?;
+            self.chain_state = ChainState { last_block_hash, last_block_height: skip_to };
+        }
+
+        while self.chain_state.last_block_height < tip_height {
+            let start_height = self.chain_state.last_block_height + 1;
+            let stop_height = min(start_height + FILTER_SIZE as u64 - 1, tip_height);
+            let stop_hash = self.headers.hash_at(stop_height)?
+                .ok_or(Error::IllegalState(format!("No header at height {}", stop_height)))?;
+
+            let block_filters = self.get_and_verify_compact_filters(start_height as u32, stop_hash)?;
+            if !block_filters.is_empty() {
+                self.fetch_and_save_utxos(block_filters)?;
+            }
+
+            self.chain_state = ChainState { last_block_hash: stop_hash, last_block_height: stop_height };
+        }
+
+        self.wallet.set_tip_height(self.chain_state.last_block_height as u32);
         Ok(())
-        
     }
 
-    pub fn send_transaction(& mut self, transaction: Tx) -> Result<(),Error> {
+    pub fn sync_state(& mut self) -> Result<(),Error> {
         self.p2p.keep_alive().map_err(|_| Error::NetworkError)?;
-        self.p2p.send_transaction(transaction)?;
+
+        // Announced blocks are picked up by the header sync below
+        let announcements = self.p2p.take_announcements();
+        let announced_blocks = announcements.iter().filter(|(_, inv)| inv.obj_type == INV_VECT_BLOCK).count();
+        println!("syncing, {} blocks announced", announced_blocks);
+        self.track_relayed(&announcements)?;
+        self.watch_mempool(announcements)?;
+
+        self.sync_headers()?;
+        self.sync_filter_headers()?;
+        self.sync_filters()?;
+        self.expire_mempool()?;
+        self.rebroadcast()
+    }
+
+    /// Peers announcing one of our sends show it propagated
+    fn track_relayed(&mut self, announcements: &[(PeerId, InvVect)]) -> Result<(), Error> {
+        let mut relayed = false;
+        for (_, inv) in announcements.iter().filter(|(_, inv)| inv.obj_type == INV_VECT_TX) {
+            relayed |= self.broadcasts.mark_relayed(&inv.hash);
+        }
+        if relayed {
+            self.broadcasts.save(&self.db)?;
+        }
         Ok(())
     }
 
+    /// Announces our unconfirmed sends again, stopping for the ones every peer now rejects
+    fn rebroadcast(&mut self) -> Result<(), Error> {
+        self.broadcasts.prune(self.chain_state.last_block_height);
+
+        let now = wall_clock::now().seconds;
+        for (transaction, fee_rate) in self.broadcasts.due_for_rebroadcast(now)? {
+            let txid = transaction.txid();
+            match self.p2p.send_transaction(transaction, fee_rate) {
+                Ok(()) => self.broadcasts.mark_broadcast(&txid, now),
+                Err(Error::TransactionRejected(code, reason)) => self.broadcasts.mark_rejected(&txid, code, reason),
+                // Tried again once it is due
+                Err(_) => continue,
+            }
+        }
+        self.broadcasts.save(&self.db)
+    }
+
+    /// Fetches announced transactions and hands their outputs paying the wallet to it as unconfirmed
+    fn watch_mempool(&mut self, announcements: Vec<(PeerId, InvVect)>) -> Result<(), Error> {
+        let mut announced: BTreeMap<PeerId, Vec<Hash256>> = BTreeMap::new();
+        for (peer, inv) in announcements {
+            if inv.obj_type == INV_VECT_TX {
+                announced.entry(peer).or_default().push(inv.hash);
+            }
+        }
+        if announced.is_empty() {
+            return Ok(());
+        }
+
+        let scripts = self.wallet.get_pubkeys().map_err(|_| Error::WalletError(1))?;
+        let utxos = self.wallet.get_utxos().map_err(|_| Error::WalletError(1))?;
+        let now = wall_clock::now().seconds;
+        let mut budget = MAX_MEMPOOL_FETCH;
+        let mut found = Vec::new();
+
+        for (peer, txids) in announced {
+            let mut txids = self.mempool.unseen(txids);
+            txids.truncate(budget);
+            if txids.is_empty() {
+                continue;
+            }
+            budget -= txids.len();
+
+            let inv = Inv { objects: txids.into_iter().map(|hash| InvVect { obj_type: INV_VECT_TX, hash }).collect() };
+            // Transactions a peer fails to serve stay unseen and are fetched when announced again
+            let transactions = match self.p2p.get_transactions_from(peer, inv) {
+                Ok(transactions) => transactions,
+                Err(_) => continue,
+            };
+            for transaction in transactions.iter() {
+                found.extend(self.mempool.add_transaction(transaction, &scripts, now));
+                if let Some(record) = self.unconfirmed_record(transaction, &utxos, &scripts, now) {
+                    self.history.add_unconfirmed(record);
+                }
+            }
+        }
+
+        if !found.is_empty() {
+            let utxos: Vec<PartialUtxo> = found.iter().map(|record| record.to_partial_utxo(false)).collect();
+            self.wallet.insert_unconfirmed_utxos(&utxos).map_err(|_| Error::WalletError(1))?;
+        }
+        self.history.save(&self.db)?;
+        self.mempool.save(&self.db)
+    }
+
+    /// Forgets unconfirmed payments that were never mined, our sends are kept while they are
+    /// rebroadcast so their inputs stay reserved and their change stays known
+    fn expire_mempool(&mut self) -> Result<(), Error> {
+        let broadcasts = &self.broadcasts;
+        let expired = self.mempool.expire(wall_clock::now().seconds, |txid| broadcasts.is_broadcasting(txid));
+        if expired.is_empty() {
+            return Ok(());
+        }
+
+        let utxos: Vec<PartialUtxo> = expired.iter().map(|record| record.to_partial_utxo(false)).collect();
+        self.wallet.remove_utxos(&utxos).map_err(|_| Error::WalletError(1))?;
+        for record in expired.iter() {
+            self.history.remove_unconfirmed(&record.txid_hash());
+            self.wallet.release_pending_spent(&record.txid);
+        }
+        self.history.save(&self.db)?;
+        self.mempool.save(&self.db)
+    }
+
+    /// Raw bytes of our unconfirmed send with this txid, when it can still be replaced
+    pub fn replaceable_transaction(&self, txid: &Hash256) -> Option<Vec<u8>> {
+        self.broadcasts.replaceable(txid).map(|raw_tx| raw_tx.to_vec())
+    }
+
+    /// Raw bytes of the unconfirmed wallet transaction `txid`, from our sends or a peer's mempool
+    pub fn unconfirmed_transaction(& mut self, txid: &Hash256) -> Result<Vec<u8>, Error> {
+        if !matches!(self.history.get(txid), Some(record) if record.block.is_none()) {
+            return Err(Error::BadArgument(format!("{} is not an unconfirmed wallet transaction", txid.encode())));
+        }
+        if let Some(raw_tx) = self.broadcasts.replaceable(txid) {
+            return Ok(raw_tx.to_vec());
+        }
+
+        let inv = Inv { objects: vec![InvVect { obj_type: INV_VECT_TX, hash: *txid }] };
+        // Peers that fail to answer are skipped, the next one may have it
+        for peer in self.p2p.peer_ids() {
+            let transactions = self.p2p.get_transactions_from(peer, inv.clone()).unwrap_or_default();
+            if let Some(transaction) = transactions.into_iter().find(|transaction| transaction.txid() == *txid) {
+                let mut raw_tx = Vec::new();
+                transaction.write(&mut raw_tx)?;
+                return Ok(raw_tx);
+            }
+        }
+        Err(Error::IllegalState(format!("no peer has transaction {} in its mempool", txid.encode())))
+    }
+
+    /// Sends the fee bump of `original` and forgets the outputs the original paid the wallet
+    pub fn replace_transaction(& mut self, original: &Hash256, replacement: Tx, fee_rate: u64) -> Result<(), Error> {
+        let replacement_txid = replacement.txid();
+        self.send_transaction(replacement, fee_rate)?;
+
+        self.broadcasts.mark_replaced(original, replacement_txid);
+        self.broadcasts.save(&self.db)?;
+
+        let replaced: Vec<PartialUtxo> = self.mempool.remove(original).iter().map(|record| record.to_partial_utxo(false)).collect();
+        if !replaced.is_empty() {
+            self.wallet.remove_utxos(&replaced).map_err(|_| Error::WalletError(1))?;
+        }
+        // Inputs the replacement spends as well are held by it now
+        self.wallet.release_pending_spent(&original.0);
+        self.history.remove_unconfirmed(original);
+        self.history.save(&self.db)?;
+        self.mempool.save(&self.db)
+    }
+
+    pub fn send_transaction(& mut self, transaction: Tx, fee_rate: u64) -> Result<(),Error> {
+        self.p2p.keep_alive().map_err(|_| Error::NetworkError)?;
+        self.p2p.send_transaction(transaction.clone(), fee_rate)?;
+
+        let now = wall_clock::now().seconds;
+        self.broadcasts.track(&transaction, fee_rate, now)?;
+        self.broadcasts.save(&self.db)?;
+
+        let scripts = self.wallet.get_pubkeys().map_err(|_| Error::WalletError(1))?;
+        let utxos = self.wallet.get_utxos().map_err(|_| Error::WalletError(1))?;
+        if let Some(record) = self.unconfirmed_record(&transaction, &utxos, &scripts, now) {
+            self.history.add_unconfirmed(record);
+        }
+        self.hold_unconfirmed_send(&transaction, &scripts, now)?;
+        self.history.save(&self.db)
+    }
 
-    
+    /// Keeps the wallet from spending the inputs of our unconfirmed send again and hands it the
+    /// send's change, so both balance and coin selection see the send before a block mines it
+    fn hold_unconfirmed_send(&mut self, transaction: &Tx, scripts: &[Vec<u8>], now: u64) -> Result<(), Error> {
+        let mut raw_tx = Vec::new();
+        transaction.write(&mut raw_tx)?;
+        self.wallet.mark_pending_spent(&raw_tx).map_err(|_| Error::WalletError(1))?;
+
+        let change: Vec<PartialUtxo> = self.mempool.add_transaction(transaction, scripts, now).iter().map(|record| record.to_partial_utxo(false)).collect();
+        if !change.is_empty() {
+            self.wallet.insert_unconfirmed_utxos(&change).map_err(|_| Error::WalletError(1))?;
+        }
+        self.mempool.save(&self.db)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{tx_in::TxIn, OutPoint, COINBASE_OUTPOINT_HASH, COINBASE_OUTPOINT_INDEX};
+
+    /// Wallet outputs by outpoint, inserted and removed the way the wallet does it
+    type Wallet = BTreeMap<(Vec<u8>, u32), PartialUtxo>;
+
+    fn insert(wallet: &mut Wallet, utxos: &[PartialUtxo]) {
+        for utxo in utxos {
+            wallet.insert((utxo.txid.clone(), utxo.vout), utxo.clone());
+        }
+    }
+
+    fn remove(wallet: &mut Wallet, utxos: &[PartialUtxo]) {
+        for utxo in utxos {
+            wallet.remove(&(utxo.txid.clone(), utxo.vout));
+        }
+    }
+
+    fn transaction(inputs: Vec<OutPoint>, outputs: Vec<(i64, Vec<u8>)>) -> Tx {
+        Tx {
+            version: 2,
+            inputs: inputs.into_iter().map(|prev_output| TxIn { prev_output, unlock_script: vec![], sequence: 0xffffffff }).collect(),
+            outputs: outputs.into_iter().map(|(satoshis, lock_script)| TxOut { satoshis, lock_script }).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn coinbase(amount: i64, script: Vec<u8>) -> Tx {
+        transaction(vec![OutPoint { hash: COINBASE_OUTPOINT_HASH, index: COINBASE_OUTPOINT_INDEX }], vec![(amount, script)])
+    }
+
+    /// Scans `txns` as the block at `height`, saving its undo record like `apply_block`
+    fn apply(db: &KeyValueDb, wallet: &mut Wallet, block_hash: &Hash256, height: u64, txns: &[Tx], pub_keys: &[Vec<u8>]) {
+        let utxos: Vec<_> = wallet.values().cloned().collect();
+        let mut scan = BlockScan::new(height);
+        for (position, txn) in txns.iter().enumerate() {
+            scan.spend_inputs(txn, &utxos);
+            scan.add_outputs(txn, &txn.txid(), position == 0, pub_keys);
+        }
+        insert(wallet, &scan.utxos);
+        scan.undo.save(db, block_hash).unwrap();
+    }
+
+    /// Reverts the undo record of `block_hash` like `revert_blocks`
+    fn revert(db: &KeyValueDb, wallet: &mut Wallet, block_hash: &Hash256) {
+        let undo = BlockUndo::load(db, block_hash).unwrap().unwrap();
+        remove(wallet, &undo.created_utxos());
+        insert(wallet, &undo.spent_utxos());
+        BlockUndo::delete(db, block_hash).unwrap();
+    }
+
+    fn candidate(peer: PeerId, filter_headers: Vec<Hash256>) -> FilterHeaderCandidate {
+        FilterHeaderCandidate { peer, filter_hashes: vec![Hash256::default(); filter_headers.len()], filter_headers }
+    }
+
+    #[test]
+    fn forged_range_from_peer_without_checkpoints_is_not_taken_alone() {
+        let honest = Hash256([1; 32]);
+        let forged = Hash256([2; 32]);
+        let checkpoints = BTreeMap::from([(0, vec![honest]), (1, vec![honest])]);
+        let agreed = agreed_checkpoint(&checkpoints, CHECKPOINT_INTERVAL);
+        assert_eq!(agreed, Some(honest));
+
+        // Peer 2 never answered getcfcheckpt, its range has to reach the agreed checkpoint
+        assert!(!checkpoints.contains_key(&2));
+        assert!(!candidate(2, vec![Hash256([3; 32]), forged]).ends_on(agreed.as_ref()));
+        assert!(candidate(2, vec![Hash256([3; 32]), honest]).ends_on(agreed.as_ref()));
+
+        assert_eq!(agreed_checkpoint(&checkpoints, CHECKPOINT_INTERVAL - 1), None);
+        let disputed = BTreeMap::from([(0, vec![honest]), (1, vec![forged])]);
+        assert_eq!(agreed_checkpoint(&disputed, CHECKPOINT_INTERVAL), None);
+        assert!(!candidate(2, vec![honest]).ends_on(None));
+    }
+
+    #[test]
+    fn filter_headers_served_by_most_peers_have_most_support() {
+        let honest = vec![Hash256([1; 32]), Hash256([2; 32])];
+        let lying = vec![Hash256([1; 32]), Hash256([3; 32])];
+        let served = vec![honest.clone(), lying.clone(), honest.clone()];
+        assert_eq!(support(&served, &honest), 2);
+        assert_eq!(support(&served, &lying), 1);
+        assert_eq!(support(&served, &honest[..1]), 0);
+    }
+
+    #[test]
+    fn reorg_restores_spent_outputs_and_forgets_created_ones() {
+        let db = KeyValueDb::in_memory();
+        let receive = [vec![0x00, 0x14], vec![1; 20]].concat();
+        let change = [vec![0x00, 0x14], vec![2; 20]].concat();
+        let pub_keys = vec![receive.clone(), change.clone()];
+        let mut wallet = Wallet::new();
+
+        let reward = coinbase(50_0000_0000, receive.clone());
+        let first = Hash256([1; 32]);
+        apply(&db, &mut wallet, &first, 1, std::slice::from_ref(&reward), &pub_keys);
+
+        let payment = transaction(vec![OutPoint { hash: reward.txid(), index: 0 }], vec![(20_0000_0000, vec![0x51]), (29_0000_0000, change.clone())]);
+        let second = Hash256([2; 32]);
+        apply(&db, &mut wallet, &second, 2, &[coinbase(50_0000_0000, vec![0x51]), payment.clone()], &pub_keys);
+
+        let reward_key = (reward.txid().0.to_vec(), 0);
+        let change_key = (payment.txid().0.to_vec(), 1);
+        assert!(wallet[&reward_key].is_spent);
+        assert_eq!(wallet[&change_key].height, Some(2));
+        assert!(!wallet[&change_key].is_coinbase);
+
+        let undo = BlockUndo::load(&db, &second).unwrap().unwrap();
+        assert_eq!(undo.created, vec![UtxoRecord::from(&wallet[&change_key])]);
+        assert_eq!(undo.spent.len(), 1);
+        assert_eq!(undo.spent[0].txid, reward_key.0);
+
+        // The second block is disconnected
+        revert(&db, &mut wallet, &second);
+        assert_eq!(wallet.len(), 1);
+        assert!(!wallet[&reward_key].is_spent);
+        assert!(wallet[&reward_key].is_coinbase);
+        assert_eq!(BlockUndo::load(&db, &second).unwrap(), None);
+
+        let undo = BlockUndo::load(&db, &first).unwrap().unwrap();
+        assert_eq!(undo.created, vec![UtxoRecord::from(&wallet[&reward_key])]);
+        assert!(undo.spent.is_empty());
+    }
 }
@@ -1,33 +1,83 @@
 use std::{cell::RefCell, sync::Arc};
 use crate::{bindings, util::Error};
 
-use bindings::component::kv::types::Kvstore ;
+use bindings::component::kv::types::{Error as StoreError, Kvstore};
 
 
 pub const CHAIN_STATE_KEY: &str = "chain_state";
 pub const WALLET_STATE_KEY: &str = "wallet_state";
 pub const NODE_STATE_KEY: &str = "node_state";
 pub const SIGNER_STATE_KEY: &str = "signer_state";
+pub const HEADER_TIP_KEY: &str = "header_tip";
+pub const HEADER_KEY_PREFIX: &str = "header_";
+pub const HEADER_HEIGHT_KEY_PREFIX: &str = "header_height_";
+pub const BLOCK_UNDO_KEY_PREFIX: &str = "block_undo_";
+pub const FILTER_HEADER_TIP_KEY: &str = "filter_header_tip";
+pub const FILTER_HEADER_KEY_PREFIX: &str = "filter_header_";
+pub const ADDRESS_BOOK_KEY: &str = "address_book";
+pub const MEMPOOL_STATE_KEY: &str = "mempool_state";
+pub const BROADCAST_STATE_KEY: &str = "broadcast_state";
+pub const HISTORY_STATE_KEY: &str = "history_state";
 
 pub struct KeyValueDb {
-    conn: Arc<Kvstore>
+    conn: Store,
+}
+
+enum Store {
+    Kv(Arc<Kvstore>),
+    /// Stands in for the host store in native tests, where the kv import cannot be called
+    #[cfg(test)]
+    Memory(RefCell<std::collections::HashMap<String, Vec<u8>>>),
 }
 
 impl KeyValueDb {
 
     pub fn new(store: RefCell<Kvstore>) -> Self {
-        Self{ conn: Arc::new(store.into_inner()) }
+        Self{ conn: Store::Kv(Arc::new(store.into_inner())) }
+    }
+
+    #[cfg(test)]
+    pub fn in_memory() -> Self {
+        Self{ conn: Store::Memory(RefCell::new(std::collections::HashMap::new())) }
     }
+
     pub fn insert(&self, key: String, value: Vec<u8>) -> Result<(), Error> {
-        self.conn.insert(&key, &value).map_err(|err| Error::DBError(err))        
+        match &self.conn {
+            Store::Kv(conn) => conn.insert(&key, &value).map_err(|err| Error::DBError(err)),
+            #[cfg(test)]
+            Store::Memory(map) => {
+                map.borrow_mut().insert(key, value);
+                Ok(())
+            },
+        }
     }
      /// Retrieve a value by its key.
     pub fn get(&self, key: String) -> Result<Vec<u8>, Error> { 
-            self.conn.get(&key).map_err(|err| Error::DBError(err))
+        self.get_optional(key)?.ok_or(Error::DBError(StoreError::EntryNotFound))
       }
 
+     /// Retrieve a value by its key, `None` if the key was never stored.
+    pub fn get_optional(&self, key: String) -> Result<Option<Vec<u8>>, Error> {
+        match &self.conn {
+            Store::Kv(conn) => match conn.get(&key) {
+                Ok(value) => Ok(Some(value)),
+                Err(StoreError::EntryNotFound) => Ok(None),
+                Err(err) => Err(Error::DBError(err)),
+            },
+            #[cfg(test)]
+            Store::Memory(map) => Ok(map.borrow().get(&key).cloned()),
+        }
+    }
+
      /// Delete a key-value pair by its key.
-     fn delete(&self, key: String) -> Result<(), Error> {
-        self.conn.delete(&key).map_err(|err| Error::DBError(err))
+     pub fn delete(&self, key: String) -> Result<(), Error> {
+        match &self.conn {
+            Store::Kv(conn) => conn.delete(&key).map_err(|err| Error::DBError(err)),
+            #[cfg(test)]
+            Store::Memory(map) => {
+                map.borrow_mut().remove(&key);
+                Ok(())
+            },
+        }
      }
-}
\ No newline at end of file
+}
@@ -0,0 +1,213 @@
+//! Inbound message handling for a peer connection
+//!
+//! Everything the peer sends goes through the dispatcher: pings are answered, announcements
+//! are queued for the chain layer, connection settings are recorded and whatever is left is
+//! kept as a response until the request waiting for it picks it up.
+
+use std::collections::VecDeque;
+use std::io::Cursor;
+
+use crate::messages::{commands, message_header::MessageHeader, InvVect, Message, MAX_PAYLOAD_SIZE};
+use crate::util::{Hash256, Result, Serializable};
+
+/// Responses kept for requests, the oldest are dropped first when nobody picks them up
+const MAX_PENDING_RESPONSES: usize = 1000;
+
+/// Announcements kept until the chain layer takes them
+const MAX_PENDING_ANNOUNCEMENTS: usize = 5000;
+
+/// Relay preferences the peer announced on the connection
+#[derive(Clone, Debug, Default)]
+pub struct PeerSettings {
+    /// New blocks are announced with headers instead of inv (BIP130)
+    pub prefers_headers: bool,
+    /// New blocks are announced with cmpctblock (BIP152)
+    pub compact_blocks: bool,
+    /// Addresses may be sent as addrv2 (BIP155)
+    pub addrv2: bool,
+    /// Transactions below this fee rate are not relayed by the peer, in sat/kvB (BIP133)
+    pub fee_filter: u64,
+}
+
+pub struct Dispatcher {
+    magic: [u8; 4],
+    // Bytes read from the connection that do not form a complete message yet
+    buffer: Vec<u8>,
+    responses: VecDeque<(MessageHeader, Message)>,
+    replies: VecDeque<Message>,
+    announcements: VecDeque<InvVect>,
+    settings: PeerSettings,
+}
+
+impl Dispatcher {
+
+    pub fn new(magic: [u8; 4]) -> Self {
+        Self { magic, buffer: Vec::new(), responses: VecDeque::new(), replies: VecDeque::new(), announcements: VecDeque::new(), settings: PeerSettings::default() }
+    }
+
+    pub fn settings(&self) -> &PeerSettings {
+        &self.settings
+    }
+
+    /// Decodes and dispatches every complete message in `bytes` and the bytes kept before
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<()> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut consumed = 0;
+        while self.buffer.len() - consumed >= MessageHeader::SIZE {
+            let header = MessageHeader::read(&mut Cursor::new(&self.buffer[consumed..]))?;
+            header.validate(self.magic, MAX_PAYLOAD_SIZE)?;
+
+            let message_len = MessageHeader::SIZE + header.payload_size as usize;
+            if self.buffer.len() - consumed < message_len {
+                break;
+            }
+
+            let (message, header) = Message::read(&mut Cursor::new(&self.buffer[consumed..consumed + message_len]))?;
+            consumed += message_len;
+            self.dispatch(header, message);
+        }
+
+        self.buffer.drain(..consumed);
+        Ok(())
+    }
+
+    fn dispatch(&mut self, header: MessageHeader, message: Message) {
+        match message {
+            Message::Ping(ping) => self.replies.push_back(Message::Pong(ping)),
+            Message::Inv(inv) => {
+                self.announcements.extend(inv.objects);
+                while self.announcements.len() > MAX_PENDING_ANNOUNCEMENTS {
+                    self.announcements.pop_front();
+                }
+            },
+            Message::SendHeaders => self.settings.prefers_headers = true,
+            Message::SendCmpct(sendcmpct) => self.settings.compact_blocks = sendcmpct.use_cmpctblock(),
+            Message::SendAddrV2 => self.settings.addrv2 = true,
+            Message::FeeFilter(feefilter) => self.settings.fee_filter = feefilter.minfee,
+            message => {
+                self.responses.push_back((header, message));
+                if self.responses.len() > MAX_PENDING_RESPONSES {
+                    self.responses.pop_front();
+                }
+            },
+        }
+    }
+
+    /// Oldest response with one of `message_types`
+    pub fn take_response(&mut self, message_types: &[[u8; 12]]) -> Option<Message> {
+        let position = self.responses.iter().position(|(header, _)| message_types.contains(&header.command))?;
+        self.responses.remove(position).map(|(_, message)| message)
+    }
+
+    /// Oldest response to a getdata for `requested`, either a `message_type` or a notfound for one of them
+    pub fn take_data(&mut self, message_type: [u8; 12], requested: &[Hash256]) -> Option<Message> {
+        let position = self.responses.iter().position(|(header, message)| match message {
+            Message::NotFound(not_found) => not_found.objects.iter().any(|object| requested.contains(&object.hash)),
+            _ => header.command == message_type,
+        })?;
+        self.responses.remove(position).map(|(_, message)| message)
+    }
+
+    /// Messages to send back to the peer, such as pongs
+    pub fn take_replies(&mut self) -> Vec<Message> {
+        self.replies.drain(..).collect()
+    }
+
+    /// Inventory the peer announced since the last call
+    pub fn take_announcements(&mut self) -> Vec<InvVect> {
+        self.announcements.drain(..).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{fee_filter::FeeFilter, ping::Ping, send_cmpct::SendCmpct, Inv};
+
+    const MAGIC: [u8; 4] = [0xfa, 0xbf, 0xb5, 0xda];
+
+    fn encode(messages: &[Message]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for message in messages {
+            message.write(&mut bytes, MAGIC).unwrap();
+        }
+        bytes
+    }
+
+    #[test]
+    fn answers_pings() {
+        let mut dispatcher = Dispatcher::new(MAGIC);
+        dispatcher.feed(&encode(&[Message::Ping(Ping { nonce: 7 })])).unwrap();
+        assert!(dispatcher.take_replies() == vec![Message::Pong(Ping { nonce: 7 })]);
+        assert!(dispatcher.take_response(&[commands::PING]).is_none());
+    }
+
+    #[test]
+    fn waits_for_complete_messages() {
+        let mut dispatcher = Dispatcher::new(MAGIC);
+        let bytes = encode(&[Message::Verack, Message::Pong(Ping { nonce: 1 })]);
+        let split = bytes.len() - 3;
+
+        dispatcher.feed(&bytes[..split]).unwrap();
+        assert!(dispatcher.take_response(&[commands::VERACK]) == Some(Message::Verack));
+        assert!(dispatcher.take_response(&[commands::PONG]).is_none());
+
+        dispatcher.feed(&bytes[split..]).unwrap();
+        assert!(dispatcher.take_response(&[commands::PONG]) == Some(Message::Pong(Ping { nonce: 1 })));
+    }
+
+    #[test]
+    fn routes_responses_by_command() {
+        let mut dispatcher = Dispatcher::new(MAGIC);
+        dispatcher.feed(&encode(&[Message::Verack, Message::Pong(Ping { nonce: 2 })])).unwrap();
+        assert!(dispatcher.take_response(&[commands::PONG]) == Some(Message::Pong(Ping { nonce: 2 })));
+        assert!(dispatcher.take_response(&[commands::VERACK]) == Some(Message::Verack));
+        assert!(dispatcher.take_response(&[commands::VERACK]).is_none());
+    }
+
+    #[test]
+    fn answers_getdata_with_notfound_for_requested_hashes_only() {
+        let mut dispatcher = Dispatcher::new(MAGIC);
+        let not_found = Inv { objects: vec![InvVect { obj_type: 1, hash: Hash256([4; 32]) }] };
+        dispatcher.feed(&encode(&[Message::NotFound(not_found.clone())])).unwrap();
+
+        assert!(dispatcher.take_response(&[commands::HEADERS]).is_none());
+        assert!(dispatcher.take_data(commands::TX, &[Hash256([5; 32])]).is_none());
+        assert!(dispatcher.take_data(commands::TX, &[Hash256([5; 32]), Hash256([4; 32])]) == Some(Message::NotFound(not_found)));
+    }
+
+    #[test]
+    fn queues_announcements_and_records_settings() {
+        let mut dispatcher = Dispatcher::new(MAGIC);
+        let inv = Inv { objects: vec![InvVect { obj_type: 1, hash: Hash256([3; 32]) }] };
+        dispatcher.feed(&encode(&[
+            Message::Inv(inv.clone()),
+            Message::SendHeaders,
+            Message::FeeFilter(FeeFilter { minfee: 1000 }),
+        ])).unwrap();
+
+        assert!(dispatcher.take_announcements() == inv.objects);
+        assert!(dispatcher.take_announcements().is_empty());
+        assert!(dispatcher.settings().prefers_headers);
+        assert!(dispatcher.settings().fee_filter == 1000);
+    }
+
+    #[test]
+    fn records_compact_block_announcements_of_any_version() {
+        let mut dispatcher = Dispatcher::new(MAGIC);
+        dispatcher.feed(&encode(&[Message::SendCmpct(SendCmpct { enable: 1, version: 2 })])).unwrap();
+        assert!(dispatcher.settings().compact_blocks);
+
+        dispatcher.feed(&encode(&[Message::SendCmpct(SendCmpct { enable: 0, version: 1 })])).unwrap();
+        assert!(!dispatcher.settings().compact_blocks);
+    }
+
+    #[test]
+    fn rejects_other_networks() {
+        let mut dispatcher = Dispatcher::new(MAGIC);
+        let mut bytes = Vec::new();
+        Message::Verack.write(&mut bytes, [0xf9, 0xbe, 0xb4, 0xd9]).unwrap();
+        assert!(dispatcher.feed(&bytes).is_err());
+    }
+}
@@ -0,0 +1,193 @@
+//! BIP157 filter header chain
+//!
+//! Each filter header commits to its block filter and to the previous filter header, so a
+//! peer that serves a filter has to stay consistent with every header it served before.
+
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::db::{KeyValueDb, FILTER_HEADER_KEY_PREFIX, FILTER_HEADER_TIP_KEY};
+use crate::messages::{block::Block, compact_filter::CompactFilter};
+use crate::util::{block_filter::BlockFilter, sha256d, Error, Hash256, Result};
+
+/// Script opcode marking provably unspendable outputs, left out of basic filters
+const OP_RETURN: u8 = 0x6a;
+
+/// Filter header of a block given its filter hash and the previous filter header
+pub fn filter_header(filter_hash: &Hash256, prev_header: &Hash256) -> Hash256 {
+    let mut preimage = Vec::with_capacity(64);
+    preimage.extend_from_slice(&filter_hash.0);
+    preimage.extend_from_slice(&prev_header.0);
+    sha256d(&preimage)
+}
+
+/// Builds the filter headers of consecutive blocks on top of `prev_header`
+pub fn chain_filter_headers(prev_header: Hash256, filter_hashes: &[Hash256]) -> Vec<Hash256> {
+    let mut headers = Vec::with_capacity(filter_hashes.len());
+    let mut prev_header = prev_header;
+    for filter_hash in filter_hashes {
+        prev_header = filter_header(filter_hash, &prev_header);
+        headers.push(prev_header);
+    }
+    headers
+}
+
+/// Checks that `filter` contains every output script of `block`
+///
+/// Input scripts are also part of a basic filter but cannot be checked without the spent
+/// outputs, so a filter passing this check is not proven correct, only one failing it is
+/// proven wrong.
+pub fn filter_matches_block(filter: &CompactFilter, block: &Block) -> Result<bool> {
+    let scripts: Vec<&[u8]> = block.txns.iter()
+        .flat_map(|txn| txn.outputs.iter())
+        .map(|output| output.lock_script.as_slice())
+        .filter(|script| !script.is_empty() && script[0] != OP_RETURN)
+        .collect();
+
+    BlockFilter::new(&filter.filter_bytes)
+        .match_all(&filter.block_hash, scripts.into_iter())
+        .map_err(Error::IOError)
+}
+
+/// Last block whose filter header is verified
+#[derive(serde::Deserialize, Serialize, Clone, Debug)]
+pub struct FilterHeaderTip {
+    pub height: u64,
+    pub block_hash: Hash256,
+    pub filter_header: Hash256,
+}
+
+/// Verified filter headers persisted in the key value store, indexed by block hash
+pub struct FilterHeaderStore {
+    db: Arc<KeyValueDb>,
+    tip: Option<FilterHeaderTip>,
+}
+
+impl FilterHeaderStore {
+
+    /// Creates an empty store, the first header to connect is the genesis one
+    pub fn new(db: Arc<KeyValueDb>) -> Result<Self> {
+        if db.get_optional(FILTER_HEADER_TIP_KEY.to_string())?.is_some() {
+            db.delete(FILTER_HEADER_TIP_KEY.to_string())?;
+        }
+        Ok(Self { db, tip: None })
+    }
+
+    pub fn restore(db: Arc<KeyValueDb>) -> Result<Self> {
+        let tip = match db.get_optional(FILTER_HEADER_TIP_KEY.to_string())? {
+            Some(bytes) => Some(bincode::deserialize(&bytes).map_err(|err| Error::SerializationError(err.to_string()))?),
+            None => None,
+        };
+        Ok(Self { db, tip })
+    }
+
+    /// Height of the next block whose filter header is needed
+    pub fn next_height(&self) -> u64 {
+        self.tip.as_ref().map_or(0, |tip| tip.height + 1)
+    }
+
+    /// Filter header the next one has to build on, zero before genesis
+    pub fn prev_header(&self) -> Hash256 {
+        self.tip.as_ref().map_or(Hash256::default(), |tip| tip.filter_header)
+    }
+
+    pub fn get(&self, block_hash: &Hash256) -> Result<Option<Hash256>> {
+        match self.db.get_optional(format!("{}{}", FILTER_HEADER_KEY_PREFIX, block_hash.encode()))? {
+            Some(bytes) => {
+                let header = bincode::deserialize(&bytes).map_err(|err| Error::SerializationError(err.to_string()))?;
+                Ok(Some(header))
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Stores verified filter headers for `block_hashes`, which must follow the current tip
+    pub fn connect(&mut self, block_hashes: &[Hash256], filter_headers: &[Hash256]) -> Result<()> {
+        if block_hashes.len() != filter_headers.len() {
+            return Err(Error::IllegalState("Filter header count does not match block count".to_string()));
+        }
+
+        let start_height = self.next_height();
+        for (offset, (block_hash, filter_header)) in block_hashes.iter().zip(filter_headers).enumerate() {
+            let encoded = bincode::serialize(filter_header).map_err(|err| Error::SerializationError(err.to_string()))?;
+            self.db.insert(format!("{}{}", FILTER_HEADER_KEY_PREFIX, block_hash.encode()), encoded)?;
+            self.tip = Some(FilterHeaderTip { height: start_height + offset as u64, block_hash: *block_hash, filter_header: *filter_header });
+        }
+        self.put_tip()
+    }
+
+    /// Moves the tip back to the fork point of a reorganisation
+    pub fn rewind(&mut self, fork_height: u64, fork_hash: Hash256) -> Result<()> {
+        let tip_height = match &self.tip {
+            Some(tip) => tip.height,
+            None => return Ok(()),
+        };
+        if tip_height <= fork_height {
+            return Ok(());
+        }
+
+        let filter_header = self.get(&fork_hash)?
+            .ok_or(Error::IllegalState("Filter header of the fork block not found".to_string()))?;
+        self.tip = Some(FilterHeaderTip { height: fork_height, block_hash: fork_hash, filter_header });
+        self.put_tip()
+    }
+
+    fn put_tip(&self) -> Result<()> {
+        let encoded = bincode::serialize(&self.tip).map_err(|err| Error::SerializationError(err.to_string()))?;
+        self.db.insert(FILTER_HEADER_TIP_KEY.to_string(), encoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use bitcoin::{blockdata::constants::genesis_block, consensus::encode, Network};
+
+    use super::*;
+    use crate::util::Serializable;
+
+    // BIP158 test vector for the testnet genesis block
+    const GENESIS_FILTER: &str = "019dfca8";
+    const GENESIS_FILTER_HEADER: &str = "21584579b7eb08997773e5aeff3a7f932700042d0ed2a6129012b7d7ae81b750";
+
+    fn testnet_genesis() -> (Block, CompactFilter) {
+        let bytes = encode::serialize(&genesis_block(Network::Testnet));
+        let block = Block::read(&mut Cursor::new(&bytes)).unwrap();
+        let filter = CompactFilter { filter_type: 0, block_hash: block.header.hash(), filter_bytes: hex::decode(GENESIS_FILTER).unwrap() };
+        (block, filter)
+    }
+
+    #[test]
+    fn genesis_filter_header() {
+        let (_, filter) = testnet_genesis();
+        let filter_hash = sha256d(&filter.filter_bytes);
+        let headers = chain_filter_headers(Hash256::default(), &[filter_hash]);
+        assert_eq!(headers, vec![Hash256::decode(GENESIS_FILTER_HEADER).unwrap()]);
+    }
+
+    #[test]
+    fn chain_links_headers() {
+        let hashes = vec![Hash256([1; 32]), Hash256([2; 32])];
+        let headers = chain_filter_headers(Hash256([9; 32]), &hashes);
+        assert_eq!(headers[0], filter_header(&hashes[0], &Hash256([9; 32])));
+        assert_eq!(headers[1], filter_header(&hashes[1], &headers[0]));
+    }
+
+    #[test]
+    fn filter_must_contain_block_outputs() {
+        let (block, filter) = testnet_genesis();
+        assert!(filter_matches_block(&filter, &block).unwrap());
+
+        let mut other_block = block.clone();
+        other_block.txns[0].outputs[0].lock_script = vec![0x00, 0x14, 0x01, 0x02];
+        assert!(!filter_matches_block(&filter, &other_block).unwrap());
+    }
+
+    #[test]
+    fn merkle_root_matches_header() {
+        let (block, _) = testnet_genesis();
+        assert!(block.merkle_root() == block.header.merkle_root);
+    }
+}
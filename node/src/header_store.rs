@@ -0,0 +1,363 @@
+use std::sync::Arc;
+
+use bitcoin::{network as bitcoin_network, pow::{CompactTarget, Target, Work}};
+use serde::Serialize;
+
+use crate::db::{KeyValueDb, HEADER_HEIGHT_KEY_PREFIX, HEADER_KEY_PREFIX, HEADER_TIP_KEY};
+use crate::messages::BlockHeader;
+use crate::util::{Error, Hash256, Result};
+use crate::validation::{validate_headers, HeaderSource};
+
+/// Number of locator hashes taken one by one from the tip before stepping back exponentially
+const LOCATOR_DENSE_LEN: usize = 10;
+
+/// A validated header together with its position in the header tree
+#[derive(serde::Deserialize, Serialize, Clone, Debug)]
+pub struct StoredHeader {
+    pub header: BlockHeader,
+    pub height: u64,
+    /// Total work of the chain ending at this header, big endian
+    chain_work: [u8; 32],
+}
+
+impl StoredHeader {
+    pub fn hash(&self) -> Hash256 {
+        self.header.hash()
+    }
+
+    fn work(&self) -> Work {
+        Work::from_be_bytes(self.chain_work)
+    }
+}
+
+/// Blocks that left the best chain after a reorganisation
+#[derive(Debug)]
+pub struct Reorg {
+    /// Height of the last block shared by the old and the new best chain
+    pub fork_height: u64,
+    /// Hash of the last block shared by the old and the new best chain
+    pub fork_hash: Hash256,
+    /// Hashes of the blocks removed from the best chain, highest first
+    pub disconnected: Vec<Hash256>,
+}
+
+/// Header tree persisted in the key value store
+///
+/// Every validated header is stored by hash, including the ones on side branches so a branch
+/// can overtake the best chain later. The best chain, the one with the most cumulative work,
+/// is additionally indexed by height.
+pub struct HeaderStore {
+    db: Arc<KeyValueDb>,
+    network: bitcoin_network::Network,
+    tip: StoredHeader,
+}
+
+/// Proof of work represented by a single header
+fn header_work(header: &BlockHeader) -> Work {
+    Target::from_compact(CompactTarget::from_consensus(header.bits)).to_work()
+}
+
+impl HeaderStore {
+
+    /// Creates a store whose best chain only holds `genesis_header`
+    pub fn new(db: Arc<KeyValueDb>, network: bitcoin_network::Network, genesis_header: BlockHeader) -> Result<Self> {
+        let chain_work = header_work(&genesis_header).to_be_bytes();
+        let tip = StoredHeader { header: genesis_header, height: 0, chain_work };
+
+        let store = Self { db, network, tip: tip.clone() };
+        store.put_header(&tip)?;
+        store.put_height(tip.height, &tip.hash())?;
+        store.put_tip()?;
+        Ok(store)
+    }
+
+    pub fn restore(db: Arc<KeyValueDb>, network: bitcoin_network::Network) -> Result<Self> {
+        let tip_hash = db.get(HEADER_TIP_KEY.to_string())?;
+        let tip_hash: Hash256 = bincode::deserialize(&tip_hash).map_err(|err| Error::SerializationError(err.to_string()))?;
+
+        let mut store = Self { db, network, tip: StoredHeader { header: BlockHeader::default(), height: 0, chain_work: [0; 32] } };
+        store.tip = store.get_header(&tip_hash)?.ok_or(Error::IllegalState("Header tip not found".to_string()))?;
+        Ok(store)
+    }
+
+    pub fn tip(&self) -> &StoredHeader {
+        &self.tip
+    }
+
+    pub fn get_header(&self, hash: &Hash256) -> Result<Option<StoredHeader>> {
+        match self.db.get_optional(format!("{}{}", HEADER_KEY_PREFIX, hash.encode()))? {
+            Some(bytes) => {
+                let stored = bincode::deserialize(&bytes).map_err(|err| Error::SerializationError(err.to_string()))?;
+                Ok(Some(stored))
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Hash of the block at `height` on the best chain
+    pub fn hash_at(&self, height: u64) -> Result<Option<Hash256>> {
+        if height > self.tip.height {
+            return Ok(None);
+        }
+        match self.db.get_optional(format!("{}{}", HEADER_HEIGHT_KEY_PREFIX, height))? {
+            Some(bytes) => {
+                let hash = bincode::deserialize(&bytes).map_err(|err| Error::SerializationError(err.to_string()))?;
+                Ok(Some(hash))
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Lowest best chain height whose block is timestamped at or after `timestamp`
+    ///
+    /// Timestamps are only roughly ordered, callers looking for the first block after some
+    /// moment should leave a margin. `None` when the tip itself is older.
+    pub fn first_height_after(&self, timestamp: u64) -> Result<Option<u64>> {
+        if (self.tip.header.timestamp as u64) < timestamp {
+            return Ok(None);
+        }
+
+        let (mut low, mut high) = (0, self.tip.height);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let hash = self.hash_at(middle)?.ok_or(Error::IllegalState(format!("No header at height {}", middle)))?;
+            let header = self.get_header(&hash)?.ok_or(Error::IllegalState(format!("No header at height {}", middle)))?;
+            match (header.header.timestamp as u64) < timestamp {
+                true => low = middle + 1,
+                false => high = middle,
+            }
+        }
+        Ok(Some(low))
+    }
+
+    /// Block locator for `getheaders`, dense near the tip and sparse towards genesis
+    pub fn locator(&self) -> Result<Vec<Hash256>> {
+        let mut hashes = Vec::new();
+        let mut height = self.tip.height;
+        let mut step = 1;
+
+        loop {
+            if let Some(hash) = self.hash_at(height)? {
+                hashes.push(hash);
+            }
+            if height == 0 {
+                return Ok(hashes);
+            }
+            if hashes.len() >= LOCATOR_DENSE_LEN {
+                step *= 2;
+            }
+            height = height.saturating_sub(step);
+        }
+    }
+
+    /// Validates and stores `headers`, switching the best chain if they lead to more work
+    ///
+    /// The first header may build on any header already in the store. A [`Reorg`] is returned
+    /// when blocks of the previous best chain had to be disconnected.
+    pub fn connect(&mut self, headers: &[BlockHeader]) -> Result<Option<Reorg>> {
+        let first = match headers.first() {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+        let fork = self.get_header(&first.prev_hash)?.ok_or_else(|| {
+            Error::InvalidHeader(format!("Header {} does not connect to a known block", first.hash().encode()))
+        })?;
+
+        let on_best_chain = self.hash_at(fork.height)? == Some(fork.hash());
+        let branch_view = BranchView { store: self, fork: fork.clone(), on_best_chain };
+        validate_headers(self.network, &branch_view, fork.height, fork.hash(), headers)?;
+
+        let mut new_chain = Vec::with_capacity(headers.len());
+        let mut parent = fork;
+        for header in headers {
+            let chain_work = (parent.work() + header_work(header)).to_be_bytes();
+            let stored = StoredHeader { header: header.clone(), height: parent.height + 1, chain_work };
+            self.put_header(&stored)?;
+            new_chain.push(stored.clone());
+            parent = stored;
+        }
+
+        // Keep the branch around, it may still overtake the best chain later
+        if parent.work() <= self.tip.work() {
+            return Ok(None);
+        }
+
+        // Walk back to the last block the branch shares with the best chain
+        let mut ancestor = self.get_header(&first.prev_hash)?.ok_or(Error::IllegalState("Fork header vanished".to_string()))?;
+        while self.hash_at(ancestor.height)? != Some(ancestor.hash()) {
+            let prev = self.get_header(&ancestor.header.prev_hash)?.ok_or(Error::IllegalState("Side branch is not connected".to_string()))?;
+            new_chain.insert(0, ancestor);
+            ancestor = prev;
+        }
+
+        let mut disconnected = Vec::new();
+        for height in (ancestor.height + 1..=self.tip.height).rev() {
+            if let Some(hash) = self.hash_at(height)? {
+                disconnected.push(hash);
+            }
+            if height > parent.height {
+                self.db.delete(format!("{}{}", HEADER_HEIGHT_KEY_PREFIX, height))?;
+            }
+        }
+
+        for stored in new_chain.iter() {
+            self.put_height(stored.height, &stored.hash())?;
+        }
+        self.tip = parent;
+        self.put_tip()?;
+
+        if disconnected.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Reorg { fork_height: ancestor.height, fork_hash: ancestor.hash(), disconnected }))
+    }
+
+    fn put_header(&self, stored: &StoredHeader) -> Result<()> {
+        let encoded = bincode::serialize(stored).map_err(|err| Error::SerializationError(err.to_string()))?;
+        self.db.insert(format!("{}{}", HEADER_KEY_PREFIX, stored.hash().encode()), encoded)
+    }
+
+    fn put_height(&self, height: u64, hash: &Hash256) -> Result<()> {
+        let encoded = bincode::serialize(hash).map_err(|err| Error::SerializationError(err.to_string()))?;
+        self.db.insert(format!("{}{}", HEADER_HEIGHT_KEY_PREFIX, height), encoded)
+    }
+
+    fn put_tip(&self) -> Result<()> {
+        let encoded = bincode::serialize(&self.tip.hash()).map_err(|err| Error::SerializationError(err.to_string()))?;
+        self.db.insert(HEADER_TIP_KEY.to_string(), encoded)
+    }
+}
+
+impl HeaderSource for HeaderStore {
+    fn header_at(&self, height: u64) -> Option<BlockHeader> {
+        let hash = self.hash_at(height).ok()??;
+        self.get_header(&hash).ok()?.map(|stored| stored.header)
+    }
+}
+
+/// Ancestors of a fork point, which may sit on a side branch
+struct BranchView<'a> {
+    store: &'a HeaderStore,
+    fork: StoredHeader,
+    on_best_chain: bool,
+}
+
+impl<'a> HeaderSource for BranchView<'a> {
+    fn header_at(&self, height: u64) -> Option<BlockHeader> {
+        if height > self.fork.height {
+            return None;
+        }
+        if self.on_best_chain {
+            return self.store.header_at(height);
+        }
+
+        let mut current = self.fork.clone();
+        while current.height > height {
+            current = self.store.get_header(&current.header.prev_hash).ok()??;
+        }
+        Some(current.header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::Network;
+
+    use super::*;
+    use crate::util::network_const::genesis_block_header_from_network;
+
+    const REGTEST_BITS: u32 = 0x207fffff;
+
+    /// Headers mined one after the other on top of `prev`, `skew` seconds apart from the
+    /// ones of another branch so their hashes differ
+    fn mine_branch(prev: &BlockHeader, len: usize, skew: u32) -> Vec<BlockHeader> {
+        let mut headers: Vec<BlockHeader> = Vec::with_capacity(len);
+        for _ in 0..len {
+            let parent = headers.last().unwrap_or(prev);
+            let mut header = BlockHeader { version: 4, prev_hash: parent.hash(), timestamp: parent.timestamp + 600 + skew, bits: REGTEST_BITS, ..Default::default() };
+            while header.validate(&header.hash(), &[]).is_err() {
+                header.nonce += 1;
+            }
+            headers.push(header);
+        }
+        headers
+    }
+
+    fn regtest_store() -> (HeaderStore, BlockHeader) {
+        let genesis = genesis_block_header_from_network(Network::Regtest);
+        let store = HeaderStore::new(Arc::new(KeyValueDb::in_memory()), Network::Regtest, genesis.clone()).unwrap();
+        (store, genesis)
+    }
+
+    fn best_chain(store: &HeaderStore) -> Vec<Hash256> {
+        (1..=store.tip().height).map(|height| store.hash_at(height).unwrap().unwrap()).collect()
+    }
+
+    #[test]
+    fn extends_the_tip() {
+        let (mut store, genesis) = regtest_store();
+        let headers = mine_branch(&genesis, 5, 0);
+
+        assert!(store.connect(&headers[..3]).unwrap().is_none());
+        assert!(store.connect(&headers[3..]).unwrap().is_none());
+        assert_eq!(store.tip().height, 5);
+        assert_eq!(store.tip().hash(), headers[4].hash());
+        assert_eq!(best_chain(&store), headers.iter().map(|header| header.hash()).collect::<Vec<_>>());
+        assert_eq!(store.hash_at(6).unwrap(), None);
+    }
+
+    #[test]
+    fn keeps_branch_with_less_work() {
+        let (mut store, genesis) = regtest_store();
+        let main = mine_branch(&genesis, 5, 0);
+        store.connect(&main).unwrap();
+
+        let side = mine_branch(&main[2], 2, 1);
+        assert!(store.connect(&side).unwrap().is_none());
+        assert_eq!(store.tip().hash(), main[4].hash());
+        assert_eq!(store.hash_at(4).unwrap(), Some(main[3].hash()));
+        assert_eq!(store.get_header(&side[1].hash()).unwrap().unwrap().height, 5);
+    }
+
+    #[test]
+    fn reorgs_to_branch_with_more_work() {
+        let (mut store, genesis) = regtest_store();
+        let main = mine_branch(&genesis, 5, 0);
+        store.connect(&main).unwrap();
+
+        // The side branch first trails the best chain, then overtakes it from a header that is
+        // not on the best chain
+        let side = mine_branch(&main[1], 4, 1);
+        assert!(store.connect(&side[..2]).unwrap().is_none());
+        let reorg = store.connect(&side[2..]).unwrap().unwrap();
+        assert_eq!(reorg.fork_height, 2);
+        assert_eq!(reorg.fork_hash, main[1].hash());
+        assert_eq!(reorg.disconnected, vec![main[4].hash(), main[3].hash(), main[2].hash()]);
+
+        assert_eq!(store.tip().height, 6);
+        let expected: Vec<_> = main[..2].iter().chain(side.iter()).map(|header| header.hash()).collect();
+        assert_eq!(best_chain(&store), expected);
+
+        // Rolling back to the old branch once it has more work again
+        let extension = mine_branch(&main[4], 2, 0);
+        let reorg = store.connect(&extension).unwrap().unwrap();
+        assert_eq!(reorg.fork_height, 2);
+        assert_eq!(reorg.disconnected, side.iter().rev().map(|header| header.hash()).collect::<Vec<_>>());
+        let expected: Vec<_> = main.iter().chain(extension.iter()).map(|header| header.hash()).collect();
+        assert_eq!(best_chain(&store), expected);
+
+        let restored = HeaderStore::restore(store.db.clone(), Network::Regtest).unwrap();
+        assert_eq!(restored.tip().hash(), extension[1].hash());
+    }
+
+    #[test]
+    fn rejects_orphan_header() {
+        let (mut store, genesis) = regtest_store();
+        let headers = mine_branch(&genesis, 3, 0);
+
+        let result = store.connect(&headers[1..]);
+        assert!(matches!(result, Err(Error::InvalidHeader(_))));
+        assert_eq!(store.tip().height, 0);
+        assert!(store.get_header(&headers[2].hash()).unwrap().is_none());
+    }
+}
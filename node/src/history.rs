@@ -0,0 +1,248 @@
+//! Statement of the wallet's transactions
+//!
+//! Every transaction paying the wallet or spending its outputs is recorded when first seen, in
+//! the mempool, when we send it or in a block, and updated as blocks confirm or disconnect it.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::chain::UtxoRecord;
+use crate::db::{KeyValueDb, HISTORY_STATE_KEY};
+use crate::messages::tx::Tx;
+use crate::util::{Error, Hash256, Result};
+
+#[derive(serde::Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    /// Pays the wallet without spending any of its outputs
+    Incoming,
+    /// Spends wallet outputs to pay someone else
+    Outgoing,
+    /// Spends wallet outputs and pays every output back to the wallet
+    SelfTransfer,
+}
+
+/// Output of a wallet transaction locked to one of our scripts
+#[derive(serde::Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct WalletOutput {
+    pub vout: u32,
+    pub amount: u64,
+    pub script: Vec<u8>,
+    /// Paid to the internal keychain, so back to ourselves as change
+    pub is_change: bool,
+}
+
+#[derive(serde::Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct TransactionRecord {
+    pub txid: Hash256,
+    /// Height and hash of the block that mined it, `None` while unconfirmed
+    pub block: Option<(u64, Hash256)>,
+    /// Block time once mined, the time it was first seen before that
+    pub timestamp: u64,
+    /// Wallet outputs it spends
+    pub inputs: Vec<UtxoRecord>,
+    pub outputs: Vec<WalletOutput>,
+    pub direction: Direction,
+    /// Only known when every input belongs to the wallet
+    pub fee: Option<u64>,
+}
+
+impl TransactionRecord {
+
+    /// Record of `tx` from the wallet outputs it spends and creates, `None` when it touches neither
+    pub fn new(tx: &Tx, inputs: Vec<UtxoRecord>, outputs: Vec<WalletOutput>, timestamp: u64) -> Option<Self> {
+        if inputs.is_empty() && outputs.is_empty() {
+            return None;
+        }
+
+        let direction = match (inputs.is_empty(), outputs.len() == tx.outputs.len()) {
+            (true, _) => Direction::Incoming,
+            (false, true) => Direction::SelfTransfer,
+            (false, false) => Direction::Outgoing,
+        };
+        let fee = match inputs.len() == tx.inputs.len() {
+            true => {
+                let spent: u64 = inputs.iter().map(|input| input.amount).sum();
+                let paid: u64 = tx.outputs.iter().map(|output| output.satoshis as u64).sum();
+                spent.checked_sub(paid)
+            },
+            false => None,
+        };
+
+        Some(Self { txid: tx.txid(), block: None, timestamp, inputs, outputs, direction, fee })
+    }
+
+    pub fn received(&self) -> u64 {
+        self.outputs.iter().map(|output| output.amount).sum()
+    }
+
+    pub fn sent(&self) -> u64 {
+        self.inputs.iter().map(|input| input.amount).sum()
+    }
+
+    /// Change of the wallet balance, the fee included when sending
+    pub fn net_amount(&self) -> i64 {
+        self.received() as i64 - self.sent() as i64
+    }
+}
+
+#[derive(serde::Deserialize, Serialize, Default)]
+pub struct TransactionHistory {
+    transactions: BTreeMap<Hash256, TransactionRecord>,
+}
+
+impl TransactionHistory {
+
+    pub fn load(db: &Arc<KeyValueDb>) -> Result<Self> {
+        match db.get_optional(HISTORY_STATE_KEY.to_string())? {
+            Some(bytes) => bincode::deserialize(&bytes).map_err(|err| Error::SerializationError(err.to_string())),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, db: &Arc<KeyValueDb>) -> Result<()> {
+        let encoded = bincode::serialize(self).map_err(|err| Error::SerializationError(err.to_string()))?;
+        db.insert(HISTORY_STATE_KEY.to_string(), encoded)
+    }
+
+    /// Records a transaction seen outside a block, a known one is left as it is
+    pub fn add_unconfirmed(&mut self, record: TransactionRecord) {
+        self.transactions.entry(record.txid).or_insert(record);
+    }
+
+    /// Records a transaction mined in the block `block_hash` at `height`
+    ///
+    /// The fee of a transaction we sent stays known even if the block view misses some inputs.
+    pub fn add_confirmed(&mut self, mut record: TransactionRecord, height: u64, block_hash: Hash256, block_time: u64) {
+        if let Some(known) = self.transactions.get(&record.txid) {
+            record.fee = record.fee.or(known.fee);
+        }
+        record.block = Some((height, block_hash));
+        record.timestamp = block_time;
+        self.transactions.insert(record.txid, record);
+    }
+
+    /// Marks the transactions of a disconnected block unconfirmed again
+    pub fn unconfirm_block(&mut self, block_hash: &Hash256) {
+        for record in self.transactions.values_mut() {
+            if matches!(record.block, Some((_, hash)) if hash == *block_hash) {
+                record.block = None;
+            }
+        }
+    }
+
+    /// Forgets a transaction that left the mempool without being mined
+    pub fn remove_unconfirmed(&mut self, txid: &Hash256) {
+        if matches!(self.transactions.get(txid), Some(record) if record.block.is_none()) {
+            self.transactions.remove(txid);
+        }
+    }
+
+    pub fn get(&self, txid: &Hash256) -> Option<&TransactionRecord> {
+        self.transactions.get(txid)
+    }
+
+    /// Unconfirmed transactions first, then the most recently mined
+    pub fn list(&self, offset: usize, limit: usize) -> Vec<&TransactionRecord> {
+        let mut records: Vec<_> = self.transactions.values().collect();
+        records.sort_by_key(|record| (Reverse(record.block.map_or(u64::MAX, |(height, _)| height)), Reverse(record.timestamp)));
+        records.into_iter().skip(offset).take(limit).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{tx_in::TxIn, tx_out::TxOut, OutPoint};
+
+    const WALLET_SCRIPT: [u8; 4] = [0x00, 0x14, 1, 2];
+    const CHANGE_SCRIPT: [u8; 4] = [0x00, 0x14, 3, 4];
+
+    fn transaction(inputs: usize, outputs: Vec<(i64, Vec<u8>)>) -> Tx {
+        Tx {
+            version: 2,
+            inputs: (0..inputs).map(|index| TxIn { prev_output: OutPoint { hash: Hash256([7; 32]), index: index as u32 }, unlock_script: vec![], sequence: 0xffffffff }).collect(),
+            outputs: outputs.into_iter().map(|(satoshis, lock_script)| TxOut { satoshis, lock_script }).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn spent(index: u32, amount: u64) -> UtxoRecord {
+        UtxoRecord { txid: vec![7; 32], vout: index, amount, script: WALLET_SCRIPT.to_vec(), height: Some(1), is_coinbase: false }
+    }
+
+    fn output(vout: u32, amount: u64, is_change: bool) -> WalletOutput {
+        let script = match is_change {
+            true => CHANGE_SCRIPT.to_vec(),
+            false => WALLET_SCRIPT.to_vec(),
+        };
+        WalletOutput { vout, amount, script, is_change }
+    }
+
+    #[test]
+    fn records_direction_amount_and_fee() {
+        let payment = transaction(1, vec![(5000, vec![0x00, 0x14, 9, 9]), (1000, WALLET_SCRIPT.to_vec())]);
+        let incoming = TransactionRecord::new(&payment, vec![], vec![output(1, 1000, false)], 10).unwrap();
+        assert_eq!(incoming.direction, Direction::Incoming);
+        assert_eq!(incoming.net_amount(), 1000);
+        assert_eq!(incoming.fee, None);
+
+        let send = transaction(2, vec![(5000, vec![0x00, 0x14, 9, 9]), (4500, CHANGE_SCRIPT.to_vec())]);
+        let outgoing = TransactionRecord::new(&send, vec![spent(0, 6000), spent(1, 4000)], vec![output(1, 4500, true)], 10).unwrap();
+        assert_eq!(outgoing.direction, Direction::Outgoing);
+        assert_eq!(outgoing.fee, Some(500));
+        assert_eq!(outgoing.net_amount(), -5500);
+
+        let consolidation = transaction(1, vec![(5800, CHANGE_SCRIPT.to_vec())]);
+        let own = TransactionRecord::new(&consolidation, vec![spent(0, 6000)], vec![output(0, 5800, true)], 10).unwrap();
+        assert_eq!(own.direction, Direction::SelfTransfer);
+        assert_eq!(own.net_amount(), -200);
+
+        assert!(TransactionRecord::new(&payment, vec![], vec![], 10).is_none());
+    }
+
+    #[test]
+    fn follows_confirmations() {
+        let mut history = TransactionHistory::default();
+        let send = transaction(1, vec![(5000, vec![0x00, 0x14, 9, 9])]);
+        let record = TransactionRecord::new(&send, vec![spent(0, 6000)], vec![], 100).unwrap();
+        history.add_unconfirmed(record.clone());
+
+        // The block only saw one of the inputs as ours
+        let mut partial = record.clone();
+        partial.fee = None;
+        history.add_confirmed(partial, 5, Hash256([1; 32]), 90);
+        let confirmed = history.get(&send.txid()).unwrap();
+        assert_eq!(confirmed.block, Some((5, Hash256([1; 32]))));
+        assert_eq!(confirmed.timestamp, 90);
+        assert_eq!(confirmed.fee, Some(1000));
+
+        history.remove_unconfirmed(&send.txid());
+        assert!(history.get(&send.txid()).is_some());
+
+        history.unconfirm_block(&Hash256([1; 32]));
+        assert_eq!(history.get(&send.txid()).unwrap().block, None);
+        history.remove_unconfirmed(&send.txid());
+        assert!(history.get(&send.txid()).is_none());
+    }
+
+    #[test]
+    fn lists_most_recent_first() {
+        let mut history = TransactionHistory::default();
+        let records: Vec<_> = (0..4).map(|amount| {
+            let tx = transaction(1, vec![(1000 + amount, WALLET_SCRIPT.to_vec())]);
+            TransactionRecord::new(&tx, vec![], vec![output(0, 1000 + amount as u64, false)], 100).unwrap()
+        }).collect();
+        history.add_confirmed(records[0].clone(), 3, Hash256([3; 32]), 30);
+        history.add_confirmed(records[1].clone(), 8, Hash256([8; 32]), 80);
+        history.add_unconfirmed(records[2].clone());
+        history.add_confirmed(records[3].clone(), 5, Hash256([5; 32]), 50);
+
+        let txids: Vec<_> = history.list(0, 10).iter().map(|record| record.txid).collect();
+        assert!(txids == vec![records[2].txid, records[1].txid, records[3].txid, records[0].txid]);
+        let page: Vec<_> = history.list(1, 2).iter().map(|record| record.txid).collect();
+        assert!(page == vec![records[1].txid, records[3].txid]);
+    }
+}
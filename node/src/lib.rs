@@ -2,11 +2,16 @@
 mod bindings;
 use std::{cell::RefCell};
 
-use node::{ CustomIPV4SocketAddress, Node, NodeConfig};
+use node::{ CustomIPV4SocketAddress, Node, NodeConfig, NodeKeys};
+use chain::WalletBirthday;
+use history::{Direction, TransactionRecord};
+use payment::PaymentRequest;
 use bindings::component::kv::types::{Kvstore};
-use bindings::exports::component::node::types::{BitcoinNetwork as WasiBitcoinNetwork, Guest, GuestClientNode, Initialization, NodeConfig as WasiNodeConfig };
+use bindings::component::wallet::types::{Keychain, UnspentUtxo, WalletType};
+use bindings::exports::component::node::types::{BitcoinNetwork as WasiBitcoinNetwork, Guest, ClientNode, GuestClientNode, Initialization, NodeConfig as WasiNodeConfig, WalletBirthday as WasiWalletBirthday, WalletType as WasiWalletType, NodeKeys as WasiNodeKeys, Balance, Direction as WasiDirection, TransactionDetails, PaymentRequest as WasiPaymentRequest, Outpoint, Recipient, UnspentOutput, WalletInput, WalletOutput as WasiWalletOutput };
 use bitcoin::network as bitcoin_network;
 use util::Hash256;
+use p2p::DEFAULT_MAX_CONNECTIONS;
 
 
 mod node;
@@ -16,6 +21,15 @@ mod util;
 mod messages;
 mod chain;
 mod db;
+mod validation;
+mod header_store;
+mod filter_headers;
+mod address_book;
+mod dispatcher;
+mod mempool;
+mod broadcast;
+mod history;
+mod payment;
 struct Component;
 
 struct BitcoinNode {
@@ -38,18 +52,55 @@ impl From<WasiBitcoinNetwork> for bitcoin_network::Network {
 
 
 
+impl From<WasiWalletType> for WalletType {
+    fn from(val: WasiWalletType) -> Self {
+        match val {
+            WasiWalletType::P2wpkh => WalletType::P2wpkh,
+            WasiWalletType::P2tr => WalletType::P2tr,
+        }
+    }
+}
+
+impl From<WasiNodeKeys> for NodeKeys {
+    fn from(val: WasiNodeKeys) -> Self {
+        match val {
+            WasiNodeKeys::Xpriv(xpriv) => NodeKeys::Xpriv(xpriv),
+            WasiNodeKeys::Account(account) => NodeKeys::Account { xpub: account.xpub, account_derivation: account.account_derivation, master_fingerprint: account.master_fingerprint },
+            WasiNodeKeys::Descriptors(descriptors) => NodeKeys::Descriptors { external: descriptors.external, internal: descriptors.internal },
+        }
+    }
+}
+
+impl From<WasiWalletBirthday> for WalletBirthday {
+    fn from(val: WasiWalletBirthday) -> Self {
+        match val {
+            WasiWalletBirthday::Height(height) => WalletBirthday::Height(height),
+            WasiWalletBirthday::Timestamp(timestamp) => WalletBirthday::Timestamp(timestamp),
+        }
+    }
+}
+
 impl From<WasiNodeConfig> for NodeConfig {
     fn from(val: WasiNodeConfig) -> Self {
-        let WasiNodeConfig { network, socket_address, xpriv    } = val;
+        let WasiNodeConfig { network, wallet_type, socket_address, peers, max_connections, use_seeds, birthday, lookahead, min_confirmations, keys    } = val;
 
         // Convert the network type
         let network: bitcoin_network::Network = network.into();
 
+        let peers = peers.into_iter().map(|peer| CustomIPV4SocketAddress{ ip: peer.address, port: peer.port }).collect();
+
         // Construct and return the NodeConfig
         NodeConfig {
             network,
+            wallet_type: wallet_type.map_or(WalletType::P2wpkh, |wallet_type| wallet_type.into()),
             socket_address: CustomIPV4SocketAddress{ ip: socket_address.address, port: socket_address.port  },
-            xpriv
+            peers,
+            max_connections: max_connections.map_or(DEFAULT_MAX_CONNECTIONS, |max| max as usize),
+            use_seeds,
+            birthday: birthday.map(|birthday| birthday.into()),
+            lookahead,
+            min_confirmations,
+            keys: keys.into(),
         }
     }
 }
@@ -58,22 +109,118 @@ impl From<WasiNodeConfig> for NodeConfig {
 
 
 
+impl From<Direction> for WasiDirection {
+    fn from(val: Direction) -> Self {
+        match val {
+            Direction::Incoming => WasiDirection::Incoming,
+            Direction::Outgoing => WasiDirection::Outgoing,
+            Direction::SelfTransfer => WasiDirection::SelfTransfer,
+        }
+    }
+}
+
+impl From<TransactionRecord> for TransactionDetails {
+    fn from(val: TransactionRecord) -> Self {
+        let net_amount = val.net_amount();
+        let inputs = val.inputs.iter().map(|input| WalletInput { txid: input.txid_hash().encode(), vout: input.vout, amount: input.amount }).collect();
+        let outputs = val.outputs.into_iter().map(|output| WasiWalletOutput { vout: output.vout, amount: output.amount, script: output.script, is_change: output.is_change }).collect();
+
+        TransactionDetails {
+            txid: val.txid.encode(),
+            block_height: val.block.map(|(height, _)| height),
+            block_hash: val.block.map(|(_, hash)| hash.encode()),
+            timestamp: val.timestamp,
+            net_amount,
+            fee: val.fee,
+            direction: val.direction.into(),
+            inputs,
+            outputs,
+        }
+    }
+}
+
+impl From<PaymentRequest> for WasiPaymentRequest {
+    fn from(val: PaymentRequest) -> Self {
+        WasiPaymentRequest {
+            address: val.address.to_string(),
+            amount: val.amount.map(|amount| amount.to_sat()),
+            label: val.label,
+            message: val.message,
+        }
+    }
+}
+
+impl From<UnspentUtxo> for UnspentOutput {
+    fn from(val: UnspentUtxo) -> Self {
+        let mut txid = Hash256::default();
+        txid.0.copy_from_slice(&val.outpoint.txid);
+
+        UnspentOutput {
+            txid: txid.encode(),
+            vout: val.outpoint.vout,
+            amount: val.amount,
+            script: val.script,
+            is_change: val.keychain == Keychain::Internal,
+            block_height: val.height.map(u64::from),
+            confirmations: val.confirmations,
+            is_coinbase: val.is_coinbase,
+            is_frozen: val.is_frozen,
+        }
+    }
+}
+
 impl GuestClientNode for BitcoinNode {
     fn get_balance(&self) -> Result<u64, u32> {
         return  self.inner.borrow_mut().balance().map_err(|err| err.to_error_code());
     }
 
+    fn rescan(&self, from_height: u64) -> Result<(), u32> {
+        return  self.inner.borrow_mut().rescan(from_height).map_err(|err| err.to_error_code());
+    }
+
+    fn get_balance_breakdown(&self) -> Result<Balance, u32> {
+        let balance = self.inner.borrow_mut().balance_breakdown().map_err(|err| err.to_error_code())?;
+        return Ok(Balance {
+            confirmed: balance.confirmed,
+            trusted_pending: balance.trusted_pending,
+            untrusted_pending: balance.untrusted_pending,
+            immature: balance.immature,
+        });
+    }
+
+    fn list_transactions(&self, offset: u32, limit: u32) -> Result<Vec<TransactionDetails>, u32> {
+        let records = self.inner.borrow_mut().list_transactions(offset as usize, limit as usize).map_err(|err| err.to_error_code())?;
+        return Ok(records.into_iter().map(|record| record.into()).collect());
+    }
+
+    fn get_transaction(&self, txid: String) -> Result<Option<TransactionDetails>, u32> {
+        let record = self.inner.borrow_mut().get_transaction(&txid).map_err(|err| err.to_error_code())?;
+        return Ok(record.map(|record| record.into()));
+    }
+
+    fn get_pending_balance(&self) -> Result<u64, u32> {
+        return  self.inner.borrow_mut().pending_balance().map_err(|err| err.to_error_code());
+    }
+
     fn new(init: Initialization) -> Self {
         match init {
             Initialization::OldState => {
                 Self{ inner:  Node::restore().into()}
             },
             Initialization::Config(config) => {
-                Self{ inner:  Node::new(config.into()).into()}
+                Self{ inner:  Node::new(config.into()).expect("invalid node config").into()}
             },
         }
 
     }
+
+    fn create(init: Initialization) -> Result<ClientNode, u32> {
+        let node = match init {
+            Initialization::OldState => Node::restore(),
+            Initialization::Config(config) => Node::new(config.into()).map_err(|err| err.to_error_code())?,
+        };
+        Ok(ClientNode::new(BitcoinNode { inner: node.into() }))
+    }
     
     fn get_receive_address(&self) -> Result<String, u32> {
         return  self.inner.borrow_mut().get_receive_address().map_err(|err| err.to_error_code());
@@ -81,11 +228,70 @@ impl GuestClientNode for BitcoinNode {
     
     fn send_to_address(
         &self,
-        recepient: Vec<u8>,
+        address: String,
         amount: u64,
         fee_rate: u64,
     ) -> Result<(), u32> {
-        return self.inner.borrow_mut().send_to_address(&recepient, amount, fee_rate).map_err(|err| err.to_error_code());
+        return self.inner.borrow_mut().send_to_address(&address, amount, fee_rate).map_err(|err| err.to_error_code());
+    }
+
+    fn send_many(&self, recipients: Vec<Recipient>, fee_rate: u64, must_spend: Vec<Outpoint>) -> Result<(), u32> {
+        let recipients: Vec<_> = recipients.into_iter().map(|recipient| (recipient.address, recipient.amount)).collect();
+        let must_spend: Vec<_> = must_spend.into_iter().map(|outpoint| (outpoint.txid, outpoint.vout)).collect();
+        return self.inner.borrow_mut().send_many(&recipients, fee_rate, &must_spend).map_err(|err| err.to_error_code());
+    }
+
+    fn drain_to_address(&self, address: String, fee_rate: u64, outpoints: Option<Vec<Outpoint>>) -> Result<(), u32> {
+        let outpoints = outpoints.map(|outpoints| outpoints.into_iter().map(|outpoint| (outpoint.txid, outpoint.vout)).collect());
+        return self.inner.borrow_mut().drain_to_address(&address, fee_rate, outpoints).map_err(|err| err.to_error_code());
+    }
+
+    fn create_drain_psbt(&self, address: String, fee_rate: u64, outpoints: Option<Vec<Outpoint>>) -> Result<Vec<u8>, u32> {
+        let outpoints = outpoints.map(|outpoints| outpoints.into_iter().map(|outpoint| (outpoint.txid, outpoint.vout)).collect());
+        return self.inner.borrow_mut().create_drain_psbt(&address, fee_rate, outpoints).map_err(|err| err.to_error_code());
+    }
+
+    fn bump_fee(&self, txid: String, fee_rate: u64) -> Result<String, u32> {
+        return self.inner.borrow_mut().bump_fee(&txid, fee_rate).map_err(|err| err.to_error_code());
+    }
+
+    fn cpfp(&self, txid: String, target_fee_rate: u64) -> Result<String, u32> {
+        return self.inner.borrow_mut().cpfp(&txid, target_fee_rate).map_err(|err| err.to_error_code());
+    }
+
+    fn send_to_uri(&self, uri: String, fee_rate: u64) -> Result<(), u32> {
+        return self.inner.borrow_mut().send_to_uri(&uri, fee_rate).map_err(|err| err.to_error_code());
+    }
+
+    fn parse_payment_uri(&self, uri: String) -> Result<WasiPaymentRequest, u32> {
+        let request = self.inner.borrow().parse_payment_uri(&uri).map_err(|err| err.to_error_code())?;
+        return Ok(request.into());
+    }
+
+    fn create_psbt(
+        &self,
+        address: String,
+        amount: u64,
+        fee_rate: u64,
+    ) -> Result<Vec<u8>, u32> {
+        return self.inner.borrow_mut().create_psbt(&address, amount, fee_rate).map_err(|err| err.to_error_code());
+    }
+
+    fn broadcast_psbt(&self, psbt: Vec<u8>) -> Result<String, u32> {
+        return self.inner.borrow_mut().broadcast_psbt(&psbt).map_err(|err| err.to_error_code());
+    }
+
+    fn list_unspent(&self) -> Result<Vec<UnspentOutput>, u32> {
+        let unspent = self.inner.borrow_mut().list_unspent().map_err(|err| err.to_error_code())?;
+        return Ok(unspent.into_iter().map(|utxo| utxo.into()).collect());
+    }
+
+    fn freeze_utxo(&self, outpoint: Outpoint) -> Result<(), u32> {
+        return self.inner.borrow_mut().freeze_utxo(&(outpoint.txid, outpoint.vout)).map_err(|err| err.to_error_code());
+    }
+
+    fn unfreeze_utxo(&self, outpoint: Outpoint) -> Result<(), u32> {
+        return self.inner.borrow_mut().unfreeze_utxo(&(outpoint.txid, outpoint.vout)).map_err(|err| err.to_error_code());
     }
 
     
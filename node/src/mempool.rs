@@ -0,0 +1,175 @@
+//! Wallet payments seen in transactions that are not mined yet
+//!
+//! Outputs found here are handed to the wallet as unconfirmed and forgotten once their
+//! transaction shows up in a block, or after it stayed unconfirmed for as long as nodes keep
+//! transactions in their mempool.
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::chain::UtxoRecord;
+use crate::db::{KeyValueDb, MEMPOOL_STATE_KEY};
+use crate::messages::tx::Tx;
+use crate::util::{Error, Hash256, Result};
+
+/// Unconfirmed transactions are dropped after two weeks, like bitcoind expires them
+const MEMPOOL_EXPIRY_SECS: u64 = 14 * 24 * 60 * 60;
+
+/// Announced txids remembered so each transaction is fetched once
+const MAX_SEEN_TXIDS: usize = 50_000;
+
+/// Unconfirmed transaction paying the wallet
+#[derive(serde::Deserialize, Serialize, Clone)]
+struct PendingTx {
+    first_seen: u64,
+    outputs: Vec<UtxoRecord>,
+}
+
+#[derive(serde::Deserialize, Serialize, Default)]
+pub struct MempoolState {
+    pending: BTreeMap<Hash256, PendingTx>,
+    // Only avoids fetching the same announcement twice, no need to persist it
+    #[serde(skip)]
+    seen: HashSet<Hash256>,
+}
+
+impl MempoolState {
+
+    pub fn load(db: &Arc<KeyValueDb>) -> Result<Self> {
+        match db.get_optional(MEMPOOL_STATE_KEY.to_string())? {
+            Some(bytes) => bincode::deserialize(&bytes).map_err(|err| Error::SerializationError(err.to_string())),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, db: &Arc<KeyValueDb>) -> Result<()> {
+        let encoded = bincode::serialize(self).map_err(|err| Error::SerializationError(err.to_string()))?;
+        db.insert(MEMPOOL_STATE_KEY.to_string(), encoded)
+    }
+
+    /// Keeps the txids not looked at before and remembers them
+    pub fn unseen(&mut self, txids: Vec<Hash256>) -> Vec<Hash256> {
+        if self.seen.len() + txids.len() > MAX_SEEN_TXIDS {
+            self.seen.clear();
+        }
+        txids.into_iter()
+            .filter(|txid| !self.pending.contains_key(txid) && self.seen.insert(*txid))
+            .collect()
+    }
+
+    /// Records the outputs of `tx` locked to one of `scripts` and returns them
+    pub fn add_transaction(&mut self, tx: &Tx, scripts: &[Vec<u8>], now: u64) -> Vec<UtxoRecord> {
+        let txid = tx.txid();
+        let outputs: Vec<UtxoRecord> = tx.outputs.iter().enumerate()
+            .filter(|(_, output)| scripts.contains(&output.lock_script))
+            .map(|(vout, output)| UtxoRecord { txid: txid.0.to_vec(), vout: vout as u32, amount: output.satoshis as u64, script: output.lock_script.clone(), height: None, is_coinbase: false })
+            .collect();
+
+        if !outputs.is_empty() && !self.pending.contains_key(&txid) {
+            self.pending.insert(txid, PendingTx { first_seen: now, outputs: outputs.clone() });
+        }
+        outputs
+    }
+
+    /// Stops tracking a transaction that was mined
+    pub fn confirm(&mut self, txid: &Hash256) -> bool {
+        self.pending.remove(txid).is_some()
+    }
+
+    /// Stops tracking a transaction a fee bump replaced and returns the outputs to forget
+    pub fn remove(&mut self, txid: &Hash256) -> Vec<UtxoRecord> {
+        self.pending.remove(txid).map(|pending| pending.outputs).unwrap_or_default()
+    }
+
+    /// Drops transactions unconfirmed for too long, except the ones `keep` holds on to, and
+    /// returns the outputs to forget
+    pub fn expire(&mut self, now: u64, keep: impl Fn(&Hash256) -> bool) -> Vec<UtxoRecord> {
+        let expired: Vec<Hash256> = self.pending.iter()
+            .filter(|(txid, pending)| pending.first_seen + MEMPOOL_EXPIRY_SECS <= now && !keep(txid))
+            .map(|(txid, _)| *txid)
+            .collect();
+
+        expired.iter()
+            .filter_map(|txid| self.pending.remove(txid))
+            .flat_map(|pending| pending.outputs)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::tx_out::TxOut;
+
+    const WALLET_SCRIPT: [u8; 22] = [0x00, 0x14, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20];
+
+    fn payment(amount: i64) -> Tx {
+        Tx {
+            version: 2,
+            outputs: vec![
+                TxOut { satoshis: 5000, lock_script: vec![0x00, 0x14, 9, 9] },
+                TxOut { satoshis: amount, lock_script: WALLET_SCRIPT.to_vec() },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn finds_wallet_outputs() {
+        let mut mempool = MempoolState::default();
+        let tx = payment(1000);
+        let outputs = mempool.add_transaction(&tx, &[WALLET_SCRIPT.to_vec()], 100);
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].vout, 1);
+        assert_eq!(outputs[0].amount, 1000);
+        assert!(outputs[0].txid == tx.txid().0.to_vec());
+        assert!(mempool.add_transaction(&payment(7), &[], 100).is_empty());
+    }
+
+    #[test]
+    fn fetches_announcements_once() {
+        let mut mempool = MempoolState::default();
+        let tx = payment(1000);
+        mempool.add_transaction(&tx, &[WALLET_SCRIPT.to_vec()], 100);
+
+        let unseen = mempool.unseen(vec![Hash256([1; 32]), tx.txid()]);
+        assert!(unseen == vec![Hash256([1; 32])]);
+        assert!(mempool.unseen(vec![Hash256([1; 32])]).is_empty());
+    }
+
+    #[test]
+    fn confirmed_transactions_do_not_expire() {
+        let mut mempool = MempoolState::default();
+        let mined = payment(1000);
+        let stuck = payment(2000);
+        mempool.add_transaction(&mined, &[WALLET_SCRIPT.to_vec()], 100);
+        mempool.add_transaction(&stuck, &[WALLET_SCRIPT.to_vec()], 100);
+
+        assert!(mempool.confirm(&mined.txid()));
+        assert!(mempool.expire(100 + MEMPOOL_EXPIRY_SECS - 1, |_| false).is_empty());
+
+        let expired = mempool.expire(100 + MEMPOOL_EXPIRY_SECS, |_| false);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].amount, 2000);
+    }
+
+    #[test]
+    fn kept_transactions_do_not_expire() {
+        let mut mempool = MempoolState::default();
+        let sent = payment(1000);
+        let stuck = payment(2000);
+        mempool.add_transaction(&sent, &[WALLET_SCRIPT.to_vec()], 100);
+        mempool.add_transaction(&stuck, &[WALLET_SCRIPT.to_vec()], 100);
+
+        let expired = mempool.expire(100 + MEMPOOL_EXPIRY_SECS, |txid| *txid == sent.txid());
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].amount, 2000);
+
+        let expired = mempool.expire(100 + MEMPOOL_EXPIRY_SECS, |_| false);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].amount, 1000);
+    }
+}
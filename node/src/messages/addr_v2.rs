@@ -0,0 +1,76 @@
+use crate::messages::message::Payload;
+use crate::messages::node_addr_v2::NodeAddrV2;
+use crate::util::{var_int, Error, Result, Serializable};
+use std::fmt;
+use std::io;
+use std::io::{Read, Write};
+
+const MAX_ADDR_COUNT: u64 = 1000;
+
+/// Known node addresses in the BIP155 format
+#[derive(Default, PartialEq, Eq, Hash, Clone)]
+pub struct AddrV2 {
+    /// List of addresses of known nodes
+    pub addrs: Vec<NodeAddrV2>,
+}
+
+impl Serializable<AddrV2> for AddrV2 {
+    fn read(reader: &mut dyn Read) -> Result<AddrV2> {
+        let mut ret = AddrV2 { addrs: Vec::new() };
+        let count = var_int::read(reader)?;
+        if count > MAX_ADDR_COUNT {
+            let msg = format!("Too many addrs: {}", count);
+            return Err(Error::BadData(msg));
+        }
+        for _i in 0..count {
+            ret.addrs.push(NodeAddrV2::read(reader)?);
+        }
+        Ok(ret)
+    }
+
+    fn write(&self, writer: &mut dyn Write) -> io::Result<()> {
+        var_int::write(self.addrs.len() as u64, writer)?;
+        for item in self.addrs.iter() {
+            item.write(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl Payload<AddrV2> for AddrV2 {
+    fn size(&self) -> usize {
+        var_int::size(self.addrs.len() as u64) + self.addrs.iter().map(|addr| addr.size()).sum::<usize>()
+    }
+}
+
+impl fmt::Debug for AddrV2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.addrs.len() <= 3 {
+            f.debug_struct("AddrV2").field("addrs", &self.addrs).finish()
+        } else {
+            let s = format!("[<{} addrs>]", self.addrs.len());
+            f.debug_struct("AddrV2").field("addrs", &s).finish()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::node_addr_v2::NETWORK_IPV4;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_read() {
+        let mut v = Vec::new();
+        let f = AddrV2 {
+            addrs: vec![
+                NodeAddrV2 { time: 100, services: 9, network_id: NETWORK_IPV4, addr: vec![1, 2, 3, 4], port: 8333 },
+                NodeAddrV2 { time: 200, services: 1, network_id: 2, addr: vec![5; 16], port: 18333 },
+            ],
+        };
+        f.write(&mut v).unwrap();
+        assert!(v.len() == f.size());
+        assert!(AddrV2::read(&mut Cursor::new(&v)).unwrap() == f);
+    }
+}
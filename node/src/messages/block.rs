@@ -1,7 +1,8 @@
 use crate::messages::{BlockHeader, Payload};
 use crate::util::{
-    var_int, Result, Serializable,
+    sha256d, var_int, Hash256, Result, Serializable,
 };
+use std::collections::VecDeque;
 use std::fmt;
 use std::io;
 use std::io::{Read, Write};
@@ -125,6 +126,36 @@ pub struct Block {
     // }
 // }
 
+impl Block {
+    /// Calculates the merkle root from the transactions
+    ///
+    /// Transactions must be in their non witness serialization for their hashes to be txids.
+    pub fn merkle_root(&self) -> Hash256 {
+        let mut row = VecDeque::new();
+        for tx in self.txns.iter() {
+            row.push_back(tx.hash());
+        }
+        while row.len() > 1 {
+            let mut n = row.len();
+            while n > 0 {
+                n -= 1;
+                let h1 = row.pop_front().unwrap();
+                let h2 = if n == 0 {
+                    h1
+                } else {
+                    n -= 1;
+                    row.pop_front().unwrap()
+                };
+                let mut h = Vec::with_capacity(64);
+                h1.write(&mut h).unwrap();
+                h2.write(&mut h).unwrap();
+                row.push_back(sha256d(&h));
+            }
+        }
+        row.pop_front().unwrap_or_default()
+    }
+}
+
 impl Serializable<Block> for Block {
     fn read(reader: &mut dyn Read) -> Result<Block> {
         let header = BlockHeader::read(reader)?;
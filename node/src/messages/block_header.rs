@@ -1,11 +1,12 @@
 use crate::util::{sha256d, Error, Hash256, Result, Serializable};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use serde::{Deserialize, Serialize};
 use std::cmp::min;
 use std::io;
 use std::io::{Read, Write};
 
 /// Block header
-#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct BlockHeader {
     /// Block version specifying which validation rules to use
     pub version: u32,
@@ -0,0 +1,58 @@
+use crate::messages::message::Payload;
+use crate::util::{Hash256, Result, Serializable};
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use std::io;
+use std::io::{Read, Write};
+
+/// Requests the filter header checkpoints up to a block
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct CheckpointLocator {
+    /// Type of filter the checkpoints are requested for
+    pub filter_type: u8,
+    /// Hash of the last block covered by the checkpoints
+    pub stop_hash: Hash256,
+}
+
+impl Serializable<CheckpointLocator> for CheckpointLocator {
+    fn read(reader: &mut dyn Read) -> Result<CheckpointLocator> {
+        let filter_type = reader.read_u8()?;
+        let stop_hash = Hash256::read(reader)?;
+        Ok(CheckpointLocator {
+            filter_type,
+            stop_hash,
+        })
+    }
+
+    fn write(&self, writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_u8(self.filter_type)?;
+        self.stop_hash.write(writer)?;
+        Ok(())
+    }
+}
+
+impl Payload<CheckpointLocator> for CheckpointLocator {
+    fn size(&self) -> usize {
+        1 + 32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_read() {
+        let mut v = Vec::new();
+        let p = CheckpointLocator {
+            filter_type: 0,
+            stop_hash: Hash256::decode(
+                "1122334455112233445511223344551122334455112233445511223344551122",
+            )
+            .unwrap(),
+        };
+        p.write(&mut v).unwrap();
+        assert!(v.len() == p.size());
+        assert!(CheckpointLocator::read(&mut Cursor::new(&v)).unwrap() == p);
+    }
+}
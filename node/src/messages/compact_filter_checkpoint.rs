@@ -0,0 +1,72 @@
+use crate::util::{var_int, Hash256, Result, Serializable};
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use std::io;
+use std::io::{Read, Write};
+use crate::messages::message::Payload;
+
+/// Number of blocks between two filter header checkpoints
+pub const CHECKPOINT_INTERVAL: u64 = 1000;
+
+/// Filter headers at every checkpoint interval up to a block
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct CompactFilterCheckpoint {
+    /// Type of filter the checkpoints belong to
+    pub filter_type: u8,
+    /// Hash of the last block covered by the checkpoints
+    pub stop_hash: Hash256,
+    /// Filter headers at heights 1000, 2000, ... up to the stop block
+    pub filter_headers: Vec<Hash256>,
+}
+
+impl Payload<CompactFilterCheckpoint> for CompactFilterCheckpoint {
+    fn size(&self) -> usize {
+        1 + 32 + var_int::size(self.filter_headers.len() as u64)
+            + self.filter_headers.len() * 32
+    }
+}
+
+impl Serializable<CompactFilterCheckpoint> for CompactFilterCheckpoint {
+    fn read(reader: &mut dyn Read) -> Result<CompactFilterCheckpoint> {
+        let filter_type = reader.read_u8()?;
+        let stop_hash = Hash256::read(reader)?;
+        let headers_len = var_int::read(reader)?;
+        let mut filter_headers = Vec::new();
+        for _i in 0..headers_len {
+            filter_headers.push(Hash256::read(reader)?);
+        }
+        Ok(CompactFilterCheckpoint {
+            filter_type,
+            stop_hash,
+            filter_headers
+        })
+    }
+
+    fn write(&self, writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_u8(self.filter_type)?;
+        self.stop_hash.write(writer)?;
+        var_int::write(self.filter_headers.len() as u64, writer)?;
+        for header in self.filter_headers.iter() {
+            header.write(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_read() {
+        let mut v = Vec::new();
+        let p = CompactFilterCheckpoint {
+            filter_type: 0,
+            stop_hash: Hash256([7; 32]),
+            filter_headers: vec![Hash256([1; 32]), Hash256([2; 32])],
+        };
+        p.write(&mut v).unwrap();
+        assert!(v.len() == p.size());
+        assert!(CompactFilterCheckpoint::read(&mut Cursor::new(&v)).unwrap() == p);
+    }
+}
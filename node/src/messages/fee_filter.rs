@@ -0,0 +1,49 @@
+use crate::messages::message::Payload;
+use crate::util::{Result, Serializable};
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use std::io;
+use std::io::{Read, Write};
+
+/// Minimum fee rate of transactions the peer wants announced (BIP133)
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct FeeFilter {
+    /// Fee rate in satoshis per kilobyte
+    pub minfee: u64,
+}
+
+impl FeeFilter {
+    /// Size of the fee filter payload in bytes
+    pub const SIZE: usize = 8;
+}
+
+impl Serializable<FeeFilter> for FeeFilter {
+    fn read(reader: &mut dyn Read) -> Result<FeeFilter> {
+        let minfee = reader.read_u64::<LittleEndian>()?;
+        Ok(FeeFilter { minfee })
+    }
+
+    fn write(&self, writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_u64::<LittleEndian>(self.minfee)
+    }
+}
+
+impl Payload<FeeFilter> for FeeFilter {
+    fn size(&self) -> usize {
+        FeeFilter::SIZE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_read() {
+        let mut v = Vec::new();
+        let p = FeeFilter { minfee: 1000 };
+        p.write(&mut v).unwrap();
+        assert!(v.len() == p.size());
+        assert!(FeeFilter::read(&mut Cursor::new(&v)).unwrap() == p);
+    }
+}
@@ -1,4 +1,7 @@
-// use crate::messages::addr::Addr;
+use crate::messages::addr::Addr;
+use crate::messages::addr_v2::AddrV2;
+use crate::messages::fee_filter::FeeFilter;
+use crate::messages::send_cmpct::SendCmpct;
 use crate::messages::block::Block;
 use crate::messages::block_locator::BlockLocator;
 use crate::messages::headers::Headers;
@@ -15,7 +18,9 @@ use std::fmt;
 use std::io;
 use std::io::{Cursor, Read, Write};
 
+use super::checkpoint_locator::CheckpointLocator;
 use super::compact_filter::CompactFilter;
+use super::compact_filter_checkpoint::CompactFilterCheckpoint;
 use super::compact_filter_header::CompactFilterHeader;
 use super::filter_locator::FilterLocator;
 
@@ -120,6 +125,18 @@ pub mod commands {
     /// [Cfheaders command](https://github.com/bitcoin/bips/blob/master/bip-0157.mediawiki#cfilters)
     pub const CFHEADERS: [u8; 12] = *b"cfheaders\0\0\0";
 
+    /// [GetCfcheckpt command](https://github.com/bitcoin/bips/blob/master/bip-0157.mediawiki#getcfcheckpt)
+    pub const GETCFCHECKPT: [u8; 12] = *b"getcfcheckpt";
+
+    /// [Cfcheckpt command](https://github.com/bitcoin/bips/blob/master/bip-0157.mediawiki#cfcheckpt)
+    pub const CFCHECKPT: [u8; 12] = *b"cfcheckpt\0\0\0";
+
+    /// [AddrV2 command](https://github.com/bitcoin/bips/blob/master/bip-0155.mediawiki)
+    pub const ADDRV2: [u8; 12] = *b"addrv2\0\0\0\0\0\0";
+
+    /// [SendAddrV2 command](https://github.com/bitcoin/bips/blob/master/bip-0155.mediawiki)
+    pub const SENDADDRV2: [u8; 12] = *b"sendaddrv2\0\0";
+
 
 
 }
@@ -127,8 +144,10 @@ pub mod commands {
 /// Bitcoin peer-to-peer message with its payload
 #[derive(PartialEq, Eq, Hash, Clone)]
 pub enum Message {
-    // Addr(Addr),
+    Addr(Addr),
+    AddrV2(AddrV2),
     Block(Block),
+    FeeFilter(FeeFilter),
     GetAddr,
     GetBlocks(BlockLocator),
     GetData(Inv),
@@ -145,9 +164,12 @@ pub enum Message {
     GetCFHeaders(FilterLocator),
     CFilters(CompactFilter),
     CFHeaders(CompactFilterHeader),
+    GetCFCheckpt(CheckpointLocator),
+    CFCheckpt(CompactFilterCheckpoint),
     Reject(Reject),
+    SendAddrV2,
     SendHeaders,
-    //SendCmpct(SendCmpct),
+    SendCmpct(SendCmpct),
     Tx(Tx),
     Verack,
     Version(Version),
@@ -183,11 +205,18 @@ impl Message {
     /// It may be used after read() returns Message::Partial.
     pub fn read_partial(reader: &mut dyn Read, header: &MessageHeader) -> Result<Self> {
         // Addr
-        // if header.command == commands::ADDR {
-        //     let payload = header.payload(reader)?;
-        //     let addr = Addr::read(&mut Cursor::new(payload))?;
-        //     return Ok(Message::Addr(addr));
-        // }
+        if header.command == commands::ADDR {
+            let payload = header.payload(reader)?;
+            let addr = Addr::read(&mut Cursor::new(payload))?;
+            return Ok(Message::Addr(addr));
+        }
+
+        // AddrV2
+        if header.command == commands::ADDRV2 {
+            let payload = header.payload(reader)?;
+            let addr = AddrV2::read(&mut Cursor::new(payload))?;
+            return Ok(Message::AddrV2(addr));
+        }
 
         // Block
         if header.command == commands::BLOCK {
@@ -197,6 +226,13 @@ impl Message {
         }
 
 
+        // Feefilter
+        if header.command == commands::FEEFILTER {
+            let payload = header.payload(reader)?;
+            let feefilter = FeeFilter::read(&mut Cursor::new(payload))?;
+            return Ok(Message::FeeFilter(feefilter));
+        }
+
         // Getaddr
         if header.command == commands::GETADDR {
             if header.payload_size != 0 {
@@ -241,6 +277,13 @@ impl Message {
             return Ok(Message::CFHeaders(cfheader));
         }
 
+        // CFCheckpt
+        if header.command == commands::CFCHECKPT {
+            let payload = header.payload(reader)?;
+            let cfcheckpt = CompactFilterCheckpoint::read(&mut Cursor::new(payload))?;
+            return Ok(Message::CFCheckpt(cfcheckpt));
+        }
+
         // Inv
         if header.command == commands::INV {
             let payload = header.payload(reader)?;
@@ -285,12 +328,12 @@ impl Message {
             return Ok(Message::Reject(reject));
         }
 
-        // // Sendcmpct
-        // if header.command == commands::SENDCMPCT {
-        //     let payload = header.payload(reader)?;
-        //     let sendcmpct = SendCmpct::read(&mut Cursor::new(payload))?;
-        //     return Ok(Message::SendCmpct(sendcmpct));
-        // }
+        // Sendcmpct
+        if header.command == commands::SENDCMPCT {
+            let payload = header.payload(reader)?;
+            let sendcmpct = SendCmpct::read(&mut Cursor::new(payload))?;
+            return Ok(Message::SendCmpct(sendcmpct));
+        }
 
         // Sendheaders
         if header.command == commands::SENDHEADERS {
@@ -300,6 +343,14 @@ impl Message {
             return Ok(Message::SendHeaders);
         }
 
+        // SendAddrV2
+        if header.command == commands::SENDADDRV2 {
+            if header.payload_size != 0 {
+                return Err(Error::BadData("Bad payload".to_string()));
+            }
+            return Ok(Message::SendAddrV2);
+        }
+
         // // Tx
         if header.command == commands::TX {
             let payload = header.payload(reader)?;
@@ -335,9 +386,11 @@ impl Message {
     pub fn write(&self, writer: &mut dyn Write, magic: [u8; 4]) -> io::Result<()> {
         use self::commands::*;
         match self {
-            // Message::Addr(p) => write_with_payload(writer, ADDR, p, magic),
+            Message::Addr(p) => write_with_payload(writer, ADDR, p, magic),
+            Message::AddrV2(p) => write_with_payload(writer, ADDRV2, p, magic),
             Message::Block(p) => write_with_payload(writer, BLOCK, p, magic),
             Message::Tx(p) => write_with_payload(writer, TX, p, magic),
+            Message::FeeFilter(p) => write_with_payload(writer, FEEFILTER, p, magic),
             Message::GetAddr => write_without_payload(writer, GETADDR, magic),
             Message::GetBlocks(p) => write_with_payload(writer, GETBLOCKS, p, magic),
             Message::GetData(p) => write_with_payload(writer, GETDATA, p, magic),
@@ -347,6 +400,8 @@ impl Message {
             Message::Headers(p) => write_with_payload(writer, HEADERS, p, magic),
             Message::CFHeaders(p) => write_with_payload(writer, CFHEADERS, p, magic),
             Message::CFilters(p) => write_with_payload(writer, CFILTERS, p, magic),
+            Message::GetCFCheckpt(p) => write_with_payload(writer, GETCFCHECKPT, p, magic),
+            Message::CFCheckpt(p) => write_with_payload(writer, CFCHECKPT, p, magic),
             Message::Mempool => write_without_payload(writer, MEMPOOL, magic),
             Message::NotFound(p) => write_with_payload(writer, NOTFOUND, p, magic),
             Message::Inv(p) => write_with_payload(writer, INV, p, magic),
@@ -358,8 +413,9 @@ impl Message {
             Message::Ping(p) => write_with_payload(writer, PING, p, magic),
             Message::Pong(p) => write_with_payload(writer, PONG, p, magic),
            Message::Reject(p) => write_with_payload(writer, REJECT, p, magic),
+            Message::SendAddrV2 => write_without_payload(writer, SENDADDRV2, magic),
             Message::SendHeaders => write_without_payload(writer, SENDHEADERS, magic),
-        //    Message::SendCmpct(p) => write_with_payload(writer, SENDCMPCT, p, magic),
+            Message::SendCmpct(p) => write_with_payload(writer, SENDCMPCT, p, magic),
         //    Message::Tx(p) => write_with_payload(writer, TX, p, magic),
             Message::Verack => write_without_payload(writer, VERACK, magic),
             Message::Version(v) => write_with_payload(writer, VERSION, v, magic),
@@ -370,9 +426,11 @@ impl Message {
 impl fmt::Debug for Message {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            // Message::Addr(p) => f.write_str(&format!("{:#?}", p)),
+            Message::Addr(p) => f.write_str(&format!("{:#?}", p)),
+            Message::AddrV2(p) => f.write_str(&format!("{:#?}", p)),
             Message::Block(p) => f.write_str(&format!("{:#?}", p)),
             Message::Tx(p) => f.write_str(&format!("{:#?}", p)),
+            Message::FeeFilter(p) => f.write_str(&format!("{:#?}", p)),
             Message::GetAddr => f.write_str("GetAddr"),
             Message::GetBlocks(p) => f
                 .debug_struct("GetBlocks")
@@ -398,6 +456,11 @@ impl fmt::Debug for Message {
            Message::Headers(p) => f.write_str(&format!("{:#?}", p)),
            Message::CFilters(p) => f.write_str(&format!("{:#?}", p)),
            Message::CFHeaders(p) => f.write_str(&format!("{:#?}", p)),
+            Message::GetCFCheckpt(p) => f
+                .debug_struct("GetCFCheckpt")
+                .field("stop_hash", &p.stop_hash)
+                .finish(),
+           Message::CFCheckpt(p) => f.write_str(&format!("{:#?}", p)),
             Message::Inv(p) => f.write_str(&format!("{:#?}", p)),
             Message::Mempool => f.write_str("Mempool"),
             Message::NotFound(p) => f.debug_struct("NotFound").field("inv", &p).finish(),
@@ -406,8 +469,9 @@ impl fmt::Debug for Message {
             Message::Ping(p) => f.write_str(&format!("{:#?}", p)),
             Message::Pong(p) => f.debug_struct("Pong").field("nonce", &p.nonce).finish(),
             Message::Reject(p) => f.write_str(&format!("{:#?}", p)),
+            Message::SendAddrV2 => f.write_str("SendAddrV2"),
             Message::SendHeaders => f.write_str("SendHeaders"),
-         //   Message::SendCmpct(p) => f.write_str(&format!("{:#?}", p)),
+            Message::SendCmpct(p) => f.write_str(&format!("{:#?}", p)),
           //  Message::Tx(p) => f.write_str(&format!("{:#?}", p)),
             Message::Verack => f.write_str("Verack"),
             Message::Version(p) => f.write_str(&format!("{:#?}", p)),
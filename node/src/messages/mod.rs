@@ -1,4 +1,5 @@
-//mod addr;
+pub mod addr;
+pub mod addr_v2;
 pub mod block;
 mod block_header;
 pub mod block_locator;
@@ -9,9 +10,10 @@ pub mod inv;
 pub mod inv_vect;
 mod witness;
 mod message;
-mod message_header;
+pub mod message_header;
 mod node_addr;
-mod node_addr_ex;
+pub mod node_addr_ex;
+pub mod node_addr_v2;
 mod out_point;
 pub mod ping;
 pub mod tx;
@@ -20,6 +22,10 @@ pub mod tx_out;
 mod version;
 pub mod reject;
 pub mod compact_filter_header;
+pub mod checkpoint_locator;
+pub mod compact_filter_checkpoint;
+pub mod fee_filter;
+pub mod send_cmpct;
 
 
 // pub use self::addr::Addr;
@@ -32,7 +38,7 @@ pub use self::inv_vect::{
     InvVect,
 };
 //pub use self::merkle_block::MerkleBlock;
-pub use self::message::{commands, Message, Payload};
+pub use self::message::{commands, Message, Payload, MAX_PAYLOAD_SIZE};
 pub use self::node_addr::NodeAddr;
 pub use self::out_point::{OutPoint, COINBASE_OUTPOINT_HASH, COINBASE_OUTPOINT_INDEX};
 // pub use self::ping::Ping;
@@ -43,5 +49,9 @@ pub use self::out_point::{OutPoint, COINBASE_OUTPOINT_HASH, COINBASE_OUTPOINT_IN
 // pub use self::tx_out::TxOut;
 pub use self::version::{
     Version,
+    NODE_BLOOM,
+    NODE_COMPACT_FILTERS,
+    NODE_NONE,
+    NODE_WITNESS,
     PROTOCOL_VERSION,
 };
@@ -0,0 +1,130 @@
+use crate::messages::node_addr_ex::NodeAddrEx;
+use crate::util::{var_int, Error, Result, Serializable};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
+use std::io;
+use std::io::{Read, Write};
+use std::net::Ipv4Addr;
+
+/// BIP155 network id of IPv4 addresses
+pub const NETWORK_IPV4: u8 = 1;
+
+/// BIP155 network id of IPv6 addresses
+pub const NETWORK_IPV6: u8 = 2;
+
+/// Longest address of any BIP155 network, I2P and TorV3 addresses are 32 bytes
+const MAX_ADDR_LEN: u64 = 512;
+
+/// Node address as gossiped in an addrv2 message (BIP155)
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct NodeAddrV2 {
+    /// Last seen time in seconds since the unix epoch
+    pub time: u32,
+    /// Services flags for the node
+    pub services: u64,
+    /// Network the address belongs to
+    pub network_id: u8,
+    /// Raw address, its length depends on the network
+    pub addr: Vec<u8>,
+    /// Port for Bitcoin P2P communication
+    pub port: u16,
+}
+
+impl NodeAddrV2 {
+    /// Returns the size of the address in bytes
+    pub fn size(&self) -> usize {
+        4 + var_int::size(self.services) + 1 + var_int::size(self.addr.len() as u64) + self.addr.len() + 2
+    }
+
+    /// Returns the address if it is an IPv4 one
+    pub fn ipv4(&self) -> Option<Ipv4Addr> {
+        if self.network_id != NETWORK_IPV4 || self.addr.len() != 4 {
+            return None;
+        }
+        Some(Ipv4Addr::new(self.addr[0], self.addr[1], self.addr[2], self.addr[3]))
+    }
+}
+
+impl From<&NodeAddrEx> for NodeAddrV2 {
+    fn from(addr: &NodeAddrEx) -> Self {
+        let (network_id, bytes) = match addr.addr.ip.to_ipv4_mapped() {
+            Some(ipv4) => (NETWORK_IPV4, ipv4.octets().to_vec()),
+            None => (NETWORK_IPV6, addr.addr.ip.octets().to_vec()),
+        };
+        NodeAddrV2 {
+            time: addr.last_connected_time,
+            services: addr.addr.services,
+            network_id,
+            addr: bytes,
+            port: addr.addr.port,
+        }
+    }
+}
+
+impl Serializable<NodeAddrV2> for NodeAddrV2 {
+    fn read(reader: &mut dyn Read) -> Result<NodeAddrV2> {
+        let time = reader.read_u32::<LittleEndian>()?;
+        let services = var_int::read(reader)?;
+        let network_id = reader.read_u8()?;
+        let addr_len = var_int::read(reader)?;
+        if addr_len > MAX_ADDR_LEN {
+            return Err(Error::BadData(format!("Address too long: {}", addr_len)));
+        }
+        let mut addr = vec![0; addr_len as usize];
+        reader.read_exact(&mut addr)?;
+        let port = reader.read_u16::<BigEndian>()?;
+        Ok(NodeAddrV2 { time, services, network_id, addr, port })
+    }
+
+    fn write(&self, writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_u32::<LittleEndian>(self.time)?;
+        var_int::write(self.services, writer)?;
+        writer.write_u8(self.network_id)?;
+        var_int::write(self.addr.len() as u64, writer)?;
+        writer.write_all(&self.addr)?;
+        writer.write_u16::<BigEndian>(self.port)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::NodeAddr;
+    use std::io::Cursor;
+    use std::net::IpAddr;
+
+    #[test]
+    fn write_read() {
+        let mut v = Vec::new();
+        let a = NodeAddrV2 {
+            time: 12345,
+            services: 1033,
+            network_id: NETWORK_IPV4,
+            addr: vec![10, 0, 0, 1],
+            port: 8333,
+        };
+        a.write(&mut v).unwrap();
+        assert!(v.len() == a.size());
+        assert!(NodeAddrV2::read(&mut Cursor::new(&v)).unwrap() == a);
+        assert!(a.ipv4() == Some(Ipv4Addr::new(10, 0, 0, 1)));
+    }
+
+    #[test]
+    fn from_ipv4_mapped_addr() {
+        let a = NodeAddrEx {
+            last_connected_time: 700,
+            addr: NodeAddr::new(IpAddr::V4(Ipv4Addr::new(67, 205, 179, 161)), 18333),
+        };
+        let v2 = NodeAddrV2::from(&a);
+        assert!(v2.network_id == NETWORK_IPV4);
+        assert!(v2.time == 700);
+        assert!(v2.port == 18333);
+        assert!(v2.ipv4() == Some(Ipv4Addr::new(67, 205, 179, 161)));
+    }
+
+    #[test]
+    fn ignores_other_networks() {
+        let a = NodeAddrV2 { network_id: 4, addr: vec![7; 32], ..Default::default() };
+        assert!(a.ipv4().is_none());
+    }
+}
@@ -15,6 +15,52 @@ pub const REJECT_DUST: u8 = 0x41;
 pub const REJECT_INSUFFICIENT_FEE: u8 = 0x42;
 pub const REJECT_CHECKPOINT: u8 = 0x43;
 
+/// Why a peer refused a message, decoded from the reject code
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RejectCode {
+    Malformed,
+    Invalid,
+    Obsolete,
+    Duplicate,
+    NonStandard,
+    Dust,
+    InsufficientFee,
+    Checkpoint,
+    Unknown(u8),
+}
+
+impl From<u8> for RejectCode {
+    fn from(code: u8) -> Self {
+        match code {
+            REJECT_MALFORMED => RejectCode::Malformed,
+            REJECT_INVALID => RejectCode::Invalid,
+            REJECT_OBSOLETE => RejectCode::Obsolete,
+            REJECT_DUPLICATE => RejectCode::Duplicate,
+            REJECT_NONSTANDARD => RejectCode::NonStandard,
+            REJECT_DUST => RejectCode::Dust,
+            REJECT_INSUFFICIENT_FEE => RejectCode::InsufficientFee,
+            REJECT_CHECKPOINT => RejectCode::Checkpoint,
+            code => RejectCode::Unknown(code),
+        }
+    }
+}
+
+impl fmt::Display for RejectCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RejectCode::Malformed => f.write_str("malformed"),
+            RejectCode::Invalid => f.write_str("invalid"),
+            RejectCode::Obsolete => f.write_str("obsolete"),
+            RejectCode::Duplicate => f.write_str("duplicate"),
+            RejectCode::NonStandard => f.write_str("non-standard"),
+            RejectCode::Dust => f.write_str("dust"),
+            RejectCode::InsufficientFee => f.write_str("insufficient fee"),
+            RejectCode::Checkpoint => f.write_str("checkpoint"),
+            RejectCode::Unknown(code) => f.write_str(&format!("unknown ({:#x})", code)),
+        }
+    }
+}
+
 /// Rejected message
 #[derive(Default, PartialEq, Eq, Hash, Clone)]
 pub struct Reject {
@@ -40,6 +86,10 @@ impl Reject {
         txid.0.clone_from_slice(&self.data);
         Ok(txid)
     }
+
+    pub fn reject_code(&self) -> RejectCode {
+        self.code.into()
+    }
 }
 
 impl Serializable<Reject> for Reject {
@@ -121,6 +171,13 @@ mod tests {
         assert!(reject.txid().is_err());
     }
 
+    #[test]
+    fn reject_code() {
+        let reject = Reject { code: REJECT_INSUFFICIENT_FEE, ..Default::default() };
+        assert!(reject.reject_code() == RejectCode::InsufficientFee);
+        assert!(RejectCode::from(0x99) == RejectCode::Unknown(0x99));
+    }
+
     #[test]
     fn read_bytes() {
         let b = hex::decode("027478104f6d616e6461746f72792d7363726970742d7665726966792d666c61672d6661696c65642028536372697074206661696c656420616e204f505f455155414c564552494659206f7065726174696f6e292f174bfe9e5b6e32ef2fabd164df5469f44977d93e0625238465ded771083993".as_bytes()).unwrap();
@@ -9,7 +9,7 @@ use std::io::{Read, Write};
 pub struct SendCmpct {
     /// Whether compact blocks may be sent
     pub enable: u8,
-    /// Should always be 1
+    /// Compact block version, 1 without segwit and 2 with it
     pub version: u64,
 }
 
@@ -17,9 +17,9 @@ impl SendCmpct {
     /// Size of the SendCmpct payload in bytes
     pub const SIZE: usize = 9;
 
-    /// Returns whether compact blocks should be used
+    /// Returns whether new blocks should be announced with cmpctblock, whatever the version
     pub fn use_cmpctblock(&self) -> bool {
-        self.enable == 1 && self.version == 1
+        self.enable == 1
     }
 }
 
@@ -54,6 +54,7 @@ mod tests {
         let f = SendCmpct::read(&mut Cursor::new(&b)).unwrap();
         assert!(f.enable == 0);
         assert!(f.version == 1);
+        assert!(!f.use_cmpctblock());
     }
 
     #[test]
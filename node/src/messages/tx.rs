@@ -39,6 +39,11 @@ impl Tx {
         sha256d(&b)
     }
 
+    /// Calculates the txid, leaving out the witnesses that `hash` covers when present
+    pub fn txid(&self) -> Hash256 {
+        Tx { flag: None, witnesses: None, ..self.clone() }.hash()
+    }
+
     // /// Validates a non-coinbase transaction
     // pub fn validate(
     //     &self,
@@ -312,6 +317,28 @@ mod tests {
     use crate::util::Hash256;
     use std::io::Cursor;
 
+    #[test]
+    fn txid_ignores_witnesses() {
+        use bitcoin::hashes::Hash;
+
+        let tx = bitcoin::Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![bitcoin::TxIn {
+                previous_output: bitcoin::OutPoint { txid: bitcoin::Txid::from_byte_array([1; 32]), vout: 0 },
+                script_sig: bitcoin::ScriptBuf::new(),
+                sequence: bitcoin::Sequence::MAX,
+                witness: bitcoin::Witness::from_slice(&[vec![2; 71], vec![3; 33]]),
+            }],
+            output: vec![bitcoin::TxOut { value: bitcoin::Amount::from_sat(1000), script_pubkey: bitcoin::ScriptBuf::from_bytes(vec![0; 22]) }],
+        };
+        let bytes = bitcoin::consensus::encode::serialize(&tx);
+        let parsed = Tx::read(&mut Cursor::new(&bytes)).unwrap();
+
+        assert!(parsed.txid().0 == tx.compute_txid().to_byte_array());
+        assert!(parsed.hash().0 == tx.compute_wtxid().to_byte_array());
+    }
+
     // #[test]
     // fn write_read() {
     //     let mut v = Vec::new();
@@ -24,6 +24,15 @@ pub const NODE_NETWORK: u64 = 1;
 /// Service flag that node is a full node and implements all protocol features
 pub const NODE_BITCOIN_CASH: u64 = 1 << 5;
 
+/// Service flag that node answers bloom filter and mempool requests (BIP111)
+pub const NODE_BLOOM: u64 = 1 << 2;
+
+/// Service flag that node can serve blocks and transactions with witness data (BIP144)
+pub const NODE_WITNESS: u64 = 1 << 3;
+
+/// Service flag that node serves compact block filters (BIP157)
+pub const NODE_COMPACT_FILTERS: u64 = 1 << 6;
+
 /// Version payload defining a node's capabilities
 #[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
 pub struct Version {
@@ -48,6 +57,11 @@ pub struct Version {
 }
 
 impl Version {
+    /// Checks if the node advertises every service flag in `services`
+    pub fn has_services(&self, services: u64) -> bool {
+        self.services & services == services
+    }
+
     /// Checks if the version message is valid
     pub fn validate(&self) -> Result<()> {
         if self.version < MIN_SUPPORTED_PROTOCOL_VERSION {
@@ -119,6 +133,8 @@ mod tests {
         let v = Version::read(&mut Cursor::new(&b)).unwrap();
         assert!(v.version == 70015);
         assert!(v.services == 37);
+        assert!(v.has_services(NODE_NETWORK));
+        assert!(!v.has_services(NODE_NETWORK | NODE_COMPACT_FILTERS));
         assert!(v.timestamp == 1523766002);
         assert!(v.recv_addr.services == 0);
         assert!(
@@ -1,23 +1,28 @@
 use std::io::Cursor;
+use std::str::FromStr;
 use std::sync::Arc;
 use bitcoin::network as bitcoin_network;
+use bitcoin::consensus::deserialize;
+use bitcoin::{bip32::Xpriv, Psbt, Transaction};
 
 use crate::bindings::component::kv::types::Kvstore ;
-use crate::bindings::component::wallet::types::{WatchOnly, Initialization, Config as WalletConfig, BitcoinNetwork as WalletBitcoinNetwork };
-use crate::bindings::component::signer::types::{SimpleSigner, Initialization as SignerInitialization, Config as SignerConfig };
+use crate::bindings::component::wallet::types::{Account, Balance, Descriptors, WatchOnly, Initialization, Config as WalletConfig, BitcoinNetwork as WalletBitcoinNetwork, Keys, Outpoint as WalletOutpoint, Recipient as WalletRecipient, UnspentUtxo, WalletType };
+use crate::bindings::component::signer::types::{SimpleSigner, Initialization as SignerInitialization, Config as SignerConfig, Network as SignerNetwork, WalletType as SignerWalletType };
 use crate::messages::tx::Tx;
-use crate::util::network_const::genesis_block_hash_from_network;
+use crate::util::network_const::genesis_block_header_from_network;
 
 use serde::Serialize;
 
-use crate::chain::CompactChain;
+use crate::chain::{CompactChain, WalletBirthday};
+use crate::history::TransactionRecord;
+use crate::payment::{parse_address, PaymentRequest};
 use crate::db::{KeyValueDb, CHAIN_STATE_KEY, NODE_STATE_KEY, SIGNER_STATE_KEY, WALLET_STATE_KEY};
-use crate::util::{Error, Serializable};
+use crate::util::{Error, Hash256, Serializable};
 
 
 
 
-#[derive(serde::Deserialize, Serialize, Clone)]
+#[derive(serde::Deserialize, Serialize, Clone, PartialEq, Debug)]
 pub struct CustomIPV4SocketAddress {
     pub ip: (u8,u8,u8,u8),
     pub port: u16
@@ -26,8 +31,23 @@ pub struct CustomIPV4SocketAddress {
 #[derive(Clone)]
 pub struct NodeConfig {
     pub socket_address: CustomIPV4SocketAddress,
+    pub peers: Vec<CustomIPV4SocketAddress>,
+    pub max_connections: usize,
+    pub use_seeds: bool,
+    pub birthday: Option<WalletBirthday>,
+    pub lookahead: Option<u32>,
+    pub min_confirmations: Option<u32>,
     pub network: bitcoin_network::Network,
-    pub xpriv: String,
+    pub wallet_type: WalletType,
+    pub keys: NodeKeys,
+}
+
+/// Keys behind the node's wallet, only an xpriv lets the node sign
+#[derive(Clone)]
+pub enum NodeKeys {
+    Xpriv(String),
+    Account { xpub: String, account_derivation: String, master_fingerprint: String },
+    Descriptors { external: String, internal: Option<String> },
 }
 
 
@@ -35,7 +55,7 @@ pub struct NodeConfig {
 pub struct Node {
     chain: CompactChain,
     wallet: Arc<WatchOnly>,
-    signer: Arc<SimpleSigner>,
+    signer: Option<Arc<SimpleSigner>>,
     node_state: NodeState,
     db: Arc<KeyValueDb>
 
@@ -44,7 +64,25 @@ pub struct Node {
 #[derive(serde::Deserialize, Serialize, Clone)]
 pub struct NodeState {
     socket_address: CustomIPV4SocketAddress,
-    network: bitcoin_network::Network
+    peers: Vec<CustomIPV4SocketAddress>,
+    max_connections: usize,
+    use_seeds: bool,
+    birthday: Option<WalletBirthday>,
+    network: bitcoin_network::Network,
+    watch_only: bool,
+}
+
+impl NodeState {
+    /// Configured peers, the preferred one first
+    fn peer_addresses(&self) -> Vec<CustomIPV4SocketAddress> {
+        let mut addresses = vec![self.socket_address.clone()];
+        for peer in self.peers.iter() {
+            if !addresses.contains(peer) {
+                addresses.push(peer.clone());
+            }
+        }
+        addresses
+    }
 }
 
 impl Into<WalletBitcoinNetwork> for bitcoin_network::Network {
@@ -62,28 +100,63 @@ impl Into<WalletBitcoinNetwork> for bitcoin_network::Network {
     }
 }
 
+impl Into<SignerNetwork> for bitcoin_network::Network {
+    fn into(self) -> SignerNetwork {
+        match self {
+            bitcoin::Network::Bitcoin => SignerNetwork::Bitcoin,
+            bitcoin::Network::Testnet => SignerNetwork::Testnet,
+            bitcoin::Network::Testnet4 => SignerNetwork::Testnet4,
+            bitcoin::Network::Signet => SignerNetwork::Signet,
+            bitcoin::Network::Regtest => SignerNetwork::Regtest,
+            _ => SignerNetwork::Bitcoin,
+        }
+    }
+}
+
+/// Wallet outpoint of a hex txid and output index
+fn wallet_outpoint((txid, vout): &(String, u32)) -> Result<WalletOutpoint, Error> {
+    Ok(WalletOutpoint { txid: Hash256::decode(txid)?.0.to_vec(), vout: *vout })
+}
+
 
 impl Node {
 
-    pub fn new(node_config: NodeConfig) -> Self {
+    pub fn new(node_config: NodeConfig) -> Result<Self, Error> {
         let store  = Kvstore::new();
         let db = Arc::new(KeyValueDb::new(store.into()));
 
-        // Initialize P2WPKH Signer and Watch Only Wallet
-        let signer = Arc::new(SimpleSigner::new(&SignerInitialization::Config(SignerConfig { xpiv: node_config.xpriv})));
-        let  ( xpub, master_fingerprint, account_derivation )= signer.derive_account().unwrap();
+        // Initialize the Signer when the node holds the private key, and the Watch Only Wallet
+        let (signer, keys) = match node_config.keys {
+            NodeKeys::Xpriv(xpriv) => {
+                // The signer traps on an xpriv it cannot parse
+                Xpriv::from_str(&xpriv).map_err(|err| Error::InvalidDescriptor(format!("xpriv: {}", err)))?;
+                let signer = Arc::new(SimpleSigner::new(&SignerInitialization::Config(SignerConfig { xpiv: xpriv })));
+                let signer_wallet_type = match node_config.wallet_type {
+                    WalletType::P2wpkh => SignerWalletType::P2wpkh,
+                    WalletType::P2tr => SignerWalletType::P2tr,
+                };
+                let  ( xpub, master_fingerprint, account_derivation )= signer.derive_account(signer_wallet_type, node_config.network.into())?;
+                (Some(signer), Keys::Account(Account { xpub, account_derivation, master_fingerprint, wallet_type: Some(node_config.wallet_type) }))
+            },
+            NodeKeys::Account { xpub, account_derivation, master_fingerprint } => {
+                (None, Keys::Account(Account { xpub, account_derivation, master_fingerprint, wallet_type: Some(node_config.wallet_type) }))
+            },
+            NodeKeys::Descriptors { external, internal } => (None, Keys::Descriptors(Descriptors { external, internal })),
+        };
         let wallet_config = WalletConfig {
-            xpub,
-            account_derivation,
-            master_fingerprint,
+            keys,
             network: node_config.network.into(), 
+            lookahead: node_config.lookahead,
+            change_lookahead: None,
+            min_confirmations: node_config.min_confirmations,
         };
 
-        let wallet = Arc::new(WatchOnly::new(&Initialization::Config(wallet_config)));
+        let wallet = Arc::new(WatchOnly::create(&Initialization::Config(wallet_config)).map_err(|e| Error::from_wallet(e, 8))?);
          
-        let chain = CompactChain::new(node_config.socket_address.clone(), node_config.network, genesis_block_hash_from_network(node_config.network), wallet.clone());
+        let node_state = NodeState{ socket_address: node_config.socket_address, peers: node_config.peers, max_connections: node_config.max_connections, use_seeds: node_config.use_seeds, birthday: node_config.birthday, network: node_config.network, watch_only: signer.is_none() };
+        let chain = CompactChain::new(node_state.peer_addresses(), node_state.max_connections, node_state.use_seeds, node_config.network, genesis_block_header_from_network(node_config.network), node_state.birthday, wallet.clone(), db.clone());
 
-        Self { chain, wallet, node_state: NodeState{ socket_address: node_config.socket_address, network: node_config.network }, db: db.clone(), signer }
+        Ok(Self { chain, wallet, node_state, db: db.clone(), signer })
 
     }
 
@@ -94,14 +167,18 @@ impl Node {
         let wallet_state = db.get(WALLET_STATE_KEY.to_string()).expect("cannot retrieve old wallet state");
         let wallet = Arc::new(WatchOnly::new(&Initialization::OldState(wallet_state)));
 
-        let signer_state = db.get(SIGNER_STATE_KEY.to_string()).expect("cannot retrieve old signer state");
-        let signer = Arc::new(SimpleSigner::new(&SignerInitialization::OldState(signer_state)));
-
         let serialized_node_state = db.get(NODE_STATE_KEY.to_string()).expect("cannot retrieve node state");
         let node_state: NodeState = bincode::deserialize(&serialized_node_state).unwrap();
 
+        let signer = if node_state.watch_only {
+            None
+        } else {
+            let signer_state = db.get(SIGNER_STATE_KEY.to_string()).expect("cannot retrieve old signer state");
+            Some(Arc::new(SimpleSigner::new(&SignerInitialization::OldState(signer_state))))
+        };
+
         let chain_state = db.get(CHAIN_STATE_KEY.to_string()).expect("cannot retrieve old chain state");
-        let chain = CompactChain::restore(node_state.socket_address.clone(), node_state.network, wallet.clone(), chain_state);
+        let chain = CompactChain::restore(node_state.peer_addresses(), node_state.max_connections, node_state.use_seeds, node_state.network, node_state.birthday, wallet.clone(), chain_state, db.clone());
 
         Self {  chain, wallet, node_state, db, signer }
 
@@ -116,6 +193,22 @@ impl Node {
 
     }
 
+    pub fn pending_balance(&mut self) -> Result<u64, Error> {
+        self.chain.sync_state()?;
+
+        self.store_state();
+
+        return self.wallet.pending_balance().map_err(|_| Error::WalletError(3));
+    }
+
+    pub fn balance_breakdown(&mut self) -> Result<Balance, Error> {
+        self.chain.sync_state()?;
+
+        self.store_state();
+
+        return self.wallet.balance_breakdown().map_err(|_| Error::WalletError(3));
+    }
+
     pub fn get_receive_address(&mut self) -> Result<String, Error> {
         let address =  self.wallet.get_receive_address().map_err(|_| Error::WalletError(4))?;
 
@@ -124,16 +217,80 @@ impl Node {
         Ok(address)
     }
 
-    pub fn send_to_address(& mut self, recepient: &[u8], amount: u64, fee_rate: u64) -> Result<(), Error> {
+    pub fn send_to_address(& mut self, address: &str, amount: u64, fee_rate: u64) -> Result<(), Error> {
+        self.send_many(&[(address.to_string(), amount)], fee_rate, &[])
+    }
+
+    /// Pays every `(address, amount)` from one transaction, spending every `must_spend` output
+    pub fn send_many(& mut self, recipients: &[(String, u64)], fee_rate: u64, must_spend: &[(String, u32)]) -> Result<(), Error> {
+        let signer = self.signer.clone().ok_or(Error::InvalidOperation("watch-only node cannot sign, use create-psbt".to_string()))?;
+        let recipients = recipients.iter()
+            .map(|(address, amount)| Ok(WalletRecipient { script: parse_address(address, self.node_state.network)?.script_pubkey().to_bytes(), amount: *amount }))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let must_spend = must_spend.iter().map(wallet_outpoint).collect::<Result<Vec<_>, Error>>()?;
         self.chain.sync_state()?;
     
-        let transaction = self.wallet.create_transaction(recepient, amount, fee_rate).unwrap();
-        let signed_transaction = self.signer.sign_psbt(&transaction).unwrap();
-        let finalised_transaction = self.wallet.finalise_transaction(&signed_transaction).unwrap();
+        let transaction = self.wallet.create_batch_transaction(&recipients, fee_rate, &must_spend, None).map_err(|e| Error::from_wallet(e, 5))?;
+        self.sign_and_send(&signer, &transaction, fee_rate)
+    }
+
+    /// Empties the wallet, or only `outpoints`, to `address` with the fee taken from the amount sent
+    pub fn drain_to_address(& mut self, address: &str, fee_rate: u64, outpoints: Option<Vec<(String, u32)>>) -> Result<(), Error> {
+        let signer = self.signer.clone().ok_or(Error::InvalidOperation("watch-only node cannot sign, use create-drain-psbt".to_string()))?;
+
+        let transaction = self.create_drain_psbt(address, fee_rate, outpoints)?;
+        self.sign_and_send(&signer, &transaction, fee_rate)
+    }
+
+    /// Replaces our unconfirmed send `txid` by one paying `fee_rate`, returning the new txid
+    pub fn bump_fee(& mut self, txid: &str, fee_rate: u64) -> Result<String, Error> {
+        let signer = self.signer.clone().ok_or(Error::InvalidOperation("watch-only node cannot sign".to_string()))?;
+        let txid = Hash256::decode(txid)?;
+        self.chain.sync_state()?;
+
+        let original = self.chain.replaceable_transaction(&txid)
+            .ok_or(Error::BadArgument(format!("{} is not an unconfirmed transaction sent by the wallet", txid.encode())))?;
+        let transaction = self.wallet.create_bump_fee_transaction(&original, fee_rate).map_err(|e| Error::from_wallet(e, 5))?;
+        let signed_transaction = signer.sign_psbt(&transaction)?;
+        let finalised_transaction = self.wallet.finalise_transaction(&signed_transaction).map_err(|_| Error::WalletError(6))?;
+        let replacement = Tx::read(&mut Cursor::new(finalised_transaction))?;
+        let replacement_txid = replacement.txid().encode();
+
+        self.chain.replace_transaction(&txid, replacement, fee_rate)?;
+
+        self.store_state();
+
+        Ok(replacement_txid)
+    }
+
+    /// Accelerates the unconfirmed payment `txid` by spending our output of it back to the wallet,
+    /// so both pay `fee_rate` together. Returns the txid of the child.
+    pub fn cpfp(& mut self, txid: &str, fee_rate: u64) -> Result<String, Error> {
+        let signer = self.signer.clone().ok_or(Error::InvalidOperation("watch-only node cannot sign".to_string()))?;
+        let txid = Hash256::decode(txid)?;
+        self.chain.sync_state()?;
+
+        let parent = self.chain.unconfirmed_transaction(&txid)?;
+        let transaction = self.wallet.create_cpfp_transaction(&parent, fee_rate).map_err(|e| Error::from_wallet(e, 5))?;
+        let signed_transaction = signer.sign_psbt(&transaction)?;
+        let finalised_transaction = self.wallet.finalise_transaction(&signed_transaction).map_err(|_| Error::WalletError(6))?;
+        let child = Tx::read(&mut Cursor::new(finalised_transaction))?;
+        let child_txid = child.txid().encode();
+
+        self.chain.send_transaction(child, fee_rate)?;
+
+        self.store_state();
+
+        Ok(child_txid)
+    }
+
+    fn sign_and_send(& mut self, signer: &SimpleSigner, transaction: &[u8], fee_rate: u64) -> Result<(), Error> {
+        let signed_transaction = signer.sign_psbt(transaction)?;
+        let finalised_transaction = self.wallet.finalise_transaction(&signed_transaction).map_err(|_| Error::WalletError(6))?;
         let mut cursor_transaction = Cursor::new(finalised_transaction);
-        let deserialised_transaction = Tx::read(&mut cursor_transaction).unwrap();
+        let deserialised_transaction = Tx::read(&mut cursor_transaction)?;
 
-        self.chain.send_transaction(deserialised_transaction).unwrap();
+        self.chain.send_transaction(deserialised_transaction, fee_rate)?;
 
         self.store_state();
 
@@ -141,6 +298,117 @@ impl Node {
 
     }
 
+    /// Pays the amount a BIP21 URI asks for, failing when it names none
+    pub fn send_to_uri(&mut self, uri: &str, fee_rate: u64) -> Result<(), Error> {
+        let request = PaymentRequest::parse(uri, self.node_state.network)?;
+        let amount = request.amount.ok_or(Error::InvalidPaymentUri("no amount".to_string()))?;
+
+        self.send_to_address(&request.address.to_string(), amount.to_sat(), fee_rate)
+    }
+
+    pub fn parse_payment_uri(&self, uri: &str) -> Result<PaymentRequest, Error> {
+        PaymentRequest::parse(uri, self.node_state.network)
+    }
+
+    pub fn create_psbt(&mut self, address: &str, amount: u64, fee_rate: u64) -> Result<Vec<u8>, Error> {
+        let recepient = parse_address(address, self.node_state.network)?.script_pubkey();
+        self.chain.sync_state()?;
+
+        let psbt = self.wallet.create_transaction(recepient.as_bytes(), amount, fee_rate, None).map_err(|e| Error::from_wallet(e, 5))?;
+
+        self.store_state();
+
+        Ok(psbt)
+    }
+
+    /// Unsigned psbt sending every confirmed output, or only `outpoints`, to `address`
+    pub fn create_drain_psbt(&mut self, address: &str, fee_rate: u64, outpoints: Option<Vec<(String, u32)>>) -> Result<Vec<u8>, Error> {
+        let destination = parse_address(address, self.node_state.network)?.script_pubkey();
+        let outpoints = match outpoints {
+            Some(outpoints) => Some(outpoints.iter().map(wallet_outpoint).collect::<Result<Vec<_>, Error>>()?),
+            None => None,
+        };
+        self.chain.sync_state()?;
+
+        let psbt = self.wallet.create_drain_transaction(destination.as_bytes(), fee_rate, outpoints.as_deref(), None).map_err(|e| Error::from_wallet(e, 5))?;
+
+        self.store_state();
+
+        Ok(psbt)
+    }
+
+    /// Unspent wallet outputs, oldest first
+    pub fn list_unspent(&mut self) -> Result<Vec<UnspentUtxo>, Error> {
+        self.chain.sync_state()?;
+
+        self.store_state();
+
+        Ok(self.wallet.list_unspent())
+    }
+
+    /// Keeps sends from spending the output until it is unfrozen
+    pub fn freeze_utxo(&mut self, outpoint: &(String, u32)) -> Result<(), Error> {
+        self.wallet.freeze_utxo(&wallet_outpoint(outpoint)?).map_err(|e| Error::from_wallet(e, 7))?;
+
+        self.store_state();
+
+        Ok(())
+    }
+
+    pub fn unfreeze_utxo(&mut self, outpoint: &(String, u32)) -> Result<(), Error> {
+        self.wallet.unfreeze_utxo(&wallet_outpoint(outpoint)?).map_err(|e| Error::from_wallet(e, 7))?;
+
+        self.store_state();
+
+        Ok(())
+    }
+
+    /// Finalises a psbt signed elsewhere and broadcasts it at the fee rate it pays
+    pub fn broadcast_psbt(&mut self, psbt: &[u8]) -> Result<String, Error> {
+        let fee = Psbt::deserialize(psbt).map_err(|err| Error::BadData(err.to_string()))?
+            .fee().map_err(|err| Error::BadData(err.to_string()))?;
+        let finalised_transaction = self.wallet.finalise_transaction(psbt).map_err(|_| Error::WalletError(6))?;
+        let vsize = deserialize::<Transaction>(&finalised_transaction).map_err(|err| Error::BadData(err.to_string()))?.vsize() as u64;
+        let fee_rate = fee.to_sat() / vsize;
+
+        self.chain.sync_state()?;
+
+        let transaction = Tx::read(&mut Cursor::new(finalised_transaction))?;
+        let txid = transaction.txid().encode();
+        self.chain.send_transaction(transaction, fee_rate)?;
+
+        self.store_state();
+
+        Ok(txid)
+    }
+
+    pub fn rescan(&mut self, from_height: u64) -> Result<(), Error> {
+        self.chain.rescan(from_height)?;
+        self.node_state.birthday = self.chain.birthday();
+        self.chain.sync_state()?;
+
+        self.store_state();
+
+        Ok(())
+    }
+
+    pub fn list_transactions(&mut self, offset: usize, limit: usize) -> Result<Vec<TransactionRecord>, Error> {
+        self.chain.sync_state()?;
+
+        self.store_state();
+
+        Ok(self.chain.history().list(offset, limit).into_iter().cloned().collect())
+    }
+
+    pub fn get_transaction(&mut self, txid: &str) -> Result<Option<TransactionRecord>, Error> {
+        let txid = Hash256::decode(txid)?;
+        self.chain.sync_state()?;
+
+        self.store_state();
+
+        Ok(self.chain.history().get(&txid).cloned())
+    }
+
     fn store_state(& mut self) {
         let chain_state = self.chain.get_state();
         let encoded_chain_state = bincode::serialize(&chain_state).unwrap();
@@ -149,8 +417,10 @@ impl Node {
         let wallet_state = self.wallet.get_state();
         self.db.insert(WALLET_STATE_KEY.to_string(), wallet_state).unwrap();
 
-        let signer_state = self.signer.get_state();
-        self.db.insert(SIGNER_STATE_KEY.to_string(), signer_state).unwrap();
+        if let Some(signer) = &self.signer {
+            let signer_state = signer.get_state();
+            self.db.insert(SIGNER_STATE_KEY.to_string(), signer_state).unwrap();
+        }
 
         let node_state = self.node_state.clone();
         let encoded_node_state = bincode::serialize(&node_state).unwrap();
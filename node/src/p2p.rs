@@ -1,30 +1,64 @@
-use std::{net::Ipv4Addr, sync::atomic::AtomicUsize};
+use std::{net::Ipv4Addr, sync::{atomic::AtomicUsize, Arc}};
 
-use wasi::{clocks::{monotonic_clock, wall_clock}, random::random, sockets::{instance_network, network::{self, Ipv4SocketAddress}, tcp::{InputStream, IpSocketAddress, OutputStream}, tcp_create_socket::create_tcp_socket}};
+use wasi::{clocks::{monotonic_clock, wall_clock}, io::poll, random::random, sockets::{instance_network, ip_name_lookup, network::{self, ErrorCode, IpAddress, Ipv4SocketAddress}, tcp::{InputStream, IpSocketAddress, OutputStream}, tcp_create_socket::create_tcp_socket}};
 use bitcoin::{
     network as bitcoin_network, Network
 };
-use crate::{messages::{self, block::Block, block_locator::{BlockLocator, NO_HASH_STOP }, commands::{self, PONG}, compact_filter::CompactFilter, compact_filter_header::CompactFilterHeader, filter_locator::FilterLocator, tx::Tx, BlockHeader, Inv, InvVect, Message, NodeAddr, Version, PROTOCOL_VERSION}, util::{network_const::magic_from_network, Hash256}};
+use crate::{address_book::{AddressBook, AddressSource}, db::KeyValueDb, dispatcher::Dispatcher, messages::{self, block::Block, block_locator::{BlockLocator, NO_HASH_STOP }, checkpoint_locator::CheckpointLocator, commands::{self, PONG}, compact_filter::CompactFilter, compact_filter_checkpoint::CompactFilterCheckpoint, compact_filter_header::CompactFilterHeader, filter_locator::FilterLocator, node_addr_v2::NodeAddrV2, reject::{Reject, RejectCode}, tx::Tx, BlockHeader, Inv, InvVect, Message, NodeAddr, Version, NODE_BLOOM, NODE_COMPACT_FILTERS, NODE_NONE, NODE_WITNESS, PROTOCOL_VERSION}, util::{network_const::{default_port_from_network, dns_seeds_from_network, magic_from_network}, Hash256}};
 use crate::node::CustomIPV4SocketAddress;
 use crate::tcpsocket::WasiTcpSocket;
 use core::sync::atomic::Ordering;
 use crate::messages::Message::Ping;
 use crate::util::{Error, Result};
 
-const MAX_PROTOCOL_VERSION: u32 = 70015;
+const MAX_PROTOCOL_VERSION: u32 = 70016;
+
+/// First protocol version expected to understand sendaddrv2 (BIP155)
+const ADDRV2_PROTOCOL_VERSION: u32 = 70016;
+
 const USER_AGENT: &str = concat!("/BITSPEND_CLIENT:", env!("CARGO_PKG_VERSION"), '/');
 
+/// Time a peer has to answer a request, in nanoseconds
+const RESPONSE_TIMEOUT_NANOS: u64 = 10_000_000_000;
+
+/// Bytes read from a connection at once
+const READ_CHUNK_SIZE: u64 = 64 * 1024;
+
+/// Services a peer must advertise, filters are fetched with getcfilters and blocks with witnesses
+pub const REQUIRED_SERVICES: u64 = NODE_COMPACT_FILTERS | NODE_WITNESS;
+
+/// Identifies a peer for as long as it stays connected
+pub type PeerId = u64;
+
+/// What the remote advertised in its version message, kept for diagnostics
+#[derive(Clone, Debug, Default)]
+pub struct PeerInfo {
+    pub version: u32,
+    pub services: u64,
+    pub user_agent: String,
+    /// Best block height when the connection was opened
+    pub start_height: i32,
+}
+
 pub struct Peer {
+    id: PeerId,
+    socket_address: CustomIPV4SocketAddress,
+    // Owns the streams below, it must outlive them
+    _socket: WasiTcpSocket,
+    // Failed or slow requests in a row
+    strikes: u32,
     input_stream: InputStream,
     output_stream: OutputStream,
     remote_address: NodeAddr,
+    info: PeerInfo,
+    dispatcher: Dispatcher,
     bitcoin_config: BitcoinP2PConfig,
     magic: [u8; 4],
 }
 
 impl Peer {
       
-    pub fn new(network: bitcoin_network::Network, input_stream: InputStream, output_stream: OutputStream, remote_address: NodeAddr) -> Self {
+    pub fn new(id: PeerId, network: bitcoin_network::Network, socket: WasiTcpSocket, input_stream: InputStream, output_stream: OutputStream, remote_address: NodeAddr, socket_address: CustomIPV4SocketAddress) -> Result<Self> {
       let bitcoin_config = BitcoinP2PConfig {
          network,
          nonce: random::get_random_u64(),
@@ -32,19 +66,20 @@ impl Peer {
          user_agent: USER_AGENT.to_owned(),
          height: AtomicUsize::new(0),
       };
-      let mut peer =  Self { input_stream, output_stream, remote_address, bitcoin_config, magic: magic_from_network(network)};
-      peer.handshake().unwrap();
-      peer
+      let mut peer =  Self { id, socket_address, _socket: socket, strikes: 0, input_stream, output_stream, remote_address, info: PeerInfo::default(), dispatcher: Dispatcher::new(magic_from_network(network)), bitcoin_config, magic: magic_from_network(network)};
+      peer.handshake()?;
+      Ok(peer)
     }
 
     fn version (&self) -> Message {
       // now in unix time
       let timestamp =  wall_clock::now().seconds;
-      let services = 0;
+      // Light client, nothing to serve
+      let services = NODE_NONE;
       // build message
       Message::Version(Version {
           version:  self.bitcoin_config.max_protocol_version,
-          services: services as u64,
+          services,
           timestamp: timestamp as i64,
           recv_addr: self.remote_address.clone(),
           // sender is only dummy
@@ -52,7 +87,8 @@ impl Peer {
           nonce: self.bitcoin_config.nonce,
           user_agent: self.bitcoin_config.user_agent.clone(),
           start_height: self.bitcoin_config.height.load(Ordering::Relaxed) as i32,
-          relay: false,
+          // Transaction announcements are how incoming payments are seen before they are mined
+          relay: true,
       })
 
     }
@@ -62,7 +98,22 @@ impl Peer {
         self.send(version_message)?;
         let res = self.receive(commands::VERSION)?;
 
-        if let Message::Version(_) = res {
+        if let Message::Version(remote_version) = res {
+            self.info = PeerInfo {
+                version: remote_version.version,
+                services: remote_version.services,
+                user_agent: remote_version.user_agent.clone(),
+                start_height: remote_version.start_height,
+            };
+            if !remote_version.has_services(REQUIRED_SERVICES) {
+                return Err(Error::MissingPeerServices(remote_version.services));
+            }
+
+            // Must come before our verack
+            if remote_version.version >= ADDRV2_PROTOCOL_VERSION {
+                self.send(Message::SendAddrV2)?;
+            }
+
             let res = self.receive(commands::VERACK)?;
 
             if let Message::Verack = res {
@@ -74,7 +125,7 @@ impl Peer {
                 self.send(ping_message)?;
                 self.receive(PONG)?;
 
-                println!("handshake complete");
+                println!("handshake complete with {} (version {}, services {:#x}) at height {}", self.info.user_agent, self.info.version, self.info.services, self.info.start_height);
 
                 return Ok(());
             }
@@ -82,8 +133,8 @@ impl Peer {
         Err(Error::WrongP2PMessage)
       }
 
-      pub fn fetch_headers(& mut self, last_known_blockhash: Hash256) -> Result<Vec<BlockHeader>> {
-            let block_locator = BlockLocator{ version: PROTOCOL_VERSION, block_locator_hashes: vec![last_known_blockhash], hash_stop:  NO_HASH_STOP};
+      pub fn fetch_headers(& mut self, locator_hashes: Vec<Hash256>) -> Result<Vec<BlockHeader>> {
+            let block_locator = BlockLocator{ version: PROTOCOL_VERSION, block_locator_hashes: locator_hashes, hash_stop:  NO_HASH_STOP};
             self.send(Message::GetHeaders(block_locator))?;
 
             if let Message::Headers(headers) =  self.receive(commands::HEADERS)?{
@@ -119,6 +170,27 @@ impl Peer {
         Err(Error::WrongP2PMessage)
     }
 
+    pub fn fetch_compact_filter_checkpoints(& mut self, stop_hash: Hash256) -> Result<CompactFilterCheckpoint> {
+        let checkpoint_locator = CheckpointLocator { filter_type: 0, stop_hash };
+        self.send(Message::GetCFCheckpt(checkpoint_locator))?;
+
+        if let Message::CFCheckpt(checkpoints) = self.receive(commands::CFCHECKPT)? {
+            return Ok(checkpoints);
+        }
+        Err(Error::WrongP2PMessage)
+    }
+
+    /// Asks the peer for addresses it knows, the reply may come as addr or addrv2
+    pub fn fetch_addresses(& mut self) -> Result<Vec<NodeAddrV2>> {
+        self.send(Message::GetAddr)?;
+
+        match self.receive_one_of(&[commands::ADDR, commands::ADDRV2])? {
+            Message::Addr(addr) => Ok(addr.addrs.iter().map(NodeAddrV2::from).collect()),
+            Message::AddrV2(addr) => Ok(addr.addrs),
+            _ => Err(Error::WrongP2PMessage),
+        }
+    }
+
       pub fn keep_alive(& mut self) -> Result<()> {
             let nonce = random::get_random_u64();
             let ping_message = Ping(messages::ping::Ping { nonce });
@@ -134,13 +206,42 @@ impl Peer {
             }
       }
 
+    /// Fetches announced transactions, the ones the peer no longer has are left out
+    pub fn fetch_transactions(& mut self, inv: Inv) -> Result<Vec<Tx>> {
+        let mut requested: Vec<Hash256> = inv.objects.iter().map(|object| object.hash).collect();
+        let mut transactions = Vec::new();
+        self.send(Message::GetData(inv))?;
+
+        while !requested.is_empty() {
+            match self.receive_data(commands::TX, &requested) {
+                Ok(Message::Tx(transaction)) => {
+                    let txid = transaction.txid();
+                    requested.retain(|hash| *hash != txid);
+                    transactions.push(transaction);
+                },
+                // Mined or evicted since they were announced
+                Ok(Message::NotFound(not_found)) => requested.retain(|hash| !not_found.objects.iter().any(|object| object.hash == *hash)),
+                Ok(_) => return Err(Error::WrongP2PMessage),
+                Err(Error::Timeout) => break,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(transactions)
+    }
+
+    /// Asks the peer to announce its whole mempool, only answered by peers offering NODE_BLOOM
+    pub fn request_mempool(& mut self) -> Result<()> {
+        self.send(Message::Mempool)
+    }
+
       pub fn fetch_blocks(& mut self, inv: Inv) -> Result<Vec<Block>> {
         let mut blocks = Vec::new();
         let data_len = inv.objects.len();
+        let requested: Vec<Hash256> = inv.objects.iter().map(|object| object.hash).collect();
         self.send(Message::GetData(inv))?;
 
         loop {
-            if let Message::Block(block) =  self.receive(commands::BLOCK)?{
+            if let Message::Block(block) =  self.receive_data(commands::BLOCK, &requested)?{
                 blocks.push(block.clone());
                 if blocks.len() == data_len {
                     return Ok(blocks);
@@ -151,25 +252,25 @@ impl Peer {
         }
     }
 
-    pub fn send_transaction(&mut self, transaction: Tx) -> Result<()> {
-        let txn_hash = transaction.clone().hash();
-        let inv = Inv { objects: vec![ InvVect { obj_type: 1, hash: txn_hash }] };
-
-        self.send(Message::Inv(inv))?;
-        match self.receive(commands::GETDATA)? {
-            Message::GetData(inv) => {
-                
-                let inv_obect = inv.objects.first().unwrap();
-                if inv_obect.hash == txn_hash {
-                    let transaction_massage = Message::Tx(transaction);
-                    self.send(transaction_massage)?;
-                    Ok(())
-                } else {
-                    Err(Error::WrongP2PMessage)
-                }      
-            },  
-            _ => {
-                return Err(Error::WrongP2PMessage);
+    /// Pushes the transaction to the peer and returns its reject message if it refused it
+    ///
+    /// The transaction is sent without announcing it first, peers delay their getdata for
+    /// transactions announced by inbound connections. Messages are handled in order, so a reject
+    /// for the transaction arrives before the answer to the ping sent right after it.
+    ///
+    /// Only peers that still send BIP61 `reject` messages can be seen refusing it, Bitcoin Core
+    /// stopped sending them in 0.20 and its refusals look like acceptance here.
+    pub fn send_transaction(&mut self, transaction: Tx) -> Result<Option<Reject>> {
+        let txid = transaction.txid();
+        self.send(Message::Tx(transaction))?;
+
+        let nonce = random::get_random_u64();
+        self.send(Ping(messages::ping::Ping { nonce }))?;
+        loop {
+            match self.receive_one_of(&[commands::REJECT, PONG])? {
+                Message::Reject(reject) if reject.txid().ok() == Some(txid) => return Ok(Some(reject)),
+                Message::Pong(pong) if pong.nonce == nonce => return Ok(None),
+                _ => continue,
             }
         }
     }
@@ -182,26 +283,65 @@ impl Peer {
 
     
     fn receive(& mut self, message_type: [u8; 12]) -> Result<Message>{
-        // Recognition of Latency of Bitcoin P2P Network
-         let duration = monotonic_clock::now() + 1_000_000_000;
-         while monotonic_clock::now() < duration {
-             let decoded_message = Message::read(&mut self.input_stream);
-             match decoded_message{
-                 Ok(message) => {
-                    if message.1.command == commands::NOTFOUND {
-                        return Ok(message.0)
-                    }
-                     if message.1.command == message_type {
-                         return Ok(message.0)
-                     }
-                 },
-                 Err(Error::IOError(_)) => continue,
-                 Err(err) => {
-                     return Err(err)
-                 }
-             }   
-         }
-        Err(Error::Timeout)    
+        self.receive_one_of(&[message_type])
+    }
+
+    /// Waits for a response with one of `message_types`, handling everything else the peer sends meanwhile
+    fn receive_one_of(& mut self, message_types: &[[u8; 12]]) -> Result<Message>{
+        self.wait_for(|dispatcher| dispatcher.take_response(message_types))
+    }
+
+    /// Waits for a `message_type` or a notfound answering the getdata for `requested`
+    fn receive_data(& mut self, message_type: [u8; 12], requested: &[Hash256]) -> Result<Message>{
+        self.wait_for(|dispatcher| dispatcher.take_data(message_type, requested))
+    }
+
+    /// Pumps the connection until `take` finds a response or the request times out
+    fn wait_for(& mut self, mut take: impl FnMut(&mut Dispatcher) -> Option<Message>) -> Result<Message>{
+        let deadline = monotonic_clock::now() + RESPONSE_TIMEOUT_NANOS;
+        let readable = self.input_stream.subscribe();
+        loop {
+            self.pump()?;
+            if let Some(message) = take(&mut self.dispatcher) {
+                return Ok(message);
+            }
+            if monotonic_clock::now() >= deadline {
+                return Err(Error::Timeout);
+            }
+            let timeout = monotonic_clock::subscribe_instant(deadline);
+            poll::poll(&[&readable, &timeout]);
+        }
+    }
+
+    /// Dispatches whatever the peer sent so far without blocking
+    fn pump(&mut self) -> Result<()> {
+        loop {
+            let bytes = self.input_stream.read(READ_CHUNK_SIZE).map_err(Error::StreamingError)?;
+            if bytes.is_empty() {
+                break;
+            }
+            self.dispatcher.feed(&bytes)?;
+        }
+        for reply in self.dispatcher.take_replies() {
+            self.send(reply)?;
+        }
+        Ok(())
+    }
+
+    /// Inventory the peer announced since the last call
+    pub fn take_announcements(&mut self) -> Result<Vec<InvVect>> {
+        self.pump()?;
+        Ok(self.dispatcher.take_announcements())
+    }
+
+    /// Whether the peer relays transactions paying `fee_rate` sat/vB, according to its fee filter
+    pub fn relays_fee_rate(&self, fee_rate: u64) -> bool {
+        fee_rate.saturating_mul(1000) >= self.fee_filter()
+    }
+
+    /// Lowest fee rate the peer relays, in sat/kvB
+    pub fn fee_filter(&self) -> u64 {
+        self.dispatcher.settings().fee_filter
     }
 
 }
@@ -217,9 +357,33 @@ pub struct BitcoinP2PConfig {
     pub max_protocol_version: u32,
 }
 
+/// Number of failed or slow requests after which a peer is rotated out
+const MAX_PEER_STRIKES: u32 = 3;
+
+/// Requests answered slower than this count as a strike against the peer, in nanoseconds
+const SLOW_RESPONSE_NANOS: u64 = 5_000_000_000;
+
+/// Default number of outbound connections kept open
+pub const DEFAULT_MAX_CONNECTIONS: usize = 4;
+
+/// Manages the outbound connections and spreads requests across them
 pub struct P2P {
-    peer: Option<Peer>,
-    socket: WasiTcpSocket, 
+    network: bitcoin_network::Network,
+    peers: Vec<Peer>,
+    next_peer_id: PeerId,
+    banned: Vec<CustomIPV4SocketAddress>,
+    // Configured peers, tried in order when a connection slot is free
+    addresses: Vec<CustomIPV4SocketAddress>,
+    next_address: usize,
+    max_connections: usize,
+    // Round robin position for requests not addressed to a peer
+    next_request: usize,
+    // Addresses learned from gossip and seeds, used once the configured peers are exhausted
+    address_book: AddressBook,
+    use_seeds: bool,
+    // DNS seeds are only queried once per run
+    seeds_queried: bool,
+    db: Arc<KeyValueDb>,
 }
 pub trait  P2PControl {
     fn connect_peer(&mut self, address: CustomIPV4SocketAddress, network: bitcoin_network::Network) -> Result<()>;
@@ -227,19 +391,46 @@ pub trait  P2PControl {
 
 impl P2PControl for P2P {
     fn connect_peer(&mut self, remote_address: CustomIPV4SocketAddress, network: bitcoin_network::Network) -> Result<()> {
+        if self.banned.contains(&remote_address) {
+            return Err(Error::PeerBanned);
+        }
+
+        let now = wall_clock::now().seconds;
+        self.address_book.mark_attempt(&remote_address, now);
+
+        let raw_socket = create_tcp_socket(network::IpAddressFamily::Ipv4).map_err(Error::TCPError)?;
+        let wasi_socket = WasiTcpSocket::new(raw_socket, instance_network::instance_network());
         let wasi_socket_address = IpSocketAddress::Ipv4(Ipv4SocketAddress{ port: remote_address.port, address: remote_address.ip });
-        let connect_res = self.socket.blocking_connect(wasi_socket_address);
+        let connect_res = wasi_socket.blocking_connect(wasi_socket_address);
 
         match connect_res {
             Ok((input_stream, output_stream)) => {
                 let (a, b,c, d) = remote_address.ip;
                 let socket_address = std::net::IpAddr::V4(Ipv4Addr::new(a, b, c, d));
-                let remote_address = NodeAddr::new(socket_address, remote_address.port); 
-                let peer = Peer::new(network, input_stream, output_stream, remote_address);
-                self.peer = Some(peer);
+                let node_address = NodeAddr::new(socket_address, remote_address.port); 
+                let mut peer = match Peer::new(self.next_peer_id, network, wasi_socket, input_stream, output_stream, node_address, remote_address.clone()) {
+                    Ok(peer) => peer,
+                    // Will never serve filters, no point in retrying it
+                    Err(Error::MissingPeerServices(services)) => {
+                        self.address_book.remove(&remote_address);
+                        return Err(Error::MissingPeerServices(services));
+                    },
+                    Err(err) => {
+                        self.address_book.mark_failure(&remote_address, now);
+                        return Err(err);
+                    },
+                };
+                self.address_book.mark_success(&remote_address, peer.info.services, now);
+                if peer.info.services & NODE_BLOOM != 0 {
+                    // Announcements of what is already unconfirmed, best effort
+                    let _ = peer.request_mempool();
+                }
+                self.next_peer_id += 1;
+                self.peers.push(peer);
                 Ok(())
             },
             Err(e) => {
+                self.address_book.mark_failure(&remote_address, now);
                 Err(Error::TCPError(e))
             },
         }
@@ -250,58 +441,293 @@ impl P2PControl for P2P {
 
     impl P2P {
 
-        pub fn new() -> Self {
-            let raw_socket = create_tcp_socket(network::IpAddressFamily::Ipv4).expect("cant create socket");
-            let network =  instance_network::instance_network();
-            let wasi_socket = WasiTcpSocket::new(raw_socket, network);
-            P2P{ socket: wasi_socket, peer: None}
+        pub fn new(network: bitcoin_network::Network, addresses: Vec<CustomIPV4SocketAddress>, max_connections: usize, use_seeds: bool, db: Arc<KeyValueDb>) -> Self {
+            // An unreadable book is refilled from the configured peers, gossip and the DNS seeds
+            let mut address_book = AddressBook::load(&db).unwrap_or_default();
+            let now = wall_clock::now().seconds;
+            for address in addresses.iter() {
+                address_book.add(address.clone(), 0, now, AddressSource::Configured);
+            }
+
+            P2P{ network, peers: Vec::new(), next_peer_id: 0, banned: Vec::new(), addresses, next_address: 0, max_connections, next_request: 0, address_book, use_seeds, seeds_queried: false, db }
+        }
+
+        /// Opens connections until all slots are used, trying the configured peers first, then
+        /// the address book and finally the DNS seeds
+        pub fn maintain_connections(&mut self) -> Result<()> {
+            if self.peers.len() >= self.max_connections {
+                return Ok(());
+            }
+
+            // Configured peers back off like any other address, and banned ones stay out
+            let now = wall_clock::now().seconds;
+            let seeds_queried = self.seeds_queried;
+            let mut attempted = false;
+            let mut attempts = 0;
+            while self.peers.len() < self.max_connections && attempts < self.addresses.len() {
+                let address = self.addresses[self.next_address % self.addresses.len()].clone();
+                self.next_address += 1;
+                attempts += 1;
+
+                let connected = self.peers.iter().any(|peer| peer.socket_address == address);
+                if connected || self.banned.contains(&address) || !self.address_book.ready(&address, now) {
+                    continue;
+                }
+                self.connect_and_learn(address);
+                attempted = true;
+            }
+
+            while self.peers.len() < self.max_connections {
+                let address = match self.pick_address() {
+                    Some(address) => address,
+                    None => break,
+                };
+                self.connect_and_learn(address);
+                attempted = true;
+            }
+
+            // The book only changes when an address was tried or the seeds were resolved
+            if attempted || self.seeds_queried != seeds_queried {
+                self.address_book.save(&self.db)?;
+            }
+
+            match self.peers.is_empty() {
+                true => Err(Error::PeerNotFound),
+                false => Ok(()),
+            }
+        }
+
+        /// Next address from the book, querying the DNS seeds once the book has nothing left to try
+        fn pick_address(&mut self) -> Option<CustomIPV4SocketAddress> {
+            let mut exclude: Vec<CustomIPV4SocketAddress> = self.peers.iter().map(|peer| peer.socket_address.clone()).collect();
+            exclude.extend(self.banned.iter().cloned());
+            // Configured peers were just tried by the caller
+            exclude.extend(self.addresses.iter().cloned());
+
+            let now = wall_clock::now().seconds;
+            if let Some(address) = self.address_book.pick(&exclude, now) {
+                return Some(address);
+            }
+
+            if !self.use_seeds || self.seeds_queried {
+                return None;
+            }
+            self.seeds_queried = true;
+            for address in resolve_dns_seeds(self.network) {
+                self.address_book.add(address, 0, now, AddressSource::Seed);
+            }
+            self.address_book.pick(&exclude, now)
+        }
+
+        /// Connects to `address` and asks the new peer for more addresses
+        fn connect_and_learn(&mut self, address: CustomIPV4SocketAddress) {
+            if self.connect_peer(address, self.network).is_err() {
+                return;
+            }
+
+            // Peers answer getaddr at most once per connection and may not answer at all,
+            // a missing reply says nothing about the peer
+            let gossip = match self.peers.last_mut() {
+                Some(peer) => peer.fetch_addresses().unwrap_or_default(),
+                None => return,
+            };
+            let now = wall_clock::now().seconds;
+            for addr in gossip.iter().filter(|addr| addr.services & REQUIRED_SERVICES == REQUIRED_SERVICES) {
+                if let Some(ip) = addr.ipv4() {
+                    let [a, b, c, d] = ip.octets();
+                    let address = CustomIPV4SocketAddress { ip: (a, b, c, d), port: addr.port };
+                    // Timestamps from the future are not trusted
+                    let last_seen = (addr.time as u64).min(now);
+                    self.address_book.add(address, addr.services, last_seen, AddressSource::Gossip);
+                }
+            }
+        }
+
+        /// Identifiers of the connected peers
+        pub fn peer_ids(&self) -> Vec<PeerId> {
+            self.peers.iter().map(|peer| peer.id).collect()
+        }
+
+        /// Disconnects a peer that served provably wrong data and refuses to connect to it again
+        pub fn ban_peer(&mut self, id: PeerId) {
+            if let Some(position) = self.peers.iter().position(|peer| peer.id == id) {
+                let peer = self.peers.remove(position);
+                self.address_book.remove(&peer.socket_address);
+                self.banned.push(peer.socket_address);
+            }
+        }
+
+        /// Drops the connection to a slow or unresponsive peer, its slot is refilled by the next
+        /// address that is not backing off
+        pub fn rotate_out(&mut self, id: PeerId) {
+            if let Some(position) = self.peers.iter().position(|peer| peer.id == id) {
+                let peer = self.peers.remove(position);
+                // Counts as a failure so the address backs off instead of being reconnected at once
+                self.address_book.mark_failure(&peer.socket_address, wall_clock::now().seconds);
+            }
+        }
+
+        fn peer(&mut self, id: PeerId) -> Result<&mut Peer> {
+            self.peers.iter_mut().find(|peer| peer.id == id).ok_or(Error::PeerNotFound)
+        }
+
+        /// Runs `call` on the peer `id`, rotating the peer out once it failed or was slow too often
+        fn request_from<T>(&mut self, id: PeerId, call: &mut dyn FnMut(&mut Peer) -> Result<T>) -> Result<T> {
+            let peer = self.peer(id)?;
+            let started = monotonic_clock::now();
+            let result = call(peer);
+
+            let slow = monotonic_clock::now() - started > SLOW_RESPONSE_NANOS;
+            match (&result, slow) {
+                (Ok(_), false) => peer.strikes = 0,
+                _ => peer.strikes += 1,
+            }
+
+            if peer.strikes >= MAX_PEER_STRIKES {
+                self.rotate_out(id);
+            }
+            result
+        }
+
+        /// Runs `call` on the peers in turn, moving on to the next one when a peer fails
+        fn request<T>(&mut self, call: &mut dyn FnMut(&mut Peer) -> Result<T>) -> Result<T> {
+            self.request_with_peer(call).map(|(_, response)| response)
+        }
+
+        /// Like [`Self::request`], also returning the peer that answered
+        fn request_with_peer<T>(&mut self, call: &mut dyn FnMut(&mut Peer) -> Result<T>) -> Result<(PeerId, T)> {
+            self.maintain_connections()?;
+
+            let mut last_error = Error::PeerNotFound;
+            for _ in 0..self.peers.len() {
+                if self.peers.is_empty() {
+                    break;
+                }
+                let id = self.peers[self.next_request % self.peers.len()].id;
+                self.next_request += 1;
+
+                match self.request_from(id, call) {
+                    Ok(response) => return Ok((id, response)),
+                    Err(err) => last_error = err,
+                }
+            }
+            Err(last_error)
         }
 
-        pub fn fetch_headers(&mut self, last_known_blockhash: Hash256) -> Result<Vec<BlockHeader>> {
-            self.peer
-                .as_mut()
-                .ok_or(Error::PeerNotFound)?
-                .fetch_headers(last_known_blockhash)
+        pub fn fetch_headers(&mut self, locator_hashes: Vec<Hash256>) -> Result<Vec<BlockHeader>> {
+            self.request(&mut |peer| peer.fetch_headers(locator_hashes.clone()))
         }
     
-        pub fn get_compact_filters(&mut self, start_height: u32, hash_stop: Hash256) -> Result<Vec<CompactFilter>> { 
-            self.peer
-                .as_mut()
-                .ok_or(Error::PeerNotFound)?
-                .fetch_compact_filters(start_height, hash_stop)
+        /// Filters of the range from whichever peer answers, along with that peer
+        pub fn get_compact_filters(&mut self, start_height: u32, hash_stop: Hash256) -> Result<(PeerId, Vec<CompactFilter>)> { 
+            self.request_with_peer(&mut |peer| peer.fetch_compact_filters(start_height, hash_stop))
         }
 
-        pub fn get_compact_filter_headers(&mut self, start_height: u32, hash_stop: Hash256) -> Result<CompactFilterHeader> { 
-            self.peer
-                .as_mut()
-                .ok_or(Error::PeerNotFound)?
-                .fetch_compact_filter_headers(start_height, hash_stop)
+        pub fn get_compact_filters_from(&mut self, id: PeerId, start_height: u32, hash_stop: Hash256) -> Result<Vec<CompactFilter>> { 
+            self.request_from(id, &mut |peer| peer.fetch_compact_filters(start_height, hash_stop))
+        }
+
+        pub fn get_compact_filter_headers_from(&mut self, id: PeerId, start_height: u32, hash_stop: Hash256) -> Result<CompactFilterHeader> { 
+            self.request_from(id, &mut |peer| peer.fetch_compact_filter_headers(start_height, hash_stop))
+        }
+
+        pub fn get_compact_filter_checkpoints_from(&mut self, id: PeerId, stop_hash: Hash256) -> Result<CompactFilterCheckpoint> { 
+            self.request_from(id, &mut |peer| peer.fetch_compact_filter_checkpoints(stop_hash))
         }
     
         pub fn get_block(&mut self, inv: Inv) -> Result<Vec<Block>> {
-            self.peer
-                .as_mut()
-                .ok_or(Error::PeerNotFound)?
-                .fetch_blocks(inv)
+            self.request(&mut |peer| peer.fetch_blocks(inv.clone()))
         }
 
-        pub fn send_transaction(&mut self, transaction: Tx) -> Result<()> {
-            self.peer
-                .as_mut()
-                .ok_or(Error::PeerNotFound)?
-                .send_transaction(transaction)
+        pub fn get_transactions_from(&mut self, id: PeerId, inv: Inv) -> Result<Vec<Tx>> {
+            self.request_from(id, &mut |peer| peer.fetch_transactions(inv.clone()))
+        }
+
+        pub fn get_block_from(&mut self, id: PeerId, inv: Inv) -> Result<Vec<Block>> {
+            self.request_from(id, &mut |peer| peer.fetch_blocks(inv.clone()))
+        }
+
+        /// Sends the transaction to every connected peer that relays its fee rate, succeeding
+        /// if one of them took it
+        ///
+        /// When every peer that answered refused it, the first reject is returned as
+        /// [`Error::TransactionRejected`], which needs peers that still send BIP61 rejects. A peer
+        /// that already has the transaction counts as having taken it. When the fee rate is below
+        /// the fee filter of every peer nothing is sent and [`Error::FeeBelowPeerFeeFilter`] is
+        /// returned.
+        pub fn send_transaction(&mut self, transaction: Tx, fee_rate: u64) -> Result<()> {
+            self.maintain_connections()?;
+
+            let mut result = Err(Error::PeerNotFound);
+            let relaying: Vec<PeerId> = self.peers.iter().filter(|peer| peer.relays_fee_rate(fee_rate)).map(|peer| peer.id).collect();
+            if relaying.is_empty() {
+                if let Some(lowest) = self.peers.iter().map(|peer| peer.fee_filter()).min() {
+                    return Err(Error::FeeBelowPeerFeeFilter(lowest));
+                }
+            }
+            for id in relaying {
+                match self.request_from(id, &mut |peer| peer.send_transaction(transaction.clone())) {
+                    Ok(None) => result = Ok(()),
+                    Ok(Some(reject)) if reject.reject_code() == RejectCode::Duplicate => result = Ok(()),
+                    Ok(Some(reject)) => {
+                        if matches!(result, Err(Error::PeerNotFound)) {
+                            result = Err(Error::TransactionRejected(reject.reject_code(), reject.reason));
+                        }
+                    },
+                    Err(_) => continue,
+                }
+            }
+            result
         }
     
+        /// Handles what every peer sent since the last request and returns the inventory they
+        /// announced, peers whose connection broke are replaced
+        pub fn take_announcements(&mut self) -> Vec<(PeerId, InvVect)> {
+            let mut announcements = Vec::new();
+            for id in self.peer_ids() {
+                match self.peer(id).and_then(|peer| peer.take_announcements()) {
+                    Ok(inventory) => announcements.extend(inventory.into_iter().map(|inv| (id, inv))),
+                    Err(_) => self.rotate_out(id),
+                }
+            }
+            announcements
+        }
+
+        /// Pings every peer, replacing the ones that stopped answering
         pub fn keep_alive(&mut self) -> Result<()> {
-            self.peer
-                .as_mut()
-                .ok_or(Error::PeerNotFound)?
-                .keep_alive()
+            for id in self.peer_ids() {
+                if self.peer(id)?.keep_alive().is_err() {
+                    self.rotate_out(id);
+                }
+            }
+            self.maintain_connections()
         }
         
     }
 
+/// Resolves the network's DNS seeds into addresses on the default port
+fn resolve_dns_seeds(network: bitcoin_network::Network) -> Vec<CustomIPV4SocketAddress> {
+    let port = default_port_from_network(network);
+    let mut addresses = Vec::new();
+    for seed in dns_seeds_from_network(network) {
+        // Seeds that fail to resolve are skipped, the others are enough to find peers
+        if let Ok(ips) = resolve_host(seed) {
+            addresses.extend(ips.into_iter().map(|ip| CustomIPV4SocketAddress { ip, port }));
+        }
+    }
+    addresses
+}
 
-
-
-
+fn resolve_host(host: &str) -> std::result::Result<Vec<(u8, u8, u8, u8)>, ErrorCode> {
+    let stream = ip_name_lookup::resolve_addresses(&instance_network::instance_network(), host)?;
+    let mut ips = Vec::new();
+    loop {
+        match stream.resolve_next_address() {
+            Ok(Some(IpAddress::Ipv4(ip))) => ips.push(ip),
+            Ok(Some(IpAddress::Ipv6(_))) => continue,
+            Ok(None) => return Ok(ips),
+            Err(ErrorCode::WouldBlock) => stream.subscribe().block(),
+            Err(err) => return Err(err),
+        }
+    }
+}
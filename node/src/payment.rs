@@ -0,0 +1,130 @@
+//! Payment destinations given as address strings or BIP21 `bitcoin:` URIs
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::{Address, Amount, Denomination, Network};
+
+use crate::util::{Error, Result};
+
+const URI_SCHEME: &str = "bitcoin:";
+
+/// Parses `address` and checks it pays on `network`
+pub fn parse_address(address: &str, network: Network) -> Result<Address> {
+    let unchecked: Address<NetworkUnchecked> = address.parse().map_err(|_| Error::InvalidAddress(address.to_string()))?;
+    if !unchecked.is_valid_for_network(network) {
+        return Err(Error::WrongNetwork(address.to_string()));
+    }
+    Ok(unchecked.assume_checked())
+}
+
+/// Payment asked for by a BIP21 URI
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentRequest {
+    pub address: Address,
+    pub amount: Option<Amount>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+}
+
+impl PaymentRequest {
+    /// Parses a `bitcoin:<address>[?amount=&label=&message=]` URI, failing on any
+    /// `req-` parameter since the payer would have to understand it
+    pub fn parse(uri: &str, network: Network) -> Result<Self> {
+        let invalid = |reason: &str| Error::InvalidPaymentUri(reason.to_string());
+
+        if !uri.get(..URI_SCHEME.len()).is_some_and(|scheme| scheme.eq_ignore_ascii_case(URI_SCHEME)) {
+            return Err(invalid("missing bitcoin: scheme"));
+        }
+        let rest = &uri[URI_SCHEME.len()..];
+        let (address, query) = match rest.split_once('?') {
+            Some((address, query)) => (address, Some(query)),
+            None => (rest, None),
+        };
+        let address = parse_address(address, network)?;
+
+        let mut request = PaymentRequest { address, amount: None, label: None, message: None };
+        for param in query.into_iter().flat_map(|query| query.split('&')).filter(|param| !param.is_empty()) {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            let value = percent_decode(value).ok_or_else(|| invalid("bad percent encoding"))?;
+            match key {
+                "amount" => {
+                    if request.amount.is_some() {
+                        return Err(invalid("amount given twice"));
+                    }
+                    let amount = Amount::from_str_in(&value, Denomination::Bitcoin).map_err(|_| invalid("bad amount"))?;
+                    request.amount = Some(amount);
+                },
+                "label" => request.label = Some(value),
+                "message" => request.message = Some(value),
+                key if key.starts_with("req-") => return Err(Error::InvalidPaymentUri(format!("unsupported required parameter {}", key))),
+                _ => {},
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+/// Decodes `%XX` escapes, none when an escape is malformed or the result is not UTF-8
+fn percent_decode(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAINNET_ADDRESS: &str = "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu";
+    const REGTEST_ADDRESS: &str = "bcrt1qcr8te4kr609gcawutmrza0j4xv80jy8zeqchgx";
+
+    #[test]
+    fn parses_addresses_for_their_network() {
+        let address = parse_address(REGTEST_ADDRESS, Network::Regtest).unwrap();
+        assert_eq!(address.to_string(), REGTEST_ADDRESS);
+        assert!(parse_address(MAINNET_ADDRESS, Network::Bitcoin).is_ok());
+
+        assert!(matches!(parse_address(MAINNET_ADDRESS, Network::Regtest), Err(Error::WrongNetwork(_))));
+        assert!(matches!(parse_address("bc1qnotanaddress", Network::Bitcoin), Err(Error::InvalidAddress(_))));
+    }
+
+    #[test]
+    fn parses_payment_uris() {
+        let uri = format!("BITCOIN:{}?amount=0.0005&label=Luke-Jr&message=Donation%20for%20project%20xyz&other=1", MAINNET_ADDRESS);
+        let request = PaymentRequest::parse(&uri, Network::Bitcoin).unwrap();
+        assert_eq!(request.address.to_string(), MAINNET_ADDRESS);
+        assert_eq!(request.amount, Some(Amount::from_sat(50_000)));
+        assert_eq!(request.label.as_deref(), Some("Luke-Jr"));
+        assert_eq!(request.message.as_deref(), Some("Donation for project xyz"));
+
+        let request = PaymentRequest::parse(&format!("bitcoin:{}", MAINNET_ADDRESS), Network::Bitcoin).unwrap();
+        assert_eq!(request.amount, None);
+    }
+
+    #[test]
+    fn rejects_malformed_payment_uris() {
+        let bad = [
+            MAINNET_ADDRESS.to_string(),
+            format!("bitcoin:{}?amount=1btc", MAINNET_ADDRESS),
+            format!("bitcoin:{}?amount=1&amount=2", MAINNET_ADDRESS),
+            format!("bitcoin:{}?req-somethingyoudontunderstand=50", MAINNET_ADDRESS),
+            format!("bitcoin:{}?label=%zz", MAINNET_ADDRESS),
+        ];
+        for uri in bad.iter() {
+            assert!(matches!(PaymentRequest::parse(uri, Network::Bitcoin), Err(Error::InvalidPaymentUri(_))), "{}", uri);
+        }
+        let uri = format!("bitcoin:{}?amount=1", MAINNET_ADDRESS);
+        assert!(matches!(PaymentRequest::parse(&uri, Network::Testnet), Err(Error::WrongNetwork(_))));
+    }
+}
@@ -1,4 +1,6 @@
-use bitcoin::{hashes::hash160::Hash, Network};
+use bitcoin::{blockdata::constants::genesis_block, hashes::Hash as _, Network};
+
+use crate::messages::BlockHeader;
 
 use super::Hash256;
 
@@ -17,17 +19,114 @@ pub fn magic_from_network(network: Network) -> [u8; 4] {
     }
 }
 
-const GENESIS_BLOCK_HASH_REGTEST: &str = "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206";
-const GENESIS_BLOCK_HASH_TESTNET: &str = "000000000933ea01ad0ee984209779baaec3ced90fa3f408719526f8d77f4943";
-const GENESIS_BLOCK_HASH_MAINNET: &str = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
-const GENESIS_BLOCK_HASH_SIGNET: &str = "00000008819873e925422c1ff0f99f7cc9bbb232af63a077a480a3633bee1ef6";
+pub fn genesis_block_header_from_network(network: Network) -> BlockHeader {
+    let header = genesis_block(network).header;
+    BlockHeader {
+        version: header.version.to_consensus() as u32,
+        prev_hash: Hash256(header.prev_blockhash.to_byte_array()),
+        merkle_root: Hash256(header.merkle_root.to_byte_array()),
+        timestamp: header.time,
+        bits: header.bits.to_consensus(),
+        nonce: header.nonce,
+    }
+}
+
+const MAINNET_DNS_SEEDS: &[&str] = &[
+    "seed.bitcoin.sipa.be",
+    "dnsseed.bluematt.me",
+    "seed.bitcoin.jonasschnelli.ch",
+    "seed.btc.petertodd.net",
+    "seed.bitcoin.sprovoost.nl",
+    "dnsseed.emzy.de",
+    "seed.bitcoin.wiz.biz",
+];
+const TESTNET_DNS_SEEDS: &[&str] = &[
+    "testnet-seed.bitcoin.jonasschnelli.ch",
+    "seed.tbtc.petertodd.net",
+    "seed.testnet.bitcoin.sprovoost.nl",
+    "testnet-seed.bluematt.me",
+];
+const TESTNET4_DNS_SEEDS: &[&str] = &[
+    "seed.testnet4.bitcoin.sprovoost.nl",
+    "seed.testnet4.wiz.biz",
+];
+const SIGNET_DNS_SEEDS: &[&str] = &[
+    "seed.signet.bitcoin.sprovoost.nl",
+];
+
+/// DNS seeds queried for peer addresses when no known peer is reachable, none on regtest
+pub fn dns_seeds_from_network(network: Network) -> &'static [&'static str] {
+    match network {
+        Network::Bitcoin => MAINNET_DNS_SEEDS,
+        Network::Testnet => TESTNET_DNS_SEEDS,
+        Network::Testnet4 => TESTNET4_DNS_SEEDS,
+        Network::Signet => SIGNET_DNS_SEEDS,
+        _ => &[],
+    }
+}
 
-pub fn genesis_block_hash_from_network(network: Network) -> Hash256 {
+pub fn default_port_from_network(network: Network) -> u16 {
     match network {
-        Network::Bitcoin => Hash256::decode(GENESIS_BLOCK_HASH_MAINNET).expect("Failed to decode genesis blockhash"),
-        Network::Testnet => Hash256::decode(GENESIS_BLOCK_HASH_TESTNET).expect("Failed to decode genesis blockhash"),
-        Network::Regtest => Hash256::decode(GENESIS_BLOCK_HASH_REGTEST).expect("Failed to decode genesis blockhash"),
-        Network::Signet => Hash256::decode(GENESIS_BLOCK_HASH_SIGNET).expect("Failed to decode genesis blockhash"),
-        _ => Hash256::decode(GENESIS_BLOCK_HASH_MAINNET).expect("Failed to decode genesis blockhash"),
+        Network::Bitcoin => 8333,
+        Network::Testnet => 18333,
+        Network::Testnet4 => 48333,
+        Network::Signet => 38333,
+        Network::Regtest => 18444,
     }
-}
\ No newline at end of file
+}
+
+/// Best chain blocks known in advance, as listed by Bitcoin Core before it dropped checkpoints
+///
+/// Headers are still synced from genesis, a checkpoint only rejects a chain that does not go
+/// through it.
+const MAINNET_CHECKPOINTS: &[(u64, &str)] = &[
+    (11111, "0000000069e244f73d78e8fd29ba2fd2ed618bd6fa2ee92559f542fdb26e7c1d"),
+    (33333, "000000002dd5588a74784eaa7ab0507a18ad16a236e7b1ce69f00d7ddfb5d0a6"),
+    (74000, "0000000000573993a3c9e41ce34471c079dcf5f52a0e824a81e7f953b8661a20"),
+    (105000, "00000000000291ce28027faea320c8d2b054b2e0fe44a773f3eefb151d6bdc97"),
+    (134444, "00000000000005b12ffd4cd315cd34ffd4a594f430ac814c91184a0d42d2b0fe"),
+    (168000, "000000000000099e61ea72015e79632f216fe6cb33d7899acb35b75c8303b763"),
+    (193000, "000000000000059f452a5f7340de6682a977387c17010ff6e6c3bd83ca8b1317"),
+    (210000, "000000000000048b95347e83192f69cf0366076336c639f9b7228e9ba171342e"),
+    (216116, "00000000000001b4f4b433e81ee46494af945cf96014816a4e2370f11b23df4e"),
+    (225430, "00000000000001c108384350f74090433e7fcf79a606b8e797f065b130575932"),
+    (250000, "000000000000003887df1f29024b06fc2200b55f8af8f35453d7be294df2d214"),
+    (279000, "0000000000000001ae8c72a0b0c301f67e3afca10e819efa9041e458e9bd7e40"),
+    (295000, "00000000000000004d9b4ef50f0f9d686fd69db2e03af35a100370c64632a983"),
+];
+const TESTNET_CHECKPOINTS: &[(u64, &str)] = &[
+    (546, "000000002a936ca763904c3c35fce2f3556c559c0214345d31b1bcebf76acb70"),
+];
+
+/// Hash the best chain must have at `height`, if the height is a checkpoint on `network`
+pub fn checkpoint_from_network(network: Network, height: u64) -> Option<Hash256> {
+    let checkpoints = match network {
+        Network::Bitcoin => MAINNET_CHECKPOINTS,
+        Network::Testnet => TESTNET_CHECKPOINTS,
+        _ => &[],
+    };
+    checkpoints.iter()
+        .find(|(checkpoint_height, _)| *checkpoint_height == height)
+        .and_then(|(_, hash)| Hash256::decode(hash).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkpoints_decode() {
+        for (network, checkpoints) in [(Network::Bitcoin, MAINNET_CHECKPOINTS), (Network::Testnet, TESTNET_CHECKPOINTS)] {
+            assert!(checkpoints.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            for (height, hash) in checkpoints {
+                let decoded = Hash256::decode(hash).unwrap();
+                assert!(decoded.encode() == *hash);
+                // Proof of work leaves the top bytes of every block hash zero
+                assert!(decoded.0[28..] == [0; 4]);
+                assert!(checkpoint_from_network(network, *height) == Some(decoded));
+            }
+        }
+        assert!(checkpoint_from_network(Network::Bitcoin, 546).is_none());
+        assert!(checkpoint_from_network(Network::Regtest, 11111).is_none());
+    }
+}
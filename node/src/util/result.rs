@@ -5,7 +5,10 @@ use wasi::sockets::tcp::ErrorCode;
 use std::io;
 use std::string::FromUtf8Error;
 use crate::bindings;
+use crate::messages::reject::RejectCode;
 use bindings::component::kv::types::Error as StoreError;
+use bindings::component::signer::types::Error as SignerError;
+use bindings::component::wallet::types::{Error as WalletTypesError, Outpoint as WalletOutpoint};
 
 /// Standard error type used in the library
 #[derive(Debug)]
@@ -57,7 +60,46 @@ pub enum Error {
     /// Peer Not Found Error
     PeerNotFound,
     ///
-    WalletError(u32)
+    WalletError(u32),
+    /// Block header failed consensus validation
+    InvalidHeader(String),
+    /// Compact filter or filter header inconsistent with the verified filter header chain
+    InvalidFilterHeader(String),
+    /// The peer was banned for serving provably wrong data
+    PeerBanned,
+    /// The peer does not advertise the services a light client needs, holds its service flags
+    MissingPeerServices(u64),
+    /// A peer refused our transaction, holds the reject code and the peer's reason
+    TransactionRejected(RejectCode, String),
+    /// The string is not a bitcoin address
+    InvalidAddress(String),
+    /// The address pays on another network than the node's
+    WrongNetwork(String),
+    /// The BIP21 payment URI is malformed or cannot be paid
+    InvalidPaymentUri(String),
+    /// No connected peer relays the transaction's fee rate, holds the lowest fee filter among
+    /// them in sat/kvB (BIP133)
+    FeeBelowPeerFeeFilter(u64),
+    /// The signer could not derive a key for or sign the transaction
+    SignerError(SignerError),
+    /// The recipient at this index would be paid less than its script's dust limit
+    OutputBelowDustLimit(u32),
+    /// The outpoint is not a confirmed, spendable output of the wallet
+    UnknownUtxo(String),
+    /// The replacement or child must pay at least this fee rate in sat/vB
+    FeeRateTooLow(u64),
+    /// The replacement must pay at least this absolute fee in sats
+    FeeTooLow(u64),
+    /// The transaction does not signal BIP125 replaceability
+    IrreplaceableTransaction(String),
+    /// The transaction was already mined
+    TransactionConfirmed(String),
+    /// The transaction pays nothing to the wallet
+    NoWalletOutputs(String),
+    /// The outpoint is frozen by coin control and cannot be spent until unfrozen
+    FrozenUtxo(String),
+    /// The wallet keys, derivations, descriptors or policy do not parse
+    InvalidDescriptor(String),
 }
 
 impl Error {
@@ -91,8 +133,52 @@ impl Error {
             Error::NetworkError => 26,
             Error::FetchHeader(_) => 27,
             Error::WalletError(_) => 28,
+            Error::InvalidHeader(_) => 29,
+            Error::InvalidFilterHeader(_) => 30,
+            Error::PeerBanned => 32,
+            Error::MissingPeerServices(_) => 33,
+            Error::TransactionRejected(_, _) => 34,
+            Error::InvalidAddress(_) => 35,
+            Error::WrongNetwork(_) => 36,
+            Error::InvalidPaymentUri(_) => 37,
+            Error::FeeBelowPeerFeeFilter(_) => 38,
+            Error::SignerError(_) => 39,
+            Error::OutputBelowDustLimit(_) => 40,
+            Error::UnknownUtxo(_) => 41,
+            Error::FeeRateTooLow(_) => 42,
+            Error::FeeTooLow(_) => 43,
+            Error::IrreplaceableTransaction(_) => 44,
+            Error::TransactionConfirmed(_) => 45,
+            Error::NoWalletOutputs(_) => 46,
+            Error::FrozenUtxo(_) => 47,
+            Error::InvalidDescriptor(_) => 48,
         }
     }
+
+    /// Keeps the wallet errors a caller can act on and folds the rest into `WalletError(code)`
+    pub fn from_wallet(error: WalletTypesError, code: u32) -> Error {
+        match error {
+            WalletTypesError::OutputBelowDustLimit(index) => Error::OutputBelowDustLimit(index),
+            WalletTypesError::UnknownUtxo(outpoint) => Error::UnknownUtxo(encode_outpoint(&outpoint)),
+            WalletTypesError::FeeRateTooLow(required) => Error::FeeRateTooLow(required),
+            WalletTypesError::FeeTooLow(required) => Error::FeeTooLow(required),
+            WalletTypesError::IrreplaceableTransaction(txid) => Error::IrreplaceableTransaction(encode_txid(&txid)),
+            WalletTypesError::TransactionConfirmed(txid) => Error::TransactionConfirmed(encode_txid(&txid)),
+            WalletTypesError::NoWalletOutputs(txid) => Error::NoWalletOutputs(encode_txid(&txid)),
+            WalletTypesError::FrozenUtxo(outpoint) => Error::FrozenUtxo(encode_outpoint(&outpoint)),
+            WalletTypesError::InvalidDescriptor(reason) => Error::InvalidDescriptor(reason),
+            _ => Error::WalletError(code),
+        }
+    }
+}
+
+/// Wallet txids are in internal byte order, shown reversed like every txid
+fn encode_txid(txid: &[u8]) -> String {
+    hex::encode(txid.iter().rev().copied().collect::<Vec<u8>>())
+}
+
+fn encode_outpoint(outpoint: &WalletOutpoint) -> String {
+    format!("{}:{}", encode_txid(&outpoint.txid), outpoint.vout)
 }
 
 impl std::fmt::Display for Error {
@@ -126,6 +212,25 @@ impl std::fmt::Display for Error {
             Error::NetworkError => f.write_str(&format!("Network Error")),
             Error::FetchHeader(e) => f.write_str(&format!("Fetching Header Error: {}", e)),
             Error::WalletError(_) => f.write_str(&format!("Wallet Error")),
+            Error::InvalidHeader(s) => f.write_str(&format!("Invalid block header: {}", s)),
+            Error::InvalidFilterHeader(s) => f.write_str(&format!("Invalid filter header: {}", s)),
+            Error::PeerBanned => f.write_str("P2P peer is banned"),
+            Error::MissingPeerServices(services) => f.write_str(&format!("P2P peer lacks compact filter or witness support, services: {:#x}", services)),
+            Error::TransactionRejected(code, reason) => f.write_str(&format!("Transaction rejected as {}: {}", code, reason)),
+            Error::InvalidAddress(address) => f.write_str(&format!("Invalid address: {}", address)),
+            Error::WrongNetwork(address) => f.write_str(&format!("Address for another network: {}", address)),
+            Error::InvalidPaymentUri(reason) => f.write_str(&format!("Invalid payment URI: {}", reason)),
+            Error::FeeBelowPeerFeeFilter(fee_filter) => f.write_str(&format!("Fee rate below every peer's fee filter, lowest is {} sat/kvB", fee_filter)),
+            Error::SignerError(e) => f.write_str(&format!("Signer error: {}", e)),
+            Error::OutputBelowDustLimit(index) => f.write_str(&format!("Recipient {} is paid less than the dust limit", index)),
+            Error::UnknownUtxo(outpoint) => f.write_str(&format!("Not a spendable output of the wallet: {}", outpoint)),
+            Error::FeeRateTooLow(required) => f.write_str(&format!("Fee rate too low, at least {} sat/vB is needed", required)),
+            Error::FeeTooLow(required) => f.write_str(&format!("Fee too low, at least {} sats are needed", required)),
+            Error::IrreplaceableTransaction(txid) => f.write_str(&format!("Transaction cannot be replaced: {}", txid)),
+            Error::TransactionConfirmed(txid) => f.write_str(&format!("Transaction already confirmed: {}", txid)),
+            Error::NoWalletOutputs(txid) => f.write_str(&format!("Transaction pays nothing to the wallet: {}", txid)),
+            Error::FrozenUtxo(outpoint) => f.write_str(&format!("Output is frozen: {}", outpoint)),
+            Error::InvalidDescriptor(reason) => f.write_str(&format!("Invalid wallet descriptor: {}", reason)),
 
         }
     }
@@ -162,6 +267,25 @@ impl std::error::Error for Error {
             Error::NetworkError => "Network Error",
             Error::FetchHeader(_) => "Fetch Header Error",
             Error::WalletError(_) => "Wallet Error",
+            Error::InvalidHeader(_) => "Invalid Block Header",
+            Error::InvalidFilterHeader(_) => "Invalid Filter Header",
+            Error::PeerBanned => "P2P Peer Banned",
+            Error::MissingPeerServices(_) => "P2P Peer Missing Services",
+            Error::TransactionRejected(_, _) => "Transaction Rejected",
+            Error::InvalidAddress(_) => "Invalid Address",
+            Error::WrongNetwork(_) => "Wrong Network",
+            Error::InvalidPaymentUri(_) => "Invalid Payment URI",
+            Error::FeeBelowPeerFeeFilter(_) => "Fee Below Peer Fee Filter",
+            Error::SignerError(_) => "Signer Error",
+            Error::OutputBelowDustLimit(_) => "Output Below Dust Limit",
+            Error::UnknownUtxo(_) => "Unknown UTXO",
+            Error::FeeRateTooLow(_) => "Fee Rate Too Low",
+            Error::FeeTooLow(_) => "Fee Too Low",
+            Error::IrreplaceableTransaction(_) => "Irreplaceable Transaction",
+            Error::TransactionConfirmed(_) => "Transaction Confirmed",
+            Error::NoWalletOutputs(_) => "No Wallet Outputs",
+            Error::FrozenUtxo(_) => "Frozen UTXO",
+            Error::InvalidDescriptor(_) => "Invalid Descriptor",
         }
     }
 
@@ -213,6 +337,12 @@ impl From<libsecp256k1::Error> for Error {
     }
 }
 
+impl From<SignerError> for Error {
+    fn from(e: SignerError) -> Self {
+        Error::SignerError(e)
+    }
+}
+
 impl From<ring::error::Unspecified> for Error {
     fn from(_: ring::error::Unspecified) -> Self {
         Error::UnspecifiedRingError
@@ -221,3 +351,20 @@ impl From<ring::error::Unspecified> for Error {
 
 /// Standard Result used in the library
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::Hash256;
+
+    #[test]
+    fn wallet_outpoints_are_shown_as_txid_and_vout() {
+        let txid = "90c6b3b368a8aa8e5ba3b2140d8e178431d3003a9e85f0d303f63b11437451da";
+        let outpoint = WalletOutpoint { txid: Hash256::decode(txid).unwrap().0.to_vec(), vout: 1 };
+
+        let error = Error::from_wallet(WalletTypesError::UnknownUtxo(outpoint), 5);
+        assert_eq!(error.to_error_code(), 41);
+        assert!(matches!(error, Error::UnknownUtxo(ref s) if *s == format!("{}:1", txid)));
+        assert_eq!(Error::from_wallet(WalletTypesError::CoinSelection, 5).to_error_code(), 28);
+    }
+}
@@ -0,0 +1,279 @@
+//! Consensus checks applied to block headers before they are connected to our chain
+
+use std::time::UNIX_EPOCH;
+
+use bitcoin::{pow::{CompactTarget, Target}, Network};
+
+use crate::messages::BlockHeader;
+use crate::util::{network_const::checkpoint_from_network, secs_since, Error, Hash256, Result};
+
+/// Number of blocks between two difficulty adjustments
+pub const DIFFCHANGE_INTERVAL: u64 = 2016;
+
+/// Number of previous blocks used to compute the median time past
+pub const MEDIAN_TIME_SPAN: u64 = 11;
+
+/// Maximum number of seconds a block timestamp may be ahead of our clock
+const MAX_FUTURE_BLOCK_TIME: u32 = 2 * 60 * 60;
+
+/// Maximum number of seconds the first block of a period may go back in time (BIP94)
+const MAX_TIMEWARP: u32 = 600;
+
+/// Gives access to the headers that precede the ones being validated
+pub trait HeaderSource {
+    /// Returns the header at `height` on the chain being extended, if known
+    fn header_at(&self, height: u64) -> Option<BlockHeader>;
+}
+
+/// Headers of the batch under validation layered on top of an existing source
+struct PendingHeaders<'a, S: HeaderSource> {
+    source: &'a S,
+    base_height: u64,
+    headers: Vec<BlockHeader>,
+}
+
+impl<'a, S: HeaderSource> HeaderSource for PendingHeaders<'a, S> {
+    fn header_at(&self, height: u64) -> Option<BlockHeader> {
+        if height <= self.base_height {
+            return self.source.header_at(height);
+        }
+        self.headers.get((height - self.base_height - 1) as usize).cloned()
+    }
+}
+
+/// Checks that `headers` extend the chain whose tip is `tip_hash` at `tip_height`
+///
+/// Every header must link to its predecessor, carry the proof of work and difficulty
+/// expected for its height on `network`, have a plausible timestamp and match the network's
+/// checkpoint at its height if there is one. Nothing is connected here, the caller stores
+/// the headers once the whole batch is valid.
+pub fn validate_headers<S: HeaderSource>(network: Network, source: &S, tip_height: u64, tip_hash: Hash256, headers: &[BlockHeader]) -> Result<()> {
+    let mut pending = PendingHeaders { source, base_height: tip_height, headers: Vec::with_capacity(headers.len()) };
+    let mut prev_hash = tip_hash;
+
+    for (offset, header) in headers.iter().enumerate() {
+        let height = tip_height + 1 + offset as u64;
+        if header.prev_hash != prev_hash {
+            let msg = format!("Header at height {} does not connect to {}", height, prev_hash.encode());
+            return Err(Error::InvalidHeader(msg));
+        }
+
+        let hash = header.hash();
+        validate_header(network, &pending, height, header, &hash)?;
+
+        pending.headers.push(header.clone());
+        prev_hash = hash;
+    }
+
+    Ok(())
+}
+
+fn validate_header<S: HeaderSource>(network: Network, source: &S, height: u64, header: &BlockHeader, hash: &Hash256) -> Result<()> {
+    if let Some(checkpoint) = checkpoint_from_network(network, height) {
+        if *hash != checkpoint {
+            let msg = format!("Height {}: block {} does not match checkpoint {}", height, hash.encode(), checkpoint.encode());
+            return Err(Error::InvalidHeader(msg));
+        }
+    }
+
+    // Median time past and proof of work
+    let prev_headers = ancestors(source, height, MEDIAN_TIME_SPAN)?;
+    header.validate(hash, &prev_headers).map_err(|err| Error::InvalidHeader(format!("Height {}: {}", height, err)))?;
+
+    let max_time = secs_since(UNIX_EPOCH).saturating_add(MAX_FUTURE_BLOCK_TIME);
+    if header.timestamp > max_time {
+        let msg = format!("Height {}: timestamp {} is too far in the future", height, header.timestamp);
+        return Err(Error::InvalidHeader(msg));
+    }
+
+    let params = network.params();
+    if Target::from_compact(CompactTarget::from_consensus(header.bits)) > params.max_attainable_target {
+        let msg = format!("Height {}: target {:#x} is above the proof of work limit", height, header.bits);
+        return Err(Error::InvalidHeader(msg));
+    }
+
+    let expected_bits = next_work_required(network, source, height, header)?;
+    if header.bits != expected_bits {
+        let msg = format!("Height {}: bad difficulty bits {:#x}, expected {:#x}", height, header.bits, expected_bits);
+        return Err(Error::InvalidHeader(msg));
+    }
+
+    Ok(())
+}
+
+/// Returns the difficulty bits `header` must carry at `height`
+fn next_work_required<S: HeaderSource>(network: Network, source: &S, height: u64, header: &BlockHeader) -> Result<u32> {
+    let params = network.params();
+    let prev = required_header(source, height - 1)?;
+
+    if height.is_multiple_of(DIFFCHANGE_INTERVAL) {
+        let first = required_header(source, height - DIFFCHANGE_INTERVAL)?;
+
+        // BIP94: the first block of a period cannot go back in time and the
+        // retarget is based on the first block, not a min difficulty one
+        let mut base_bits = prev.bits;
+        if network == Network::Testnet4 {
+            if header.timestamp < prev.timestamp.saturating_sub(MAX_TIMEWARP) {
+                let msg = format!("Height {}: timestamp {} violates the timewarp rule", height, header.timestamp);
+                return Err(Error::InvalidHeader(msg));
+            }
+            base_bits = first.bits;
+        }
+
+        let timespan = (prev.timestamp as u64).saturating_sub(first.timestamp as u64);
+        let bits = CompactTarget::from_next_work_required(CompactTarget::from_consensus(base_bits), timespan, params);
+        return Ok(bits.to_consensus());
+    }
+
+    if params.allow_min_difficulty_blocks {
+        let pow_limit_bits = params.max_attainable_target.to_compact_lossy().to_consensus();
+
+        // Testnet rule: a block more than 20 minutes after its parent may be mined at minimum difficulty
+        if header.timestamp as u64 > prev.timestamp as u64 + 2 * params.pow_target_spacing {
+            return Ok(pow_limit_bits);
+        }
+
+        // Otherwise it uses the difficulty of the last block that was not mined at minimum difficulty
+        let mut ancestor_height = height - 1;
+        let mut ancestor = prev;
+        while !ancestor_height.is_multiple_of(DIFFCHANGE_INTERVAL) && ancestor.bits == pow_limit_bits {
+            ancestor_height -= 1;
+            ancestor = required_header(source, ancestor_height)?;
+        }
+        return Ok(ancestor.bits);
+    }
+
+    Ok(prev.bits)
+}
+
+/// Returns up to `count` headers preceding `height`, oldest first
+fn ancestors<S: HeaderSource>(source: &S, height: u64, count: u64) -> Result<Vec<BlockHeader>> {
+    (height.saturating_sub(count)..height)
+        .map(|ancestor_height| required_header(source, ancestor_height))
+        .collect()
+}
+
+fn required_header<S: HeaderSource>(source: &S, height: u64) -> Result<BlockHeader> {
+    source.header_at(height).ok_or_else(|| {
+        Error::InvalidHeader(format!("Missing ancestor header at height {}", height))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Headers(Vec<BlockHeader>);
+
+    impl HeaderSource for Headers {
+        fn header_at(&self, height: u64) -> Option<BlockHeader> {
+            self.0.get(height as usize).cloned()
+        }
+    }
+
+    const REGTEST_BITS: u32 = 0x207fffff;
+
+    fn mine(prev: &BlockHeader, timestamp: u32, bits: u32) -> BlockHeader {
+        let mut header = BlockHeader { version: 4, prev_hash: prev.hash(), timestamp, bits, ..Default::default() };
+        while header.validate(&header.hash(), &[]).is_err() {
+            header.nonce += 1;
+        }
+        header
+    }
+
+    fn regtest_chain(len: usize) -> Headers {
+        let genesis = crate::util::network_const::genesis_block_header_from_network(Network::Regtest);
+        let mut headers = vec![genesis];
+        for i in 1..len {
+            let prev = &headers[i - 1];
+            headers.push(mine(prev, prev.timestamp + 600, REGTEST_BITS));
+        }
+        Headers(headers)
+    }
+
+    #[test]
+    fn accepts_connected_chain() {
+        let chain = regtest_chain(20);
+        let source = Headers(chain.0[..10].to_vec());
+        let tip = &source.0[9];
+        assert!(validate_headers(Network::Regtest, &source, 9, tip.hash(), &chain.0[10..]).is_ok());
+    }
+
+    #[test]
+    fn rejects_disconnected_headers() {
+        let chain = regtest_chain(20);
+        let source = Headers(chain.0[..10].to_vec());
+        let tip = &source.0[9];
+        let result = validate_headers(Network::Regtest, &source, 9, tip.hash(), &chain.0[11..]);
+        assert!(matches!(result, Err(Error::InvalidHeader(_))));
+    }
+
+    #[test]
+    fn rejects_bad_pow_and_bits() {
+        let chain = regtest_chain(12);
+        let tip = &chain.0[11];
+
+        let mut bad_pow = mine(tip, tip.timestamp + 600, REGTEST_BITS);
+        while bad_pow.validate(&bad_pow.hash(), &[]).is_ok() {
+            bad_pow.nonce += 1;
+        }
+        assert!(validate_headers(Network::Regtest, &chain, 11, tip.hash(), &[bad_pow]).is_err());
+
+        let bad_bits = mine(tip, tip.timestamp + 600, 0x2000ffff);
+        assert!(validate_headers(Network::Regtest, &chain, 11, tip.hash(), &[bad_bits]).is_err());
+    }
+
+    #[test]
+    fn rejects_bad_timestamps() {
+        let chain = regtest_chain(12);
+        let tip = &chain.0[11];
+
+        let too_old = mine(tip, chain.0[2].timestamp, REGTEST_BITS);
+        assert!(validate_headers(Network::Regtest, &chain, 11, tip.hash(), &[too_old]).is_err());
+
+        let too_new = mine(tip, secs_since(UNIX_EPOCH) + 3 * 60 * 60, REGTEST_BITS);
+        assert!(validate_headers(Network::Regtest, &chain, 11, tip.hash(), &[too_new]).is_err());
+    }
+
+    #[test]
+    fn rejects_checkpoint_mismatch() {
+        let chain = regtest_chain(2);
+        let tip = &chain.0[1];
+        let header = mine(tip, tip.timestamp + 600, REGTEST_BITS);
+
+        let result = validate_headers(Network::Bitcoin, &chain, 11110, tip.hash(), &[header]);
+        assert!(matches!(result, Err(Error::InvalidHeader(msg)) if msg.contains("checkpoint")));
+    }
+
+    #[test]
+    fn mainnet_retarget() {
+        // Difficulty adjustment at mainnet block 32256
+        let mut headers = vec![BlockHeader { bits: 0x1d00ffff, ..Default::default() }; DIFFCHANGE_INTERVAL as usize];
+        headers[0].timestamp = 1261130161;
+        headers[2015].timestamp = 1262152739;
+        let source = PendingHeaders { source: &Headers(vec![]), base_height: 30239, headers };
+
+        let next = BlockHeader { timestamp: 1262153464, ..Default::default() };
+        let bits = next_work_required(Network::Bitcoin, &source, 32256, &next).unwrap();
+        assert_eq!(bits, 0x1d00d86a);
+
+        let bits = next_work_required(Network::Bitcoin, &source, 32255, &next).unwrap();
+        assert_eq!(bits, 0x1d00ffff);
+    }
+
+    #[test]
+    fn testnet_min_difficulty() {
+        let pow_limit_bits = 0x1d00ffff;
+        let mut headers = vec![BlockHeader { timestamp: 1000, bits: 0x1c0fffff, ..Default::default() }; 5];
+        headers[4] = BlockHeader { timestamp: 1600, bits: pow_limit_bits, ..Default::default() };
+        let source = Headers(headers);
+
+        // More than 20 minutes after the previous block
+        let late = BlockHeader { timestamp: 1600 + 1201, ..Default::default() };
+        assert_eq!(next_work_required(Network::Testnet, &source, 5, &late).unwrap(), pow_limit_bits);
+
+        // Otherwise the last regular difficulty applies
+        let early = BlockHeader { timestamp: 1700, ..Default::default() };
+        assert_eq!(next_work_required(Network::Testnet, &source, 5, &early).unwrap(), 0x1c0fffff);
+    }
+}